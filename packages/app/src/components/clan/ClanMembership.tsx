@@ -58,6 +58,7 @@ const ClanMembership = ({
     }
     startLeavingMutation.mutate({
       network,
+      rootData,
       memberData,
     });
   };
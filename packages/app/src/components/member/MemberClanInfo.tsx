@@ -57,6 +57,7 @@ const MemberClanInfo = ({
     startLeavingMutation.mutate({
       network,
       clan: membership.clan,
+      rootData,
       memberData: memberData!,
     });
   };
@@ -71,6 +71,7 @@ const MemberManagement = ({
     startLeavingAllMutation.mutate({
       network,
       memberData: memberData!,
+      rootData,
     });
   };
 
@@ -16,8 +16,14 @@ const useStartLeavingAll = () => {
   return useMutation({
     mutationFn: async ({
       memberData,
+      rootData,
     }: {
       network: Cluster;
+      rootData: {
+        governanceProgram: PublicKey;
+        realm: PublicKey;
+        governingTokenMint: PublicKey;
+      };
       memberData: {
         root: PublicKey;
         owner: PublicKey;
@@ -39,6 +45,7 @@ const useStartLeavingAll = () => {
           clans.push(clan);
           tx.add(
             await sdk.member.startLeavingClanInstruction({
+              rootData,
               memberData,
               clan,
             })
@@ -16,9 +16,15 @@ const useStartLeavingClan = () => {
   return useMutation({
     mutationFn: async ({
       memberData,
+      rootData,
       clan,
     }: {
       network: Cluster;
+      rootData: {
+        governanceProgram: PublicKey;
+        realm: PublicKey;
+        governingTokenMint: PublicKey;
+      };
       memberData: {
         root: PublicKey;
         owner: PublicKey;
@@ -36,6 +42,7 @@ const useStartLeavingClan = () => {
       });
       tx.add(
         await sdk.member.startLeavingClanInstruction({
+          rootData,
           memberData,
           clan,
         })
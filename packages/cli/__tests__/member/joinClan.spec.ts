@@ -10,6 +10,7 @@ import {
 } from 'vote-aggregator-tests';
 import {context} from '../../src/context';
 import {cli} from '../../src/cli';
+import {BN} from '@coral-xyz/anchor';
 import {Keypair, PublicKey} from '@solana/web3.js';
 
 describe('join-clan command', () => {
@@ -92,7 +93,7 @@ describe('join-clan command', () => {
       );
 
       const clanTester = new ClanTester({...clan, root: rootTester});
-      const {provider} = await startTest({
+      const {provider, testContext} = await startTest({
         splGovernanceId: rootTester.splGovernanceId,
         accounts: [
           ...(await realmTester.accounts()),
@@ -115,6 +116,7 @@ describe('join-clan command', () => {
       });
       const {sdk} = context!;
 
+      const time = (await testContext.banksClient.getClock()).unixTimestamp;
       await expect(
         cli()
           .exitOverride((err: Error) => {
@@ -151,9 +153,18 @@ describe('join-clan command', () => {
             10000 -
               memberTester.membership.reduce((a, {shareBp: b}) => a + b, 0),
           exitableAt: null,
+          rewardWeight: memberVoterWeight.voterWeight,
+          rewardPerWeightPaid: new BN(0),
+          unclaimedRewards: new BN(0),
+          warmupEnd: null,
+          joinedAt: new BN(time.toString()),
+          loyaltyBonusBp: 0,
+          weightTransform: {linear: {}},
+          weightTransformEpoch: new BN(0),
         }),
         voterWeightRecord: memberVoterWeight.address,
         voterWeight: memberVoterWeight.voterWeight,
+        transformedVoterWeight: memberVoterWeight.voterWeight,
         voterWeightExpiry: memberVoterWeight.voterWeightExpiry || null,
       });
 
@@ -165,6 +176,9 @@ describe('join-clan command', () => {
           memberVoterWeight.voterWeight
         ),
         permanentMembers: clanTester.clan.permanentMembers.addn(1),
+        rewardWeight: clanTester.clan.rewardWeight.add(
+          memberVoterWeight.voterWeight
+        ),
       });
 
       await expect(
@@ -105,6 +105,11 @@ describe('set-voter-weight-record command', () => {
         sdk.member.fetchMember({memberAddress: memberTester.memberAddress[0]})
       ).resolves.toStrictEqual({
         ...memberTester.member,
+        membership: memberTester.member.membership.map(entry =>
+          entry.exitableAt
+            ? entry
+            : {...entry, rewardWeight: memberVoterWeightRecord.voterWeight}
+        ),
         voterWeightRecord: memberVoterWeightRecord.address,
         voterWeight: memberVoterWeightRecord.voterWeight,
         voterWeightExpiry: memberVoterWeightRecord.voterWeightExpiry || null,
@@ -127,6 +132,9 @@ describe('set-voter-weight-record command', () => {
           permanentVoterWeight: clanTester.clan.permanentVoterWeight
             .sub(memberTester.member.voterWeight)
             .add(memberVoterWeightRecord.voterWeight),
+          rewardWeight: clanTester.clan.rewardWeight.add(
+            memberVoterWeightRecord.voterWeight
+          ),
         });
 
         await expect(
@@ -10,7 +10,7 @@ import {
 import {context} from '../../src/context';
 import {cli} from '../../src/cli';
 import {BN} from '@coral-xyz/anchor';
-import {Keypair} from '@solana/web3.js';
+import {Keypair, PublicKey} from '@solana/web3.js';
 
 describe('start-leaving-clan command', () => {
   let stdout: jest.SpyInstance;
@@ -26,11 +26,50 @@ describe('start-leaving-clan command', () => {
   it.each(startLeavingClanTestData.filter(({error}) => !error))(
     'Works',
     async ({realm, root, member, clanIndex = 0}: StartLeavingClanTestData) => {
+      const tokenConfig =
+        (root.side === 'community'
+          ? realm.communityTokenConfig
+          : realm.councilTokenConfig) || {};
+      const voteAggregatorId =
+        root.voteAggregatorId ||
+        new PublicKey('VoTaGDreyne7jk59uwbgRRbaAzxvNbyNipaJMrRXhjT');
+      const [rootAddress] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('root', 'utf-8'),
+          realm.realmAddress.toBuffer(),
+          root.side === 'community'
+            ? realm.communityMint.toBuffer()
+            : realm.councilMint!.toBuffer(),
+        ],
+        voteAggregatorId
+      );
+      const lockAuthority = PublicKey.findProgramAddressSync(
+        [Buffer.from('lock-authority', 'utf8'), rootAddress.toBuffer()],
+        voteAggregatorId
+      )[0];
+      if (tokenConfig.lockAuthorities === undefined) {
+        tokenConfig.lockAuthorities = [lockAuthority];
+      }
+      if (root.side === 'community') {
+        realm.communityTokenConfig = tokenConfig;
+      } else {
+        realm.councilTokenConfig = tokenConfig;
+      }
+
       const realmTester = new RealmTester(realm);
       const rootTester = new RootTester({
         ...root,
         realm: realmTester,
       });
+      if (member.locks === undefined) {
+        member.locks = [
+          {
+            lockType: 0,
+            authority: rootTester.lockAuthority[0],
+            expiry: null,
+          },
+        ];
+      }
 
       const memberTester = new MemberTester({
         ...member,
@@ -103,6 +103,11 @@ describe('update-voter-weight command', () => {
         sdk.member.fetchMember({memberAddress: memberTester.memberAddress[0]})
       ).resolves.toStrictEqual({
         ...memberTester.member,
+        membership: memberTester.member.membership.map(entry =>
+          entry.exitableAt
+            ? entry
+            : {...entry, rewardWeight: memberVoterWeightRecord.voterWeight}
+        ),
         voterWeightRecord: memberVoterWeightRecord.address,
         voterWeight: memberVoterWeightRecord.voterWeight,
         voterWeightExpiry: memberVoterWeightRecord.voterWeightExpiry || null,
@@ -125,6 +130,9 @@ describe('update-voter-weight command', () => {
           permanentVoterWeight: clanTester.clan.permanentVoterWeight
             .sub(memberTester.member.voterWeight)
             .add(memberVoterWeightRecord.voterWeight),
+          rewardWeight: clanTester.clan.rewardWeight.add(
+            memberVoterWeightRecord.voterWeight
+          ),
         });
 
         await expect(
@@ -39,6 +39,7 @@ const startLeavingClan = async ({
     realmAddress,
     governingTokenMint,
   });
+  const rootData = await sdk.root.fetchRoot(rootAddress);
   const memberAddress = sdk.member.memberAddress({
     rootAddress,
     owner: ownerAddress,
@@ -61,6 +62,7 @@ const startLeavingClan = async ({
   await execute({
     instructions: [
       await sdk.member.startLeavingClanInstruction({
+        rootData,
         memberAddress,
         memberData,
         clan: clanAddress,
@@ -24,12 +24,14 @@ export const installConfigureRootCLI = (program: Command) => {
     .command('pause')
     .requiredOption('--root <pubkey>', 'Root')
     .option('--realm-authority <keypair>', 'Realm authority')
+    .option('--flags <number>', 'Bitmask of operations to pause')
     .action(pause);
 
   program
     .command('resume')
     .requiredOption('--root <pubkey>', 'Root')
     .option('--realm-authority <keypair>', 'Realm authority')
+    .option('--flags <number>', 'Bitmask of operations to resume')
     .action(resume);
 
   program
@@ -114,9 +116,11 @@ const setVoterWeightReset = async ({
 const pause = async ({
   root,
   realmAuthority,
+  flags,
 }: {
   root: string;
   realmAuthority?: string;
+  flags?: string;
 }) => {
   const {sdk, provider} = context!;
   const signers = [];
@@ -132,6 +136,7 @@ const pause = async ({
   await execute({
     instructions: [
       await sdk.root.pauseInstruction({
+        flags: flags === undefined ? undefined : parseInt(flags),
         root: rootAddress,
         realm: rootData.realm,
         realmAuthority: realmAuthorityAddress,
@@ -144,9 +149,11 @@ const pause = async ({
 const resume = async ({
   root,
   realmAuthority,
+  flags,
 }: {
   root: string;
   realmAuthority?: string;
+  flags?: string;
 }) => {
   const {sdk, provider} = context!;
   const signers = [];
@@ -162,6 +169,7 @@ const resume = async ({
   await execute({
     instructions: [
       await sdk.root.resumeInstruction({
+        flags: flags === undefined ? undefined : parseInt(flags),
         root: rootAddress,
         realm: rootData.realm,
         realmAuthority: realmAuthorityAddress,
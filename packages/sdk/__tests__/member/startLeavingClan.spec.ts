@@ -29,6 +29,7 @@ describe('start_leaving_clan instruction', () => {
       const clan = memberTester.membership[clanIndex].clan;
       expect(
         sdk.member.startLeavingClanInstruction({
+          rootData: rootTester.root,
           memberData: memberTester.member,
           clan: clan instanceof PublicKey ? clan : clan.clanAddress,
         })
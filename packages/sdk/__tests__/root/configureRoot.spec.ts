@@ -79,11 +79,12 @@ describe('Configure root', () => {
   */
   it.each(
     configureRootTestData.filter(
-      ({error, paused}) => !error && paused !== undefined
+      ({error, pauseFlags, resumeFlags}) =>
+        !error && (pauseFlags !== undefined || resumeFlags !== undefined)
     )
   )(
     'Runs pause/resume instruction',
-    async ({realm, root, paused}: ConfigureRootTestData) => {
+    async ({realm, root, pauseFlags, resumeFlags}: ConfigureRootTestData) => {
       const realmTester = new RealmTester(realm);
       if (!(realmTester.authority instanceof Keypair)) {
         throw new Error('Realm authority keypair is required');
@@ -94,13 +95,15 @@ describe('Configure root', () => {
       });
       const sdk = new VoteAggregatorSdk();
       expect(
-        paused
+        pauseFlags !== undefined
           ? sdk.root.pauseInstruction({
+              flags: pauseFlags,
               realm: realmTester.realmAddress,
               root: rootTester.rootAddress[0],
               realmAuthority: realmTester.authorityAddress!,
             })
           : sdk.root.resumeInstruction({
+              flags: resumeFlags,
               realm: realmTester.realmAddress,
               root: rootTester.rootAddress[0],
               realmAuthority: realmTester.authorityAddress!,
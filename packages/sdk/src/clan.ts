@@ -4,7 +4,7 @@ import {
   SystemProgram,
 } from '@solana/web3.js';
 import {VoteAggregatorSdk} from './sdk';
-import {IdlAccounts, ProgramAccount} from '@coral-xyz/anchor';
+import {IdlAccounts, ProgramAccount, utils} from '@coral-xyz/anchor';
 import {VoteAggregator} from './vote_aggregator';
 import {
   SYSTEM_PROGRAM_ID,
@@ -66,6 +66,85 @@ export class ClanSdk {
     );
   }
 
+  rewardVaultAddress(clanAddress: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from('reward-vault', 'utf-8'), clanAddress.toBuffer()],
+      this.sdk.programId
+    );
+  }
+
+  voteHistoryAddress(clanAddress: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from('vote-history', 'utf-8'), clanAddress.toBuffer()],
+      this.sdk.programId
+    );
+  }
+
+  weightCheckpointsAddress(clanAddress: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from('weight-checkpoints', 'utf-8'), clanAddress.toBuffer()],
+      this.sdk.programId
+    );
+  }
+
+  treasuryAddress(clanAddress: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from('treasury', 'utf-8'), clanAddress.toBuffer()],
+      this.sdk.programId
+    );
+  }
+
+  treasuryWithdrawalAddress({
+    clanAddress,
+    index,
+  }: {
+    clanAddress: PublicKey;
+    index: BN;
+  }): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('treasury-withdrawal', 'utf-8'),
+        clanAddress.toBuffer(),
+        index.toArrayLike(Buffer, 'le', 8),
+      ],
+      this.sdk.programId
+    );
+  }
+
+  recallProposalAddress({
+    clanAddress,
+    index,
+  }: {
+    clanAddress: PublicKey;
+    index: BN;
+  }): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('recall', 'utf-8'),
+        clanAddress.toBuffer(),
+        index.toArrayLike(Buffer, 'le', 8),
+      ],
+      this.sdk.programId
+    );
+  }
+
+  snapshotVwrAddress({
+    clanAddress,
+    proposalAddress,
+  }: {
+    clanAddress: PublicKey;
+    proposalAddress: PublicKey;
+  }): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('snapshot-weight', 'utf-8'),
+        clanAddress.toBuffer(),
+        proposalAddress.toBuffer(),
+      ],
+      this.sdk.programId
+    );
+  }
+
   fetchClan(clanAddress: PublicKey): Promise<ClanAccount> {
     return this.sdk.program.account.clan.fetch(clanAddress);
   }
@@ -248,16 +327,356 @@ export class ClanSdk {
     clanAddress,
     owner,
     newOwner,
+    siblingClan = null,
   }: {
     clanAddress: PublicKey;
     owner: PublicKey;
     newOwner: PublicKey;
+    siblingClan?: PublicKey | null;
   }) {
     return await this.sdk.program.methods
       .setClanOwner(newOwner)
       .accountsStrict({
         clan: clanAddress,
         owner,
+        siblingClan,
+      })
+      .instruction();
+  }
+
+  async linkSiblingClanInstruction({
+    rootAddress,
+    siblingRootAddress,
+    clanAddress,
+    siblingClanAddress,
+    owner,
+  }: {
+    rootAddress: PublicKey;
+    siblingRootAddress: PublicKey;
+    clanAddress: PublicKey;
+    siblingClanAddress: PublicKey;
+    owner: PublicKey;
+  }) {
+    return await this.sdk.program.methods
+      .linkSiblingClan()
+      .accountsStrict({
+        root: rootAddress,
+        siblingRoot: siblingRootAddress,
+        clan: clanAddress,
+        siblingClan: siblingClanAddress,
+        owner,
+      })
+      .instruction();
+  }
+
+  async unlinkSiblingClanInstruction({
+    rootAddress,
+    siblingRootAddress,
+    clanAddress,
+    siblingClanAddress,
+    owner,
+  }: {
+    rootAddress: PublicKey;
+    siblingRootAddress: PublicKey;
+    clanAddress: PublicKey;
+    siblingClanAddress: PublicKey;
+    owner: PublicKey;
+  }) {
+    return await this.sdk.program.methods
+      .unlinkSiblingClan()
+      .accountsStrict({
+        root: rootAddress,
+        siblingRoot: siblingRootAddress,
+        clan: clanAddress,
+        siblingClan: siblingClanAddress,
+        owner,
+      })
+      .instruction();
+  }
+
+  async createClanRewardVaultInstruction({
+    clanAddress,
+    clanAuthority,
+    rewardMint,
+    payer = clanAuthority,
+  }: {
+    clanAddress: PublicKey;
+    clanAuthority: PublicKey;
+    rewardMint: PublicKey;
+    payer?: PublicKey;
+  }) {
+    return await this.sdk.program.methods
+      .createClanRewardVault()
+      .accountsStrict({
+        clan: clanAddress,
+        clanAuthority,
+        voterAuthority: this.voterAuthority({clanAddress})[0],
+        rewardMint,
+        rewardVault: this.rewardVaultAddress(clanAddress)[0],
+        payer,
+        tokenProgram: utils.token.TOKEN_PROGRAM_ID,
+        systemProgram: SYSTEM_PROGRAM_ID,
+      })
+      .instruction();
+  }
+
+  async depositClanRewardsInstruction({
+    clanAddress,
+    source,
+    depositor,
+    amount,
+  }: {
+    clanAddress: PublicKey;
+    source: PublicKey;
+    depositor: PublicKey;
+    amount: BN;
+  }) {
+    return await this.sdk.program.methods
+      .depositClanRewards(amount)
+      .accountsStrict({
+        clan: clanAddress,
+        rewardVault: this.rewardVaultAddress(clanAddress)[0],
+        source,
+        depositor,
+        tokenProgram: utils.token.TOKEN_PROGRAM_ID,
+      })
+      .instruction();
+  }
+
+  async claimClanCommissionInstruction({
+    clanAddress,
+    owner,
+    destination,
+  }: {
+    clanAddress: PublicKey;
+    owner: PublicKey;
+    destination: PublicKey;
+  }) {
+    return await this.sdk.program.methods
+      .claimClanCommission()
+      .accountsStrict({
+        clan: clanAddress,
+        owner,
+        voterAuthority: this.voterAuthority({clanAddress})[0],
+        rewardVault: this.rewardVaultAddress(clanAddress)[0],
+        destination,
+        tokenProgram: utils.token.TOKEN_PROGRAM_ID,
+      })
+      .instruction();
+  }
+
+  async depositClanTreasuryInstruction({
+    clanAddress,
+    owner,
+    amount,
+  }: {
+    clanAddress: PublicKey;
+    owner: PublicKey;
+    amount: BN;
+  }) {
+    return await this.sdk.program.methods
+      .depositClanTreasury(amount)
+      .accountsStrict({
+        clan: clanAddress,
+        owner,
+        treasury: this.treasuryAddress(clanAddress)[0],
+        systemProgram: SYSTEM_PROGRAM_ID,
+      })
+      .instruction();
+  }
+
+  // The treasury token account is any account owned by the treasury
+  async depositClanTreasuryTokensInstruction({
+    clanAddress,
+    owner,
+    treasuryTokens,
+    source,
+    amount,
+  }: {
+    clanAddress: PublicKey;
+    owner: PublicKey;
+    treasuryTokens: PublicKey;
+    source: PublicKey;
+    amount: BN;
+  }) {
+    return await this.sdk.program.methods
+      .depositClanTreasuryTokens(amount)
+      .accountsStrict({
+        clan: clanAddress,
+        owner,
+        treasury: this.treasuryAddress(clanAddress)[0],
+        treasuryTokens,
+        source,
+        tokenProgram: utils.token.TOKEN_PROGRAM_ID,
+      })
+      .instruction();
+  }
+
+  async proposeTreasuryWithdrawalInstruction({
+    rootAddress,
+    clanAddress,
+    clanData,
+    owner = clanData.owner,
+    mint,
+    amount,
+    destination,
+    payer = owner,
+  }: {
+    rootAddress: PublicKey;
+    clanAddress: PublicKey;
+    clanData: {
+      owner: PublicKey;
+      treasuryWithdrawals: BN;
+    };
+    owner?: PublicKey;
+    mint: PublicKey | null;
+    amount: BN;
+    destination: PublicKey;
+    payer?: PublicKey;
+  }) {
+    return await this.sdk.program.methods
+      .proposeTreasuryWithdrawal(mint, amount, destination)
+      .accountsStrict({
+        root: rootAddress,
+        clan: clanAddress,
+        owner,
+        withdrawal: this.treasuryWithdrawalAddress({
+          clanAddress,
+          index: clanData.treasuryWithdrawals,
+        })[0],
+        payer,
+        systemProgram: SYSTEM_PROGRAM_ID,
+      })
+      .instruction();
+  }
+
+  async withdrawClanTreasuryInstruction({
+    clanAddress,
+    owner,
+    withdrawal = null,
+    destination,
+    amount,
+  }: {
+    clanAddress: PublicKey;
+    owner: PublicKey;
+    withdrawal?: PublicKey | null;
+    destination: PublicKey;
+    amount: BN;
+  }) {
+    return await this.sdk.program.methods
+      .withdrawClanTreasury(amount)
+      .accountsStrict({
+        clan: clanAddress,
+        owner,
+        treasury: this.treasuryAddress(clanAddress)[0],
+        withdrawal,
+        destination,
+        systemProgram: SYSTEM_PROGRAM_ID,
+      })
+      .instruction();
+  }
+
+  async withdrawClanTreasuryTokensInstruction({
+    clanAddress,
+    owner,
+    treasuryTokens,
+    withdrawal = null,
+    destination,
+    amount,
+  }: {
+    clanAddress: PublicKey;
+    owner: PublicKey;
+    treasuryTokens: PublicKey;
+    withdrawal?: PublicKey | null;
+    destination: PublicKey;
+    amount: BN;
+  }) {
+    return await this.sdk.program.methods
+      .withdrawClanTreasuryTokens(amount)
+      .accountsStrict({
+        clan: clanAddress,
+        owner,
+        treasury: this.treasuryAddress(clanAddress)[0],
+        treasuryTokens,
+        withdrawal,
+        destination,
+        tokenProgram: utils.token.TOKEN_PROGRAM_ID,
+      })
+      .instruction();
+  }
+
+  async createClanVoteHistoryInstruction({
+    clanAddress,
+    clanAuthority,
+    payer = clanAuthority,
+  }: {
+    clanAddress: PublicKey;
+    clanAuthority: PublicKey;
+    payer?: PublicKey;
+  }) {
+    return await this.sdk.program.methods
+      .createClanVoteHistory()
+      .accountsStrict({
+        clan: clanAddress,
+        clanAuthority,
+        voteHistory: this.voteHistoryAddress(clanAddress)[0],
+        payer,
+        systemProgram: SYSTEM_PROGRAM_ID,
+      })
+      .instruction();
+  }
+
+  async createClanWeightCheckpointsInstruction({
+    clanAddress,
+    clanAuthority,
+    payer = clanAuthority,
+  }: {
+    clanAddress: PublicKey;
+    clanAuthority: PublicKey;
+    payer?: PublicKey;
+  }) {
+    return await this.sdk.program.methods
+      .createClanWeightCheckpoints()
+      .accountsStrict({
+        clan: clanAddress,
+        clanAuthority,
+        clanVwr: this.voterWeightAddress(clanAddress)[0],
+        weightCheckpoints: this.weightCheckpointsAddress(clanAddress)[0],
+        payer,
+        systemProgram: SYSTEM_PROGRAM_ID,
+      })
+      .instruction();
+  }
+
+  async createClanSnapshotVwrInstruction({
+    rootAddress,
+    rootData,
+    clanAddress,
+    governanceAddress,
+    proposalAddress,
+    payer,
+  }: {
+    rootAddress: PublicKey;
+    rootData: {
+      governanceProgram: PublicKey;
+    };
+    clanAddress: PublicKey;
+    governanceAddress: PublicKey;
+    proposalAddress: PublicKey;
+    payer: PublicKey;
+  }) {
+    return await this.sdk.program.methods
+      .createClanSnapshotVwr()
+      .accountsStrict({
+        root: rootAddress,
+        clan: clanAddress,
+        weightCheckpoints: this.weightCheckpointsAddress(clanAddress)[0],
+        governance: governanceAddress,
+        proposal: proposalAddress,
+        snapshotVwr: this.snapshotVwrAddress({clanAddress, proposalAddress})[0],
+        payer,
+        systemProgram: SYSTEM_PROGRAM_ID,
+        governanceProgram: rootData.governanceProgram,
       })
       .instruction();
   }
@@ -288,16 +707,20 @@ export class ClanSdk {
     clanAddress,
     clanAuthority,
     delegate,
+    siblingClan = null,
   }: {
     clanAddress: PublicKey;
     clanAuthority: PublicKey;
     delegate: PublicKey;
+    siblingClan?: PublicKey | null;
   }) {
     return await this.sdk.program.methods
       .setClanDelegate(delegate)
       .accountsStrict({
         clan: clanAddress,
         clanAuthority,
+        siblingClan,
+        root: null,
       })
       .instruction();
   }
@@ -306,16 +729,20 @@ export class ClanSdk {
     clanAddress,
     clanAuthority,
     name,
+    siblingClan = null,
   }: {
     clanAddress: PublicKey;
     clanAuthority: PublicKey;
     name: string;
+    siblingClan?: PublicKey | null;
   }) {
     return await this.sdk.program.methods
       .setClanName(name)
       .accountsStrict({
         clan: clanAddress,
         clanAuthority,
+        siblingClan,
+        root: null,
       })
       .instruction();
   }
@@ -324,16 +751,20 @@ export class ClanSdk {
     clanAddress,
     clanAuthority,
     description,
+    siblingClan = null,
   }: {
     clanAddress: PublicKey;
     clanAuthority: PublicKey;
     description: string;
+    siblingClan?: PublicKey | null;
   }) {
     return await this.sdk.program.methods
       .setClanDescription(description)
       .accountsStrict({
         clan: clanAddress,
         clanAuthority,
+        siblingClan,
+        root: null,
       })
       .instruction();
   }
@@ -342,16 +773,20 @@ export class ClanSdk {
     clanAddress,
     clanAuthority,
     minVotingWeightToJoin,
+    siblingClan = null,
   }: {
     clanAddress: PublicKey;
     clanAuthority: PublicKey;
     minVotingWeightToJoin: BN;
+    siblingClan?: PublicKey | null;
   }) {
     return await this.sdk.program.methods
       .setClanMinVotingWeightToJoin(minVotingWeightToJoin)
       .accountsStrict({
         clan: clanAddress,
         clanAuthority,
+        siblingClan,
+        root: null,
       })
       .instruction();
   }
@@ -360,16 +795,106 @@ export class ClanSdk {
     clanAddress,
     clanAuthority,
     acceptTemporaryMembers,
+    siblingClan = null,
   }: {
     clanAddress: PublicKey;
     clanAuthority: PublicKey;
     acceptTemporaryMembers: boolean;
+    siblingClan?: PublicKey | null;
   }) {
     return await this.sdk.program.methods
       .setClanAcceptTemporaryMembers(acceptTemporaryMembers)
       .accountsStrict({
         clan: clanAddress,
         clanAuthority,
+        siblingClan,
+        root: null,
+      })
+      .instruction();
+  }
+
+  async setClanCommissionInstruction({
+    rootAddress,
+    clanAddress,
+    clanAuthority,
+    commissionBp,
+  }: {
+    rootAddress: PublicKey;
+    clanAddress: PublicKey;
+    clanAuthority: PublicKey;
+    commissionBp: number;
+  }) {
+    return await this.sdk.program.methods
+      .setClanCommission(commissionBp)
+      .accountsStrict({
+        clan: clanAddress,
+        clanAuthority,
+        siblingClan: null,
+        root: rootAddress,
+      })
+      .instruction();
+  }
+
+  async setClanLoyaltyCurveInstruction({
+    rootAddress,
+    clanAddress,
+    clanAuthority,
+    loyaltyCurve,
+  }: {
+    rootAddress: PublicKey;
+    clanAddress: PublicKey;
+    clanAuthority: PublicKey;
+    loyaltyCurve: {maxBonusBp: number; period: BN} | null;
+  }) {
+    return await this.sdk.program.methods
+      .setClanLoyaltyCurve(loyaltyCurve)
+      .accountsStrict({
+        clan: clanAddress,
+        clanAuthority,
+        siblingClan: null,
+        root: rootAddress,
+      })
+      .instruction();
+  }
+
+  async setClanSnapshotVotingInstruction({
+    clanAddress,
+    clanAuthority,
+    snapshotVoting,
+  }: {
+    clanAddress: PublicKey;
+    clanAuthority: PublicKey;
+    snapshotVoting: boolean;
+  }) {
+    return await this.sdk.program.methods
+      .setClanSnapshotVoting(snapshotVoting)
+      .accountsStrict({
+        clan: clanAddress,
+        clanAuthority,
+        siblingClan: null,
+        root: null,
+      })
+      .instruction();
+  }
+
+  async setClanTreasuryPolicyInstruction({
+    rootAddress,
+    clanAddress,
+    owner,
+    treasuryPolicy,
+  }: {
+    rootAddress: PublicKey;
+    clanAddress: PublicKey;
+    owner: PublicKey;
+    treasuryPolicy: {spendingLimit: BN; approvalThresholdBp: number} | null;
+  }) {
+    return await this.sdk.program.methods
+      .setClanTreasuryPolicy(treasuryPolicy)
+      .accountsStrict({
+        clan: clanAddress,
+        clanAuthority: owner,
+        siblingClan: null,
+        root: rootAddress,
       })
       .instruction();
   }
@@ -411,6 +936,43 @@ export class ClanSdk {
       .instruction();
   }
 
+  async reviseClanVwrInstruction({
+    rootAddress,
+    clanAddress,
+  }: {
+    rootAddress: PublicKey;
+    clanAddress: PublicKey;
+  }) {
+    return await this.sdk.program.methods
+      .reviseClanVwr()
+      .accountsStrict({
+        root: rootAddress,
+        clan: clanAddress,
+        clanVwr: this.sdk.clan.voterWeightAddress(clanAddress)[0],
+      })
+      .instruction();
+  }
+
+  // Permissionless once the recall is supported by the members
+  async executeRecallProposalInstruction({
+    clanAddress,
+    recall,
+    siblingClan = null,
+  }: {
+    clanAddress: PublicKey;
+    recall: PublicKey;
+    siblingClan?: PublicKey | null;
+  }) {
+    return await this.sdk.program.methods
+      .executeRecallProposal()
+      .accountsStrict({
+        recall,
+        clan: clanAddress,
+        siblingClan,
+      })
+      .instruction();
+  }
+
   async forcedCancelProposalInstruction({
     rootAddress,
     rootData,
@@ -464,6 +1026,7 @@ export class ClanSdk {
     proposal,
     proposalOwnerRecord,
     maxVwr = null,
+    voteHistory = null,
     clanAddress,
     payer,
   }: {
@@ -477,6 +1040,7 @@ export class ClanSdk {
     proposal: PublicKey;
     proposalOwnerRecord: PublicKey;
     maxVwr?: PublicKey | null;
+    voteHistory?: PublicKey | null;
     clanAddress: PublicKey;
     payer: PublicKey;
   }) {
@@ -517,6 +1081,73 @@ export class ClanSdk {
             splGovernanceId: rootData.governanceProgram,
           })[0]
         ),
+        voteHistory,
+        payer,
+      })
+      .instruction();
+  }
+
+  async castVetoVoteInstruction({
+    rootAddress,
+    rootData,
+    governance,
+    proposal,
+    proposalOwnerRecord,
+    maxVwr = null,
+    voteHistory = null,
+    clanAddress,
+    clanAuthority,
+    payer,
+  }: {
+    rootAddress: PublicKey;
+    rootData: {
+      governanceProgram: PublicKey;
+      realm: PublicKey;
+      governingTokenMint: PublicKey;
+    };
+    governance: PublicKey;
+    proposal: PublicKey;
+    proposalOwnerRecord: PublicKey;
+    maxVwr?: PublicKey | null;
+    voteHistory?: PublicKey | null;
+    clanAddress: PublicKey;
+    clanAuthority: PublicKey;
+    payer: PublicKey;
+  }) {
+    const [voterAuthority] = this.voterAuthority({clanAddress});
+    const [clanTor] = this.tokenOwnerRecordAddress({
+      realmAddress: rootData.realm,
+      governingTokenMint: rootData.governingTokenMint,
+      clanAddress,
+      splGovernanceId: rootData.governanceProgram,
+    });
+    return await this.sdk.program.methods
+      .castVetoVote()
+      .accountsStrict({
+        root: rootAddress,
+        clan: clanAddress,
+        clanAuthority,
+        governanceProgram: rootData.governanceProgram,
+        voterAuthority,
+        clanTor,
+        realm: rootData.realm,
+        realmConfig: await getRealmConfigAddress(
+          rootData.governanceProgram,
+          rootData.realm
+        ),
+        governingTokenMint: rootData.governingTokenMint,
+        systemProgram: SYSTEM_PROGRAM_ID,
+        governance,
+        proposal,
+        clanVwr: this.sdk.clan.voterWeightAddress(clanAddress)[0],
+        maxVwr,
+        proposalOwnerRecord,
+        voteRecord: await getVoteRecordAddress(
+          rootData.governanceProgram,
+          proposal,
+          clanTor
+        ),
+        voteHistory,
         payer,
       })
       .instruction();
@@ -5,7 +5,7 @@ import {
   TransactionInstruction,
 } from '@solana/web3.js';
 import {VoteAggregatorSdk} from './sdk';
-import {IdlAccounts, IdlTypes} from '@coral-xyz/anchor';
+import {IdlAccounts, IdlTypes, utils} from '@coral-xyz/anchor';
 import {VoteAggregator} from './vote_aggregator';
 import {SYSTEM_PROGRAM_ID, getRealmConfigAddress} from '@solana/spl-governance';
 import {VoterWeightAccount} from './clan';
@@ -182,6 +182,43 @@ export class MemberSdk {
     return voterWeightRecords[bestIndex];
   }
 
+  // The clan accounts followed by the checkpoints if the clan has them
+  clanChunkAccounts(
+    clan: PublicKey,
+    checkpointedClans: PublicKey[] = []
+  ): AccountMeta[] {
+    const accounts: AccountMeta[] = [
+      {
+        pubkey: clan,
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: this.sdk.clan.voterWeightAddress(clan)[0],
+        isSigner: false,
+        isWritable: true,
+      },
+    ];
+    const weightCheckpoints = this.weightCheckpoints(clan, checkpointedClans);
+    if (weightCheckpoints) {
+      accounts.push({
+        pubkey: weightCheckpoints,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
+    return accounts;
+  }
+
+  weightCheckpoints(
+    clan: PublicKey,
+    checkpointedClans: PublicKey[] = []
+  ): PublicKey | null {
+    return checkpointedClans.some(c => c.equals(clan))
+      ? this.sdk.clan.weightCheckpointsAddress(clan)[0]
+      : null;
+  }
+
   async createMemberInstruction({
     rootAddress,
     rootData,
@@ -250,6 +287,7 @@ export class MemberSdk {
     shareBp = 10000,
     payer,
     memberAuthority = memberData.owner,
+    checkpointedClans = [],
   }: {
     rootData: {
       governanceProgram: PublicKey;
@@ -268,6 +306,7 @@ export class MemberSdk {
     shareBp?: number;
     payer: PublicKey;
     memberAuthority?: PublicKey;
+    checkpointedClans?: PublicKey[];
   }) {
     if (!memberAddress) {
       [memberAddress] = this.memberAddress({
@@ -297,16 +336,7 @@ export class MemberSdk {
     const rest: AccountMeta[] = [];
     for (const {clan, exitableAt} of memberData.membership) {
       if (!exitableAt) {
-        rest.push({
-          pubkey: clan,
-          isSigner: false,
-          isWritable: true,
-        });
-        rest.push({
-          pubkey: this.sdk.clan.voterWeightAddress(clan)[0],
-          isSigner: false,
-          isWritable: true,
-        });
+        rest.push(...this.clanChunkAccounts(clan, checkpointedClans));
       }
     }
 
@@ -318,6 +348,10 @@ export class MemberSdk {
         clan: clanAddress,
         memberAuthority,
         clanVwr: this.sdk.clan.voterWeightAddress(clanAddress)[0],
+        weightCheckpoints: this.weightCheckpoints(
+          clanAddress,
+          checkpointedClans
+        ),
         memberTor,
         memberVwr,
         maxVwr: this.sdk.root.maxVoterWieghtAddress({
@@ -338,19 +372,30 @@ export class MemberSdk {
   }
 
   async startLeavingClanInstruction({
+    rootData,
     memberData,
     memberAddress,
     memberAuthority = memberData.owner,
     clan,
+    payer = memberAuthority,
+    checkpointedClans = [],
   }: {
+    rootData: {
+      governanceProgram: PublicKey;
+      realm: PublicKey;
+      governingTokenMint: PublicKey;
+    };
     memberData: {
       root: PublicKey;
       owner: PublicKey;
+      tokenOwnerRecord?: PublicKey;
       // delegate: PublicKey;
     };
     memberAddress?: PublicKey;
     memberAuthority?: PublicKey;
     clan: PublicKey;
+    payer?: PublicKey;
+    checkpointedClans?: PublicKey[];
   }) {
     if (!memberAddress) {
       [memberAddress] = this.memberAddress({
@@ -358,6 +403,21 @@ export class MemberSdk {
         owner: memberData.owner,
       });
     }
+
+    let memberTor = memberData.tokenOwnerRecord;
+    if (!memberTor) {
+      [memberTor] = this.tokenOwnerRecordAddress({
+        realmAddress: rootData.realm,
+        governingTokenMint: rootData.governingTokenMint,
+        owner: memberData.owner,
+        splGovernanceId: rootData.governanceProgram,
+      });
+    }
+
+    const [lockAuthority] = this.sdk.root.lockAuthority({
+      rootAddress: memberData.root,
+    });
+
     const [clanVwr] = this.sdk.clan.voterWeightAddress(clan);
     return await this.sdk.program.methods
       .startLeavingClan()
@@ -367,6 +427,283 @@ export class MemberSdk {
         clan,
         memberAuthority,
         clanVwr,
+        weightCheckpoints: this.weightCheckpoints(clan, checkpointedClans),
+        memberTor,
+        lockAuthority,
+        realm: rootData.realm,
+        realmConfig: await getRealmConfigAddress(
+          rootData.governanceProgram,
+          rootData.realm
+        ),
+        payer,
+        governanceProgram: rootData.governanceProgram,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction();
+  }
+
+  async refreshMemberLockInstruction({
+    rootData,
+    memberData,
+    memberAddress,
+    payer,
+  }: {
+    rootData: {
+      governanceProgram: PublicKey;
+      realm: PublicKey;
+      governingTokenMint: PublicKey;
+    };
+    memberData: {
+      root: PublicKey;
+      owner: PublicKey;
+      tokenOwnerRecord?: PublicKey;
+    };
+    memberAddress?: PublicKey;
+    payer: PublicKey;
+  }) {
+    if (!memberAddress) {
+      [memberAddress] = this.memberAddress({
+        rootAddress: memberData.root,
+        owner: memberData.owner,
+      });
+    }
+
+    let memberTor = memberData.tokenOwnerRecord;
+    if (!memberTor) {
+      [memberTor] = this.tokenOwnerRecordAddress({
+        realmAddress: rootData.realm,
+        governingTokenMint: rootData.governingTokenMint,
+        owner: memberData.owner,
+        splGovernanceId: rootData.governanceProgram,
+      });
+    }
+
+    const [lockAuthority] = this.sdk.root.lockAuthority({
+      rootAddress: memberData.root,
+    });
+
+    return await this.sdk.program.methods
+      .refreshMemberLock()
+      .accountsStrict({
+        root: memberData.root,
+        member: memberAddress,
+        memberTor,
+        lockAuthority,
+        realm: rootData.realm,
+        realmConfig: await getRealmConfigAddress(
+          rootData.governanceProgram,
+          rootData.realm
+        ),
+        payer,
+        governanceProgram: rootData.governanceProgram,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction();
+  }
+
+  async evictStaleMemberInstruction({
+    memberData,
+    memberAddress,
+    clan,
+    checkpointedClans = [],
+  }: {
+    memberData: {
+      root: PublicKey;
+      owner: PublicKey;
+    };
+    memberAddress?: PublicKey;
+    clan: PublicKey;
+    checkpointedClans?: PublicKey[];
+  }) {
+    if (!memberAddress) {
+      [memberAddress] = this.memberAddress({
+        rootAddress: memberData.root,
+        owner: memberData.owner,
+      });
+    }
+    const [clanVwr] = this.sdk.clan.voterWeightAddress(clan);
+    return await this.sdk.program.methods
+      .evictStaleMember()
+      .accountsStrict({
+        member: memberAddress,
+        root: memberData.root,
+        clan,
+        clanVwr,
+        weightCheckpoints: this.weightCheckpoints(clan, checkpointedClans),
+      })
+      .instruction();
+  }
+
+  async claimClanRewardsInstruction({
+    memberData,
+    memberAddress,
+    memberAuthority = memberData.owner,
+    clan,
+    destination,
+  }: {
+    memberData: {
+      root: PublicKey;
+      owner: PublicKey;
+    };
+    memberAddress?: PublicKey;
+    memberAuthority?: PublicKey;
+    clan: PublicKey;
+    destination: PublicKey;
+  }) {
+    if (!memberAddress) {
+      [memberAddress] = this.memberAddress({
+        rootAddress: memberData.root,
+        owner: memberData.owner,
+      });
+    }
+
+    return await this.sdk.program.methods
+      .claimClanRewards()
+      .accountsStrict({
+        member: memberAddress,
+        memberAuthority,
+        clan,
+        voterAuthority: this.sdk.clan.voterAuthority({clanAddress: clan})[0],
+        rewardVault: this.sdk.clan.rewardVaultAddress(clan)[0],
+        destination,
+        tokenProgram: utils.token.TOKEN_PROGRAM_ID,
+      })
+      .instruction();
+  }
+
+  async approveTreasuryWithdrawalInstruction({
+    memberData,
+    memberAddress,
+    memberAuthority = memberData.owner,
+    withdrawal,
+    payer = memberAuthority,
+  }: {
+    memberData: {
+      root: PublicKey;
+      owner: PublicKey;
+    };
+    memberAddress?: PublicKey;
+    memberAuthority?: PublicKey;
+    withdrawal: PublicKey;
+    payer?: PublicKey;
+  }) {
+    if (!memberAddress) {
+      [memberAddress] = this.memberAddress({
+        rootAddress: memberData.root,
+        owner: memberData.owner,
+      });
+    }
+    const [approval] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('withdrawal-approval', 'utf-8'),
+        withdrawal.toBuffer(),
+        memberAddress.toBuffer(),
+      ],
+      this.sdk.programId
+    );
+
+    return await this.sdk.program.methods
+      .approveTreasuryWithdrawal()
+      .accountsStrict({
+        member: memberAddress,
+        memberAuthority,
+        withdrawal,
+        approval,
+        payer,
+        systemProgram: SYSTEM_PROGRAM_ID,
+      })
+      .instruction();
+  }
+
+  async openRecallProposalInstruction({
+    memberData,
+    memberAddress,
+    memberAuthority = memberData.owner,
+    clanAddress,
+    clanData,
+    newOwner,
+    newDelegate,
+    payer = memberAuthority,
+  }: {
+    memberData: {
+      root: PublicKey;
+      owner: PublicKey;
+    };
+    memberAddress?: PublicKey;
+    memberAuthority?: PublicKey;
+    clanAddress: PublicKey;
+    clanData: {
+      recallProposals: BN;
+    };
+    newOwner: PublicKey | null;
+    newDelegate: PublicKey | null;
+    payer?: PublicKey;
+  }) {
+    if (!memberAddress) {
+      [memberAddress] = this.memberAddress({
+        rootAddress: memberData.root,
+        owner: memberData.owner,
+      });
+    }
+
+    return await this.sdk.program.methods
+      .openRecallProposal(newOwner, newDelegate)
+      .accountsStrict({
+        member: memberAddress,
+        memberAuthority,
+        root: memberData.root,
+        clan: clanAddress,
+        clanVwr: this.sdk.clan.voterWeightAddress(clanAddress)[0],
+        recall: this.sdk.clan.recallProposalAddress({
+          clanAddress,
+          index: clanData.recallProposals,
+        })[0],
+        payer,
+        systemProgram: SYSTEM_PROGRAM_ID,
+      })
+      .instruction();
+  }
+
+  async signRecallProposalInstruction({
+    memberData,
+    memberAddress,
+    memberAuthority = memberData.owner,
+    recall,
+    payer = memberAuthority,
+  }: {
+    memberData: {
+      root: PublicKey;
+      owner: PublicKey;
+    };
+    memberAddress?: PublicKey;
+    memberAuthority?: PublicKey;
+    recall: PublicKey;
+    payer?: PublicKey;
+  }) {
+    if (!memberAddress) {
+      [memberAddress] = this.memberAddress({
+        rootAddress: memberData.root,
+        owner: memberData.owner,
+      });
+    }
+    const [signature] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('recall-signature', 'utf-8'),
+        recall.toBuffer(),
+        memberAddress.toBuffer(),
+      ],
+      this.sdk.programId
+    );
+
+    return await this.sdk.program.methods
+      .signRecallProposal()
+      .accountsStrict({
+        member: memberAddress,
+        memberAuthority,
+        recall,
+        signature,
+        payer,
+        systemProgram: SYSTEM_PROGRAM_ID,
       })
       .instruction();
   }
@@ -438,9 +775,158 @@ export class MemberSdk {
       .instruction();
   }
 
+  async forceLeaveClanInstruction({
+    rootData,
+    memberData,
+    memberAddress,
+    clan,
+    realmAuthority,
+    payer = realmAuthority,
+    immediate = false,
+    releaseLock = false,
+    reason = 0,
+    checkpointedClans = [],
+  }: {
+    rootData: {
+      governanceProgram: PublicKey;
+      realm: PublicKey;
+      governingTokenMint: PublicKey;
+    };
+    memberData: {
+      root: PublicKey;
+      owner: PublicKey;
+      tokenOwnerRecord?: PublicKey;
+    };
+    memberAddress?: PublicKey;
+    clan: PublicKey;
+    realmAuthority: PublicKey;
+    payer?: PublicKey;
+    immediate?: boolean;
+    releaseLock?: boolean;
+    reason?: number;
+    checkpointedClans?: PublicKey[];
+  }) {
+    if (!memberAddress) {
+      [memberAddress] = this.memberAddress({
+        rootAddress: memberData.root,
+        owner: memberData.owner,
+      });
+    }
+
+    let memberTor = memberData.tokenOwnerRecord;
+    if (!memberTor) {
+      [memberTor] = this.tokenOwnerRecordAddress({
+        realmAddress: rootData.realm,
+        governingTokenMint: rootData.governingTokenMint,
+        owner: memberData.owner,
+        splGovernanceId: rootData.governanceProgram,
+      });
+    }
+
+    const [lockAuthority] = this.sdk.root.lockAuthority({
+      rootAddress: memberData.root,
+    });
+
+    return await this.sdk.program.methods
+      .forceLeaveClan(immediate, releaseLock, reason)
+      .accountsStrict({
+        configureRoot: {
+          root: memberData.root,
+          realm: rootData.realm,
+          realmAuthority,
+        },
+        member: memberAddress,
+        clan,
+        clanVwr: this.sdk.clan.voterWeightAddress(clan)[0],
+        weightCheckpoints: this.weightCheckpoints(clan, checkpointedClans),
+        memberTor,
+        lockAuthority,
+        realmConfig: await getRealmConfigAddress(
+          rootData.governanceProgram,
+          rootData.realm
+        ),
+        payer,
+        systemProgram: SystemProgram.programId,
+        governanceProgram: rootData.governanceProgram,
+      })
+      .instruction();
+  }
+
+  async windDownExitInstruction({
+    rootData,
+    memberData,
+    memberAddress,
+    memberAuthority = memberData.owner,
+    checkpointedClans = [],
+  }: {
+    rootData: {
+      governanceProgram: PublicKey;
+      realm: PublicKey;
+      governingTokenMint: PublicKey;
+    };
+    memberData: {
+      root: PublicKey;
+      owner: PublicKey;
+      tokenOwnerRecord?: PublicKey;
+      membership: MembershipEntry[];
+    };
+    memberAddress?: PublicKey;
+    memberAuthority?: PublicKey;
+    checkpointedClans?: PublicKey[];
+  }) {
+    if (!memberAddress) {
+      [memberAddress] = this.memberAddress({
+        rootAddress: memberData.root,
+        owner: memberData.owner,
+      });
+    }
+
+    let memberTor = memberData.tokenOwnerRecord;
+    if (!memberTor) {
+      [memberTor] = this.tokenOwnerRecordAddress({
+        realmAddress: rootData.realm,
+        governingTokenMint: rootData.governingTokenMint,
+        owner: memberData.owner,
+        splGovernanceId: rootData.governanceProgram,
+      });
+    }
+
+    const [lockAuthority] = this.sdk.root.lockAuthority({
+      rootAddress: memberData.root,
+    });
+
+    // Including the clans the member is leaving
+    const rest: AccountMeta[] = [];
+    for (const {clan} of memberData.membership) {
+      rest.push(...this.clanChunkAccounts(clan, checkpointedClans));
+    }
+
+    return await this.sdk.program.methods
+      .windDownExit()
+      .accountsStrict({
+        member: memberAddress,
+        memberAuthority,
+        memberTor,
+        root: memberData.root,
+        realm: rootData.realm,
+        realmConfig: await getRealmConfigAddress(
+          rootData.governanceProgram,
+          rootData.realm
+        ),
+        lockAuthority,
+        maxVwr: this.sdk.root.maxVoterWieghtAddress({
+          rootAddress: memberData.root,
+        })[0],
+        governanceProgram: rootData.governanceProgram,
+      })
+      .remainingAccounts(rest)
+      .instruction();
+  }
+
   async updateVoterWeightInstruction({
     memberData,
     memberAddress,
+    checkpointedClans = [],
   }: {
     memberData: {
       root: PublicKey;
@@ -449,6 +935,7 @@ export class MemberSdk {
       membership: MembershipEntry[];
     };
     memberAddress?: PublicKey;
+    checkpointedClans?: PublicKey[];
   }) {
     if (!memberAddress) {
       [memberAddress] = this.memberAddress({
@@ -460,16 +947,7 @@ export class MemberSdk {
     const rest: AccountMeta[] = [];
     for (const {clan, exitableAt} of memberData.membership) {
       if (!exitableAt) {
-        rest.push({
-          pubkey: clan,
-          isSigner: false,
-          isWritable: true,
-        });
-        rest.push({
-          pubkey: this.sdk.clan.voterWeightAddress(clan)[0],
-          isSigner: false,
-          isWritable: true,
-        });
+        rest.push(...this.clanChunkAccounts(clan, checkpointedClans));
       }
     }
 
@@ -492,6 +970,7 @@ export class MemberSdk {
     memberAddress,
     memberAuthority = memberData.owner,
     memberVwr,
+    checkpointedClans = [],
   }: {
     memberData: {
       root: PublicKey;
@@ -501,6 +980,7 @@ export class MemberSdk {
     memberAddress?: PublicKey;
     memberAuthority?: PublicKey;
     memberVwr: PublicKey;
+    checkpointedClans?: PublicKey[];
   }) {
     if (!memberAddress) {
       [memberAddress] = this.memberAddress({
@@ -512,16 +992,7 @@ export class MemberSdk {
     const rest: AccountMeta[] = [];
     for (const {clan, exitableAt} of memberData.membership) {
       if (!exitableAt) {
-        rest.push({
-          pubkey: clan,
-          isSigner: false,
-          isWritable: true,
-        });
-        rest.push({
-          pubkey: this.sdk.clan.voterWeightAddress(clan)[0],
-          isSigner: false,
-          isWritable: true,
-        });
+        rest.push(...this.clanChunkAccounts(clan, checkpointedClans));
       }
     }
 
@@ -539,4 +1010,56 @@ export class MemberSdk {
       .remainingAccounts(rest)
       .instruction();
   }
+
+  async setVoterWeightDecayInstruction({
+    memberData,
+    memberAddress,
+    memberAuthority = memberData.owner,
+    memberVwr,
+    rate,
+    endTime,
+    checkpointedClans = [],
+  }: {
+    memberData: {
+      root: PublicKey;
+      owner: PublicKey;
+      membership: MembershipEntry[];
+    };
+    memberAddress?: PublicKey;
+    memberAuthority?: PublicKey;
+    memberVwr: PublicKey;
+    rate: BN;
+    endTime: BN;
+    checkpointedClans?: PublicKey[];
+  }) {
+    if (!memberAddress) {
+      [memberAddress] = this.memberAddress({
+        rootAddress: memberData.root,
+        owner: memberData.owner,
+      });
+    }
+
+    const rest: AccountMeta[] = [];
+    for (const {clan, exitableAt} of memberData.membership) {
+      if (!exitableAt) {
+        rest.push(...this.clanChunkAccounts(clan, checkpointedClans));
+      }
+    }
+
+    return await this.sdk.program.methods
+      .setVoterWeightDecay(rate, endTime)
+      .accountsStrict({
+        setVoterWeightRecord: {
+          root: memberData.root,
+          member: memberAddress,
+          memberAuthority,
+          memberVwr,
+          maxVwr: this.sdk.root.maxVoterWieghtAddress({
+            rootAddress: memberData.root,
+          })[0],
+        },
+      })
+      .remainingAccounts(rest)
+      .instruction();
+  }
 }
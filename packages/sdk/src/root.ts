@@ -22,6 +22,14 @@ export type RootAccount = IdlAccounts<VoteAggregator>['root'];
 export type MaxVoterWeightAccount =
   IdlAccounts<VoteAggregator>['maxVoterWeightRecord'];
 
+export const PAUSE_CREATE = 1 << 0;
+export const PAUSE_JOIN = 1 << 1;
+export const PAUSE_LEAVE = 1 << 2;
+export const PAUSE_VOTER_WEIGHT = 1 << 3;
+export const PAUSE_PROPOSAL_VOTE = 1 << 4;
+export const PAUSE_CLAN_CONFIG = 1 << 5;
+export const PAUSE_ALL = 0xffffffff;
+
 export class RootSdk {
   constructor(public readonly sdk: VoteAggregatorSdk) {}
 
@@ -53,6 +61,17 @@ export class RootSdk {
     );
   }
 
+  pendingRootConfigAddress({
+    rootAddress,
+  }: {
+    rootAddress: PublicKey;
+  }): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from('pending-root-config', 'utf-8'), rootAddress.toBuffer()],
+      this.sdk.programId
+    );
+  }
+
   lockAuthority({rootAddress}: {rootAddress: PublicKey}): [PublicKey, number] {
     return PublicKey.findProgramAddressSync(
       [Buffer.from('lock-authority', 'utf-8'), rootAddress.toBuffer()],
@@ -229,20 +248,23 @@ export class RootSdk {
       })
       .instruction();
 
+    // verify_root requires both addins of the side to point to the aggregator
     const communityTokenConfigArgs = {
       voterWeightAddin:
         side === 'community'
           ? this.sdk.program.programId
           : realmConfigData.communityTokenConfig.voterWeightAddin,
       maxVoterWeightAddin:
-        realmConfigData.communityTokenConfig.maxVoterWeightAddin,
+        side === 'community'
+          ? this.sdk.program.programId
+          : realmConfigData.communityTokenConfig.maxVoterWeightAddin,
       tokenType: realmConfigData.communityTokenConfig.tokenType,
       useVoterWeightAddin:
         side === 'community' ||
         Boolean(realmConfigData.communityTokenConfig.voterWeightAddin),
-      useMaxVoterWeightAddin: Boolean(
-        realmConfigData.communityTokenConfig.maxVoterWeightAddin
-      ),
+      useMaxVoterWeightAddin:
+        side === 'community' ||
+        Boolean(realmConfigData.communityTokenConfig.maxVoterWeightAddin),
     };
     const councilTokenConfigArgs = realmData.config.councilMint
       ? {
@@ -251,14 +273,16 @@ export class RootSdk {
               ? this.sdk.program.programId
               : realmConfigData.councilTokenConfig.voterWeightAddin,
           maxVoterWeightAddin:
-            realmConfigData.councilTokenConfig.maxVoterWeightAddin,
+            side === 'council'
+              ? this.sdk.program.programId
+              : realmConfigData.councilTokenConfig.maxVoterWeightAddin,
           tokenType: realmConfigData.councilTokenConfig.tokenType,
           useVoterWeightAddin:
             side === 'council' ||
             Boolean(realmConfigData.councilTokenConfig.voterWeightAddin),
-          useMaxVoterWeightAddin: Boolean(
-            realmConfigData.councilTokenConfig.maxVoterWeightAddin
-          ),
+          useMaxVoterWeightAddin:
+            side === 'council' ||
+            Boolean(realmConfigData.councilTokenConfig.maxVoterWeightAddin),
         }
       : undefined;
 
@@ -314,6 +338,60 @@ export class RootSdk {
     return [createRootIx, setRealmConfigIx, setLockAuthorityIx];
   }
 
+  async verifyRootInstruction({
+    rootAddress,
+    rootData,
+  }: {
+    rootAddress: PublicKey;
+    rootData: {
+      governanceProgram: PublicKey;
+      realm: PublicKey;
+    };
+  }) {
+    return await this.sdk.program.methods
+      .verifyRoot()
+      .accountsStrict({
+        root: rootAddress,
+        realm: rootData.realm,
+        realmConfig: await getRealmConfigAddress(
+          rootData.governanceProgram,
+          rootData.realm
+        ),
+        governanceProgram: rootData.governanceProgram,
+      })
+      .instruction();
+  }
+
+  async checkPluginConfigInstruction({
+    rootAddress,
+    rootData,
+  }: {
+    rootAddress: PublicKey;
+    rootData: {
+      governanceProgram: PublicKey;
+      realm: PublicKey;
+      votingWeightPlugin: PublicKey;
+    };
+  }) {
+    return await this.sdk.program.methods
+      .checkPluginConfig()
+      .accountsStrict({
+        root: rootAddress,
+        realm: rootData.realm,
+        realmConfig: await getRealmConfigAddress(
+          rootData.governanceProgram,
+          rootData.realm
+        ),
+        votingWeightPlugin: rootData.votingWeightPlugin.equals(
+          PublicKey.default
+        )
+          ? null
+          : rootData.votingWeightPlugin,
+        governanceProgram: rootData.governanceProgram,
+      })
+      .instruction();
+  }
+
   async setMaxProposalLifetimeInstruction({
     maxProposalLifetime,
     root,
@@ -358,17 +436,312 @@ export class RootSdk {
       .instruction();
   }
 
+  async disableVoterWeightResetInstruction({
+    root,
+    realm,
+    realmAuthority,
+  }: {
+    root: PublicKey;
+    realm: PublicKey;
+    realmAuthority: PublicKey;
+  }) {
+    return await this.sdk.program.methods
+      .disableVoterWeightReset()
+      .accountsStrict({
+        realm,
+        realmAuthority,
+        root,
+      })
+      .instruction();
+  }
+
+  async alignVoterWeightResetInstruction({
+    votingWindows,
+    nextResetTime,
+    root,
+    realm,
+    realmAuthority,
+    governance,
+  }: {
+    votingWindows: BN;
+    nextResetTime: BN | null;
+    root: PublicKey;
+    realm: PublicKey;
+    realmAuthority: PublicKey;
+    governance: PublicKey;
+  }) {
+    return await this.sdk.program.methods
+      .alignVoterWeightReset(votingWindows, nextResetTime)
+      .accountsStrict({
+        configureRoot: {
+          realm,
+          realmAuthority,
+          root,
+        },
+        governance,
+      })
+      .instruction();
+  }
+
+  async setVoterWeightExpiryToleranceInstruction({
+    voterWeightExpiryTolerance,
+    root,
+    realm,
+    realmAuthority,
+  }: {
+    voterWeightExpiryTolerance: BN;
+    root: PublicKey;
+    realm: PublicKey;
+    realmAuthority: PublicKey;
+  }) {
+    return await this.sdk.program.methods
+      .setVoterWeightExpiryTolerance(voterWeightExpiryTolerance)
+      .accountsStrict({
+        realm,
+        realmAuthority,
+        root,
+      })
+      .instruction();
+  }
+
+  async setStaleMemberGracePeriodInstruction({
+    staleMemberGracePeriod,
+    root,
+    realm,
+    realmAuthority,
+  }: {
+    staleMemberGracePeriod: BN | null;
+    root: PublicKey;
+    realm: PublicKey;
+    realmAuthority: PublicKey;
+  }) {
+    return await this.sdk.program.methods
+      .setStaleMemberGracePeriod(staleMemberGracePeriod)
+      .accountsStrict({
+        realm,
+        realmAuthority,
+        root,
+      })
+      .instruction();
+  }
+
+  async setMemberLockDurationInstruction({
+    memberLockDuration,
+    root,
+    realm,
+    realmAuthority,
+  }: {
+    memberLockDuration: BN;
+    root: PublicKey;
+    realm: PublicKey;
+    realmAuthority: PublicKey;
+  }) {
+    return await this.sdk.program.methods
+      .setMemberLockDuration(memberLockDuration)
+      .accountsStrict({
+        realm,
+        realmAuthority,
+        root,
+      })
+      .instruction();
+  }
+
+  async setMembershipWarmupInstruction({
+    membershipWarmup,
+    root,
+    realm,
+    realmAuthority,
+  }: {
+    membershipWarmup: BN;
+    root: PublicKey;
+    realm: PublicKey;
+    realmAuthority: PublicKey;
+  }) {
+    return await this.sdk.program.methods
+      .setMembershipWarmup(membershipWarmup)
+      .accountsStrict({
+        realm,
+        realmAuthority,
+        root,
+      })
+      .instruction();
+  }
+
+  async setMaxLoyaltyBonusInstruction({
+    maxLoyaltyBonusBp,
+    root,
+    realm,
+    realmAuthority,
+  }: {
+    maxLoyaltyBonusBp: number;
+    root: PublicKey;
+    realm: PublicKey;
+    realmAuthority: PublicKey;
+  }) {
+    return await this.sdk.program.methods
+      .setMaxLoyaltyBonus(maxLoyaltyBonusBp)
+      .accountsStrict({
+        realm,
+        realmAuthority,
+        root,
+      })
+      .instruction();
+  }
+
+  async setWeightTransformInstruction({
+    weightTransform,
+    root,
+    realm,
+    realmAuthority,
+  }: {
+    weightTransform:
+      | {linear: {}}
+      | {squareRoot: {}}
+      | {cap: {maxVoterWeight: BN}};
+    root: PublicKey;
+    realm: PublicKey;
+    realmAuthority: PublicKey;
+  }) {
+    return await this.sdk.program.methods
+      .setWeightTransform(weightTransform)
+      .accountsStrict({
+        realm,
+        realmAuthority,
+        root,
+      })
+      .instruction();
+  }
+
+  async setRecallThresholdInstruction({
+    recallThresholdBp,
+    root,
+    realm,
+    realmAuthority,
+  }: {
+    recallThresholdBp: number;
+    root: PublicKey;
+    realm: PublicKey;
+    realmAuthority: PublicKey;
+  }) {
+    return await this.sdk.program.methods
+      .setRecallThreshold(recallThresholdBp)
+      .accountsStrict({
+        realm,
+        realmAuthority,
+        root,
+      })
+      .instruction();
+  }
+
+  async proposeRootAdminInstruction({
+    newPendingAdmin,
+    root,
+    realm,
+    realmAuthority,
+  }: {
+    newPendingAdmin: PublicKey | null;
+    root: PublicKey;
+    realm: PublicKey;
+    realmAuthority: PublicKey;
+  }) {
+    return await this.sdk.program.methods
+      .proposeRootAdmin(newPendingAdmin)
+      .accountsStrict({
+        realm,
+        realmAuthority,
+        root,
+      })
+      .instruction();
+  }
+
+  async acceptRootAdminInstruction({
+    root,
+    pendingAdmin,
+  }: {
+    root: PublicKey;
+    pendingAdmin: PublicKey;
+  }) {
+    return await this.sdk.program.methods
+      .acceptRootAdmin()
+      .accountsStrict({
+        root,
+        pendingAdmin,
+      })
+      .instruction();
+  }
+
+  async reclaimRootAdminInstruction({
+    root,
+    realm,
+    realmAuthority,
+  }: {
+    root: PublicKey;
+    realm: PublicKey;
+    realmAuthority: PublicKey;
+  }) {
+    return await this.sdk.program.methods
+      .reclaimRootAdmin()
+      .accountsStrict({
+        realm,
+        realmAuthority,
+        root,
+      })
+      .instruction();
+  }
+
+  async setPauseGuardianInstruction({
+    newPauseGuardian,
+    root,
+    realm,
+    realmAuthority,
+  }: {
+    newPauseGuardian: PublicKey | null;
+    root: PublicKey;
+    realm: PublicKey;
+    realmAuthority: PublicKey;
+  }) {
+    return await this.sdk.program.methods
+      .setPauseGuardian(newPauseGuardian)
+      .accountsStrict({
+        realm,
+        realmAuthority,
+        root,
+      })
+      .instruction();
+  }
+
+  async startWindDownInstruction({
+    root,
+    realm,
+    realmAuthority,
+  }: {
+    root: PublicKey;
+    realm: PublicKey;
+    realmAuthority: PublicKey;
+  }) {
+    return await this.sdk.program.methods
+      .startWindDown()
+      .accountsStrict({
+        root,
+        realm,
+        realmAuthority,
+      })
+      .instruction();
+  }
+
   async pauseInstruction({
+    flags = PAUSE_ALL,
     root,
     realm,
     realmAuthority,
   }: {
+    flags?: number;
     root: PublicKey;
     realm: PublicKey;
     realmAuthority: PublicKey;
   }) {
     return await this.sdk.program.methods
-      .pause()
+      .pause(flags)
       .accountsStrict({
         root,
         realm,
@@ -378,16 +751,18 @@ export class RootSdk {
   }
 
   async resumeInstruction({
+    flags = PAUSE_ALL,
     root,
     realm,
     realmAuthority,
   }: {
+    flags?: number;
     root: PublicKey;
     realm: PublicKey;
     realmAuthority: PublicKey;
   }) {
     return await this.sdk.program.methods
-      .resume()
+      .resume(flags)
       .accountsStrict({
         root,
         realm,
@@ -419,4 +794,83 @@ export class RootSdk {
       })
       .instruction();
   }
+
+  async queueRootConfigInstruction({
+    maxProposalLifetime = null,
+    votingWeightPlugin = null,
+    root,
+    realm,
+    realmAuthority,
+    payer,
+  }: {
+    maxProposalLifetime?: BN | null;
+    votingWeightPlugin?: PublicKey | null;
+    root: PublicKey;
+    realm: PublicKey;
+    realmAuthority: PublicKey;
+    payer: PublicKey;
+  }) {
+    return await this.sdk.program.methods
+      .queueRootConfig(maxProposalLifetime, votingWeightPlugin)
+      .accountsStrict({
+        configureRoot: {
+          root,
+          realm,
+          realmAuthority,
+        },
+        pendingRootConfig: this.pendingRootConfigAddress({
+          rootAddress: root,
+        })[0],
+        payer,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction();
+  }
+
+  async applyRootConfigInstruction({
+    root,
+    rentPayer,
+  }: {
+    root: PublicKey;
+    rentPayer: PublicKey;
+  }) {
+    return await this.sdk.program.methods
+      .applyRootConfig()
+      .accountsStrict({
+        root,
+        pendingRootConfig: this.pendingRootConfigAddress({
+          rootAddress: root,
+        })[0],
+        maxVwr: this.maxVoterWieghtAddress({rootAddress: root})[0],
+        rentPayer,
+      })
+      .instruction();
+  }
+
+  async cancelRootConfigInstruction({
+    root,
+    realm,
+    realmAuthority,
+    rentPayer,
+  }: {
+    root: PublicKey;
+    realm: PublicKey;
+    realmAuthority: PublicKey;
+    rentPayer: PublicKey;
+  }) {
+    return await this.sdk.program.methods
+      .cancelRootConfig()
+      .accountsStrict({
+        configureRoot: {
+          root,
+          realm,
+          realmAuthority,
+        },
+        pendingRootConfig: this.pendingRootConfigAddress({
+          rootAddress: root,
+        })[0],
+        rentPayer,
+      })
+      .instruction();
+  }
 }
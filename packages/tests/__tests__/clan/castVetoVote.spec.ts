@@ -0,0 +1,275 @@
+import {startTest} from '../../dev/startTest';
+import {
+  CastVetoVoteTestData,
+  RealmTester,
+  parseLogsEvent,
+  castVetoVoteTestData,
+  buildSplGovernanceProgram,
+} from '../../src';
+import {ClanTester, RootTester} from '../../src/VoteAggregator';
+import {Keypair, PublicKey} from '@solana/web3.js';
+import {GovernanceTester} from '../../src/SplGovernance/governance';
+import {ProposalTester} from '../../src/SplGovernance';
+import {
+  SYSTEM_PROGRAM_ID,
+  getVoteRecordAddress,
+} from '@solana/spl-governance';
+import BN from 'bn.js';
+
+const VOTE_HISTORY_CAPACITY = 32;
+
+describe('cast_veto_vote instruction', () => {
+  it.each(castVetoVoteTestData.filter(({error}) => !error))(
+    'Works',
+    async ({
+      realm,
+      root,
+      clan,
+      governance,
+      proposal,
+    }: CastVetoVoteTestData) => {
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const clanTester = new ClanTester({...clan, root: rootTester});
+      const governanceTester = new GovernanceTester({
+        ...governance,
+        realm: realmTester,
+      });
+      const proposalTester = new ProposalTester({
+        ...proposal,
+        governance: governanceTester,
+        clan: proposal.owner ? undefined : clanTester,
+      });
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          ...(await clanTester.accounts()),
+          await governanceTester.account(),
+          await proposalTester.account(),
+          await realmTester.tokenOwnerRecord({
+            owner: proposalTester.owner,
+            governingTokenMint: proposalTester.proposal.governingTokenMint,
+          }),
+        ],
+      });
+      const splGovernance = buildSplGovernanceProgram({
+        splGovernanceId: rootTester.splGovernanceId,
+        connection: program.provider.connection,
+      });
+
+      const [voterAuthority] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('voter-authority', 'utf-8'),
+          clanTester.clanAddress.toBuffer(),
+        ],
+        program.programId
+      );
+      const [clanTor] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('governance', 'utf-8'),
+          rootTester.realm.realmAddress.toBuffer(),
+          rootTester.governingTokenMint.toBuffer(),
+          voterAuthority.toBuffer(),
+        ],
+        rootTester.splGovernanceId
+      );
+      const voteRecord = await getVoteRecordAddress(
+        rootTester.splGovernanceId,
+        proposalTester.proposalAddress,
+        clanTor
+      );
+
+      const tx = await program.methods
+        .castVetoVote()
+        .accountsStrict({
+          root: rootTester.rootAddress[0],
+          clan: clanTester.clanAddress,
+          clanAuthority: (clanTester.owner as Keypair).publicKey,
+          governanceProgram: rootTester.splGovernanceId,
+          voterAuthority,
+          clanTor,
+          realm: realmTester.realmAddress,
+          realmConfig: await realmTester.realmConfigId(),
+          governingTokenMint: rootTester.governingTokenMint,
+          systemProgram: SYSTEM_PROGRAM_ID,
+          governance: governanceTester.governanceAddress,
+          proposal: proposalTester.proposalAddress,
+          clanVwr: clanTester.voterWeightAddress[0],
+          payer: program.provider.publicKey!,
+          proposalOwnerRecord: proposalTester.proposal.tokenOwnerRecord,
+          maxVwr: null,
+          voteRecord,
+          voteHistory: clanTester.clan.voteHistory,
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer, clanTester.owner as Keypair);
+
+      const clock = await testContext.banksClient.getClock();
+      await expect(
+        testContext.banksClient
+          .processTransaction(tx)
+          .then(meta => parseLogsEvent(program, meta.logMessages))
+      ).resolves.toStrictEqual([
+        {
+          name: 'ProposalVetoCast',
+          data: {
+            clan: clanTester.clanAddress,
+            proposal: proposalTester.proposalAddress,
+            votingWeight: clanTester.voterWeightRecord.voterWeight,
+          },
+        },
+      ]);
+
+      await expect(
+        splGovernance.account.voteRecordV2.fetch(voteRecord)
+      ).resolves.toMatchObject({
+        governingTokenOwner: voterAuthority,
+        voterWeight: clanTester.voterWeightRecord.voterWeight,
+        vote: {veto: {}},
+      });
+
+      await expect(
+        program.account.clan.fetch(clanTester.clanAddress)
+      ).resolves.toMatchObject({
+        vetoVotesCast: clanTester.clan.vetoVotesCast.addn(1),
+      });
+
+      if (clanTester.voteHistory) {
+        const {entries, nextIndex} = clanTester.voteHistory;
+        const entry = {
+          proposal: proposalTester.proposalAddress,
+          vote: {veto: {}},
+          approvedOptions: new BN(0),
+          voterWeight: clanTester.voterWeightRecord.voterWeight,
+          votedAt: new BN(clock.unixTimestamp.toString()),
+        };
+        // The full history replaces its oldest entry
+        const isFull = entries.length === VOTE_HISTORY_CAPACITY;
+        await expect(
+          program.account.clanVoteHistory.fetch(
+            clanTester.voteHistoryAddress[0]
+          )
+        ).resolves.toStrictEqual({
+          ...clanTester.voteHistory,
+          nextIndex: (nextIndex + 1) % VOTE_HISTORY_CAPACITY,
+          entries: isFull
+            ? entries.map((e, i) => (i === nextIndex ? entry : e))
+            : [...entries, entry],
+        });
+      }
+
+      await expect(
+        splGovernance.account.proposalV2.fetch(proposalTester.proposalAddress)
+      ).resolves.toMatchObject({
+        options: proposalTester.proposal.options,
+        vetoVoteWeight: proposalTester.proposal.vetoVoteWeight.add(
+          clanTester.voterWeightRecord.voterWeight
+        ),
+      });
+    }
+  );
+
+  it.each(castVetoVoteTestData.filter(({error}) => error))(
+    'Fails with $error',
+    async ({
+      realm,
+      root,
+      clan,
+      governance,
+      proposal,
+      error,
+    }: CastVetoVoteTestData) => {
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const clanTester = new ClanTester({...clan, root: rootTester});
+      const governanceTester = new GovernanceTester({
+        ...governance,
+        realm: realmTester,
+      });
+      const proposalTester = new ProposalTester({
+        ...proposal,
+        governance: governanceTester,
+        clan: proposal.owner ? undefined : clanTester,
+      });
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          ...(await clanTester.accounts()),
+          await governanceTester.account(),
+          await proposalTester.account(),
+          await realmTester.tokenOwnerRecord({
+            owner: proposalTester.owner,
+            governingTokenMint: proposalTester.proposal.governingTokenMint,
+          }),
+        ],
+      });
+
+      const [voterAuthority] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('voter-authority', 'utf-8'),
+          clanTester.clanAddress.toBuffer(),
+        ],
+        program.programId
+      );
+      const [clanTor] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('governance', 'utf-8'),
+          rootTester.realm.realmAddress.toBuffer(),
+          rootTester.governingTokenMint.toBuffer(),
+          voterAuthority.toBuffer(),
+        ],
+        rootTester.splGovernanceId
+      );
+      const voteRecord = await getVoteRecordAddress(
+        rootTester.splGovernanceId,
+        proposalTester.proposalAddress,
+        clanTor
+      );
+
+      const tx = await program.methods
+        .castVetoVote()
+        .accountsStrict({
+          root: rootTester.rootAddress[0],
+          clan: clanTester.clanAddress,
+          clanAuthority: (clanTester.owner as Keypair).publicKey,
+          governanceProgram: rootTester.splGovernanceId,
+          voterAuthority,
+          clanTor,
+          realm: realmTester.realmAddress,
+          realmConfig: await realmTester.realmConfigId(),
+          governingTokenMint: rootTester.governingTokenMint,
+          systemProgram: SYSTEM_PROGRAM_ID,
+          governance: governanceTester.governanceAddress,
+          proposal: proposalTester.proposalAddress,
+          clanVwr: clanTester.voterWeightAddress[0],
+          payer: program.provider.publicKey!,
+          proposalOwnerRecord: proposalTester.proposal.tokenOwnerRecord,
+          maxVwr: null,
+          voteRecord,
+          voteHistory: clanTester.clan.voteHistory,
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer, clanTester.owner as Keypair);
+
+      const result = await testContext.banksClient.tryProcessTransaction(tx);
+      expect(result.result).not.toBeNull();
+      expect(result.meta?.logMessages).toContainEqual(
+        expect.stringContaining(`Error Code: ${error}.`)
+      );
+    }
+  );
+});
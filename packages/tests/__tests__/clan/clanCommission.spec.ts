@@ -0,0 +1,219 @@
+import {startTest} from '../../dev/startTest';
+import {Keypair, PublicKey} from '@solana/web3.js';
+import {TOKEN_PROGRAM_ID} from '@solana/spl-token';
+import {
+  ClanCommissionTestData,
+  RealmTester,
+  clanCommissionTestData,
+  parseLogsEvent,
+  tokenAccount,
+  tokenAccountAmount,
+} from '../../src';
+import {ClanTester, RootTester} from '../../src/VoteAggregator';
+import BN from 'bn.js';
+
+const buildTesters = ({realm, root, clan}: ClanCommissionTestData) => {
+  const realmTester = new RealmTester(realm);
+  const rootTester = new RootTester({
+    ...root,
+    realm: realmTester,
+  });
+  const clanTester = new ClanTester({
+    ...clan,
+    root: rootTester,
+  });
+  return {realmTester, rootTester, clanTester};
+};
+
+const clanAuthorityKeypair = ({
+  clan,
+  clanAuthority,
+}: ClanCommissionTestData) => {
+  if (clanAuthority === 'owner') {
+    return clan.owner;
+  }
+  if (!(clan.delegate instanceof Keypair)) {
+    throw new Error('Clan delegate is not a keypair');
+  }
+  return clan.delegate;
+};
+
+describe('Clan commission instructions', () => {
+  it.each(clanCommissionTestData.filter(({error}) => !error))(
+    'Sets the commission',
+    async (testData: ClanCommissionTestData) => {
+      const {newCommissionBp} = testData;
+      const {realmTester, rootTester, clanTester} = buildTesters(testData);
+      const clanAuthority = clanAuthorityKeypair(testData);
+
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          ...(await clanTester.accounts()),
+        ],
+      });
+
+      const tx = await program.methods
+        .setClanCommission(newCommissionBp)
+        .accountsStrict({
+          clan: clanTester.clanAddress,
+          clanAuthority: clanAuthority.publicKey,
+          siblingClan: null,
+          root: rootTester.rootAddress[0],
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer, clanAuthority);
+
+      const clock = await testContext.banksClient.getClock();
+      const now = new BN(clock.unixTimestamp.toString());
+      const {commissionBp, pendingCommission} = clanTester.clan;
+      const isChanged =
+        newCommissionBp !== (pendingCommission?.commissionBp ?? commissionBp);
+      const isIncrease = newCommissionBp > commissionBp;
+      const effectiveAt = isIncrease
+        ? now.add(rootTester.root.maxProposalLifetime)
+        : now;
+      const events = [];
+      if (isChanged) {
+        events.push({
+          name: 'ClanCommissionChanged',
+          data: {
+            clan: clanTester.clanAddress,
+            oldCommissionBp: commissionBp,
+            newCommissionBp,
+            effectiveAt,
+          },
+        });
+      }
+      await expect(
+        testContext.banksClient
+          .processTransaction(tx)
+          .then(meta => parseLogsEvent(program, meta.logMessages))
+      ).resolves.toStrictEqual(events);
+
+      let clan = clanTester.clan;
+      if (isChanged) {
+        clan = isIncrease
+          ? {
+              ...clan,
+              pendingCommission: {commissionBp: newCommissionBp, effectiveAt},
+            }
+          : {...clan, commissionBp: newCommissionBp, pendingCommission: null};
+      }
+      await expect(
+        program.account.clan.fetch(clanTester.clanAddress)
+      ).resolves.toStrictEqual(clan);
+    }
+  );
+
+  it.each(clanCommissionTestData.filter(({error}) => !error))(
+    'Claims the commission',
+    async (testData: ClanCommissionTestData) => {
+      const {clan, claimDestination} = testData;
+      const {realmTester, rootTester, clanTester} = buildTesters(testData);
+
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          ...(await clanTester.accounts()),
+          tokenAccount({
+            address: claimDestination,
+            mint: clanTester.clan.rewardMint!,
+            owner: PublicKey.default,
+            amount: new BN(0),
+          }),
+        ],
+      });
+
+      const tx = await program.methods
+        .claimClanCommission()
+        .accountsStrict({
+          clan: clanTester.clanAddress,
+          owner: clan.owner.publicKey,
+          voterAuthority: clanTester.voterAuthority[0],
+          rewardVault: clanTester.rewardVaultAddress[0],
+          destination: claimDestination,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer, clan.owner);
+
+      const amount = clanTester.clan.unclaimedCommission;
+      await expect(
+        testContext.banksClient
+          .processTransaction(tx)
+          .then(meta => parseLogsEvent(program, meta.logMessages))
+      ).resolves.toStrictEqual([
+        {
+          name: 'ClanCommissionClaimed',
+          data: {
+            clan: clanTester.clanAddress,
+            owner: clan.owner.publicKey,
+            amount,
+          },
+        },
+      ]);
+
+      await expect(
+        program.account.clan.fetch(clanTester.clanAddress)
+      ).resolves.toStrictEqual({
+        ...clanTester.clan,
+        unclaimedCommission: new BN(0),
+      });
+      const rewardVault = await testContext.banksClient.getAccount(
+        clanTester.rewardVaultAddress[0]
+      );
+      expect(tokenAccountAmount(rewardVault!.data)).toStrictEqual(
+        clanTester.rewardVaultAmount.sub(amount)
+      );
+      const destination =
+        await testContext.banksClient.getAccount(claimDestination);
+      expect(tokenAccountAmount(destination!.data)).toStrictEqual(amount);
+    }
+  );
+
+  it.each(clanCommissionTestData.filter(({error}) => error))(
+    'Fails to set the commission with $error',
+    async (testData: ClanCommissionTestData) => {
+      const {newCommissionBp, error} = testData;
+      const {realmTester, rootTester, clanTester} = buildTesters(testData);
+      const clanAuthority = clanAuthorityKeypair(testData);
+
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          ...(await clanTester.accounts()),
+        ],
+      });
+
+      const tx = await program.methods
+        .setClanCommission(newCommissionBp)
+        .accountsStrict({
+          clan: clanTester.clanAddress,
+          clanAuthority: clanAuthority.publicKey,
+          siblingClan: null,
+          root: rootTester.rootAddress[0],
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer, clanAuthority);
+
+      const result = await testContext.banksClient.tryProcessTransaction(tx);
+      expect(result.result).not.toBeNull();
+      expect(result.meta?.logMessages).toContainEqual(
+        expect.stringContaining(`Error Code: ${error}.`)
+      );
+    }
+  );
+});
@@ -0,0 +1,393 @@
+import {startTest} from '../../dev/startTest';
+import {PublicKey, SystemProgram, Transaction} from '@solana/web3.js';
+import {TOKEN_PROGRAM_ID} from '@solana/spl-token';
+import {
+  ClanTreasuryTestData,
+  RealmTester,
+  TreasurySpendingTestData,
+  clanTreasuryTestData,
+  parseLogsEvent,
+  tokenAccount,
+  treasurySpendingTestData,
+} from '../../src';
+import {ClanTester, MemberTester, RootTester} from '../../src/VoteAggregator';
+import BN from 'bn.js';
+
+const buildTesters = ({realm, root, member}: ClanTreasuryTestData) => {
+  const realmTester = new RealmTester(realm);
+  const rootTester = new RootTester({
+    ...root,
+    realm: realmTester,
+  });
+  const memberTester = new MemberTester({
+    ...member,
+    root: rootTester,
+    membership: MemberTester.membershipTesters({
+      membership: member.membership || [],
+      root: rootTester,
+    }),
+  });
+  const clanTester = memberTester.membership[0].clan as ClanTester;
+  return {realmTester, rootTester, memberTester, clanTester};
+};
+
+const withdrawalAddresses = (
+  clanTester: ClanTester,
+  memberTester: MemberTester
+) => {
+  const [withdrawal] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from('treasury-withdrawal', 'utf-8'),
+      clanTester.clanAddress.toBuffer(),
+      clanTester.clan.treasuryWithdrawals.toArrayLike(Buffer, 'le', 8),
+    ],
+    clanTester.root.voteAggregatorId
+  );
+  const [approval] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from('withdrawal-approval', 'utf-8'),
+      withdrawal.toBuffer(),
+      memberTester.memberAddress[0].toBuffer(),
+    ],
+    clanTester.root.voteAggregatorId
+  );
+  return {withdrawal, approval};
+};
+
+describe('Clan treasury', () => {
+  it.each(clanTreasuryTestData.filter(({error}) => !error))(
+    'Proposes, approves and withdraws above the spending limit',
+    async (testData: ClanTreasuryTestData) => {
+      const {member, amount, destination} = testData;
+      const {realmTester, rootTester, memberTester, clanTester} =
+        buildTesters(testData);
+      const {withdrawal, approval} = withdrawalAddresses(
+        clanTester,
+        memberTester
+      );
+      const owner = clanTester.owner!;
+
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          ...(await memberTester.accounts()),
+          ...(await clanTester.accounts()),
+        ],
+      });
+
+      const proposeTx = await program.methods
+        .proposeTreasuryWithdrawal(null, amount, destination)
+        .accountsStrict({
+          root: rootTester.rootAddress[0],
+          clan: clanTester.clanAddress,
+          owner: owner.publicKey,
+          withdrawal,
+          payer: testContext.payer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .transaction();
+      proposeTx.recentBlockhash = testContext.lastBlockhash;
+      proposeTx.feePayer = testContext.payer.publicKey;
+      proposeTx.sign(testContext.payer, owner);
+
+      const time = new BN(
+        (await testContext.banksClient.getClock()).unixTimestamp.toString()
+      );
+      const requiredWeight = clanTester.clan.rewardWeight
+        .muln(clanTester.clan.treasuryPolicy!.approvalThresholdBp)
+        .divn(10000);
+      const expiresAt = time.add(rootTester.root.maxProposalLifetime);
+      await expect(
+        testContext.banksClient
+          .processTransaction(proposeTx)
+          .then(meta => parseLogsEvent(program, meta.logMessages))
+      ).resolves.toStrictEqual([
+        {
+          name: 'TreasuryWithdrawalProposed',
+          data: {
+            clan: clanTester.clanAddress,
+            withdrawal,
+            index: clanTester.clan.treasuryWithdrawals,
+            mint: null,
+            amount,
+            destination,
+            requiredWeight,
+            expiresAt,
+          },
+        },
+      ]);
+
+      const approveTx = await program.methods
+        .approveTreasuryWithdrawal()
+        .accountsStrict({
+          member: memberTester.memberAddress[0],
+          memberAuthority: member.owner.publicKey,
+          withdrawal,
+          approval,
+          payer: testContext.payer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .transaction();
+      approveTx.recentBlockhash = testContext.lastBlockhash;
+      approveTx.feePayer = testContext.payer.publicKey;
+      approveTx.sign(testContext.payer, member.owner);
+
+      const voterWeight = memberTester.member.membership[0].rewardWeight;
+      await expect(
+        testContext.banksClient
+          .processTransaction(approveTx)
+          .then(meta => parseLogsEvent(program, meta.logMessages))
+      ).resolves.toStrictEqual([
+        {
+          name: 'TreasuryWithdrawalApproved',
+          data: {
+            withdrawal,
+            clan: clanTester.clanAddress,
+            member: memberTester.memberAddress[0],
+            voterWeight,
+            approvedWeight: voterWeight,
+          },
+        },
+      ]);
+
+      const withdrawTx = await program.methods
+        .withdrawClanTreasury(amount)
+        .accountsStrict({
+          clan: clanTester.clanAddress,
+          owner: owner.publicKey,
+          treasury: clanTester.treasuryAddress[0],
+          withdrawal,
+          destination,
+          systemProgram: SystemProgram.programId,
+        })
+        .transaction();
+      withdrawTx.recentBlockhash = testContext.lastBlockhash;
+      withdrawTx.feePayer = testContext.payer.publicKey;
+      withdrawTx.sign(testContext.payer, owner);
+
+      await expect(
+        testContext.banksClient
+          .processTransaction(withdrawTx)
+          .then(meta => parseLogsEvent(program, meta.logMessages))
+      ).resolves.toStrictEqual([
+        {
+          name: 'ClanTreasuryWithdrawn',
+          data: {
+            clan: clanTester.clanAddress,
+            mint: null,
+            amount,
+            destination,
+            withdrawal,
+          },
+        },
+      ]);
+
+      await expect(
+        program.account.treasuryWithdrawal.fetch(withdrawal)
+      ).resolves.toMatchObject({
+        approvedWeight: voterWeight,
+        approvals: new BN(1),
+        executed: true,
+      });
+      // The approved withdrawal is not counted against the spending limit
+      await expect(
+        program.account.clan.fetch(clanTester.clanAddress)
+      ).resolves.toStrictEqual({
+        ...clanTester.clan,
+        treasuryWithdrawals: clanTester.clan.treasuryWithdrawals.addn(1),
+      });
+      await expect(
+        testContext.banksClient.getBalance(destination)
+      ).resolves.toStrictEqual(BigInt(amount.toString()));
+    }
+  );
+
+  it.each(clanTreasuryTestData.filter(({error}) => error))(
+    'Fails with $error',
+    async (testData: ClanTreasuryTestData) => {
+      const {member, amount, destination, error} = testData;
+      const {realmTester, rootTester, memberTester, clanTester} =
+        buildTesters(testData);
+      const {withdrawal, approval} = withdrawalAddresses(
+        clanTester,
+        memberTester
+      );
+      const owner = clanTester.owner!;
+
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          ...(await memberTester.accounts()),
+          ...(await clanTester.accounts()),
+        ],
+      });
+
+      const tx = await program.methods
+        .proposeTreasuryWithdrawal(null, amount, destination)
+        .accountsStrict({
+          root: rootTester.rootAddress[0],
+          clan: clanTester.clanAddress,
+          owner: owner.publicKey,
+          withdrawal,
+          payer: testContext.payer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .postInstructions([
+          await program.methods
+            .approveTreasuryWithdrawal()
+            .accountsStrict({
+              member: memberTester.memberAddress[0],
+              memberAuthority: member.owner.publicKey,
+              withdrawal,
+              approval,
+              payer: testContext.payer.publicKey,
+              systemProgram: SystemProgram.programId,
+            })
+            .instruction(),
+          await program.methods
+            .withdrawClanTreasury(amount)
+            .accountsStrict({
+              clan: clanTester.clanAddress,
+              owner: owner.publicKey,
+              treasury: clanTester.treasuryAddress[0],
+              withdrawal,
+              destination,
+              systemProgram: SystemProgram.programId,
+            })
+            .instruction(),
+        ])
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer, owner, member.owner);
+
+      const result = await testContext.banksClient.tryProcessTransaction(tx);
+      expect(result.result).not.toBeNull();
+      expect(result.meta?.logMessages).toContainEqual(
+        expect.stringContaining(`Error Code: ${error}.`)
+      );
+    }
+  );
+});
+
+const buildSpending = async ({
+  realm,
+  root,
+  clan,
+  withdrawals,
+  treasuryTokens,
+  destination,
+}: TreasurySpendingTestData) => {
+  const realmTester = new RealmTester(realm);
+  const rootTester = new RootTester({
+    ...root,
+    realm: realmTester,
+  });
+  const clanTester = new ClanTester({...clan, root: rootTester});
+  const total = withdrawals.reduce((sum, amount) => sum.add(amount));
+
+  const {testContext, program} = await startTest({
+    splGovernanceId: rootTester.splGovernanceId,
+    accounts: [
+      ...(await realmTester.accounts()),
+      ...(await rootTester.accounts()),
+      ...(await clanTester.accounts()),
+      ...(treasuryTokens
+        ? [
+            tokenAccount({
+              address: treasuryTokens.address,
+              mint: treasuryTokens.mint,
+              owner: clanTester.treasuryAddress[0],
+              amount: total,
+            }),
+            tokenAccount({
+              address: destination,
+              mint: treasuryTokens.mint,
+              owner: PublicKey.default,
+              amount: new BN(0),
+            }),
+          ]
+        : []),
+    ],
+  });
+
+  const tx = new Transaction();
+  for (const amount of withdrawals) {
+    tx.add(
+      treasuryTokens
+        ? await program.methods
+            .withdrawClanTreasuryTokens(amount)
+            .accountsStrict({
+              clan: clanTester.clanAddress,
+              owner: clan.owner.publicKey,
+              treasury: clanTester.treasuryAddress[0],
+              treasuryTokens: treasuryTokens.address,
+              withdrawal: null,
+              destination,
+              tokenProgram: TOKEN_PROGRAM_ID,
+            })
+            .instruction()
+        : await program.methods
+            .withdrawClanTreasury(amount)
+            .accountsStrict({
+              clan: clanTester.clanAddress,
+              owner: clan.owner.publicKey,
+              treasury: clanTester.treasuryAddress[0],
+              withdrawal: null,
+              destination,
+              systemProgram: SystemProgram.programId,
+            })
+            .instruction()
+    );
+  }
+  tx.recentBlockhash = testContext.lastBlockhash;
+  tx.feePayer = testContext.payer.publicKey;
+  tx.sign(testContext.payer, clan.owner);
+
+  return {clanTester, testContext, program, tx, total};
+};
+
+describe('Clan treasury spending limit', () => {
+  it.each(treasurySpendingTestData.filter(({error}) => !error))(
+    'Withdraws without approvals',
+    async (testData: TreasurySpendingTestData) => {
+      const {destination} = testData;
+      const {clanTester, testContext, program, tx, total} =
+        await buildSpending(testData);
+
+      const time = new BN(
+        (await testContext.banksClient.getClock()).unixTimestamp.toString()
+      );
+      await testContext.banksClient.processTransaction(tx);
+
+      // The spending of the previous period is forgotten
+      await expect(
+        program.account.clan.fetch(clanTester.clanAddress)
+      ).resolves.toStrictEqual({
+        ...clanTester.clan,
+        treasurySpent: total,
+        treasuryPeriodStart: time,
+      });
+      await expect(
+        testContext.banksClient.getBalance(destination)
+      ).resolves.toStrictEqual(BigInt(total.toString()));
+    }
+  );
+
+  it.each(treasurySpendingTestData.filter(({error}) => error))(
+    'Fails with $error',
+    async (testData: TreasurySpendingTestData) => {
+      const {testContext, tx} = await buildSpending(testData);
+
+      const result = await testContext.banksClient.tryProcessTransaction(tx);
+      expect(result.result).not.toBeNull();
+      expect(result.meta?.logMessages).toContainEqual(
+        expect.stringContaining(`Error Code: ${testData.error}.`)
+      );
+    }
+  );
+});
@@ -0,0 +1,201 @@
+import {startTest} from '../../dev/startTest';
+import {
+  ClanWeightCheckpointsTestData,
+  RealmTester,
+  clanWeightCheckpointsTestData,
+  parseLogsEvent,
+} from '../../src';
+import {ClanTester, MemberTester, RootTester} from '../../src/VoteAggregator';
+import {GovernanceTester} from '../../src/SplGovernance/governance';
+import {ProposalTester} from '../../src/SplGovernance';
+import {Keypair, PublicKey, SystemProgram} from '@solana/web3.js';
+import {Clock} from 'solana-bankrun';
+
+describe('Clan weight checkpoints', () => {
+  it.each(clanWeightCheckpointsTestData)(
+    'Are recorded and read by create_clan_snapshot_vwr',
+    async ({
+      realm,
+      root,
+      member,
+      governance,
+      proposal,
+      slot,
+    }: ClanWeightCheckpointsTestData) => {
+      const voteAggregatorId =
+        root.voteAggregatorId ||
+        new PublicKey('VoTaGDreyne7jk59uwbgRRbaAzxvNbyNipaJMrRXhjT');
+      const [rootAddress] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('root', 'utf-8'),
+          realm.realmAddress.toBuffer(),
+          realm.communityMint.toBuffer(),
+        ],
+        voteAggregatorId
+      );
+      realm.communityTokenConfig = {
+        ...realm.communityTokenConfig,
+        lockAuthorities: [
+          PublicKey.findProgramAddressSync(
+            [Buffer.from('lock-authority', 'utf8'), rootAddress.toBuffer()],
+            voteAggregatorId
+          )[0],
+        ],
+      };
+
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const memberTester = new MemberTester({
+        ...member,
+        locks: [
+          {
+            lockType: 0,
+            authority: rootTester.lockAuthority[0],
+            expiry: null,
+          },
+        ],
+        root: rootTester,
+        membership: MemberTester.membershipTesters({
+          membership: member.membership || [],
+          root: rootTester,
+        }),
+      });
+      const clanTester = memberTester.membership[0].clan as ClanTester;
+      const governanceTester = new GovernanceTester({
+        ...governance,
+        realm: realmTester,
+      });
+      const proposalTester = new ProposalTester({
+        ...proposal,
+        governance: governanceTester,
+      });
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          ...(await memberTester.accounts()),
+          ...(await clanTester.accounts()),
+          await governanceTester.account(),
+          await proposalTester.account(),
+        ],
+      });
+
+      const clock = await testContext.banksClient.getClock();
+      testContext.setClock(
+        new Clock(
+          BigInt(slot.toString()),
+          clock.epochStartTimestamp,
+          clock.epoch,
+          clock.leaderScheduleEpoch,
+          clock.unixTimestamp
+        )
+      );
+
+      // The leaving member changes the clan voter weight
+      const leaveTx = await program.methods
+        .startLeavingClan()
+        .accountsStrict({
+          root: rootTester.rootAddress[0],
+          member: memberTester.memberAddress[0],
+          clan: clanTester.clanAddress,
+          memberAuthority: memberTester.ownerAddress,
+          clanVwr: clanTester.voterWeightAddress[0],
+          weightCheckpoints: clanTester.clan.weightCheckpoints,
+          memberTor: memberTester.tokenOwnerRecordAddress[0],
+          lockAuthority: rootTester.lockAuthority[0],
+          realm: realmTester.realmAddress,
+          realmConfig: await realmTester.realmConfigId(),
+          payer: program.provider.publicKey!,
+          systemProgram: SystemProgram.programId,
+          governanceProgram: rootTester.splGovernanceId,
+        })
+        .transaction();
+      leaveTx.recentBlockhash = testContext.lastBlockhash;
+      leaveTx.feePayer = testContext.payer.publicKey;
+      leaveTx.sign(testContext.payer, member.owner as Keypair);
+      await testContext.banksClient.processTransaction(leaveTx);
+
+      const checkpoints = clanTester.weightCheckpoints!;
+      const checkpoint = {
+        slot,
+        voterWeight: clanTester.voterWeightRecord.voterWeight.sub(
+          memberTester.member.voterWeight
+        ),
+      };
+      await expect(
+        program.account.clanWeightCheckpoints.fetch(
+          clanTester.weightCheckpointsAddress[0]
+        )
+      ).resolves.toStrictEqual({
+        ...checkpoints,
+        nextIndex: checkpoints.nextIndex + 1,
+        checkpoints: [...checkpoints.checkpoints, checkpoint],
+      });
+
+      // The snapshot takes the last weight before the voting start
+      const votingAtSlot = proposalTester.proposal.votingAtSlot!;
+      const voterWeight = [...checkpoints.checkpoints, checkpoint]
+        .filter(c => c.slot.lt(votingAtSlot))
+        .reduce((last, c) => (c.slot.gt(last.slot) ? c : last)).voterWeight;
+      const [snapshotVwr] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('snapshot-weight', 'utf-8'),
+          clanTester.clanAddress.toBuffer(),
+          proposalTester.proposalAddress.toBuffer(),
+        ],
+        program.programId
+      );
+      const snapshotTx = await program.methods
+        .createClanSnapshotVwr()
+        .accountsStrict({
+          root: rootTester.rootAddress[0],
+          clan: clanTester.clanAddress,
+          weightCheckpoints: clanTester.weightCheckpointsAddress[0],
+          governance: governanceTester.governanceAddress,
+          proposal: proposalTester.proposalAddress,
+          snapshotVwr,
+          payer: program.provider.publicKey!,
+          systemProgram: SystemProgram.programId,
+          governanceProgram: rootTester.splGovernanceId,
+        })
+        .transaction();
+      snapshotTx.recentBlockhash = testContext.lastBlockhash;
+      snapshotTx.feePayer = testContext.payer.publicKey;
+      snapshotTx.sign(testContext.payer);
+
+      await expect(
+        testContext.banksClient
+          .processTransaction(snapshotTx)
+          .then(meta => parseLogsEvent(program, meta.logMessages))
+      ).resolves.toStrictEqual([
+        {
+          name: 'ClanSnapshotVwrCreated',
+          data: {
+            clan: clanTester.clanAddress,
+            proposal: proposalTester.proposalAddress,
+            snapshotVwr,
+            votingAtSlot,
+            voterWeight,
+          },
+        },
+      ]);
+
+      await expect(
+        program.account.voterWeightRecord.fetch(snapshotVwr)
+      ).resolves.toStrictEqual({
+        realm: realmTester.realmAddress,
+        governingTokenMint: rootTester.governingTokenMint,
+        governingTokenOwner: clanTester.clan.voterAuthority,
+        voterWeight,
+        voterWeightExpiry: null,
+        weightAction: {castVote: {}},
+        weightActionTarget: proposalTester.proposalAddress,
+        reserved: [0, 0, 0, 0, 0, 0, 0, 0],
+      });
+    }
+  );
+});
@@ -1,11 +1,13 @@
 import {startTest} from '../../dev/startTest';
 import {
   ConfigureClanTestData,
+  RealmTester,
   parseLogsEvent,
   configureClanTestData,
 } from '../../src';
-import {ClanTester} from '../../src/VoteAggregator';
+import {ClanTester, RootTester} from '../../src/VoteAggregator';
 import {Keypair, PublicKey} from '@solana/web3.js';
+import {BN} from '@coral-xyz/anchor';
 
 describe('Configure clan instructions', () => {
   it.each(
@@ -14,13 +16,27 @@ describe('Configure clan instructions', () => {
     )
   )(
     'Sets the delegate',
-    async ({clan, clanAuthority, newDelegate}: ConfigureClanTestData) => {
-      const clanAccount = ClanTester.clanAccount(clan);
+    async ({
+      realm,
+      root,
+      clan,
+      clanAuthority,
+      newDelegate,
+    }: ConfigureClanTestData) => {
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const clanAccount = ClanTester.clanAccount({
+        ...clan,
+        root: rootTester.rootAddress[0],
+      });
       const {testContext, program} = await startTest({
-        splGovernanceId: new PublicKey(
-          'BdhNf6wxdcDudYT4KD4uft3nvJah1LNx7QzGKMuGoSgX'
-        ), // some dummy key
+        splGovernanceId: rootTester.splGovernanceId,
         accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
           await ClanTester.encodeClanAccount({
             address: clan.address,
             clan: clanAccount,
@@ -49,20 +65,28 @@ describe('Configure clan instructions', () => {
         .accountsStrict({
           clan: clan.address,
           clanAuthority: clanAuthority.publicKey,
+          siblingClan: null,
+          root: rootTester.rootAddress[0],
         })
         .transaction();
       tx.recentBlockhash = testContext.lastBlockhash;
       tx.feePayer = testContext.payer.publicKey;
       tx.sign(testContext.payer, clanAuthority);
 
+      const clock = await testContext.banksClient.getClock();
+      const isChanged = !clanAccount.delegate.equals(newDelegate!);
+      const controlChangedAt = isChanged
+        ? new BN(clock.unixTimestamp.toString())
+        : clanAccount.controlChangedAt;
       const events = [];
-      if (!clanAccount.delegate.equals(newDelegate!)) {
+      if (isChanged) {
         events.push({
           name: 'ClanDelegateChanged',
           data: {
             clan: clan.address,
             oldDelegate: clanAccount.delegate,
             newDelegate,
+            controlChangedAt,
           },
         });
       }
@@ -77,6 +101,7 @@ describe('Configure clan instructions', () => {
       ).resolves.toStrictEqual({
         ...clanAccount,
         delegate: newDelegate!,
+        controlChangedAt,
       });
     }
   );
@@ -87,13 +112,27 @@ describe('Configure clan instructions', () => {
     )
   )(
     'Sets the name',
-    async ({clan, clanAuthority, newName}: ConfigureClanTestData) => {
-      const clanAccount = ClanTester.clanAccount(clan);
+    async ({
+      realm,
+      root,
+      clan,
+      clanAuthority,
+      newName,
+    }: ConfigureClanTestData) => {
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const clanAccount = ClanTester.clanAccount({
+        ...clan,
+        root: rootTester.rootAddress[0],
+      });
       const {testContext, program} = await startTest({
-        splGovernanceId: new PublicKey(
-          'BdhNf6wxdcDudYT4KD4uft3nvJah1LNx7QzGKMuGoSgX'
-        ), // some dummy key
+        splGovernanceId: rootTester.splGovernanceId,
         accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
           await ClanTester.encodeClanAccount({
             address: clan.address,
             clan: clanAccount,
@@ -122,6 +161,8 @@ describe('Configure clan instructions', () => {
         .accountsStrict({
           clan: clan.address,
           clanAuthority: clanAuthority.publicKey,
+          siblingClan: null,
+          root: rootTester.rootAddress[0],
         })
         .transaction();
       tx.recentBlockhash = testContext.lastBlockhash;
@@ -162,13 +203,27 @@ describe('Configure clan instructions', () => {
     )
   )(
     'Sets the description',
-    async ({clan, clanAuthority, newDescription}: ConfigureClanTestData) => {
-      const clanAccount = ClanTester.clanAccount(clan);
+    async ({
+      realm,
+      root,
+      clan,
+      clanAuthority,
+      newDescription,
+    }: ConfigureClanTestData) => {
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const clanAccount = ClanTester.clanAccount({
+        ...clan,
+        root: rootTester.rootAddress[0],
+      });
       const {testContext, program} = await startTest({
-        splGovernanceId: new PublicKey(
-          'BdhNf6wxdcDudYT4KD4uft3nvJah1LNx7QzGKMuGoSgX'
-        ), // some dummy key
+        splGovernanceId: rootTester.splGovernanceId,
         accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
           await ClanTester.encodeClanAccount({
             address: clan.address,
             clan: clanAccount,
@@ -197,6 +252,8 @@ describe('Configure clan instructions', () => {
         .accountsStrict({
           clan: clan.address,
           clanAuthority: clanAuthority.publicKey,
+          siblingClan: null,
+          root: rootTester.rootAddress[0],
         })
         .transaction();
       tx.recentBlockhash = testContext.lastBlockhash;
@@ -238,16 +295,26 @@ describe('Configure clan instructions', () => {
   )(
     'Sets minVotingWeightToJoin',
     async ({
+      realm,
+      root,
       clan,
       clanAuthority,
       newMinVotingWeightToJoin,
     }: ConfigureClanTestData) => {
-      const clanAccount = ClanTester.clanAccount(clan);
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const clanAccount = ClanTester.clanAccount({
+        ...clan,
+        root: rootTester.rootAddress[0],
+      });
       const {testContext, program} = await startTest({
-        splGovernanceId: new PublicKey(
-          'BdhNf6wxdcDudYT4KD4uft3nvJah1LNx7QzGKMuGoSgX'
-        ), // some dummy key
+        splGovernanceId: rootTester.splGovernanceId,
         accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
           await ClanTester.encodeClanAccount({
             address: clan.address,
             clan: clanAccount,
@@ -276,6 +343,8 @@ describe('Configure clan instructions', () => {
         .accountsStrict({
           clan: clan.address,
           clanAuthority: clanAuthority.publicKey,
+          siblingClan: null,
+          root: rootTester.rootAddress[0],
         })
         .transaction();
       tx.recentBlockhash = testContext.lastBlockhash;
@@ -317,16 +386,26 @@ describe('Configure clan instructions', () => {
   )(
     'Sets AcceptTemporaryMembers',
     async ({
+      realm,
+      root,
       clan,
       clanAuthority,
       newAcceptTemporaryMembers,
     }: ConfigureClanTestData) => {
-      const clanAccount = ClanTester.clanAccount(clan);
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const clanAccount = ClanTester.clanAccount({
+        ...clan,
+        root: rootTester.rootAddress[0],
+      });
       const {testContext, program} = await startTest({
-        splGovernanceId: new PublicKey(
-          'BdhNf6wxdcDudYT4KD4uft3nvJah1LNx7QzGKMuGoSgX'
-        ), // some dummy key
+        splGovernanceId: rootTester.splGovernanceId,
         accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
           await ClanTester.encodeClanAccount({
             address: clan.address,
             clan: clanAccount,
@@ -355,6 +434,8 @@ describe('Configure clan instructions', () => {
         .accountsStrict({
           clan: clan.address,
           clanAuthority: clanAuthority.publicKey,
+          siblingClan: null,
+          root: rootTester.rootAddress[0],
         })
         .transaction();
       tx.recentBlockhash = testContext.lastBlockhash;
@@ -387,4 +468,310 @@ describe('Configure clan instructions', () => {
       });
     }
   );
+
+  it.each(
+    configureClanTestData.filter(
+      ({error, newSnapshotVoting}) =>
+        !error && newSnapshotVoting !== undefined
+    )
+  )(
+    'Sets the snapshot voting',
+    async ({
+      realm,
+      root,
+      clan,
+      clanAuthority,
+      newSnapshotVoting,
+    }: ConfigureClanTestData) => {
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const clanAccount = ClanTester.clanAccount({
+        ...clan,
+        root: rootTester.rootAddress[0],
+      });
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          await ClanTester.encodeClanAccount({
+            address: clan.address,
+            clan: clanAccount,
+            size: clan.size,
+            voteAggregatorId: new PublicKey(
+              'VoTaGDreyne7jk59uwbgRRbaAzxvNbyNipaJMrRXhjT'
+            ),
+          }),
+        ],
+      });
+
+      if (clanAuthority === 'owner') {
+        if (!(clan.owner instanceof Keypair)) {
+          throw new Error('Clan owner is not a keypair');
+        }
+        clanAuthority = clan.owner;
+      } else if (clanAuthority === 'delegate') {
+        if (!(clan.delegate instanceof Keypair)) {
+          throw new Error('Clan delegate is not a keypair');
+        }
+        clanAuthority = clan.delegate;
+      }
+
+      const tx = await program.methods
+        .setClanSnapshotVoting(newSnapshotVoting!)
+        .accountsStrict({
+          clan: clan.address,
+          clanAuthority: clanAuthority.publicKey,
+          siblingClan: null,
+          root: rootTester.rootAddress[0],
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer, clanAuthority);
+
+      const events = [];
+      if (clanAccount.snapshotVoting !== newSnapshotVoting) {
+        events.push({
+          name: 'ClanSnapshotVotingChanged',
+          data: {
+            clan: clan.address,
+            snapshotVoting: newSnapshotVoting,
+          },
+        });
+      }
+
+      await expect(
+        testContext.banksClient
+          .processTransaction(tx)
+          .then(meta => parseLogsEvent(program, meta.logMessages))
+      ).resolves.toStrictEqual(events);
+
+      await expect(
+        program.account.clan.fetch(clan.address)
+      ).resolves.toStrictEqual({
+        ...clanAccount,
+        snapshotVoting: newSnapshotVoting!,
+      });
+    }
+  );
+
+  it.each(
+    configureClanTestData.filter(
+      ({error, newName}) => error && newName !== undefined
+    )
+  )(
+    'Fails to set the name with $error',
+    async ({
+      realm,
+      root,
+      clan,
+      clanAuthority,
+      newName,
+      error,
+    }: ConfigureClanTestData) => {
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const clanAccount = ClanTester.clanAccount({
+        ...clan,
+        root: rootTester.rootAddress[0],
+      });
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          await ClanTester.encodeClanAccount({
+            address: clan.address,
+            clan: clanAccount,
+            size: clan.size,
+            voteAggregatorId: new PublicKey(
+              'VoTaGDreyne7jk59uwbgRRbaAzxvNbyNipaJMrRXhjT'
+            ),
+          }),
+        ],
+      });
+
+      if (clanAuthority === 'owner') {
+        if (!(clan.owner instanceof Keypair)) {
+          throw new Error('Clan owner is not a keypair');
+        }
+        clanAuthority = clan.owner;
+      } else if (clanAuthority === 'delegate') {
+        if (!(clan.delegate instanceof Keypair)) {
+          throw new Error('Clan delegate is not a keypair');
+        }
+        clanAuthority = clan.delegate;
+      }
+
+      const tx = await program.methods
+        .setClanName(newName!)
+        .accountsStrict({
+          clan: clan.address,
+          clanAuthority: clanAuthority.publicKey,
+          siblingClan: null,
+          root: rootTester.rootAddress[0],
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer, clanAuthority);
+
+      const result = await testContext.banksClient.tryProcessTransaction(tx);
+      expect(result.result).not.toBeNull();
+      expect(result.meta?.logMessages).toContainEqual(
+        expect.stringContaining(`Error Code: ${error}.`)
+      );
+    }
+  );
+
+  it.each(
+    configureClanTestData.filter(
+      ({error, newSnapshotVoting}) => error && newSnapshotVoting !== undefined
+    )
+  )(
+    'Fails to set the snapshot voting with $error',
+    async ({
+      realm,
+      root,
+      clan,
+      clanAuthority,
+      newSnapshotVoting,
+      error,
+    }: ConfigureClanTestData) => {
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const clanAccount = ClanTester.clanAccount({
+        ...clan,
+        root: rootTester.rootAddress[0],
+      });
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          await ClanTester.encodeClanAccount({
+            address: clan.address,
+            clan: clanAccount,
+            size: clan.size,
+            voteAggregatorId: new PublicKey(
+              'VoTaGDreyne7jk59uwbgRRbaAzxvNbyNipaJMrRXhjT'
+            ),
+          }),
+        ],
+      });
+
+      if (clanAuthority === 'owner') {
+        if (!(clan.owner instanceof Keypair)) {
+          throw new Error('Clan owner is not a keypair');
+        }
+        clanAuthority = clan.owner;
+      } else if (clanAuthority === 'delegate') {
+        if (!(clan.delegate instanceof Keypair)) {
+          throw new Error('Clan delegate is not a keypair');
+        }
+        clanAuthority = clan.delegate;
+      }
+
+      const tx = await program.methods
+        .setClanSnapshotVoting(newSnapshotVoting!)
+        .accountsStrict({
+          clan: clan.address,
+          clanAuthority: clanAuthority.publicKey,
+          siblingClan: null,
+          root: rootTester.rootAddress[0],
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer, clanAuthority);
+
+      const result = await testContext.banksClient.tryProcessTransaction(tx);
+      expect(result.result).not.toBeNull();
+      expect(result.meta?.logMessages).toContainEqual(
+        expect.stringContaining(`Error Code: ${error}.`)
+      );
+    }
+  );
+
+  it.each(
+    configureClanTestData.filter(
+      ({error, newTreasuryPolicy}) => error && newTreasuryPolicy !== undefined
+    )
+  )(
+    'Fails to set the treasury policy with $error',
+    async ({
+      realm,
+      root,
+      clan,
+      clanAuthority,
+      newTreasuryPolicy,
+      error,
+    }: ConfigureClanTestData) => {
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const clanAccount = ClanTester.clanAccount({
+        ...clan,
+        root: rootTester.rootAddress[0],
+      });
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          await ClanTester.encodeClanAccount({
+            address: clan.address,
+            clan: clanAccount,
+            size: clan.size,
+            voteAggregatorId: new PublicKey(
+              'VoTaGDreyne7jk59uwbgRRbaAzxvNbyNipaJMrRXhjT'
+            ),
+          }),
+        ],
+      });
+
+      if (clanAuthority === 'owner') {
+        if (!(clan.owner instanceof Keypair)) {
+          throw new Error('Clan owner is not a keypair');
+        }
+        clanAuthority = clan.owner;
+      } else if (clanAuthority === 'delegate') {
+        if (!(clan.delegate instanceof Keypair)) {
+          throw new Error('Clan delegate is not a keypair');
+        }
+        clanAuthority = clan.delegate;
+      }
+
+      const tx = await program.methods
+        .setClanTreasuryPolicy(newTreasuryPolicy!)
+        .accountsStrict({
+          clan: clan.address,
+          clanAuthority: clanAuthority.publicKey,
+          siblingClan: null,
+          root: rootTester.rootAddress[0],
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer, clanAuthority);
+
+      const result = await testContext.banksClient.tryProcessTransaction(tx);
+      expect(result.result).not.toBeNull();
+      expect(result.meta?.logMessages).toContainEqual(
+        expect.stringContaining(`Error Code: ${error}.`)
+      );
+    }
+  );
 });
@@ -56,6 +56,10 @@ describe('create_clan instruction', () => {
         ],
         program.programId
       );
+      const [, treasuryBump] = PublicKey.findProgramAddressSync(
+        [Buffer.from('treasury', 'utf-8'), clan.address.publicKey.toBuffer()],
+        program.programId
+      );
 
       const tx = await program.methods
         .createClan(clan.owner)
@@ -92,6 +96,7 @@ describe('create_clan instruction', () => {
         },
       ]);
 
+      const clock = await testContext.banksClient.getClock();
       await expect(
         program.account.clan.fetch(clan.address.publicKey)
       ).resolves.toStrictEqual({
@@ -106,6 +111,8 @@ describe('create_clan instruction', () => {
           voterAuthority: voterAuthorityBump,
           tokenOwnerRecord: clanTorBump,
           voterWeightRecord: clanVwrBump,
+          rewardVault: 0,
+          treasury: treasuryBump,
         },
         permanentMembers: new BN(0),
         temporaryMembers: new BN(0),
@@ -114,6 +121,41 @@ describe('create_clan instruction', () => {
         permanentVoterWeight: new BN(0),
         nextVoterWeightResetTime:
           rootTester.root.voterWeightReset?.nextResetTime || null,
+        rescheduledVoterWeightResetTime: null,
+        decayingMembers: new BN(0),
+        decayingVoterWeight: new BN(0),
+        voterWeightDecayRateBp: new BN(0),
+        voterWeightDecayUpdatedAt: new BN(clock.unixTimestamp.toString()),
+        voterWeightDecayEnds: [],
+        frozen: false,
+        sibling: null,
+        rewardMint: null,
+        rewardWeight: new BN(0),
+        rewardPerWeight: new BN(0),
+        commissionBp: 0,
+        pendingCommission: null,
+        unclaimedCommission: new BN(0),
+        previousCommissionBp: 0,
+        commissionChangedRewardPerWeight: new BN(0),
+        voteHistory: null,
+        vetoVotesCast: new BN(0),
+        voteUpdates: new BN(0),
+        lastVotedAt: null,
+        warmingUpVoterWeight: new BN(0),
+        warmupEnds: [],
+        loyaltyCurve: null,
+        weightTransform: {linear: {}},
+        weightTransformEpoch: new BN(0),
+        unmigratedMembers: new BN(0),
+        weightCheckpoints: null,
+        snapshotVoting: false,
+        treasuryPolicy: null,
+        pendingTreasuryPolicy: null,
+        treasurySpent: new BN(0),
+        treasuryPeriodStart: new BN(0),
+        treasuryWithdrawals: new BN(0),
+        recallProposals: new BN(0),
+        controlChangedAt: null,
         acceptTemporaryMembers: true,
         name: '',
         description: '',
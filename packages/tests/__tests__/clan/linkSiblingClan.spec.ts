@@ -0,0 +1,218 @@
+import {startTest} from '../../dev/startTest';
+import {
+  LinkSiblingClanTestData,
+  RealmTester,
+  linkSiblingClanTestData,
+  parseLogsEvent,
+} from '../../src';
+import {ClanTester, RootTester} from '../../src/VoteAggregator';
+import {PublicKey} from '@solana/web3.js';
+
+const voteAggregatorId = new PublicKey(
+  'VoTaGDreyne7jk59uwbgRRbaAzxvNbyNipaJMrRXhjT'
+);
+
+const start = async ({realm, clan, siblingClan}: LinkSiblingClanTestData) => {
+  const realmTester = new RealmTester(realm);
+  const rootTester = new RootTester({
+    side: 'community',
+    realm: realmTester,
+  });
+  const siblingRootTester = new RootTester({
+    side: 'council',
+    realm: realmTester,
+  });
+  const clanAccount = ClanTester.clanAccount({
+    ...clan,
+    root: rootTester.rootAddress[0],
+  });
+  const siblingClanAccount = ClanTester.clanAccount({
+    ...siblingClan,
+    root: siblingRootTester.rootAddress[0],
+  });
+  const {testContext, program} = await startTest({
+    splGovernanceId: rootTester.splGovernanceId,
+    accounts: [
+      ...(await realmTester.accounts()),
+      ...(await rootTester.accounts()),
+      ...(await siblingRootTester.accounts()),
+      await ClanTester.encodeClanAccount({
+        address: clan.address,
+        clan: clanAccount,
+        size: clan.size,
+        voteAggregatorId,
+      }),
+      await ClanTester.encodeClanAccount({
+        address: siblingClan.address,
+        clan: siblingClanAccount,
+        size: siblingClan.size,
+        voteAggregatorId,
+      }),
+    ],
+  });
+  return {
+    testContext,
+    program,
+    rootTester,
+    siblingRootTester,
+    clanAccount,
+    siblingClanAccount,
+  };
+};
+
+describe('link_sibling_clan and unlink_sibling_clan instructions', () => {
+  it.each(linkSiblingClanTestData.filter(({error}) => !error))(
+    'Works',
+    async (data: LinkSiblingClanTestData) => {
+      const {clan, siblingClan, unlink} = data;
+      const {
+        testContext,
+        program,
+        rootTester,
+        siblingRootTester,
+        clanAccount,
+        siblingClanAccount,
+      } = await start(data);
+
+      const tx = await (
+        unlink
+          ? program.methods.unlinkSiblingClan()
+          : program.methods.linkSiblingClan()
+      )
+        .accountsStrict({
+          root: rootTester.rootAddress[0],
+          siblingRoot: siblingRootTester.rootAddress[0],
+          clan: clan.address,
+          siblingClan: siblingClan.address,
+          owner: clan.owner.publicKey,
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer, clan.owner);
+
+      await expect(
+        testContext.banksClient
+          .processTransaction(tx)
+          .then(meta => parseLogsEvent(program, meta.logMessages))
+      ).resolves.toStrictEqual([
+        {
+          name: unlink ? 'ClanSiblingUnlinked' : 'ClanSiblingLinked',
+          data: {
+            clan: clan.address,
+            siblingClan: siblingClan.address,
+          },
+        },
+      ]);
+
+      await expect(
+        program.account.clan.fetch(clan.address)
+      ).resolves.toStrictEqual({
+        ...clanAccount,
+        sibling: unlink ? null : siblingClan.address,
+      });
+      await expect(
+        program.account.clan.fetch(siblingClan.address)
+      ).resolves.toStrictEqual({
+        ...siblingClanAccount,
+        sibling: unlink ? null : clan.address,
+      });
+    }
+  );
+
+  it.each(linkSiblingClanTestData.filter(({error}) => error))(
+    'Fails with $error',
+    async (data: LinkSiblingClanTestData) => {
+      const {clan, siblingClan, unlink, error} = data;
+      const {testContext, program, rootTester, siblingRootTester} =
+        await start(data);
+
+      const tx = await (
+        unlink
+          ? program.methods.unlinkSiblingClan()
+          : program.methods.linkSiblingClan()
+      )
+        .accountsStrict({
+          root: rootTester.rootAddress[0],
+          siblingRoot: siblingRootTester.rootAddress[0],
+          clan: clan.address,
+          siblingClan: siblingClan.address,
+          owner: clan.owner.publicKey,
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer, clan.owner);
+
+      const result = await testContext.banksClient.tryProcessTransaction(tx);
+      expect(result.result).not.toBeNull();
+      expect(result.meta?.logMessages).toContainEqual(
+        expect.stringContaining(`Error Code: ${error}.`)
+      );
+    }
+  );
+
+  it.each(linkSiblingClanTestData.filter(({newName}) => newName))(
+    'Applies set_clan_name to the linked sibling',
+    async (data: LinkSiblingClanTestData) => {
+      const {clan, siblingClan} = data;
+      const newName = data.newName!;
+      const {
+        testContext,
+        program,
+        rootTester,
+        clanAccount,
+        siblingClanAccount,
+      } = await start(data);
+
+      const tx = await program.methods
+        .setClanName(newName)
+        .accountsStrict({
+          clan: clan.address,
+          clanAuthority: clan.owner.publicKey,
+          siblingClan: siblingClan.address,
+          root: rootTester.rootAddress[0],
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer, clan.owner);
+
+      await expect(
+        testContext.banksClient
+          .processTransaction(tx)
+          .then(meta => parseLogsEvent(program, meta.logMessages))
+      ).resolves.toStrictEqual([
+        {
+          name: 'ClanNameChanged',
+          data: {
+            clan: clan.address,
+            oldName: clan.name,
+            newName,
+          },
+        },
+        {
+          name: 'ClanNameChanged',
+          data: {
+            clan: siblingClan.address,
+            oldName: siblingClan.name,
+            newName,
+          },
+        },
+      ]);
+
+      await expect(
+        program.account.clan.fetch(clan.address)
+      ).resolves.toStrictEqual({
+        ...clanAccount,
+        name: newName,
+      });
+      await expect(
+        program.account.clan.fetch(siblingClan.address)
+      ).resolves.toStrictEqual({
+        ...siblingClanAccount,
+        name: newName,
+      });
+    }
+  );
+});
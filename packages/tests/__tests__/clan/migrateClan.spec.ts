@@ -0,0 +1,162 @@
+import {startTest} from '../../dev/startTest';
+import {BorshAccountsCoder} from '@coral-xyz/anchor';
+import {SystemProgram} from '@solana/web3.js';
+import {
+  MigrateClanTestData,
+  RealmTester,
+  getMinimumBalanceForRentExemption,
+  migrateClanTestData,
+  parseLogsEvent,
+} from '../../src';
+import {ClanAccount, ClanTester, RootTester} from '../../src/VoteAggregator';
+import {AddedAccount} from 'solana-bankrun';
+import BN from 'bn.js';
+
+const u64 = (value: BN) => value.toArrayLike(Buffer, 'le', 8);
+
+const option = (value: BN | null) =>
+  value ? Buffer.concat([Buffer.from([1]), u64(value)]) : Buffer.from([0]);
+
+const string = (value: string) => {
+  const length = Buffer.alloc(4);
+  length.writeUInt32LE(Buffer.byteLength(value));
+  return Buffer.concat([length, Buffer.from(value, 'utf-8')]);
+};
+
+// The clan layout before the voter weight decay
+const legacyClanAccount = (clanTester: ClanTester): AddedAccount => {
+  const clan: ClanAccount = clanTester.clan;
+  const data = Buffer.concat([
+    BorshAccountsCoder.accountDiscriminator('Clan'),
+    clan.root.toBuffer(),
+    clan.owner.toBuffer(),
+    clan.delegate.toBuffer(),
+    clan.voterAuthority.toBuffer(),
+    clan.tokenOwnerRecord.toBuffer(),
+    clan.voterWeightRecord.toBuffer(),
+    u64(clan.minVotingWeightToJoin),
+    u64(clan.permanentMembers),
+    u64(clan.temporaryMembers),
+    u64(clan.updatedTemporaryMembers),
+    u64(clan.leavingMembers),
+    Buffer.from([clan.acceptTemporaryMembers ? 1 : 0]),
+    u64(clan.permanentVoterWeight),
+    option(clan.nextVoterWeightResetTime),
+    string(clan.name),
+    string(clan.description),
+    Buffer.from([
+      clan.bumps.voterAuthority,
+      clan.bumps.tokenOwnerRecord,
+      clan.bumps.voterWeightRecord,
+    ]),
+  ]);
+  return {
+    address: clanTester.clanAddress,
+    info: {
+      executable: false,
+      owner: clanTester.root.voteAggregatorId,
+      lamports: getMinimumBalanceForRentExemption(data.length),
+      data,
+    },
+  };
+};
+
+describe('migrate_clan instruction', () => {
+  it.each(migrateClanTestData.filter(({error}) => !error))(
+    'Works',
+    async ({realm, root, clan}: MigrateClanTestData) => {
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const clanTester = new ClanTester({...clan, root: rootTester});
+
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          legacyClanAccount(clanTester),
+        ],
+      });
+
+      const tx = await program.methods
+        .migrateClan()
+        .accountsStrict({
+          clan: clanTester.clanAddress,
+          payer: testContext.payer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer);
+
+      await expect(
+        testContext.banksClient
+          .processTransaction(tx)
+          .then(meta => parseLogsEvent(program, meta.logMessages))
+      ).resolves.toStrictEqual([
+        {
+          name: 'ClanMigrated',
+          data: {
+            clan: clanTester.clanAddress,
+            root: rootTester.rootAddress[0],
+            owner: clanTester.clan.owner,
+          },
+        },
+      ]);
+
+      // The decay, warmup, rewards and treasury start from scratch
+      const clock = await testContext.banksClient.getClock();
+      clanTester.clan.voterWeightDecayUpdatedAt = new BN(
+        clock.unixTimestamp.toString()
+      );
+      await expect(
+        program.account.clan.fetch(clanTester.clanAddress)
+      ).resolves.toStrictEqual(clanTester.clan);
+    }
+  );
+
+  it.each(migrateClanTestData.filter(({error}) => error))(
+    'Fails with $error',
+    async ({realm, root, clan, legacy, error}: MigrateClanTestData) => {
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const clanTester = new ClanTester({...clan, root: rootTester});
+
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          ...(legacy
+            ? [legacyClanAccount(clanTester)]
+            : await clanTester.accounts()),
+        ],
+      });
+
+      const tx = await program.methods
+        .migrateClan()
+        .accountsStrict({
+          clan: clanTester.clanAddress,
+          payer: testContext.payer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer);
+
+      const result = await testContext.banksClient.tryProcessTransaction(tx);
+      expect(result.result).not.toBeNull();
+      expect(result.meta?.logMessages).toContainEqual(
+        expect.stringContaining(`Error Code: ${error}.`)
+      );
+    }
+  );
+});
@@ -0,0 +1,111 @@
+import {startTest} from '../../dev/startTest';
+import {
+  RealmTester,
+  ReviseClanVwrTestData,
+  reviseClanVwrTestData,
+} from '../../src';
+import {ClanTester, RootTester} from '../../src/VoteAggregator';
+import BN from 'bn.js';
+
+describe('revise_clan_vwr instruction', () => {
+  it.each(reviseClanVwrTestData.filter(({error}) => !error))(
+    'Works',
+    async ({realm, root, clan, slot}: ReviseClanVwrTestData) => {
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const clanTester = new ClanTester({
+        ...clan,
+        root: rootTester,
+      });
+
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          ...(await clanTester.accounts()),
+        ],
+      });
+      if (slot !== undefined) {
+        testContext.warpToSlot(slot);
+      }
+
+      const tx = await program.methods
+        .reviseClanVwr()
+        .accountsStrict({
+          root: rootTester.rootAddress[0],
+          clan: clanTester.clanAddress,
+          clanVwr: clanTester.voterWeightAddress[0],
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer);
+
+      const clock = await testContext.banksClient.getClock();
+      await testContext.banksClient.processTransaction(tx);
+
+      // The expiring clan VWR is re-stamped with the current slot
+      await expect(
+        program.account.voterWeightRecord.fetch(
+          clanTester.voterWeightAddress[0]
+        )
+      ).resolves.toStrictEqual({
+        ...clanTester.voterWeightRecord,
+        voterWeightExpiry: new BN(clock.slot.toString()),
+      });
+
+      await expect(
+        program.account.clan.fetch(clanTester.clanAddress)
+      ).resolves.toStrictEqual(clanTester.clan);
+    }
+  );
+
+  it.each(reviseClanVwrTestData.filter(({error}) => error))(
+    'Fails with $error',
+    async ({realm, root, clan, slot, error}: ReviseClanVwrTestData) => {
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const clanTester = new ClanTester({
+        ...clan,
+        root: rootTester,
+      });
+
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          ...(await clanTester.accounts()),
+        ],
+      });
+      if (slot !== undefined) {
+        testContext.warpToSlot(slot);
+      }
+
+      const tx = await program.methods
+        .reviseClanVwr()
+        .accountsStrict({
+          root: rootTester.rootAddress[0],
+          clan: clanTester.clanAddress,
+          clanVwr: clanTester.voterWeightAddress[0],
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer);
+
+      const result = await testContext.banksClient.tryProcessTransaction(tx);
+      expect(result.result).not.toBeNull();
+      expect(result.meta?.logMessages).toContainEqual(
+        expect.stringContaining(`Error Code: ${error}.`)
+      );
+    }
+  );
+});
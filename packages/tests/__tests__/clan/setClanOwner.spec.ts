@@ -1,22 +1,38 @@
 import {startTest} from '../../dev/startTest';
 import {
+  RealmTester,
   SetClanOwnerTestData,
   parseLogsEvent,
   setClanOwnerTestData,
 } from '../../src';
-import {ClanTester} from '../../src/VoteAggregator';
+import {ClanTester, RootTester} from '../../src/VoteAggregator';
 import {Keypair, PublicKey} from '@solana/web3.js';
+import {BN} from '@coral-xyz/anchor';
 
 describe('set_clan_owner instruction', () => {
   it.each(setClanOwnerTestData.filter(({error}) => !error))(
     'Works',
-    async ({clan, clanAuthority, newOwner}: SetClanOwnerTestData) => {
-      const clanAccount = ClanTester.clanAccount(clan);
+    async ({
+      realm,
+      root,
+      clan,
+      clanAuthority,
+      newOwner,
+    }: SetClanOwnerTestData) => {
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const clanAccount = ClanTester.clanAccount({
+        ...clan,
+        root: rootTester.rootAddress[0],
+      });
       const {testContext, program} = await startTest({
-        splGovernanceId: new PublicKey(
-          'BdhNf6wxdcDudYT4KD4uft3nvJah1LNx7QzGKMuGoSgX'
-        ), // some dummy key
+        splGovernanceId: rootTester.splGovernanceId,
         accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
           await ClanTester.encodeClanAccount({
             address: clan.address,
             clan: clanAccount,
@@ -40,34 +56,114 @@ describe('set_clan_owner instruction', () => {
         .accountsStrict({
           clan: clan.address,
           owner: clanAuthority.publicKey,
+          siblingClan: null,
+          root: rootTester.rootAddress[0],
         })
         .transaction();
       tx.recentBlockhash = testContext.lastBlockhash;
       tx.feePayer = testContext.payer.publicKey;
       tx.sign(testContext.payer, clanAuthority);
 
+      const clock = await testContext.banksClient.getClock();
+      const controlChangedAt = new BN(clock.unixTimestamp.toString());
+      const oldOwner =
+        clan.owner instanceof Keypair ? clan.owner.publicKey : clan.owner;
+      const isChanged = !newOwner.equals(oldOwner);
       await expect(
         testContext.banksClient
           .processTransaction(tx)
           .then(meta => parseLogsEvent(program, meta.logMessages))
-      ).resolves.toStrictEqual([
-        {
-          name: 'ClanOwnerChanged',
-          data: {
-            clan: clan.address,
-            oldOwner:
-              clan.owner instanceof Keypair ? clan.owner.publicKey : clan.owner,
-            newOwner,
-          },
-        },
-      ]);
+      ).resolves.toStrictEqual(
+        isChanged
+          ? [
+              {
+                name: 'ClanOwnerChanged',
+                data: {
+                  clan: clan.address,
+                  oldOwner,
+                  newOwner,
+                  controlChangedAt,
+                },
+              },
+            ]
+          : []
+      );
 
+      // The same owner does not open the free exit window
       await expect(
         program.account.clan.fetch(clan.address)
-      ).resolves.toStrictEqual({
-        ...clanAccount,
-        owner: newOwner,
+      ).resolves.toStrictEqual(
+        isChanged
+          ? {
+              ...clanAccount,
+              owner: newOwner,
+              controlChangedAt,
+            }
+          : clanAccount
+      );
+    }
+  );
+
+  it.each(setClanOwnerTestData.filter(({error}) => error))(
+    'Fails with $error',
+    async ({
+      realm,
+      root,
+      clan,
+      clanAuthority,
+      newOwner,
+      error,
+    }: SetClanOwnerTestData) => {
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
       });
+      const clanAccount = ClanTester.clanAccount({
+        ...clan,
+        root: rootTester.rootAddress[0],
+      });
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          await ClanTester.encodeClanAccount({
+            address: clan.address,
+            clan: clanAccount,
+            size: clan.size,
+            voteAggregatorId: new PublicKey(
+              'VoTaGDreyne7jk59uwbgRRbaAzxvNbyNipaJMrRXhjT'
+            ),
+          }),
+        ],
+      });
+
+      if (clanAuthority === 'owner') {
+        if (!(clan.owner instanceof Keypair)) {
+          throw new Error('Clan owner is not a keypair');
+        }
+        clanAuthority = clan.owner;
+      }
+
+      const tx = await program.methods
+        .setClanOwner(newOwner)
+        .accountsStrict({
+          clan: clan.address,
+          owner: clanAuthority.publicKey,
+          siblingClan: null,
+          root: rootTester.rootAddress[0],
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer, clanAuthority);
+
+      const result = await testContext.banksClient.tryProcessTransaction(tx);
+      expect(result.result).not.toBeNull();
+      expect(result.meta?.logMessages).toContainEqual(
+        expect.stringContaining(`Error Code: ${error}.`)
+      );
     }
   );
 });
@@ -8,6 +8,7 @@ import {
 } from '../../src';
 import {ClanTester, RootTester} from '../../src/VoteAggregator';
 import {Keypair, PublicKey} from '@solana/web3.js';
+import {BN} from '@coral-xyz/anchor';
 
 describe('set_voting_delegate instruction', () => {
   it.each(setVotingDelegateTestData.filter(({error}) => !error))(
@@ -82,6 +83,7 @@ describe('set_voting_delegate instruction', () => {
       tx.feePayer = testContext.payer.publicKey;
       tx.sign(testContext.payer, clanAuthority);
 
+      const clock = await testContext.banksClient.getClock();
       await expect(
         testContext.banksClient
           .processTransaction(tx)
@@ -93,6 +95,7 @@ describe('set_voting_delegate instruction', () => {
             clan: clanTester.clanAddress,
             newVotingDelegate: newVotingDelegate || PublicKey.default,
             oldVotingDelegate: clanTester.tokenOwnerRecord.governanceDelegate,
+            controlChangedAt: new BN(clock.unixTimestamp.toString()),
           },
         },
       ]);
@@ -0,0 +1,128 @@
+import {startTest} from '../../dev/startTest';
+import {RealmTester, UpdateClanTestData, updateClanTestData} from '../../src';
+import {ClanTester, RootTester} from '../../src/VoteAggregator';
+
+describe('update_clan instruction', () => {
+  it.each(updateClanTestData.filter(({error}) => !error))(
+    'Works',
+    async ({
+      realm,
+      root,
+      clan,
+      clanChanges,
+      resetVoterWeight,
+    }: UpdateClanTestData) => {
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const clanTester = new ClanTester({
+        ...clan,
+        root: rootTester,
+      });
+
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          ...(await clanTester.accounts()),
+        ],
+      });
+
+      const tx = await program.methods
+        .updateClan()
+        .accountsStrict({
+          root: rootTester.rootAddress[0],
+          clan: clanTester.clanAddress,
+          clanWvr: clanTester.voterWeightAddress[0],
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer);
+
+      await testContext.banksClient.processTransaction(tx);
+
+      await expect(
+        program.account.clan.fetch(clanTester.clanAddress)
+      ).resolves.toStrictEqual({
+        ...clanTester.clan,
+        ...clanChanges,
+      });
+
+      // The weight of the current period is only dropped by its reset
+      await expect(
+        program.account.voterWeightRecord.fetch(
+          clanTester.voterWeightAddress[0]
+        )
+      ).resolves.toStrictEqual(
+        resetVoterWeight
+          ? {
+              ...clanTester.voterWeightRecord,
+              voterWeight: resetVoterWeight,
+              voterWeightExpiry: null,
+            }
+          : clanTester.voterWeightRecord
+      );
+    }
+  );
+
+  it.each(updateClanTestData.filter(({error}) => error))(
+    'Fails with $error',
+    async ({
+      realm,
+      root,
+      clanRoot,
+      clan,
+      otherClan,
+      error,
+    }: UpdateClanTestData) => {
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const clanRootTester = clanRoot
+        ? new RootTester({...clanRoot, realm: realmTester})
+        : rootTester;
+      const clanTester = new ClanTester({
+        ...clan,
+        root: clanRootTester,
+      });
+      const otherClanTester = otherClan
+        ? new ClanTester({...otherClan, root: rootTester})
+        : undefined;
+
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          ...(clanRoot ? await clanRootTester.accounts() : []),
+          ...(await clanTester.accounts()),
+          ...(otherClanTester ? await otherClanTester.accounts() : []),
+        ],
+      });
+
+      const tx = await program.methods
+        .updateClan()
+        .accountsStrict({
+          root: rootTester.rootAddress[0],
+          clan: clanTester.clanAddress,
+          clanWvr: (otherClanTester || clanTester).voterWeightAddress[0],
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer);
+
+      const result = await testContext.banksClient.tryProcessTransaction(tx);
+      expect(result.result).not.toBeNull();
+      expect(result.meta?.logMessages).toContainEqual(
+        expect.stringContaining(`Error Code: ${error}.`)
+      );
+    }
+  );
+});
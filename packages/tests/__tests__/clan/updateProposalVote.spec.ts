@@ -91,7 +91,7 @@ describe('forced_cancel_proposal instruction', () => {
           clanTor,
           realm: realmTester.realmAddress,
           realmConfig: await realmTester.realmConfigId(),
-          governingTokenMint: proposalTester.proposal.governingTokenMint,
+          governingTokenMint: rootTester.governingTokenMint,
           systemProgram: SYSTEM_PROGRAM_ID,
           governance: governanceTester.governanceAddress,
           proposal: proposalTester.proposalAddress,
@@ -100,6 +100,7 @@ describe('forced_cancel_proposal instruction', () => {
           proposalOwnerRecord: proposalTester.proposal.tokenOwnerRecord,
           maxVwr: null, // TODO
           voteRecord: await voteTester.voteAddress(),
+          voteHistory: null,
         })
         .transaction();
       tx.recentBlockhash = testContext.lastBlockhash;
@@ -129,17 +130,34 @@ describe('forced_cancel_proposal instruction', () => {
       });
 
       await expect(
-        splGovernance.account.proposalV2.fetch(proposalTester.proposalAddress)
+        program.account.clan.fetch(clanTester.clanAddress)
       ).resolves.toMatchObject({
-        options: [
-          {
-            ...proposalTester.proposal.options[0],
-            voteWeight: proposalTester.proposal.options[0].voteWeight
-              .sub(voteTester.vote.voterWeight)
-              .add(clanTester.voterWeightRecord.voterWeight),
-          },
-        ],
+        voteUpdates: clanTester.clan.voteUpdates.addn(1),
       });
+
+      if ('veto' in voteTester.vote.vote) {
+        await expect(
+          splGovernance.account.proposalV2.fetch(proposalTester.proposalAddress)
+        ).resolves.toMatchObject({
+          options: proposalTester.proposal.options,
+          vetoVoteWeight: proposalTester.proposal.vetoVoteWeight
+            .sub(voteTester.vote.voterWeight)
+            .add(clanTester.voterWeightRecord.voterWeight),
+        });
+      } else {
+        await expect(
+          splGovernance.account.proposalV2.fetch(proposalTester.proposalAddress)
+        ).resolves.toMatchObject({
+          options: [
+            {
+              ...proposalTester.proposal.options[0],
+              voteWeight: proposalTester.proposal.options[0].voteWeight
+                .sub(voteTester.vote.voterWeight)
+                .add(clanTester.voterWeightRecord.voterWeight),
+            },
+          ],
+        });
+      }
     }
   );
 });
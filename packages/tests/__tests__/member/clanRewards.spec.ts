@@ -0,0 +1,269 @@
+import {startTest} from '../../dev/startTest';
+import {Keypair, PublicKey} from '@solana/web3.js';
+import {TOKEN_PROGRAM_ID} from '@solana/spl-token';
+import {
+  ClanRewardsTestData,
+  RealmTester,
+  clanRewardsTestData,
+  parseLogsEvent,
+  tokenAccount,
+  tokenAccountAmount,
+} from '../../src';
+import {ClanTester, MemberTester, RootTester} from '../../src/VoteAggregator';
+import BN from 'bn.js';
+
+const REWARD_PRECISION = new BN('1000000000000');
+
+const buildTesters = ({realm, root, member}: ClanRewardsTestData) => {
+  const realmTester = new RealmTester(realm);
+  const rootTester = new RootTester({
+    ...root,
+    realm: realmTester,
+  });
+  const memberTester = new MemberTester({
+    ...member,
+    root: rootTester,
+    membership: MemberTester.membershipTesters({
+      membership: member.membership || [],
+      root: rootTester,
+    }),
+  });
+  const clanTester = memberTester.membership[0].clan as ClanTester;
+  return {realmTester, rootTester, memberTester, clanTester};
+};
+
+const tokenAccounts = (
+  {depositSource, depositAmount, claimDestination}: ClanRewardsTestData,
+  clanTester: ClanTester,
+  depositor: PublicKey
+) => [
+  tokenAccount({
+    address: depositSource,
+    mint: clanTester.clan.rewardMint!,
+    owner: depositor,
+    amount: depositAmount,
+  }),
+  tokenAccount({
+    address: claimDestination,
+    mint: clanTester.clan.rewardMint!,
+    owner: PublicKey.default,
+    amount: new BN(0),
+  }),
+];
+
+describe('Clan rewards', () => {
+  it.each(clanRewardsTestData.filter(({error}) => !error))(
+    'Runs deposit_clan_rewards and claim_clan_rewards instructions',
+    async (testData: ClanRewardsTestData) => {
+      const {depositSource, depositAmount, claimDestination, member} =
+        testData;
+      const {realmTester, rootTester, memberTester, clanTester} =
+        buildTesters(testData);
+      const depositor = Keypair.generate();
+
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          ...(await memberTester.accounts()),
+          ...(await clanTester.accounts()),
+          ...tokenAccounts(testData, clanTester, depositor.publicKey),
+        ],
+      });
+
+      const depositTx = await program.methods
+        .depositClanRewards(depositAmount)
+        .accountsStrict({
+          clan: clanTester.clanAddress,
+          rewardVault: clanTester.rewardVaultAddress[0],
+          source: depositSource,
+          depositor: depositor.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .transaction();
+      depositTx.recentBlockhash = testContext.lastBlockhash;
+      depositTx.feePayer = testContext.payer.publicKey;
+      depositTx.sign(testContext.payer, depositor);
+
+      const rewardPerWeight = clanTester.clan.rewardPerWeight.add(
+        depositAmount.mul(REWARD_PRECISION).div(clanTester.clan.rewardWeight)
+      );
+      await expect(
+        testContext.banksClient
+          .processTransaction(depositTx)
+          .then(meta => parseLogsEvent(program, meta.logMessages))
+      ).resolves.toStrictEqual([
+        {
+          name: 'ClanRewardsDeposited',
+          data: {
+            clan: clanTester.clanAddress,
+            depositor: depositor.publicKey,
+            amount: depositAmount,
+            rewardPerWeight,
+          },
+        },
+      ]);
+
+      const claimTx = await program.methods
+        .claimClanRewards()
+        .accountsStrict({
+          member: memberTester.memberAddress[0],
+          memberAuthority: member.owner.publicKey,
+          clan: clanTester.clanAddress,
+          voterAuthority: clanTester.voterAuthority[0],
+          rewardVault: clanTester.rewardVaultAddress[0],
+          destination: claimDestination,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .transaction();
+      claimTx.recentBlockhash = testContext.lastBlockhash;
+      claimTx.feePayer = testContext.payer.publicKey;
+      claimTx.sign(testContext.payer, member.owner);
+
+      const entry = memberTester.member.membership[0];
+      const rewardsFor = (from: BN, to: BN) =>
+        to.sub(from).mul(entry.rewardWeight).div(REWARD_PRECISION);
+      // The deposit activates the commission increase past its notice period
+      const {pendingCommission} = clanTester.clan;
+      const commissionChange = pendingCommission
+        ? {
+            commissionBp: pendingCommission.commissionBp,
+            pendingCommission: null,
+            previousCommissionBp: clanTester.clan.commissionBp,
+            commissionChangedRewardPerWeight: clanTester.clan.rewardPerWeight,
+          }
+        : {};
+      const {
+        commissionBp,
+        previousCommissionBp,
+        commissionChangedRewardPerWeight,
+      } = {...clanTester.clan, ...commissionChange};
+      const changedAt = BN.min(
+        BN.max(commissionChangedRewardPerWeight, entry.rewardPerWeightPaid),
+        rewardPerWeight
+      );
+      const beforeChange = rewardsFor(entry.rewardPerWeightPaid, changedAt);
+      const afterChange = rewardsFor(changedAt, rewardPerWeight);
+      const commission = beforeChange
+        .muln(Math.min(previousCommissionBp, commissionBp))
+        .divn(10000)
+        .add(afterChange.muln(commissionBp).divn(10000));
+      const amount = entry.unclaimedRewards
+        .add(beforeChange)
+        .add(afterChange)
+        .sub(commission);
+      await expect(
+        testContext.banksClient
+          .processTransaction(claimTx)
+          .then(meta => parseLogsEvent(program, meta.logMessages))
+      ).resolves.toStrictEqual([
+        {
+          name: 'ClanRewardsClaimed',
+          data: {
+            member: memberTester.memberAddress[0],
+            clan: clanTester.clanAddress,
+            owner: memberTester.ownerAddress,
+            amount,
+            commission,
+          },
+        },
+      ]);
+
+      await expect(
+        program.account.member.fetch(memberTester.memberAddress[0])
+      ).resolves.toStrictEqual({
+        ...memberTester.member,
+        membership: [
+          {
+            ...entry,
+            rewardPerWeightPaid: rewardPerWeight,
+            unclaimedRewards: new BN(0),
+          },
+        ],
+      });
+      await expect(
+        program.account.clan.fetch(clanTester.clanAddress)
+      ).resolves.toStrictEqual({
+        ...clanTester.clan,
+        ...commissionChange,
+        rewardPerWeight,
+        unclaimedCommission:
+          clanTester.clan.unclaimedCommission.add(commission),
+      });
+
+      const rewardVault = await testContext.banksClient.getAccount(
+        clanTester.rewardVaultAddress[0]
+      );
+      expect(tokenAccountAmount(rewardVault!.data)).toStrictEqual(
+        clanTester.rewardVaultAmount.add(depositAmount).sub(amount)
+      );
+      const destination =
+        await testContext.banksClient.getAccount(claimDestination);
+      expect(tokenAccountAmount(destination!.data)).toStrictEqual(amount);
+    }
+  );
+
+  it.each(clanRewardsTestData.filter(({error}) => error))(
+    'Fails with $error',
+    async (testData: ClanRewardsTestData) => {
+      const {
+        depositSource,
+        depositAmount,
+        claimDestination,
+        member,
+        memberAuthority,
+        error,
+      } = testData;
+      const {realmTester, rootTester, memberTester, clanTester} =
+        buildTesters(testData);
+      const depositor = Keypair.generate();
+      const authority = memberAuthority || member.owner;
+
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          ...(await memberTester.accounts()),
+          ...(await clanTester.accounts()),
+          ...tokenAccounts(testData, clanTester, depositor.publicKey),
+        ],
+      });
+
+      const tx = await program.methods
+        .depositClanRewards(depositAmount)
+        .accountsStrict({
+          clan: clanTester.clanAddress,
+          rewardVault: clanTester.rewardVaultAddress[0],
+          source: depositSource,
+          depositor: depositor.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .postInstructions([
+          await program.methods
+            .claimClanRewards()
+            .accountsStrict({
+              member: memberTester.memberAddress[0],
+              memberAuthority: authority.publicKey,
+              clan: clanTester.clanAddress,
+              voterAuthority: clanTester.voterAuthority[0],
+              rewardVault: clanTester.rewardVaultAddress[0],
+              destination: claimDestination,
+              tokenProgram: TOKEN_PROGRAM_ID,
+            })
+            .instruction(),
+        ])
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer, depositor, authority);
+
+      const result = await testContext.banksClient.tryProcessTransaction(tx);
+      expect(result.result).not.toBeNull();
+      expect(result.meta?.logMessages).toContainEqual(
+        expect.stringContaining(`Error Code: ${error}.`)
+      );
+    }
+  );
+});
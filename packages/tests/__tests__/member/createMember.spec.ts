@@ -91,6 +91,9 @@ describe('create_member instruction', () => {
         tokenOwnerRecord: memberTor,
         nextVoterWeightResetTime:
           rootTester.root.voterWeightReset?.nextResetTime || null,
+        voterWeightDecay: null,
+        transformedVoterWeight: new BN(0),
+        loyaltyBonusWeight: new BN(0),
         membership: [],
         bumps: {
           address: memberAddressBump,
@@ -109,4 +112,66 @@ describe('create_member instruction', () => {
       });
     }
   );
+
+  it.each(createMemberTestData.filter(({error}) => error))(
+    'Fails with $error',
+    async ({realm, root, member, error}: CreateMemberTestData) => {
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          await rootTester.realm.tokenOwnerRecord({
+            owner: member.owner.publicKey,
+            side: root.side,
+          }),
+        ],
+      });
+
+      const [memberAddress] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('member', 'utf-8'),
+          rootTester.rootAddress[0].toBuffer(),
+          member.owner.publicKey.toBuffer(),
+        ],
+        rootTester.voteAggregatorId
+      );
+
+      const [memberTor] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('governance', 'utf-8'),
+          rootTester.realm.realmAddress.toBuffer(),
+          rootTester.governingTokenMint.toBuffer(),
+          member.owner.publicKey.toBuffer(),
+        ],
+        rootTester.splGovernanceId
+      );
+
+      const tx = await program.methods
+        .createMember()
+        .accountsStrict({
+          root: rootTester.rootAddress[0],
+          member: memberAddress,
+          payer: program.provider.publicKey!,
+          systemProgram: SYSTEM_PROGRAM_ID,
+          memberTor,
+          owner: member.owner.publicKey,
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer, member.owner);
+
+      const result = await testContext.banksClient.tryProcessTransaction(tx);
+      expect(result.result).not.toBeNull();
+      expect(result.meta?.logMessages).toContainEqual(
+        expect.stringContaining(`Error Code: ${error}.`)
+      );
+    }
+  );
 });
@@ -0,0 +1,174 @@
+import {startTest} from '../../dev/startTest';
+import {
+  EvictStaleMemberTestData,
+  RealmTester,
+  evictStaleMemberTestData,
+  parseLogsEvent,
+} from '../../src';
+import {ClanTester, MemberTester, RootTester} from '../../src/VoteAggregator';
+import BN from 'bn.js';
+
+describe('evict_stale_member instruction', () => {
+  it.each(evictStaleMemberTestData.filter(({error}) => !error))(
+    'Works',
+    async ({realm, root, member}: EvictStaleMemberTestData) => {
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const memberTester = new MemberTester({
+        ...member,
+        root: rootTester,
+        membership: MemberTester.membershipTesters({
+          membership: member.membership || [],
+          root: rootTester,
+        }),
+      });
+      const clanTester = memberTester.membership[0].clan as ClanTester;
+
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          ...(await memberTester.accounts()),
+          ...(await clanTester.accounts()),
+        ],
+      });
+
+      const tx = await program.methods
+        .evictStaleMember()
+        .accountsStrict({
+          member: memberTester.memberAddress[0],
+          root: rootTester.rootAddress[0],
+          clan: clanTester.clanAddress,
+          clanVwr: clanTester.voterWeightAddress[0],
+          weightCheckpoints: null,
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer);
+
+      const clock = await testContext.banksClient.getClock();
+      const exitableAt = new BN(clock.unixTimestamp.toString()).add(
+        rootTester.root.maxProposalLifetime
+      );
+      // The stale weight was dropped by the clan reset before the eviction
+      const permanentVoterWeight = clanTester.clan.permanentVoterWeight;
+      await expect(
+        testContext.banksClient
+          .processTransaction(tx)
+          .then(meta => parseLogsEvent(program, meta.logMessages))
+      ).resolves.toStrictEqual([
+        {
+          name: 'ClanVoterWeightChanged',
+          data: {
+            clan: clanTester.clanAddress,
+            root: rootTester.rootAddress[0],
+            oldVoterWeight: permanentVoterWeight,
+            newVoterWeight: permanentVoterWeight,
+            oldPermamentVoterWeight: permanentVoterWeight,
+            newPermamentVoterWeight: permanentVoterWeight,
+            oldIsPermanent: true,
+            newIsPermanent: true,
+          },
+        },
+        {
+          name: 'StaleMemberEvicted',
+          data: {
+            member: memberTester.memberAddress[0],
+            clan: clanTester.clanAddress,
+            root: rootTester.rootAddress[0],
+            owner: memberTester.ownerAddress,
+            exitableAt,
+          },
+        },
+      ]);
+
+      await expect(
+        program.account.member.fetch(memberTester.memberAddress[0])
+      ).resolves.toStrictEqual({
+        ...memberTester.member,
+        membership: [{...memberTester.member.membership[0], exitableAt}],
+      });
+
+      await expect(
+        program.account.clan.fetch(clanTester.clanAddress)
+      ).resolves.toStrictEqual({
+        ...clanTester.clan,
+        nextVoterWeightResetTime: root.voterWeightReset!.nextResetTime,
+        ...(memberTester.member.voterWeightDecay
+          ? {
+              decayingMembers: clanTester.clan.decayingMembers.subn(1),
+              decayingVoterWeight: new BN(0),
+              voterWeightDecayRateBp: new BN(0),
+              voterWeightDecayEnds: [],
+            }
+          : {temporaryMembers: clanTester.clan.temporaryMembers.subn(1)}),
+        updatedTemporaryMembers: new BN(0),
+        leavingMembers: clanTester.clan.leavingMembers.addn(1),
+      });
+
+      await expect(
+        program.account.voterWeightRecord.fetch(
+          clanTester.voterWeightAddress[0]
+        )
+      ).resolves.toStrictEqual({
+        ...clanTester.voterWeightRecord,
+        voterWeight: permanentVoterWeight,
+      });
+    }
+  );
+
+  it.each(evictStaleMemberTestData.filter(({error}) => error))(
+    'Fails with $error',
+    async ({realm, root, member, error}: EvictStaleMemberTestData) => {
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const memberTester = new MemberTester({
+        ...member,
+        root: rootTester,
+        membership: MemberTester.membershipTesters({
+          membership: member.membership || [],
+          root: rootTester,
+        }),
+      });
+      const clanTester = memberTester.membership[0].clan as ClanTester;
+
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          ...(await memberTester.accounts()),
+          ...(await clanTester.accounts()),
+        ],
+      });
+
+      const tx = await program.methods
+        .evictStaleMember()
+        .accountsStrict({
+          member: memberTester.memberAddress[0],
+          root: rootTester.rootAddress[0],
+          clan: clanTester.clanAddress,
+          clanVwr: clanTester.voterWeightAddress[0],
+          weightCheckpoints: null,
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer);
+
+      const result = await testContext.banksClient.tryProcessTransaction(tx);
+      expect(result.result).not.toBeNull();
+      expect(result.meta?.logMessages).toContainEqual(
+        expect.stringContaining(`Error Code: ${error}.`)
+      );
+    }
+  );
+});
@@ -0,0 +1,296 @@
+import {startTest} from '../../dev/startTest';
+import {
+  EvictUnlockedMemberTestData,
+  RealmTester,
+  evictUnlockedMemberTestData,
+  joinClanTestData,
+  parseLogsEvent,
+} from '../../src';
+import {ClanTester, MemberTester, RootTester} from '../../src/VoteAggregator';
+import {Keypair, PublicKey, SystemProgram} from '@solana/web3.js';
+import {Clock} from 'solana-bankrun';
+import BN from 'bn.js';
+
+const buildTesters = ({
+  realm,
+  root,
+  member,
+  lockExpiry,
+}: EvictUnlockedMemberTestData) => {
+  const realmTester = new RealmTester(realm);
+  const rootTester = new RootTester({
+    ...root,
+    realm: realmTester,
+  });
+  const memberTester = new MemberTester({
+    ...member,
+    root: rootTester,
+    membership: MemberTester.membershipTesters({
+      membership: member.membership || [],
+      root: rootTester,
+    }),
+    locks:
+      lockExpiry === undefined
+        ? []
+        : [
+            {
+              lockType: 0,
+              authority: rootTester.lockAuthority[0],
+              expiry: lockExpiry,
+            },
+          ],
+  });
+  const clanTester = memberTester.membership[0].clan as ClanTester;
+  return {realmTester, rootTester, memberTester, clanTester};
+};
+
+describe('evict_unlocked_member instruction', () => {
+  it.each(evictUnlockedMemberTestData.filter(({error}) => !error))(
+    'Works',
+    async (data: EvictUnlockedMemberTestData) => {
+      const {realmTester, rootTester, memberTester, clanTester} =
+        buildTesters(data);
+
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          ...(await memberTester.accounts()),
+          ...(await clanTester.accounts()),
+        ],
+      });
+
+      const tx = await program.methods
+        .evictUnlockedMember()
+        .accountsStrict({
+          member: memberTester.memberAddress[0],
+          root: rootTester.rootAddress[0],
+          memberTor: memberTester.tokenOwnerRecordAddress[0],
+          lockAuthority: rootTester.lockAuthority[0],
+          clan: clanTester.clanAddress,
+          clanVwr: clanTester.voterWeightAddress[0],
+          weightCheckpoints: null,
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer);
+
+      const clock = await testContext.banksClient.getClock();
+      const exitableAt = new BN(clock.unixTimestamp.toString()).add(
+        rootTester.root.maxProposalLifetime
+      );
+      await expect(
+        testContext.banksClient
+          .processTransaction(tx)
+          .then(meta => parseLogsEvent(program, meta.logMessages))
+      ).resolves.toStrictEqual([
+        {
+          name: 'ClanVoterWeightChanged',
+          data: {
+            clan: clanTester.clanAddress,
+            root: rootTester.rootAddress[0],
+            oldVoterWeight: clanTester.voterWeightRecord.voterWeight,
+            newVoterWeight: clanTester.voterWeightRecord.voterWeight.sub(
+              memberTester.member.voterWeight
+            ),
+            oldPermamentVoterWeight: clanTester.clan.permanentVoterWeight,
+            newPermamentVoterWeight: clanTester.clan.permanentVoterWeight.sub(
+              memberTester.member.voterWeight
+            ),
+            oldIsPermanent: true,
+            newIsPermanent: true,
+          },
+        },
+        {
+          name: 'UnlockedMemberEvicted',
+          data: {
+            member: memberTester.memberAddress[0],
+            clan: clanTester.clanAddress,
+            root: rootTester.rootAddress[0],
+            owner: memberTester.ownerAddress,
+            exitableAt,
+          },
+        },
+      ]);
+
+      await expect(
+        program.account.member.fetch(memberTester.memberAddress[0])
+      ).resolves.toStrictEqual({
+        ...memberTester.member,
+        membership: [{...memberTester.member.membership[0], exitableAt}],
+      });
+
+      await expect(
+        program.account.clan.fetch(clanTester.clanAddress)
+      ).resolves.toStrictEqual({
+        ...clanTester.clan,
+        permanentMembers: clanTester.clan.permanentMembers.subn(1),
+        permanentVoterWeight: clanTester.clan.permanentVoterWeight.sub(
+          memberTester.member.voterWeight
+        ),
+        leavingMembers: clanTester.clan.leavingMembers.addn(1),
+      });
+
+      await expect(
+        program.account.voterWeightRecord.fetch(
+          clanTester.voterWeightAddress[0]
+        )
+      ).resolves.toStrictEqual({
+        ...clanTester.voterWeightRecord,
+        voterWeight: clanTester.voterWeightRecord.voterWeight.sub(
+          memberTester.member.voterWeight
+        ),
+      });
+    }
+  );
+
+  it.each(evictUnlockedMemberTestData.filter(({error}) => error))(
+    'Fails with $error',
+    async (data: EvictUnlockedMemberTestData) => {
+      const {realmTester, rootTester, memberTester, clanTester} =
+        buildTesters(data);
+
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          ...(await memberTester.accounts()),
+          ...(await clanTester.accounts()),
+        ],
+      });
+
+      const tx = await program.methods
+        .evictUnlockedMember()
+        .accountsStrict({
+          member: memberTester.memberAddress[0],
+          root: rootTester.rootAddress[0],
+          memberTor: memberTester.tokenOwnerRecordAddress[0],
+          lockAuthority: rootTester.lockAuthority[0],
+          clan: clanTester.clanAddress,
+          clanVwr: clanTester.voterWeightAddress[0],
+          weightCheckpoints: null,
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer);
+
+      const result = await testContext.banksClient.tryProcessTransaction(tx);
+      expect(result.result).not.toBeNull();
+      expect(result.meta?.logMessages).toContainEqual(
+        expect.stringContaining(`Error Code: ${data.error}.`)
+      );
+    }
+  );
+
+  it('Evicts the member once the join lock expires', async () => {
+    const {realm, root, member, memberVoterWeight, clan} = joinClanTestData[0];
+    const voteAggregatorId = new PublicKey(
+      'VoTaGDreyne7jk59uwbgRRbaAzxvNbyNipaJMrRXhjT'
+    );
+    const [rootAddress] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('root', 'utf-8'),
+        realm.realmAddress.toBuffer(),
+        realm.communityMint.toBuffer(),
+      ],
+      voteAggregatorId
+    );
+    const realmTester = new RealmTester({
+      ...realm,
+      communityTokenConfig: {
+        lockAuthorities: [
+          PublicKey.findProgramAddressSync(
+            [Buffer.from('lock-authority', 'utf8'), rootAddress.toBuffer()],
+            voteAggregatorId
+          )[0],
+        ],
+      },
+    });
+    const rootTester = new RootTester({...root, realm: realmTester});
+    const memberTester = new MemberTester({
+      ...member,
+      root: rootTester,
+      membership: [],
+    });
+    const clanTester = new ClanTester({...clan, root: rootTester});
+    const {testContext, program} = await startTest({
+      splGovernanceId: rootTester.splGovernanceId,
+      accounts: [
+        ...(await realmTester.accounts()),
+        ...(await rootTester.accounts()),
+        ...(await memberTester.accounts()),
+        ...(await clanTester.accounts()),
+        await realmTester.voterWeightRecord({
+          ...memberVoterWeight,
+          side: root.side,
+          owner: memberTester.ownerAddress,
+        }),
+      ],
+    });
+
+    const joinTx = await program.methods
+      .joinClan(10000)
+      .accountsStrict({
+        root: rootTester.rootAddress[0],
+        member: memberTester.memberAddress[0],
+        clan: clanTester.clanAddress,
+        memberAuthority: memberTester.ownerAddress,
+        clanVwr: clanTester.voterWeightAddress[0],
+        weightCheckpoints: null,
+        memberTor: memberTester.tokenOwnerRecordAddress[0],
+        memberVwr: memberVoterWeight.address,
+        maxVwr: rootTester.maxVoterWeightAddress[0],
+        realm: realmTester.realmAddress,
+        realmConfig: await realmTester.realmConfigId(),
+        lockAuthority: rootTester.lockAuthority[0],
+        payer: program.provider.publicKey!,
+        governanceProgram: rootTester.splGovernanceId,
+        systemProgram: SystemProgram.programId,
+      })
+      .transaction();
+    joinTx.recentBlockhash = testContext.lastBlockhash;
+    joinTx.feePayer = testContext.payer.publicKey;
+    joinTx.sign(testContext.payer, member.owner as Keypair);
+    await testContext.banksClient.processTransaction(joinTx);
+
+    // Nobody refreshes the lock
+    const clock = await testContext.banksClient.getClock();
+    testContext.setClock(
+      new Clock(
+        clock.slot,
+        clock.epochStartTimestamp,
+        clock.epoch,
+        clock.leaderScheduleEpoch,
+        clock.unixTimestamp +
+          BigInt(rootTester.root.memberLockDuration.toString()) +
+          BigInt(1)
+      )
+    );
+
+    const tx = await program.methods
+      .evictUnlockedMember()
+      .accountsStrict({
+        member: memberTester.memberAddress[0],
+        root: rootTester.rootAddress[0],
+        memberTor: memberTester.tokenOwnerRecordAddress[0],
+        lockAuthority: rootTester.lockAuthority[0],
+        clan: clanTester.clanAddress,
+        clanVwr: clanTester.voterWeightAddress[0],
+        weightCheckpoints: null,
+      })
+      .transaction();
+    tx.recentBlockhash = testContext.lastBlockhash;
+    tx.feePayer = testContext.payer.publicKey;
+    tx.sign(testContext.payer);
+    await testContext.banksClient.processTransaction(tx);
+
+    const {membership} = await program.account.member.fetch(
+      memberTester.memberAddress[0]
+    );
+    expect(membership[0].exitableAt).not.toBeNull();
+  });
+});
@@ -8,112 +8,160 @@ import {
 } from '../../src';
 import {ClanTester, MemberTester, RootTester} from '../../src/VoteAggregator';
 import {BN} from '@coral-xyz/anchor';
-import {Keypair, PublicKey} from '@solana/web3.js';
+import {Keypair, PublicKey, Transaction} from '@solana/web3.js';
 
-describe('exit_clan instruction', () => {
-  it.each(exitClanTestData.filter(({error}) => !error))(
-    'Works',
-    async ({
-      realm,
-      root,
-      member,
-      clanIndex = 0,
-      clanLeavingTimeOffset,
-    }: ExitClanTestData) => {
-      const tokenConfig =
-        (root.side === 'community'
-          ? realm.communityTokenConfig
-          : realm.councilTokenConfig) || {};
-      const voteAggregatorId =
-        root.voteAggregatorId ||
-        new PublicKey('VoTaGDreyne7jk59uwbgRRbaAzxvNbyNipaJMrRXhjT');
-      const [rootAddress] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from('root', 'utf-8'),
-          realm.realmAddress.toBuffer(),
-          root.side === 'community'
-            ? realm.communityMint.toBuffer()
-            : realm.councilMint!.toBuffer(),
-        ],
+const buildExitClan = async ({
+  realm,
+  root,
+  member,
+  clanIndex = 0,
+  clanLeavingTimeOffset,
+  controlChangedAtOffset,
+  clanTor,
+  flippedDelegate,
+}: ExitClanTestData) => {
+  const tokenConfig =
+    (root.side === 'community'
+      ? realm.communityTokenConfig
+      : realm.councilTokenConfig) || {};
+  const voteAggregatorId =
+    root.voteAggregatorId ||
+    new PublicKey('VoTaGDreyne7jk59uwbgRRbaAzxvNbyNipaJMrRXhjT');
+  const [rootAddress] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from('root', 'utf-8'),
+      realm.realmAddress.toBuffer(),
+      root.side === 'community'
+        ? realm.communityMint.toBuffer()
+        : realm.councilMint!.toBuffer(),
+    ],
+    voteAggregatorId
+  );
+  if (tokenConfig.lockAuthorities === undefined) {
+    tokenConfig.lockAuthorities = [
+      PublicKey.findProgramAddressSync(
+        [Buffer.from('lock-authority', 'utf8'), rootAddress.toBuffer()],
         voteAggregatorId
-      );
-      if (tokenConfig.lockAuthorities === undefined) {
-        tokenConfig.lockAuthorities = [
-          PublicKey.findProgramAddressSync(
-            [Buffer.from('lock-authority', 'utf8'), rootAddress.toBuffer()],
-            voteAggregatorId
-          )[0],
-        ];
-      }
-      if (root.side === 'community') {
-        realm.communityTokenConfig = tokenConfig;
-      } else {
-        realm.councilTokenConfig = tokenConfig;
-      }
+      )[0],
+    ];
+  }
+  if (root.side === 'community') {
+    realm.communityTokenConfig = tokenConfig;
+  } else {
+    realm.councilTokenConfig = tokenConfig;
+  }
 
-      const realmTester = new RealmTester(realm);
-      const rootTester = new RootTester({
-        ...root,
-        realm: realmTester,
-      });
-      if (member.locks === undefined) {
-        member.locks = [
-          {
-            lockType: 0,
-            authority: rootTester.lockAuthority[0],
-            expiry: null,
-          },
-        ];
-      }
-      const currentTime = new BN(Math.floor(Date.now() / 1000));
-      const membership = MemberTester.membershipTesters({
-        membership: member.membership || [],
-        root: rootTester,
-      });
-      membership[clanIndex].exitableAt ||= currentTime.add(
-        clanLeavingTimeOffset!
+  const realmTester = new RealmTester(realm);
+  const rootTester = new RootTester({
+    ...root,
+    realm: realmTester,
+  });
+  if (member.locks === undefined) {
+    member.locks = [
+      {
+        lockType: 0,
+        authority: rootTester.lockAuthority[0],
+        expiry: null,
+      },
+    ];
+  }
+  const currentTime = new BN(Math.floor(Date.now() / 1000));
+  const membership = MemberTester.membershipTesters({
+    membership: member.membership || [],
+    root: rootTester,
+  });
+  membership[clanIndex].exitableAt ||= currentTime.add(clanLeavingTimeOffset!);
+  const clanTester = membership[clanIndex].clan;
+  if (!(clanTester instanceof ClanTester)) {
+    throw new Error(`Clan #${clanIndex} must be provided`);
+  }
+  if (controlChangedAtOffset) {
+    clanTester.clan.controlChangedAt = currentTime.add(controlChangedAtOffset);
+  }
+  const memberTester = new MemberTester({
+    ...member,
+    root: rootTester,
+    membership,
+  });
+  const {testContext, program} = await startTest({
+    splGovernanceId: rootTester.splGovernanceId,
+    accounts: [
+      ...(await realmTester.accounts()),
+      ...(await rootTester.accounts()),
+      ...(await memberTester.accounts()),
+      ...(await clanTester.accounts()),
+    ],
+  });
+  const splGovernance = buildSplGovernanceProgram({
+    splGovernanceId: rootTester.splGovernanceId,
+    connection: program.provider.connection,
+  });
+
+  const tx = new Transaction();
+  const signers = [testContext.payer, member.owner as Keypair];
+  if (flippedDelegate) {
+    for (const delegate of [flippedDelegate, clanTester.clan.delegate]) {
+      tx.add(
+        await program.methods
+          .setClanDelegate(delegate)
+          .accountsStrict({
+            clan: clanTester.clanAddress,
+            clanAuthority: clanTester.clan.owner,
+            siblingClan: null,
+            root: rootTester.rootAddress[0],
+          })
+          .instruction()
       );
-      const memberTester = new MemberTester({
-        ...member,
-        root: rootTester,
-        membership,
-      });
-      const clanTester = membership[clanIndex].clan;
-      if (!(clanTester instanceof ClanTester)) {
-        throw new Error(`Clan #${clanIndex} must be provided`);
-      }
-      const {testContext, program} = await startTest({
-        splGovernanceId: rootTester.splGovernanceId,
-        accounts: [
-          ...(await realmTester.accounts()),
-          ...(await rootTester.accounts()),
-          ...(await memberTester.accounts()),
-          ...(await clanTester.accounts()),
-        ],
-      });
-      const splGovernance = buildSplGovernanceProgram({
-        splGovernanceId: rootTester.splGovernanceId,
-        connection: program.provider.connection,
-      });
+    }
+    signers.push(clanTester.owner!);
+  }
+  tx.add(
+    await program.methods
+      .exitClan()
+      .accountsStrict({
+        root: rootTester.rootAddress[0],
+        member: memberTester.memberAddress[0],
+        clan: clanTester.clanAddress,
+        memberAuthority: memberTester.ownerAddress,
+        governanceProgram: rootTester.splGovernanceId,
+        lockAuthority: rootTester.lockAuthority[0],
+        memberTor: memberTester.tokenOwnerRecordAddress[0],
+        clanTor: clanTor ? clanTester.tokenOwnerRecordAddress[0] : null,
+        realm: realmTester.realmAddress,
+        realmConfig: await realmTester.realmConfigId(),
+      })
+      .instruction()
+  );
+  tx.recentBlockhash = testContext.lastBlockhash;
+  tx.feePayer = testContext.payer.publicKey;
+  tx.sign(...signers);
 
-      const tx = await program.methods
-        .exitClan()
-        .accountsStrict({
-          root: rootTester.rootAddress[0],
-          member: memberTester.memberAddress[0],
-          clan: clanTester.clanAddress,
-          memberAuthority: memberTester.ownerAddress,
-          governanceProgram: rootTester.splGovernanceId,
-          lockAuthority: rootTester.lockAuthority[0],
-          memberTor: memberTester.tokenOwnerRecordAddress[0],
-          clanTor: null,
-          realm: realmTester.realmAddress,
-          realmConfig: await realmTester.realmConfigId(),
-        })
-        .transaction();
-      tx.recentBlockhash = testContext.lastBlockhash;
-      tx.feePayer = testContext.payer.publicKey;
-      tx.sign(testContext.payer, member.owner as Keypair);
+  return {
+    realmTester,
+    rootTester,
+    memberTester,
+    clanTester,
+    testContext,
+    program,
+    splGovernance,
+    tx,
+  };
+};
+
+describe('exit_clan instruction', () => {
+  it.each(exitClanTestData.filter(({error}) => !error))(
+    'Works',
+    async (testData: ExitClanTestData) => {
+      const {clanIndex = 0} = testData;
+      const {
+        rootTester,
+        memberTester,
+        clanTester,
+        testContext,
+        program,
+        splGovernance,
+        tx,
+      } = await buildExitClan(testData);
 
       await expect(
         testContext.banksClient
@@ -158,4 +206,17 @@ describe('exit_clan instruction', () => {
       });
     }
   );
+
+  it.each(exitClanTestData.filter(({error}) => error))(
+    'Fails with $error',
+    async (testData: ExitClanTestData) => {
+      const {testContext, tx} = await buildExitClan(testData);
+
+      const result = await testContext.banksClient.tryProcessTransaction(tx);
+      expect(result.result).not.toBeNull();
+      expect(result.meta?.logMessages).toContainEqual(
+        expect.stringContaining(`Error Code: ${testData.error}.`)
+      );
+    }
+  );
 });
@@ -0,0 +1,236 @@
+import {startTest} from '../../dev/startTest';
+import {
+  forceLeaveClanTestData,
+  RealmTester,
+  parseLogsEvent,
+  ForceLeaveClanTestData,
+  buildSplGovernanceProgram,
+} from '../../src';
+import {ClanTester, MemberTester, RootTester} from '../../src/VoteAggregator';
+import {Keypair, PublicKey, SystemProgram} from '@solana/web3.js';
+
+const buildTesters = ({realm, root, member}: ForceLeaveClanTestData) => {
+  const tokenConfig =
+    (root.side === 'community'
+      ? realm.communityTokenConfig
+      : realm.councilTokenConfig) || {};
+  const voteAggregatorId =
+    root.voteAggregatorId ||
+    new PublicKey('VoTaGDreyne7jk59uwbgRRbaAzxvNbyNipaJMrRXhjT');
+  const [rootAddress] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from('root', 'utf-8'),
+      realm.realmAddress.toBuffer(),
+      root.side === 'community'
+        ? realm.communityMint.toBuffer()
+        : realm.councilMint!.toBuffer(),
+    ],
+    voteAggregatorId
+  );
+  if (tokenConfig.lockAuthorities === undefined) {
+    tokenConfig.lockAuthorities = [
+      PublicKey.findProgramAddressSync(
+        [Buffer.from('lock-authority', 'utf8'), rootAddress.toBuffer()],
+        voteAggregatorId
+      )[0],
+    ];
+  }
+  if (root.side === 'community') {
+    realm.communityTokenConfig = tokenConfig;
+  } else {
+    realm.councilTokenConfig = tokenConfig;
+  }
+
+  const realmTester = new RealmTester(realm);
+  if (!(realmTester.authority instanceof Keypair)) {
+    throw new Error('Realm authority keypair is required');
+  }
+  const rootTester = new RootTester({
+    ...root,
+    realm: realmTester,
+  });
+  if (member.locks === undefined) {
+    member.locks = [
+      {
+        lockType: 0,
+        authority: rootTester.lockAuthority[0],
+        expiry: null,
+      },
+    ];
+  }
+  const memberTester = new MemberTester({
+    ...member,
+    root: rootTester,
+    membership: MemberTester.membershipTesters({
+      membership: member.membership || [],
+      root: rootTester,
+    }),
+  });
+  const clanTester = memberTester.membership[0].clan as ClanTester;
+  return {realmTester, rootTester, memberTester, clanTester};
+};
+
+describe('force_leave_clan instruction', () => {
+  it.each(forceLeaveClanTestData.filter(({error}) => !error))(
+    'Works',
+    async (data: ForceLeaveClanTestData) => {
+      const {realmTester, rootTester, memberTester, clanTester} =
+        buildTesters(data);
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          ...(await memberTester.accounts()),
+          ...(await clanTester.accounts()),
+        ],
+      });
+      const splGovernance = buildSplGovernanceProgram({
+        splGovernanceId: rootTester.splGovernanceId,
+        connection: program.provider.connection,
+      });
+
+      const tx = await program.methods
+        .forceLeaveClan(data.immediate, data.releaseLock, data.reason)
+        .accountsStrict({
+          configureRoot: {
+            root: rootTester.rootAddress[0],
+            realm: realmTester.realmAddress,
+            admin: realmTester.authorityAddress!,
+          },
+          member: memberTester.memberAddress[0],
+          clan: clanTester.clanAddress,
+          clanVwr: clanTester.voterWeightAddress[0],
+          weightCheckpoints: null,
+          memberTor: memberTester.tokenOwnerRecordAddress[0],
+          lockAuthority: rootTester.lockAuthority[0],
+          realmConfig: await realmTester.realmConfigId(),
+          payer: testContext.payer.publicKey,
+          systemProgram: SystemProgram.programId,
+          governanceProgram: rootTester.splGovernanceId,
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer, realmTester.authority as Keypair);
+
+      await expect(
+        testContext.banksClient
+          .processTransaction(tx)
+          .then(meta => parseLogsEvent(program, meta.logMessages))
+      ).resolves.toStrictEqual([
+        {
+          name: 'ClanVoterWeightChanged',
+          data: {
+            clan: clanTester.clanAddress,
+            root: rootTester.rootAddress[0],
+            oldVoterWeight: clanTester.voterWeightRecord.voterWeight,
+            newVoterWeight: clanTester.voterWeightRecord.voterWeight.sub(
+              memberTester.member.voterWeight
+            ),
+            oldPermamentVoterWeight: clanTester.clan.permanentVoterWeight,
+            newPermamentVoterWeight: clanTester.clan.permanentVoterWeight.sub(
+              memberTester.member.voterWeight
+            ),
+            oldIsPermanent: true,
+            newIsPermanent: true,
+          },
+        },
+        {
+          name: 'ClanMemberLeft',
+          data: {
+            member: memberTester.memberAddress[0],
+            clan: clanTester.clanAddress,
+            root: rootTester.rootAddress[0],
+            owner: memberTester.ownerAddress,
+          },
+        },
+        {
+          name: 'MemberForcedOut',
+          data: {
+            member: memberTester.memberAddress[0],
+            clan: clanTester.clanAddress,
+            root: rootTester.rootAddress[0],
+            owner: memberTester.ownerAddress,
+            exitableAt: null,
+            lockReleased: data.releaseLock,
+            reason: data.reason,
+          },
+        },
+      ]);
+
+      await expect(
+        program.account.member.fetch(memberTester.memberAddress[0])
+      ).resolves.toStrictEqual({
+        ...memberTester.member,
+        membership: [],
+      });
+
+      await expect(
+        program.account.clan.fetch(clanTester.clanAddress)
+      ).resolves.toStrictEqual({
+        ...clanTester.clan,
+        permanentMembers: clanTester.clan.permanentMembers.subn(1),
+        permanentVoterWeight: clanTester.clan.permanentVoterWeight.sub(
+          memberTester.member.voterWeight
+        ),
+      });
+
+      await expect(
+        splGovernance.account.tokenOwnerRecordV2.fetch(
+          memberTester.tokenOwnerRecordAddress[0]
+        )
+      ).resolves.toStrictEqual({
+        ...memberTester.tokenOwnerRecord,
+        locks: [],
+      });
+    }
+  );
+
+  it.each(forceLeaveClanTestData.filter(({error}) => error))(
+    'Fails with $error',
+    async (data: ForceLeaveClanTestData) => {
+      const {realmTester, rootTester, memberTester, clanTester} =
+        buildTesters(data);
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          ...(await memberTester.accounts()),
+          ...(await clanTester.accounts()),
+        ],
+      });
+
+      const tx = await program.methods
+        .forceLeaveClan(data.immediate, data.releaseLock, data.reason)
+        .accountsStrict({
+          configureRoot: {
+            root: rootTester.rootAddress[0],
+            realm: realmTester.realmAddress,
+            admin: realmTester.authorityAddress!,
+          },
+          member: memberTester.memberAddress[0],
+          clan: clanTester.clanAddress,
+          clanVwr: clanTester.voterWeightAddress[0],
+          weightCheckpoints: null,
+          memberTor: memberTester.tokenOwnerRecordAddress[0],
+          lockAuthority: rootTester.lockAuthority[0],
+          realmConfig: await realmTester.realmConfigId(),
+          payer: testContext.payer.publicKey,
+          systemProgram: SystemProgram.programId,
+          governanceProgram: rootTester.splGovernanceId,
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer, realmTester.authority as Keypair);
+
+      const result = await testContext.banksClient.tryProcessTransaction(tx);
+      expect(result.result).not.toBeNull();
+      expect(result.meta?.logMessages).toContainEqual(
+        expect.stringContaining(`Error Code: ${data.error}.`)
+      );
+    }
+  );
+});
@@ -8,6 +8,7 @@ import {
 } from '../../src';
 import {ClanTester, MemberTester, RootTester} from '../../src/VoteAggregator';
 import {AccountMeta, Keypair, PublicKey, SystemProgram} from '@solana/web3.js';
+import {BN} from '@coral-xyz/anchor';
 
 describe('join_clan instruction', () => {
   it.each(joinClanTestData.filter(({error}) => !error))(
@@ -125,6 +126,7 @@ describe('join_clan instruction', () => {
           clan: clan.address,
           memberAuthority: memberTester.ownerAddress,
           clanVwr: clanTester.voterWeightAddress[0],
+          weightCheckpoints: null,
           memberTor: memberTester.tokenOwnerRecordAddress[0],
           memberVwr: memberVoterWeight.address,
           maxVwr: rootTester.maxVoterWeightAddress[0],
@@ -159,6 +161,7 @@ describe('join_clan instruction', () => {
         },
       }));
 
+      const time = (await testContext.banksClient.getClock()).unixTimestamp;
       await expect(
         testContext.banksClient
           .processTransaction(tx)
@@ -222,9 +225,20 @@ describe('join_clan instruction', () => {
           clan: clanTester.clanAddress,
           shareBp,
           exitableAt: null,
+          rewardWeight: memberVoterWeight.voterWeight,
+          rewardPerWeightPaid: new BN(0),
+          unclaimedRewards: new BN(0),
+          warmupEnd: null,
+          warmupShareBp: shareBp,
+          heldWarmup: null,
+          joinedAt: new BN(time.toString()),
+          loyaltyBonusBp: 0,
+          weightTransform: {linear: {}},
+          weightTransformEpoch: new BN(0),
         }),
         voterWeightRecord: memberVoterWeight.address,
         voterWeight: memberVoterWeight.voterWeight,
+        transformedVoterWeight: memberVoterWeight.voterWeight,
         voterWeightExpiry: memberVoterWeight.voterWeightExpiry || null,
       });
 
@@ -236,6 +250,9 @@ describe('join_clan instruction', () => {
           memberVoterWeight.voterWeight
         ),
         permanentMembers: clanTester.clan.permanentMembers.addn(1),
+        rewardWeight: clanTester.clan.rewardWeight.add(
+          memberVoterWeight.voterWeight
+        ),
       });
 
       await expect(
@@ -269,7 +286,10 @@ describe('join_clan instruction', () => {
         locks: [
           {
             lockType: 0,
-            expiry: null,
+            // Refreshed while the member stays in the clan
+            expiry: new BN(time.toString()).add(
+              rootTester.root.memberLockDuration
+            ),
             authority: lockAuthority,
           },
         ],
@@ -0,0 +1,176 @@
+import {startTest} from '../../dev/startTest';
+import {
+  JoinClanWarmupTestData,
+  RealmTester,
+  joinClanWarmupTestData,
+} from '../../src';
+import {ClanTester, MemberTester, RootTester} from '../../src/VoteAggregator';
+import {Keypair, PublicKey, SystemProgram} from '@solana/web3.js';
+import {BN} from '@coral-xyz/anchor';
+
+describe('join_clan instruction warmup', () => {
+  it.each(joinClanWarmupTestData)(
+    'Restarts the warmup',
+    async ({
+      realm,
+      root,
+      member,
+      memberVoterWeight,
+      shareBp,
+      warmupShareBp,
+    }: JoinClanWarmupTestData) => {
+      const voteAggregatorId =
+        root.voteAggregatorId ||
+        new PublicKey('VoTaGDreyne7jk59uwbgRRbaAzxvNbyNipaJMrRXhjT');
+      const [rootAddress] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('root', 'utf-8'),
+          realm.realmAddress.toBuffer(),
+          realm.communityMint.toBuffer(),
+        ],
+        voteAggregatorId
+      );
+      const lockAuthority = PublicKey.findProgramAddressSync(
+        [Buffer.from('lock-authority', 'utf8'), rootAddress.toBuffer()],
+        voteAggregatorId
+      )[0];
+      realm.communityTokenConfig = {
+        ...realm.communityTokenConfig,
+        lockAuthorities: [lockAuthority],
+      };
+
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const memberTester = new MemberTester({
+        ...member,
+        root: rootTester,
+        membership: MemberTester.membershipTesters({
+          membership: member.membership || [],
+          root: rootTester,
+        }),
+      });
+      const clanTester = memberTester.membership[0].clan as ClanTester;
+
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          ...(await memberTester.accounts()),
+          ...(await clanTester.accounts()),
+          await realmTester.voterWeightRecord({
+            ...memberVoterWeight,
+            side: root.side,
+            owner: memberTester.ownerAddress,
+          }),
+        ],
+      });
+
+      const tx = await program.methods
+        .joinClan(shareBp)
+        .accountsStrict({
+          root: rootTester.rootAddress[0],
+          member: memberTester.memberAddress[0],
+          clan: clanTester.clanAddress,
+          memberAuthority: memberTester.ownerAddress,
+          clanVwr: clanTester.voterWeightAddress[0],
+          weightCheckpoints: null,
+          memberTor: memberTester.tokenOwnerRecordAddress[0],
+          memberVwr: memberVoterWeight.address,
+          maxVwr: rootTester.maxVoterWeightAddress[0],
+          realm: realmTester.realmAddress,
+          realmConfig: await realmTester.realmConfigId(),
+          lockAuthority: rootTester.lockAuthority[0],
+          payer: program.provider.publicKey!,
+          governanceProgram: rootTester.splGovernanceId,
+          systemProgram: SystemProgram.programId,
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer, member.owner as Keypair);
+
+      const clock = await testContext.banksClient.getClock();
+      const time = new BN(clock.unixTimestamp.toString());
+      await testContext.banksClient.processTransaction(tx);
+
+      const entry = memberTester.member.membership[0];
+      const wasMember = !entry.exitableAt;
+      const oldMemberVoterWeight = wasMember
+        ? memberTester.member.voterWeight.muln(entry.shareBp).divn(10000)
+        : new BN(0);
+      // The leaving member warmup was released with its weight
+      const oldHeldWarmup = wasMember ? entry.heldWarmup : null;
+      const oldHeldVoterWeight = oldHeldWarmup?.voterWeight || new BN(0);
+      const newMemberVoterWeight = memberVoterWeight.voterWeight
+        .muln(shareBp)
+        .divn(10000);
+      const heldWarmup = {
+        endTime: time.add(rootTester.root.membershipWarmup),
+        voterWeight: newMemberVoterWeight.muln(warmupShareBp).divn(shareBp),
+        isPermanent: true,
+      };
+
+      await expect(
+        program.account.member.fetch(memberTester.memberAddress[0])
+      ).resolves.toStrictEqual({
+        ...memberTester.member,
+        voterWeightRecord: memberVoterWeight.address,
+        voterWeight: memberVoterWeight.voterWeight,
+        transformedVoterWeight: memberVoterWeight.voterWeight,
+        membership: [
+          {
+            ...entry,
+            shareBp,
+            exitableAt: null,
+            rewardWeight: newMemberVoterWeight,
+            warmupEnd: heldWarmup.endTime,
+            warmupShareBp,
+            heldWarmup,
+            joinedAt: wasMember ? entry.joinedAt : time,
+          },
+        ],
+      });
+
+      await expect(
+        program.account.clan.fetch(clanTester.clanAddress)
+      ).resolves.toStrictEqual({
+        ...clanTester.clan,
+        permanentVoterWeight: clanTester.clan.permanentVoterWeight
+          .sub(oldMemberVoterWeight)
+          .add(newMemberVoterWeight),
+        permanentMembers: wasMember
+          ? clanTester.clan.permanentMembers
+          : clanTester.clan.permanentMembers.addn(1),
+        leavingMembers: wasMember
+          ? clanTester.clan.leavingMembers
+          : clanTester.clan.leavingMembers.subn(1),
+        rewardWeight: clanTester.clan.rewardWeight
+          .sub(entry.rewardWeight)
+          .add(newMemberVoterWeight),
+        warmingUpVoterWeight: clanTester.clan.warmingUpVoterWeight
+          .sub(oldHeldVoterWeight)
+          .add(heldWarmup.voterWeight),
+        warmupEnds: clanTester.clan.warmupEnds
+          .filter(end => !oldHeldWarmup?.endTime.eq(end.endTime))
+          .concat(heldWarmup),
+      });
+
+      await expect(
+        program.account.voterWeightRecord.fetch(
+          clanTester.voterWeightAddress[0]
+        )
+      ).resolves.toStrictEqual({
+        ...clanTester.voterWeightRecord,
+        voterWeight: clanTester.voterWeightRecord.voterWeight
+          .add(oldHeldVoterWeight)
+          .sub(oldMemberVoterWeight)
+          .add(newMemberVoterWeight)
+          .sub(heldWarmup.voterWeight),
+      });
+    }
+  );
+});
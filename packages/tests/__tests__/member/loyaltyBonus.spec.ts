@@ -0,0 +1,175 @@
+import {startTest} from '../../dev/startTest';
+import {
+  LoyaltyBonusTestData,
+  RealmTester,
+  loyaltyBonusTestData,
+} from '../../src';
+import {ClanTester, MemberTester, RootTester} from '../../src/VoteAggregator';
+import {Keypair} from '@solana/web3.js';
+import {Clock} from 'solana-bankrun';
+import BN from 'bn.js';
+
+const boostShareBp = (shareBp: number, bonusBp: number) =>
+  Math.floor((shareBp * (10000 + bonusBp)) / 10000);
+
+const weightOf = (voterWeight: BN, shareBp: number) =>
+  voterWeight.muln(shareBp).divn(10000);
+
+describe('Loyalty bonus', () => {
+  it.each(loyaltyBonusTestData.filter(({error}) => !error))(
+    'Is refreshed by update_voter_weight',
+    async ({
+      realm,
+      root,
+      member,
+      memberVoterWeightRecord,
+      tenure,
+    }: LoyaltyBonusTestData) => {
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const memberTester = new MemberTester({
+        ...member,
+        root: rootTester,
+        membership: MemberTester.membershipTesters({
+          membership: member.membership || [],
+          root: rootTester,
+        }),
+      });
+      const clanTester = memberTester.membership[0].clan as ClanTester;
+
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          ...(await memberTester.accounts()),
+          ...(await clanTester.accounts()),
+          await realmTester.voterWeightRecord({
+            ...memberVoterWeightRecord,
+            side: root.side,
+            owner:
+              member.owner instanceof Keypair
+                ? member.owner.publicKey
+                : member.owner,
+          }),
+        ],
+      });
+
+      const entry = memberTester.member.membership[0];
+      const clock = await testContext.banksClient.getClock();
+      testContext.setClock(
+        new Clock(
+          clock.slot,
+          clock.epochStartTimestamp,
+          clock.epoch,
+          clock.leaderScheduleEpoch,
+          BigInt(entry.joinedAt.add(tenure).toString())
+        )
+      );
+
+      const tx = await program.methods
+        .updateVoterWeight()
+        .accountsStrict({
+          root: rootTester.rootAddress[0],
+          member: memberTester.memberAddress[0],
+          maxVwr: rootTester.maxVoterWeightAddress[0],
+          memberVwr: memberVoterWeightRecord.address,
+          memberTor: null,
+          lockAuthority: null,
+          realm: null,
+          realmConfig: null,
+          payer: null,
+          systemProgram: null,
+          governanceProgram: null,
+        })
+        .remainingAccounts([
+          {
+            pubkey: clanTester.clanAddress,
+            isWritable: true,
+            isSigner: false,
+          },
+          {
+            pubkey: clanTester.voterWeightAddress[0],
+            isWritable: true,
+            isSigner: false,
+          },
+        ])
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer);
+      await testContext.banksClient.processTransaction(tx);
+
+      // The current curve applies to the whole tenure
+      const curve = clanTester.clan.loyaltyCurve;
+      const loyaltyBonusBp = curve
+        ? new BN(curve.maxBonusBp)
+            .mul(BN.min(tenure, curve.period))
+            .div(curve.period)
+            .toNumber()
+        : 0;
+      const {voterWeight} = memberVoterWeightRecord;
+      const oldClanWeight = weightOf(
+        memberTester.member.voterWeight,
+        boostShareBp(entry.shareBp, entry.loyaltyBonusBp)
+      );
+      const newClanWeight = weightOf(
+        voterWeight,
+        boostShareBp(entry.shareBp, loyaltyBonusBp)
+      );
+      const loyaltyBonusWeight = newClanWeight.sub(
+        weightOf(voterWeight, entry.shareBp)
+      );
+
+      await expect(
+        program.account.member.fetch(memberTester.memberAddress[0])
+      ).resolves.toStrictEqual({
+        ...memberTester.member,
+        membership: [{...entry, rewardWeight: newClanWeight, loyaltyBonusBp}],
+        voterWeight,
+        voterWeightExpiry: null,
+        transformedVoterWeight: voterWeight,
+        loyaltyBonusWeight,
+      });
+
+      // The bonus is counted in the max voter weight
+      await expect(
+        program.account.maxVoterWeightRecord.fetch(
+          rootTester.maxVoterWeightAddress[0]
+        )
+      ).resolves.toMatchObject({
+        maxVoterWeight: rootTester.maxVoterWeight.maxVoterWeight
+          .sub(memberTester.member.transformedVoterWeight)
+          .sub(memberTester.member.loyaltyBonusWeight)
+          .add(voterWeight)
+          .add(loyaltyBonusWeight),
+      });
+
+      await expect(
+        program.account.clan.fetch(clanTester.clanAddress)
+      ).resolves.toStrictEqual({
+        ...clanTester.clan,
+        permanentVoterWeight: clanTester.clan.permanentVoterWeight
+          .sub(oldClanWeight)
+          .add(newClanWeight),
+        rewardWeight: clanTester.clan.rewardWeight
+          .sub(entry.rewardWeight)
+          .add(newClanWeight),
+      });
+
+      await expect(
+        program.account.voterWeightRecord.fetch(
+          clanTester.voterWeightAddress[0]
+        )
+      ).resolves.toStrictEqual({
+        ...clanTester.voterWeightRecord,
+        voterWeight: clanTester.voterWeightRecord.voterWeight
+          .sub(oldClanWeight)
+          .add(newClanWeight),
+      });
+    }
+  );
+});
@@ -0,0 +1,294 @@
+import {startTest} from '../../dev/startTest';
+import {BorshAccountsCoder} from '@coral-xyz/anchor';
+import {Keypair, SystemProgram} from '@solana/web3.js';
+import {
+  MigrateMemberTestData,
+  RealmTester,
+  getMinimumBalanceForRentExemption,
+  migratedMemberTenureTestData,
+  migrateMemberTestData,
+  parseLogsEvent,
+} from '../../src';
+import {
+  ClanTester,
+  MemberAccount,
+  MemberTester,
+  RootTester,
+} from '../../src/VoteAggregator';
+import {AddedAccount} from 'solana-bankrun';
+import BN from 'bn.js';
+
+const u64 = (value: BN) => value.toArrayLike(Buffer, 'le', 8);
+
+const option = (value: BN | null) =>
+  value ? Buffer.concat([Buffer.from([1]), u64(value)]) : Buffer.from([0]);
+
+// The member layout before the clan rewards
+const legacyMemberAccount = (memberTester: MemberTester): AddedAccount => {
+  const member: MemberAccount = memberTester.member;
+  const membershipLength = Buffer.alloc(4);
+  membershipLength.writeUInt32LE(member.membership.length);
+  const data = Buffer.concat([
+    BorshAccountsCoder.accountDiscriminator('Member'),
+    member.root.toBuffer(),
+    member.owner.toBuffer(),
+    member.delegate.toBuffer(),
+    member.tokenOwnerRecord.toBuffer(),
+    member.voterWeightRecord.toBuffer(),
+    u64(member.voterWeight),
+    option(member.voterWeightExpiry),
+    option(member.nextVoterWeightResetTime),
+    membershipLength,
+    ...member.membership.map(({clan, shareBp, exitableAt}) => {
+      const share = Buffer.alloc(2);
+      share.writeUInt16LE(shareBp);
+      return Buffer.concat([clan.toBuffer(), share, option(exitableAt)]);
+    }),
+    Buffer.from([member.bumps.address, member.bumps.tokenOwnerRecord]),
+  ]);
+  return {
+    address: memberTester.memberAddress[0],
+    info: {
+      executable: false,
+      owner: memberTester.root.voteAggregatorId,
+      lamports: getMinimumBalanceForRentExemption(data.length),
+      data,
+    },
+  };
+};
+
+describe('migrate_member instruction', () => {
+  it.each(migrateMemberTestData.filter(({error}) => !error))(
+    'Works',
+    async ({realm, root, member}: MigrateMemberTestData) => {
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const memberTester = new MemberTester({
+        ...member,
+        root: rootTester,
+        membership: MemberTester.membershipTesters({
+          membership: member.membership || [],
+          root: rootTester,
+        }),
+      });
+
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          legacyMemberAccount(memberTester),
+        ],
+      });
+
+      const tx = await program.methods
+        .migrateMember()
+        .accountsStrict({
+          member: memberTester.memberAddress[0],
+          payer: testContext.payer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer);
+
+      const clock = await testContext.banksClient.getClock();
+      const migratedAt = new BN(clock.unixTimestamp.toString());
+      await expect(
+        testContext.banksClient
+          .processTransaction(tx)
+          .then(meta => parseLogsEvent(program, meta.logMessages))
+      ).resolves.toStrictEqual([
+        {
+          name: 'MemberMigrated',
+          data: {
+            member: memberTester.memberAddress[0],
+            root: rootTester.rootAddress[0],
+            owner: memberTester.ownerAddress,
+          },
+        },
+      ]);
+
+      // The rewards, warmup and loyalty start from scratch
+      await expect(
+        program.account.member.fetch(memberTester.memberAddress[0])
+      ).resolves.toStrictEqual({
+        ...memberTester.member,
+        membership: memberTester.member.membership.map(entry => ({
+          ...entry,
+          joinedAt: migratedAt,
+        })),
+      });
+    }
+  );
+
+  it.each(migrateMemberTestData.filter(({error}) => error))(
+    'Fails with $error',
+    async ({realm, root, member, legacy, error}: MigrateMemberTestData) => {
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const memberTester = new MemberTester({
+        ...member,
+        root: rootTester,
+        membership: MemberTester.membershipTesters({
+          membership: member.membership || [],
+          root: rootTester,
+        }),
+      });
+
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          ...(legacy
+            ? [legacyMemberAccount(memberTester)]
+            : await memberTester.accounts()),
+        ],
+      });
+
+      const tx = await program.methods
+        .migrateMember()
+        .accountsStrict({
+          member: memberTester.memberAddress[0],
+          payer: testContext.payer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer);
+
+      const result = await testContext.banksClient.tryProcessTransaction(tx);
+      expect(result.result).not.toBeNull();
+      expect(result.meta?.logMessages).toContainEqual(
+        expect.stringContaining(`Error Code: ${error}.`)
+      );
+    }
+  );
+
+  it('Counts the loyalty and the free exits from the migration', async () => {
+    const {realm, root, member} = migratedMemberTenureTestData;
+    const realmTester = new RealmTester(realm);
+    const rootTester = new RootTester({
+      ...root,
+      realm: realmTester,
+    });
+    const membership = MemberTester.membershipTesters({
+      membership: member.membership || [],
+      root: rootTester,
+    });
+    const [loyalClan, changedClan] = membership.map(
+      ({clan}) => clan as ClanTester
+    );
+    // Still in the free exit window of a member who joined before the change
+    changedClan.clan.controlChangedAt = new BN(
+      Math.floor(Date.now() / 1000) - 100
+    );
+    // Only the free exit could waive the cooldown with a proposal open
+    changedClan.tokenOwnerRecord.outstandingProposalCount = 1;
+    const memberTester = new MemberTester({
+      ...member,
+      root: rootTester,
+      membership,
+    });
+
+    const {testContext, program} = await startTest({
+      splGovernanceId: rootTester.splGovernanceId,
+      accounts: [
+        ...(await realmTester.accounts()),
+        ...(await rootTester.accounts()),
+        ...(await memberTester.accounts()).filter(
+          ({address}) => !address.equals(memberTester.memberAddress[0])
+        ),
+        legacyMemberAccount(memberTester),
+        ...(await loyalClan.accounts()),
+        ...(await changedClan.accounts()),
+      ],
+    });
+
+    const migrateTx = await program.methods
+      .migrateMember()
+      .accountsStrict({
+        member: memberTester.memberAddress[0],
+        payer: testContext.payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .transaction();
+    migrateTx.recentBlockhash = testContext.lastBlockhash;
+    migrateTx.feePayer = testContext.payer.publicKey;
+    migrateTx.sign(testContext.payer);
+    await testContext.banksClient.processTransaction(migrateTx);
+
+    const updateTx = await program.methods
+      .updateVoterWeight()
+      .accountsStrict({
+        root: rootTester.rootAddress[0],
+        member: memberTester.memberAddress[0],
+        maxVwr: rootTester.maxVoterWeightAddress[0],
+        memberVwr: null,
+        memberTor: null,
+        lockAuthority: null,
+        realm: null,
+        realmConfig: null,
+        payer: null,
+        systemProgram: null,
+        governanceProgram: null,
+      })
+      .remainingAccounts([
+        {
+          pubkey: loyalClan.clanAddress,
+          isWritable: true,
+          isSigner: false,
+        },
+        {
+          pubkey: loyalClan.voterWeightAddress[0],
+          isWritable: true,
+          isSigner: false,
+        },
+      ])
+      .transaction();
+    updateTx.recentBlockhash = testContext.lastBlockhash;
+    updateTx.feePayer = testContext.payer.publicKey;
+    updateTx.sign(testContext.payer);
+    await testContext.banksClient.processTransaction(updateTx);
+
+    // No loyalty bonus for the time before the migration
+    const migrated = await program.account.member.fetch(
+      memberTester.memberAddress[0]
+    );
+    expect(migrated.membership[0].loyaltyBonusBp).toBe(0);
+
+    // The control changed before the migration so the cooldown applies
+    const exitTx = await program.methods
+      .exitClan()
+      .accountsStrict({
+        root: rootTester.rootAddress[0],
+        member: memberTester.memberAddress[0],
+        clan: changedClan.clanAddress,
+        memberAuthority: memberTester.ownerAddress,
+        governanceProgram: rootTester.splGovernanceId,
+        lockAuthority: rootTester.lockAuthority[0],
+        memberTor: memberTester.tokenOwnerRecordAddress[0],
+        clanTor: changedClan.tokenOwnerRecordAddress[0],
+        realm: realmTester.realmAddress,
+        realmConfig: await realmTester.realmConfigId(),
+      })
+      .transaction();
+    exitTx.recentBlockhash = testContext.lastBlockhash;
+    exitTx.feePayer = testContext.payer.publicKey;
+    exitTx.sign(testContext.payer, member.owner as Keypair);
+    const result = await testContext.banksClient.tryProcessTransaction(exitTx);
+    expect(result.result).not.toBeNull();
+    expect(result.meta?.logMessages).toContainEqual(
+      expect.stringContaining('Error Code: TooEarlyToExitClan.')
+    );
+  });
+});
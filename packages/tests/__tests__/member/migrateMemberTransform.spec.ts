@@ -0,0 +1,170 @@
+import {startTest} from '../../dev/startTest';
+import {
+  MigrateMemberTransformTestData,
+  RealmTester,
+  migrateMemberTransformTestData,
+  parseLogsEvent,
+} from '../../src';
+import {ClanTester, MemberTester, RootTester} from '../../src/VoteAggregator';
+
+const buildTesters = ({
+  realm,
+  root,
+  member,
+}: MigrateMemberTransformTestData) => {
+  const realmTester = new RealmTester(realm);
+  const rootTester = new RootTester({
+    ...root,
+    realm: realmTester,
+  });
+  const memberTester = new MemberTester({
+    ...member,
+    root: rootTester,
+    membership: MemberTester.membershipTesters({
+      membership: member.membership || [],
+      root: rootTester,
+    }),
+  });
+  const clanTester = memberTester.membership[0].clan as ClanTester;
+  return {realmTester, rootTester, memberTester, clanTester};
+};
+
+describe('migrate_member_transform instruction', () => {
+  it.each(migrateMemberTransformTestData.filter(({error}) => !error))(
+    'Works',
+    async (testData: MigrateMemberTransformTestData) => {
+      const {realmTester, rootTester, memberTester, clanTester} =
+        buildTesters(testData);
+
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          ...(await memberTester.accounts()),
+          ...(await clanTester.accounts()),
+        ],
+      });
+
+      const tx = await program.methods
+        .migrateMemberTransform()
+        .accountsStrict({
+          member: memberTester.memberAddress[0],
+          root: rootTester.rootAddress[0],
+          clan: clanTester.clanAddress,
+          clanVwr: clanTester.voterWeightAddress[0],
+          weightCheckpoints: null,
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer);
+
+      // The linear transform keeps the weight, only the epoch moves
+      const voterWeight = clanTester.voterWeightRecord.voterWeight;
+      const permanentVoterWeight = clanTester.clan.permanentVoterWeight;
+      const entry = memberTester.member.membership[0];
+      const memberVoterWeight = memberTester.member.voterWeight
+        .muln(entry.shareBp)
+        .divn(10000);
+      const unmigratedMembers = clanTester.clan.unmigratedMembers.subn(1);
+      await expect(
+        testContext.banksClient
+          .processTransaction(tx)
+          .then(meta => parseLogsEvent(program, meta.logMessages))
+      ).resolves.toStrictEqual([
+        {
+          name: 'ClanVoterWeightChanged',
+          data: {
+            clan: clanTester.clanAddress,
+            root: rootTester.rootAddress[0],
+            oldVoterWeight: voterWeight,
+            newVoterWeight: voterWeight,
+            oldPermamentVoterWeight: permanentVoterWeight,
+            newPermamentVoterWeight: permanentVoterWeight,
+            oldIsPermanent: true,
+            newIsPermanent: true,
+          },
+        },
+        {
+          name: 'MemberWeightTransformMigrated',
+          data: {
+            member: memberTester.memberAddress[0],
+            clan: clanTester.clanAddress,
+            root: rootTester.rootAddress[0],
+            weightTransformEpoch: clanTester.clan.weightTransformEpoch,
+            unmigratedMembers,
+          },
+        },
+      ]);
+
+      await expect(
+        program.account.member.fetch(memberTester.memberAddress[0])
+      ).resolves.toStrictEqual({
+        ...memberTester.member,
+        membership: [
+          {
+            ...entry,
+            rewardWeight: memberVoterWeight,
+            weightTransform: clanTester.clan.weightTransform,
+            weightTransformEpoch: clanTester.clan.weightTransformEpoch,
+          },
+        ],
+      });
+
+      await expect(
+        program.account.clan.fetch(clanTester.clanAddress)
+      ).resolves.toStrictEqual({
+        ...clanTester.clan,
+        rewardWeight: clanTester.clan.rewardWeight
+          .sub(entry.rewardWeight)
+          .add(memberVoterWeight),
+        unmigratedMembers,
+      });
+
+      await expect(
+        program.account.voterWeightRecord.fetch(
+          clanTester.voterWeightAddress[0]
+        )
+      ).resolves.toStrictEqual(clanTester.voterWeightRecord);
+    }
+  );
+
+  it.each(migrateMemberTransformTestData.filter(({error}) => error))(
+    'Fails with $error',
+    async (testData: MigrateMemberTransformTestData) => {
+      const {realmTester, rootTester, memberTester, clanTester} =
+        buildTesters(testData);
+
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          ...(await memberTester.accounts()),
+          ...(await clanTester.accounts()),
+        ],
+      });
+
+      const tx = await program.methods
+        .migrateMemberTransform()
+        .accountsStrict({
+          member: memberTester.memberAddress[0],
+          root: rootTester.rootAddress[0],
+          clan: clanTester.clanAddress,
+          clanVwr: clanTester.voterWeightAddress[0],
+          weightCheckpoints: null,
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer);
+
+      const result = await testContext.banksClient.tryProcessTransaction(tx);
+      expect(result.result).not.toBeNull();
+      expect(result.meta?.logMessages).toContainEqual(
+        expect.stringContaining(`Error Code: ${testData.error}.`)
+      );
+    }
+  );
+});
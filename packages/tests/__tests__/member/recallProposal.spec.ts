@@ -0,0 +1,376 @@
+import {startTest} from '../../dev/startTest';
+import {PublicKey, SystemProgram} from '@solana/web3.js';
+import {
+  RealmTester,
+  RecallProposalTestData,
+  getMinimumBalanceForRentExemption,
+  parseLogsEvent,
+  recallProposalTestData,
+} from '../../src';
+import {
+  ClanTester,
+  MemberTester,
+  RootTester,
+  buildVoteAggregatorProgram,
+} from '../../src/VoteAggregator';
+import {AddedAccount} from 'solana-bankrun';
+import BN from 'bn.js';
+
+const buildTesters = ({realm, root, member}: RecallProposalTestData) => {
+  const realmTester = new RealmTester(realm);
+  const rootTester = new RootTester({
+    ...root,
+    realm: realmTester,
+  });
+  const memberTester = new MemberTester({
+    ...member,
+    root: rootTester,
+    membership: MemberTester.membershipTesters({
+      membership: member.membership || [],
+      root: rootTester,
+    }),
+  });
+  const clanTester = memberTester.membership[0].clan as ClanTester;
+  return {realmTester, rootTester, memberTester, clanTester};
+};
+
+const recallAddresses = (
+  clanTester: ClanTester,
+  memberTester: MemberTester,
+  index: BN
+) => {
+  const [recall] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from('recall', 'utf-8'),
+      clanTester.clanAddress.toBuffer(),
+      index.toArrayLike(Buffer, 'le', 8),
+    ],
+    clanTester.root.voteAggregatorId
+  );
+  const [signature] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from('recall-signature', 'utf-8'),
+      recall.toBuffer(),
+      memberTester.memberAddress[0].toBuffer(),
+    ],
+    clanTester.root.voteAggregatorId
+  );
+  return {recall, signature};
+};
+
+// The recall signed by the member before it started leaving the clan
+const signedRecallAccounts = async ({
+  rootTester,
+  clanTester,
+  memberTester,
+  index,
+  newOwner,
+}: {
+  rootTester: RootTester;
+  clanTester: ClanTester;
+  memberTester: MemberTester;
+  index: BN;
+  newOwner: PublicKey;
+}): Promise<AddedAccount[]> => {
+  const program = buildVoteAggregatorProgram({
+    voteAggregatorId: rootTester.voteAggregatorId,
+  });
+  const {recall, signature} = recallAddresses(clanTester, memberTester, index);
+  const voterWeight = memberTester.member.membership[0].rewardWeight;
+  const recallData = await program.coder.accounts.encode('recallProposal', {
+    clan: clanTester.clanAddress,
+    index,
+    proposer: memberTester.memberAddress[0],
+    newOwner,
+    newDelegate: null,
+    requiredWeight: clanTester.voterWeightRecord.voterWeight
+      .muln(rootTester.root.recallThresholdBp)
+      .divn(10000),
+    signedWeight: voterWeight,
+    signatures: new BN(1),
+    expiresAt: new BN('10000000000'),
+    executed: false,
+    bump: 0,
+  });
+  const signatureData = await program.coder.accounts.encode(
+    'recallSignature',
+    {
+      recall,
+      member: memberTester.memberAddress[0],
+      voterWeight,
+      bump: 0,
+    }
+  );
+  const account = (address: PublicKey, data: Buffer): AddedAccount => ({
+    address,
+    info: {
+      executable: false,
+      owner: rootTester.voteAggregatorId,
+      lamports: getMinimumBalanceForRentExemption(data.length),
+      data,
+    },
+  });
+  return [account(recall, recallData), account(signature, signatureData)];
+};
+
+describe('Recall proposal', () => {
+  it.each(recallProposalTestData.filter(({error}) => !error))(
+    'Opens, signs and executes the recall',
+    async (testData: RecallProposalTestData) => {
+      const {member, newOwner} = testData;
+      const {realmTester, rootTester, memberTester, clanTester} =
+        buildTesters(testData);
+      const index = clanTester.clan.recallProposals;
+      const {recall, signature} = recallAddresses(
+        clanTester,
+        memberTester,
+        index
+      );
+
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          ...(await memberTester.accounts()),
+          ...(await clanTester.accounts()),
+        ],
+      });
+
+      const openTx = await program.methods
+        .openRecallProposal(newOwner, null)
+        .accountsStrict({
+          member: memberTester.memberAddress[0],
+          memberAuthority: member.owner.publicKey,
+          root: rootTester.rootAddress[0],
+          clan: clanTester.clanAddress,
+          clanVwr: clanTester.voterWeightAddress[0],
+          recall,
+          payer: testContext.payer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .transaction();
+      openTx.recentBlockhash = testContext.lastBlockhash;
+      openTx.feePayer = testContext.payer.publicKey;
+      openTx.sign(testContext.payer, member.owner);
+
+      const time = new BN(
+        (await testContext.banksClient.getClock()).unixTimestamp.toString()
+      );
+      const requiredWeight = clanTester.voterWeightRecord.voterWeight
+        .muln(rootTester.root.recallThresholdBp)
+        .divn(10000);
+      await expect(
+        testContext.banksClient
+          .processTransaction(openTx)
+          .then(meta => parseLogsEvent(program, meta.logMessages))
+      ).resolves.toStrictEqual([
+        {
+          name: 'RecallProposalOpened',
+          data: {
+            recall,
+            clan: clanTester.clanAddress,
+            member: memberTester.memberAddress[0],
+            index,
+            newOwner,
+            newDelegate: null,
+            requiredWeight,
+            expiresAt: time.add(rootTester.root.maxProposalLifetime),
+          },
+        },
+      ]);
+
+      const signTx = await program.methods
+        .signRecallProposal()
+        .accountsStrict({
+          member: memberTester.memberAddress[0],
+          memberAuthority: member.owner.publicKey,
+          recall,
+          signature,
+          payer: testContext.payer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .transaction();
+      signTx.recentBlockhash = testContext.lastBlockhash;
+      signTx.feePayer = testContext.payer.publicKey;
+      signTx.sign(testContext.payer, member.owner);
+
+      const voterWeight = memberTester.member.membership[0].rewardWeight;
+      await expect(
+        testContext.banksClient
+          .processTransaction(signTx)
+          .then(meta => parseLogsEvent(program, meta.logMessages))
+      ).resolves.toStrictEqual([
+        {
+          name: 'RecallProposalSigned',
+          data: {
+            recall,
+            clan: clanTester.clanAddress,
+            member: memberTester.memberAddress[0],
+            voterWeight,
+            signedWeight: voterWeight,
+          },
+        },
+      ]);
+
+      // Permissionless
+      const executeTx = await program.methods
+        .executeRecallProposal()
+        .accountsStrict({
+          recall,
+          clan: clanTester.clanAddress,
+          siblingClan: null,
+        })
+        .remainingAccounts([
+          {pubkey: signature, isSigner: false, isWritable: false},
+          {
+            pubkey: memberTester.memberAddress[0],
+            isSigner: false,
+            isWritable: false,
+          },
+        ])
+        .transaction();
+      executeTx.recentBlockhash = testContext.lastBlockhash;
+      executeTx.feePayer = testContext.payer.publicKey;
+      executeTx.sign(testContext.payer);
+
+      await expect(
+        testContext.banksClient
+          .processTransaction(executeTx)
+          .then(meta => parseLogsEvent(program, meta.logMessages))
+      ).resolves.toStrictEqual([
+        {
+          name: 'ClanOwnerChanged',
+          data: {
+            clan: clanTester.clanAddress,
+            oldOwner: clanTester.clan.owner,
+            newOwner,
+            controlChangedAt: time,
+          },
+        },
+        {
+          name: 'RecallProposalExecuted',
+          data: {
+            recall,
+            clan: clanTester.clanAddress,
+            newOwner,
+            newDelegate: null,
+            signedWeight: voterWeight,
+          },
+        },
+      ]);
+
+      await expect(
+        program.account.clan.fetch(clanTester.clanAddress)
+      ).resolves.toStrictEqual({
+        ...clanTester.clan,
+        owner: newOwner,
+        recallProposals: index.addn(1),
+        controlChangedAt: time,
+      });
+      await expect(
+        program.account.recallProposal.fetch(recall)
+      ).resolves.toMatchObject({
+        signedWeight: voterWeight,
+        signatures: new BN(1),
+        executed: true,
+      });
+    }
+  );
+
+  it.each(recallProposalTestData.filter(({error}) => error))(
+    'Fails with $error',
+    async (testData: RecallProposalTestData) => {
+      const {member, newOwner, signed, error} = testData;
+      const {realmTester, rootTester, memberTester, clanTester} =
+        buildTesters(testData);
+      // The existing recall is the last one of the clan
+      const index = signed
+        ? clanTester.clan.recallProposals.subn(1)
+        : clanTester.clan.recallProposals;
+      const {recall, signature} = recallAddresses(
+        clanTester,
+        memberTester,
+        index
+      );
+      const signedAccounts = signed
+        ? await signedRecallAccounts({
+            rootTester,
+            clanTester,
+            memberTester,
+            index,
+            newOwner,
+          })
+        : [];
+
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          ...(await memberTester.accounts()),
+          ...(await clanTester.accounts()),
+          ...signedAccounts,
+        ],
+      });
+
+      const execute = program.methods
+        .executeRecallProposal()
+        .accountsStrict({
+          recall,
+          clan: clanTester.clanAddress,
+          siblingClan: null,
+        })
+        .remainingAccounts([
+          {pubkey: signature, isSigner: false, isWritable: false},
+          {
+            pubkey: memberTester.memberAddress[0],
+            isSigner: false,
+            isWritable: false,
+          },
+        ]);
+      const tx = signed
+        ? await execute.transaction()
+        : await program.methods
+            .openRecallProposal(newOwner, null)
+            .accountsStrict({
+              member: memberTester.memberAddress[0],
+              memberAuthority: member.owner.publicKey,
+              root: rootTester.rootAddress[0],
+              clan: clanTester.clanAddress,
+              clanVwr: clanTester.voterWeightAddress[0],
+              recall,
+              payer: testContext.payer.publicKey,
+              systemProgram: SystemProgram.programId,
+            })
+            .postInstructions([
+              await program.methods
+                .signRecallProposal()
+                .accountsStrict({
+                  member: memberTester.memberAddress[0],
+                  memberAuthority: member.owner.publicKey,
+                  recall,
+                  signature,
+                  payer: testContext.payer.publicKey,
+                  systemProgram: SystemProgram.programId,
+                })
+                .instruction(),
+              await execute.instruction(),
+            ])
+            .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      if (signed) {
+        tx.sign(testContext.payer);
+      } else {
+        tx.sign(testContext.payer, member.owner);
+      }
+
+      const result = await testContext.banksClient.tryProcessTransaction(tx);
+      expect(result.result).not.toBeNull();
+      expect(result.meta?.logMessages).toContainEqual(
+        expect.stringContaining(`Error Code: ${error}.`)
+      );
+    }
+  );
+});
@@ -0,0 +1,137 @@
+import {startTest} from '../../dev/startTest';
+import {
+  SetVoterWeightDecayTestData,
+  RealmTester,
+  setVoterWeightDecayTestData,
+} from '../../src';
+import {MemberTester, RootTester} from '../../src/VoteAggregator';
+import {Keypair} from '@solana/web3.js';
+import BN from 'bn.js';
+
+describe('set_voter_weight_decay instruction', () => {
+  it.each(setVoterWeightDecayTestData.filter(({error}) => !error))(
+    'Works',
+    async ({
+      realm,
+      root,
+      member,
+      memberVoterWeightRecord,
+      rate,
+      endTime,
+    }: SetVoterWeightDecayTestData) => {
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const memberTester = new MemberTester({
+        ...member,
+        root: rootTester,
+      });
+
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          ...(await memberTester.accounts()),
+          await realmTester.voterWeightRecord({
+            ...memberVoterWeightRecord,
+            side: root.side,
+            owner: memberTester.ownerAddress,
+          }),
+        ],
+      });
+
+      const tx = await program.methods
+        .setVoterWeightDecay(rate, endTime)
+        .accountsStrict({
+          setVoterWeightRecord: {
+            root: rootTester.rootAddress[0],
+            member: memberTester.memberAddress[0],
+            maxVwr: rootTester.maxVoterWeightAddress[0],
+            memberVwr: memberVoterWeightRecord.address,
+            memberAuthority: memberTester.ownerAddress,
+          },
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer, memberTester.owner as Keypair);
+
+      const clock = await testContext.banksClient.getClock();
+      await testContext.banksClient.processTransaction(tx);
+
+      await expect(
+        program.account.member.fetch(memberTester.memberAddress[0])
+      ).resolves.toMatchObject({
+        voterWeight: memberVoterWeightRecord.voterWeight,
+        voterWeightDecay: {
+          startTime: new BN(clock.unixTimestamp.toString()),
+          rate,
+          endTime,
+        },
+        nextVoterWeightResetTime: root.voterWeightReset!.nextResetTime,
+      });
+    }
+  );
+
+  it.each(setVoterWeightDecayTestData.filter(({error}) => error))(
+    'Fails with $error',
+    async ({
+      realm,
+      root,
+      member,
+      memberVoterWeightRecord,
+      rate,
+      endTime,
+      error,
+    }: SetVoterWeightDecayTestData) => {
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const memberTester = new MemberTester({
+        ...member,
+        root: rootTester,
+      });
+
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          ...(await memberTester.accounts()),
+          await realmTester.voterWeightRecord({
+            ...memberVoterWeightRecord,
+            side: root.side,
+            owner: memberTester.ownerAddress,
+          }),
+        ],
+      });
+
+      const tx = await program.methods
+        .setVoterWeightDecay(rate, endTime)
+        .accountsStrict({
+          setVoterWeightRecord: {
+            root: rootTester.rootAddress[0],
+            member: memberTester.memberAddress[0],
+            maxVwr: rootTester.maxVoterWeightAddress[0],
+            memberVwr: memberVoterWeightRecord.address,
+            memberAuthority: memberTester.ownerAddress,
+          },
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer, memberTester.owner as Keypair);
+
+      const result = await testContext.banksClient.tryProcessTransaction(tx);
+      expect(result.result).not.toBeNull();
+      expect(result.meta?.logMessages).toContainEqual(
+        expect.stringContaining(`Error Code: ${error}.`)
+      );
+    }
+  );
+});
@@ -148,6 +148,11 @@ describe('set_voter_weight_record instruction', () => {
         program.account.member.fetch(memberTester.memberAddress[0])
       ).resolves.toStrictEqual({
         ...memberTester.member,
+        membership: memberTester.member.membership.map(entry =>
+          entry.exitableAt
+            ? entry
+            : {...entry, rewardWeight: memberVoterWeightRecord.voterWeight}
+        ),
         voterWeightRecord: memberVoterWeightRecord.address,
         voterWeight: memberVoterWeightRecord.voterWeight,
         voterWeightExpiry: memberVoterWeightRecord.voterWeightExpiry || null,
@@ -172,6 +177,9 @@ describe('set_voter_weight_record instruction', () => {
           permanentVoterWeight: clanTester.clan.permanentVoterWeight
             .sub(memberTester.member.voterWeight)
             .add(memberVoterWeightRecord.voterWeight),
+          rewardWeight: clanTester.clan.rewardWeight.add(
+            memberVoterWeightRecord.voterWeight
+          ),
         });
 
         await expect(
@@ -187,4 +195,63 @@ describe('set_voter_weight_record instruction', () => {
       }
     }
   );
+
+  it.each(setVoterWeightRecordTestData.filter(({error}) => error))(
+    'Fails with $error',
+    async ({
+      realm,
+      root,
+      member,
+      memberVoterWeightRecord,
+      slot,
+      error,
+    }: SetVoterWeightRecordTestData) => {
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const memberTester = new MemberTester({
+        ...member,
+        root: rootTester,
+      });
+
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          ...(await memberTester.accounts()),
+          await realmTester.voterWeightRecord({
+            ...memberVoterWeightRecord,
+            side: root.side,
+            owner: memberTester.ownerAddress,
+          }),
+        ],
+      });
+      if (slot !== undefined) {
+        testContext.warpToSlot(slot);
+      }
+
+      const tx = await program.methods
+        .setVoterWeightRecord()
+        .accountsStrict({
+          root: rootTester.rootAddress[0],
+          member: memberTester.memberAddress[0],
+          maxVwr: rootTester.maxVoterWeightAddress[0],
+          memberVwr: memberVoterWeightRecord.address,
+          memberAuthority: memberTester.ownerAddress,
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer, memberTester.owner as Keypair);
+
+      const result = await testContext.banksClient.tryProcessTransaction(tx);
+      expect(result.result).not.toBeNull();
+      expect(result.meta?.logMessages).toContainEqual(
+        expect.stringContaining(`Error Code: ${error}.`)
+      );
+    }
+  );
 });
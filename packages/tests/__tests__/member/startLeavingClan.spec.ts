@@ -4,20 +4,61 @@ import {
   RealmTester,
   parseLogsEvent,
   startLeavingClanTestData,
+  buildSplGovernanceProgram,
 } from '../../src';
 import {ClanTester, MemberTester, RootTester} from '../../src/VoteAggregator';
 import {BN} from '@coral-xyz/anchor';
-import {Keypair} from '@solana/web3.js';
+import {Keypair, PublicKey, SystemProgram} from '@solana/web3.js';
 
 describe('start_leaving_clan instruction', () => {
   it.each(startLeavingClanTestData.filter(({error}) => !error))(
     'Works',
     async ({realm, root, member, clanIndex = 0}: StartLeavingClanTestData) => {
+      const tokenConfig =
+        (root.side === 'community'
+          ? realm.communityTokenConfig
+          : realm.councilTokenConfig) || {};
+      const voteAggregatorId =
+        root.voteAggregatorId ||
+        new PublicKey('VoTaGDreyne7jk59uwbgRRbaAzxvNbyNipaJMrRXhjT');
+      const [rootAddress] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('root', 'utf-8'),
+          realm.realmAddress.toBuffer(),
+          root.side === 'community'
+            ? realm.communityMint.toBuffer()
+            : realm.councilMint!.toBuffer(),
+        ],
+        voteAggregatorId
+      );
+      if (tokenConfig.lockAuthorities === undefined) {
+        tokenConfig.lockAuthorities = [
+          PublicKey.findProgramAddressSync(
+            [Buffer.from('lock-authority', 'utf8'), rootAddress.toBuffer()],
+            voteAggregatorId
+          )[0],
+        ];
+      }
+      if (root.side === 'community') {
+        realm.communityTokenConfig = tokenConfig;
+      } else {
+        realm.councilTokenConfig = tokenConfig;
+      }
+
       const realmTester = new RealmTester(realm);
       const rootTester = new RootTester({
         ...root,
         realm: realmTester,
       });
+      if (member.locks === undefined) {
+        member.locks = [
+          {
+            lockType: 0,
+            authority: rootTester.lockAuthority[0],
+            expiry: null,
+          },
+        ];
+      }
       const memberTester = new MemberTester({
         ...member,
         root: rootTester,
@@ -39,6 +80,10 @@ describe('start_leaving_clan instruction', () => {
           ...(await clanTester.accounts()),
         ],
       });
+      const splGovernance = buildSplGovernanceProgram({
+        splGovernanceId: rootTester.splGovernanceId,
+        connection: program.provider.connection,
+      });
 
       const tx = await program.methods
         .startLeavingClan()
@@ -48,6 +93,14 @@ describe('start_leaving_clan instruction', () => {
           clan: clanTester.clanAddress,
           memberAuthority: memberTester.ownerAddress,
           clanVwr: clanTester.voterWeightAddress[0],
+          weightCheckpoints: null,
+          memberTor: memberTester.tokenOwnerRecordAddress[0],
+          lockAuthority: rootTester.lockAuthority[0],
+          realm: realmTester.realmAddress,
+          realmConfig: await realmTester.realmConfigId(),
+          payer: program.provider.publicKey!,
+          systemProgram: SystemProgram.programId,
+          governanceProgram: rootTester.splGovernanceId,
         })
         .transaction();
       tx.recentBlockhash = testContext.lastBlockhash;
@@ -55,6 +108,9 @@ describe('start_leaving_clan instruction', () => {
       tx.sign(testContext.payer, member.owner as Keypair);
 
       const time = (await testContext.banksClient.getClock()).unixTimestamp;
+      const exitableAt = new BN(time.toString()).add(
+        rootTester.root.maxProposalLifetime
+      );
       await expect(
         testContext.banksClient
           .processTransaction(tx)
@@ -94,9 +150,7 @@ describe('start_leaving_clan instruction', () => {
         ...memberTester.member,
         membership: memberTester.member.membership.map((m, index) => {
           if (index === clanIndex) {
-            m.exitableAt = new BN(time.toString()).add(
-              rootTester.root.maxProposalLifetime
-            );
+            m.exitableAt = exitableAt;
           }
           return m;
         }),
@@ -123,6 +177,21 @@ describe('start_leaving_clan instruction', () => {
           memberTester.member.voterWeight
         ),
       });
+
+      await expect(
+        splGovernance.account.tokenOwnerRecordV2.fetch(
+          memberTester.tokenOwnerRecordAddress[0]
+        )
+      ).resolves.toStrictEqual({
+        ...memberTester.tokenOwnerRecord,
+        locks: [
+          {
+            lockType: 0,
+            expiry: exitableAt,
+            authority: rootTester.lockAuthority[0],
+          },
+        ],
+      });
     }
   );
 });
@@ -89,6 +89,13 @@ describe('update_voter_weight instruction', () => {
           member: memberTester.memberAddress[0],
           maxVwr: rootTester.maxVoterWeightAddress[0],
           memberVwr: memberVoterWeightRecord.address,
+          memberTor: null,
+          lockAuthority: null,
+          realm: null,
+          realmConfig: null,
+          payer: null,
+          systemProgram: null,
+          governanceProgram: null,
         })
         .remainingAccounts(rest)
         .transaction();
@@ -147,6 +154,11 @@ describe('update_voter_weight instruction', () => {
         program.account.member.fetch(memberTester.memberAddress[0])
       ).resolves.toStrictEqual({
         ...memberTester.member,
+        membership: memberTester.member.membership.map(entry =>
+          entry.exitableAt
+            ? entry
+            : {...entry, rewardWeight: memberVoterWeightRecord.voterWeight}
+        ),
         voterWeight: memberVoterWeightRecord.voterWeight,
         voterWeightExpiry: memberVoterWeightRecord.voterWeightExpiry || null,
       });
@@ -170,6 +182,9 @@ describe('update_voter_weight instruction', () => {
           permanentVoterWeight: clanTester.clan.permanentVoterWeight
             .sub(memberTester.member.voterWeight)
             .add(memberVoterWeightRecord.voterWeight),
+          rewardWeight: clanTester.clan.rewardWeight.add(
+            memberVoterWeightRecord.voterWeight
+          ),
         });
 
         await expect(
@@ -0,0 +1,261 @@
+import {startTest} from '../../dev/startTest';
+import {
+  windDownExitTestData,
+  RealmTester,
+  parseLogsEvent,
+  WindDownExitTestData,
+  buildSplGovernanceProgram,
+} from '../../src';
+import {ClanTester, MemberTester, RootTester} from '../../src/VoteAggregator';
+import {BN} from '@coral-xyz/anchor';
+import {AccountMeta, Keypair, PublicKey} from '@solana/web3.js';
+
+const buildTesters = ({realm, root, member}: WindDownExitTestData) => {
+  const tokenConfig =
+    (root.side === 'community'
+      ? realm.communityTokenConfig
+      : realm.councilTokenConfig) || {};
+  const voteAggregatorId =
+    root.voteAggregatorId ||
+    new PublicKey('VoTaGDreyne7jk59uwbgRRbaAzxvNbyNipaJMrRXhjT');
+  const [rootAddress] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from('root', 'utf-8'),
+      realm.realmAddress.toBuffer(),
+      root.side === 'community'
+        ? realm.communityMint.toBuffer()
+        : realm.councilMint!.toBuffer(),
+    ],
+    voteAggregatorId
+  );
+  if (tokenConfig.lockAuthorities === undefined) {
+    tokenConfig.lockAuthorities = [
+      PublicKey.findProgramAddressSync(
+        [Buffer.from('lock-authority', 'utf8'), rootAddress.toBuffer()],
+        voteAggregatorId
+      )[0],
+    ];
+  }
+  if (root.side === 'community') {
+    realm.communityTokenConfig = tokenConfig;
+  } else {
+    realm.councilTokenConfig = tokenConfig;
+  }
+
+  const realmTester = new RealmTester(realm);
+  const rootTester = new RootTester({
+    ...root,
+    realm: realmTester,
+  });
+  if (member.locks === undefined) {
+    member.locks = [
+      {
+        lockType: 0,
+        authority: rootTester.lockAuthority[0],
+        expiry: null,
+      },
+    ];
+  }
+  const memberTester = new MemberTester({
+    ...member,
+    root: rootTester,
+    membership: MemberTester.membershipTesters({
+      membership: member.membership || [],
+      root: rootTester,
+    }),
+  });
+  const clanTesters = memberTester.membership.map(({clan}) => {
+    if (!(clan instanceof ClanTester)) {
+      throw new Error('Clan data should be provided');
+    }
+    return clan;
+  });
+  const rest: AccountMeta[] = clanTesters.flatMap(clanTester => [
+    {
+      pubkey: clanTester.clanAddress,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: clanTester.voterWeightAddress[0],
+      isWritable: true,
+      isSigner: false,
+    },
+  ]);
+  return {realmTester, rootTester, memberTester, clanTesters, rest};
+};
+
+describe('wind_down_exit instruction', () => {
+  it.each(windDownExitTestData.filter(({error}) => !error))(
+    'Works',
+    async (data: WindDownExitTestData) => {
+      const {realmTester, rootTester, memberTester, clanTesters, rest} =
+        buildTesters(data);
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          ...(await memberTester.accounts()),
+          ...(
+            await Promise.all(clanTesters.map(clan => clan.accounts()))
+          ).flat(),
+        ],
+      });
+      const splGovernance = buildSplGovernanceProgram({
+        splGovernanceId: rootTester.splGovernanceId,
+        connection: program.provider.connection,
+      });
+
+      const tx = await program.methods
+        .windDownExit()
+        .accountsStrict({
+          member: memberTester.memberAddress[0],
+          memberAuthority: memberTester.ownerAddress,
+          memberTor: memberTester.tokenOwnerRecordAddress[0],
+          root: rootTester.rootAddress[0],
+          realm: realmTester.realmAddress,
+          realmConfig: await realmTester.realmConfigId(),
+          lockAuthority: rootTester.lockAuthority[0],
+          maxVwr: rootTester.maxVoterWeightAddress[0],
+          governanceProgram: rootTester.splGovernanceId,
+        })
+        .remainingAccounts(rest)
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer, data.member.owner as Keypair);
+
+      const clanEvents = clanTesters.flatMap(clanTester => [
+        {
+          name: 'ClanVoterWeightChanged',
+          data: {
+            clan: clanTester.clanAddress,
+            root: rootTester.rootAddress[0],
+            oldVoterWeight: clanTester.voterWeightRecord.voterWeight,
+            newVoterWeight: clanTester.voterWeightRecord.voterWeight.sub(
+              memberTester.member.voterWeight
+            ),
+            oldPermamentVoterWeight: clanTester.clan.permanentVoterWeight,
+            newPermamentVoterWeight: clanTester.clan.permanentVoterWeight.sub(
+              memberTester.member.voterWeight
+            ),
+            oldIsPermanent: true,
+            newIsPermanent: true,
+          },
+        },
+        {
+          name: 'ClanMemberLeft',
+          data: {
+            member: memberTester.memberAddress[0],
+            clan: clanTester.clanAddress,
+            root: rootTester.rootAddress[0],
+            owner: memberTester.ownerAddress,
+          },
+        },
+      ]);
+      await expect(
+        testContext.banksClient
+          .processTransaction(tx)
+          .then(meta => parseLogsEvent(program, meta.logMessages))
+      ).resolves.toStrictEqual([
+        ...clanEvents,
+        {
+          name: 'MemberVoterWeightChanged',
+          data: {
+            member: memberTester.memberAddress[0],
+            root: rootTester.rootAddress[0],
+            oldVoterWeight: memberTester.member.voterWeight,
+            newVoterWeight: new BN(0),
+            oldVoterWeightRecord: memberTester.member.voterWeightRecord,
+            newVoterWeightRecord: memberTester.member.voterWeightRecord,
+          },
+        },
+        {
+          name: 'MaxVoterWeightChanged',
+          data: {
+            root: rootTester.rootAddress[0],
+            oldMaxVoterWeight: rootTester.maxVoterWeight.maxVoterWeight,
+            newMaxVoterWeight: rootTester.maxVoterWeight.maxVoterWeight.sub(
+              memberTester.member.voterWeight
+            ),
+          },
+        },
+      ]);
+
+      await expect(
+        program.account.member.fetch(memberTester.memberAddress[0])
+      ).resolves.toStrictEqual({
+        ...memberTester.member,
+        membership: [],
+        voterWeight: new BN(0),
+        transformedVoterWeight: new BN(0),
+      });
+
+      for (const clanTester of clanTesters) {
+        await expect(
+          program.account.clan.fetch(clanTester.clanAddress)
+        ).resolves.toStrictEqual({
+          ...clanTester.clan,
+          permanentMembers: clanTester.clan.permanentMembers.subn(1),
+          permanentVoterWeight: clanTester.clan.permanentVoterWeight.sub(
+            memberTester.member.voterWeight
+          ),
+        });
+      }
+
+      await expect(
+        splGovernance.account.tokenOwnerRecordV2.fetch(
+          memberTester.tokenOwnerRecordAddress[0]
+        )
+      ).resolves.toStrictEqual({
+        ...memberTester.tokenOwnerRecord,
+        locks: [],
+      });
+    }
+  );
+
+  it.each(windDownExitTestData.filter(({error}) => error))(
+    'Fails with $error',
+    async (data: WindDownExitTestData) => {
+      const {realmTester, rootTester, memberTester, clanTesters, rest} =
+        buildTesters(data);
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+          ...(await memberTester.accounts()),
+          ...(
+            await Promise.all(clanTesters.map(clan => clan.accounts()))
+          ).flat(),
+        ],
+      });
+
+      const tx = await program.methods
+        .windDownExit()
+        .accountsStrict({
+          member: memberTester.memberAddress[0],
+          memberAuthority: memberTester.ownerAddress,
+          memberTor: memberTester.tokenOwnerRecordAddress[0],
+          root: rootTester.rootAddress[0],
+          realm: realmTester.realmAddress,
+          realmConfig: await realmTester.realmConfigId(),
+          lockAuthority: rootTester.lockAuthority[0],
+          maxVwr: rootTester.maxVoterWeightAddress[0],
+          governanceProgram: rootTester.splGovernanceId,
+        })
+        .remainingAccounts(rest)
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer, data.member.owner as Keypair);
+
+      const result = await testContext.banksClient.tryProcessTransaction(tx);
+      expect(result.result).not.toBeNull();
+      expect(result.meta?.logMessages).toContainEqual(
+        expect.stringContaining(`Error Code: ${data.error}.`)
+      );
+    }
+  );
+});
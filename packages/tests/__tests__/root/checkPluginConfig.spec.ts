@@ -0,0 +1,121 @@
+import {startTest} from '../../dev/startTest';
+import {PublicKey} from '@solana/web3.js';
+import {
+  CheckPluginConfigTestData,
+  RealmTester,
+  checkPluginConfigTestData,
+  parseLogsEvent,
+} from '../../src';
+import {RootAccount, RootTester} from '../../src/VoteAggregator';
+
+describe('check_plugin_config instruction', () => {
+  it.each(checkPluginConfigTestData.filter(({error}) => !error))(
+    'Works',
+    async ({realm, root, drift}: CheckPluginConfigTestData) => {
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+        ],
+      });
+
+      const tx = await program.methods
+        .checkPluginConfig()
+        .accountsStrict({
+          root: rootTester.rootAddress[0],
+          realm: realmTester.realmAddress,
+          realmConfig: await realmTester.realmConfigId(),
+          votingWeightPlugin: rootTester.root.votingWeightPlugin.equals(
+            PublicKey.default
+          )
+            ? null
+            : rootTester.root.votingWeightPlugin,
+          governanceProgram: rootTester.splGovernanceId,
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer);
+
+      const events = [];
+      if (drift && !rootTester.root.pluginDrift) {
+        events.push({
+          name: 'PluginConfigDrift',
+          data: {
+            root: rootTester.rootAddress[0],
+            realmVoterWeightAddin:
+              realmTester.config.communityTokenConfig.voterWeightAddin,
+            realmMaxVoterWeightAddin:
+              realmTester.config.communityTokenConfig.maxVoterWeightAddin,
+            votingWeightPlugin: rootTester.root.votingWeightPlugin,
+            verifiedVotingWeightPlugin:
+              rootTester.root.verifiedVotingWeightPlugin,
+          },
+        });
+      } else if (!drift && rootTester.root.pluginDrift) {
+        events.push({
+          name: 'PluginConfigRestored',
+          data: {
+            root: rootTester.rootAddress[0],
+          },
+        });
+      }
+      await expect(
+        testContext.banksClient
+          .processTransaction(tx)
+          .then(meta => parseLogsEvent(program, meta.logMessages))
+      ).resolves.toStrictEqual(events);
+
+      await expect(
+        program.account.root.fetch(rootTester.rootAddress[0])
+      ).resolves.toStrictEqual<RootAccount>({
+        ...rootTester.root,
+        pluginDrift: drift!,
+      });
+    }
+  );
+
+  it.each(checkPluginConfigTestData.filter(({error}) => error))(
+    'Fails without the plugin account with $error',
+    async ({realm, root, error}: CheckPluginConfigTestData) => {
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+        ],
+      });
+
+      const tx = await program.methods
+        .checkPluginConfig()
+        .accountsStrict({
+          root: rootTester.rootAddress[0],
+          realm: realmTester.realmAddress,
+          realmConfig: await realmTester.realmConfigId(),
+          votingWeightPlugin: null,
+          governanceProgram: rootTester.splGovernanceId,
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer);
+
+      const result = await testContext.banksClient.tryProcessTransaction(tx);
+      expect(result.result).not.toBeNull();
+      expect(result.meta?.logMessages).toContainEqual(
+        expect.stringContaining(`Error Code: ${error}.`)
+      );
+    }
+  );
+});
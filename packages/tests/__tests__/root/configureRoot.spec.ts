@@ -39,7 +39,7 @@ describe('Configure root', () => {
         .accountsStrict({
           root: rootTester.rootAddress[0],
           realm: rootTester.root.realm,
-          realmAuthority: realmTester.authorityAddress!,
+          admin: realmTester.authorityAddress!,
         })
         .transaction();
       tx.recentBlockhash = testContext.lastBlockhash;
@@ -110,7 +110,7 @@ describe('Configure root', () => {
         .accountsStrict({
           root: rootTester.rootAddress[0],
           realm: rootTester.root.realm,
-          realmAuthority: realmTester.authorityAddress!,
+          admin: realmTester.authorityAddress!,
         })
         .transaction();
       tx.recentBlockhash = testContext.lastBlockhash;
@@ -148,11 +148,11 @@ describe('Configure root', () => {
 
   it.each(
     configureRootTestData.filter(
-      ({error, paused}) => !error && paused !== undefined
+      ({error, disableVoterWeightReset}) => !error && disableVoterWeightReset
     )
   )(
-    'Runs pause/resume instruction',
-    async ({realm, root, paused}: ConfigureRootTestData) => {
+    'Runs disable_voter_weight_reset instruction',
+    async ({realm, root}: ConfigureRootTestData) => {
       const realmTester = new RealmTester(realm);
       if (!(realmTester.authority instanceof Keypair)) {
         throw new Error('Realm authority keypair is required');
@@ -169,14 +169,76 @@ describe('Configure root', () => {
         ],
       });
 
-      const tx = await (paused
-        ? program.methods.pause()
-        : program.methods.resume()
-      )
+      const tx = await program.methods
+        .disableVoterWeightReset()
         .accountsStrict({
           root: rootTester.rootAddress[0],
           realm: rootTester.root.realm,
-          realmAuthority: realmTester.authorityAddress!,
+          admin: realmTester.authorityAddress!,
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer, realmTester.authority! as Keypair);
+
+      await expect(
+        testContext.banksClient
+          .processTransaction(tx)
+          .then(meta => parseLogsEvent(program, meta.logMessages))
+      ).resolves.toStrictEqual([
+        {
+          name: 'VoterWeightResetChanged',
+          data: {
+            root: rootTester.rootAddress[0],
+            oldVoterWeightReset: rootTester.root.voterWeightReset,
+            newVoterWeightReset: null,
+          },
+        },
+      ]);
+
+      await expect(
+        program.account.root.fetch(rootTester.rootAddress[0])
+      ).resolves.toStrictEqual<RootAccount>({
+        ...rootTester.root,
+        voterWeightReset: null,
+      });
+    }
+  );
+
+  it.each(
+    configureRootTestData.filter(
+      ({error, voterWeightExpiryTolerance}) =>
+        !error && voterWeightExpiryTolerance !== undefined
+    )
+  )(
+    'Runs set_voter_weight_expiry_tolerance instruction',
+    async ({
+      realm,
+      root,
+      voterWeightExpiryTolerance,
+    }: ConfigureRootTestData) => {
+      const realmTester = new RealmTester(realm);
+      if (!(realmTester.authority instanceof Keypair)) {
+        throw new Error('Realm authority keypair is required');
+      }
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+        ],
+      });
+
+      const tx = await program.methods
+        .setVoterWeightExpiryTolerance(voterWeightExpiryTolerance!)
+        .accountsStrict({
+          root: rootTester.rootAddress[0],
+          realm: rootTester.root.realm,
+          admin: realmTester.authorityAddress!,
         })
         .transaction();
       tx.recentBlockhash = testContext.lastBlockhash;
@@ -184,19 +246,87 @@ describe('Configure root', () => {
       tx.sign(testContext.payer, realmTester.authority! as Keypair);
 
       const events = [];
-      if (!rootTester.root.paused && paused) {
+      if (
+        !rootTester.root.voterWeightExpiryTolerance.eq(
+          voterWeightExpiryTolerance!
+        )
+      ) {
         events.push({
-          name: 'Paused',
+          name: 'VoterWeightExpiryToleranceChanged',
           data: {
             root: rootTester.rootAddress[0],
+            oldVoterWeightExpiryTolerance:
+              rootTester.root.voterWeightExpiryTolerance,
+            newVoterWeightExpiryTolerance: voterWeightExpiryTolerance,
           },
         });
       }
-      else if (rootTester.root.paused && !paused) {
+      await expect(
+        testContext.banksClient
+          .processTransaction(tx)
+          .then(meta => parseLogsEvent(program, meta.logMessages))
+      ).resolves.toStrictEqual(events);
+
+      await expect(
+        program.account.root.fetch(rootTester.rootAddress[0])
+      ).resolves.toStrictEqual<RootAccount>({
+        ...rootTester.root,
+        voterWeightExpiryTolerance: voterWeightExpiryTolerance!,
+      });
+    }
+  );
+
+  it.each(
+    configureRootTestData.filter(
+      ({error, pauseFlags, resumeFlags}) =>
+        !error && (pauseFlags !== undefined || resumeFlags !== undefined)
+    )
+  )(
+    'Runs pause/resume instruction',
+    async ({realm, root, pauseFlags, resumeFlags}: ConfigureRootTestData) => {
+      const realmTester = new RealmTester(realm);
+      if (!(realmTester.authority instanceof Keypair)) {
+        throw new Error('Realm authority keypair is required');
+      }
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+        ],
+      });
+
+      const tx = await (pauseFlags !== undefined
+        ? program.methods.pause(pauseFlags)
+        : program.methods.resume(resumeFlags!)
+      )
+        .accountsStrict({
+          root: rootTester.rootAddress[0],
+          realm: rootTester.root.realm,
+          admin: realmTester.authorityAddress!,
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer, realmTester.authority! as Keypair);
+
+      const oldPauseFlags = rootTester.root.pauseFlags;
+      const newPauseFlags =
+        pauseFlags !== undefined
+          ? (oldPauseFlags | pauseFlags) >>> 0
+          : (oldPauseFlags & ~resumeFlags!) >>> 0;
+      const events = [];
+      if (newPauseFlags !== oldPauseFlags) {
         events.push({
-          name: 'Resumed',
+          name: pauseFlags !== undefined ? 'Paused' : 'Resumed',
           data: {
             root: rootTester.rootAddress[0],
+            flags: (newPauseFlags ^ oldPauseFlags) >>> 0,
+            pauseFlags: newPauseFlags,
           },
         });
       }
@@ -210,8 +340,234 @@ describe('Configure root', () => {
         program.account.root.fetch(rootTester.rootAddress[0])
       ).resolves.toStrictEqual<RootAccount>({
         ...rootTester.root,
-        paused: paused!,
+        pauseFlags: newPauseFlags,
       });
     }
   );
+
+  it.each(
+    configureRootTestData.filter(
+      ({error, newAdmin}) => !error && newAdmin !== undefined
+    )
+  )(
+    'Runs propose_root_admin and accept_root_admin instructions',
+    async ({realm, root, newAdmin}: ConfigureRootTestData) => {
+      const realmTester = new RealmTester(realm);
+      if (!(realmTester.authority instanceof Keypair)) {
+        throw new Error('Realm authority keypair is required');
+      }
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+        ],
+      });
+
+      const proposeTx = await program.methods
+        .proposeRootAdmin(newAdmin!.publicKey)
+        .accountsStrict({
+          root: rootTester.rootAddress[0],
+          realm: rootTester.root.realm,
+          admin: rootTester.root.admin,
+        })
+        .transaction();
+      proposeTx.recentBlockhash = testContext.lastBlockhash;
+      proposeTx.feePayer = testContext.payer.publicKey;
+      proposeTx.sign(testContext.payer, realmTester.authority! as Keypair);
+
+      await expect(
+        testContext.banksClient
+          .processTransaction(proposeTx)
+          .then(meta => parseLogsEvent(program, meta.logMessages))
+      ).resolves.toStrictEqual([
+        {
+          name: 'RootAdminProposed',
+          data: {
+            root: rootTester.rootAddress[0],
+            admin: rootTester.root.admin,
+            pendingAdmin: newAdmin!.publicKey,
+          },
+        },
+      ]);
+
+      const acceptTx = await program.methods
+        .acceptRootAdmin()
+        .accountsStrict({
+          root: rootTester.rootAddress[0],
+          pendingAdmin: newAdmin!.publicKey,
+        })
+        .transaction();
+      acceptTx.recentBlockhash = testContext.lastBlockhash;
+      acceptTx.feePayer = testContext.payer.publicKey;
+      acceptTx.sign(testContext.payer, newAdmin!);
+
+      await expect(
+        testContext.banksClient
+          .processTransaction(acceptTx)
+          .then(meta => parseLogsEvent(program, meta.logMessages))
+      ).resolves.toStrictEqual([
+        {
+          name: 'RootAdminChanged',
+          data: {
+            root: rootTester.rootAddress[0],
+            oldAdmin: rootTester.root.admin,
+            newAdmin: newAdmin!.publicKey,
+          },
+        },
+      ]);
+
+      await expect(
+        program.account.root.fetch(rootTester.rootAddress[0])
+      ).resolves.toStrictEqual<RootAccount>({
+        ...rootTester.root,
+        admin: newAdmin!.publicKey,
+        pendingAdmin: null,
+      });
+    }
+  );
+
+  it.each(
+    configureRootTestData.filter(
+      ({error, weightTransform}) => error && weightTransform !== undefined
+    )
+  )(
+    'Fails to run set_weight_transform instruction with $error',
+    async ({
+      realm,
+      root,
+      admin,
+      weightTransform,
+      error,
+    }: ConfigureRootTestData) => {
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+        ],
+      });
+
+      const tx = await program.methods
+        .setWeightTransform(weightTransform!)
+        .accountsStrict({
+          root: rootTester.rootAddress[0],
+          realm: rootTester.root.realm,
+          admin: admin!.publicKey,
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer, admin!);
+
+      const result = await testContext.banksClient.tryProcessTransaction(tx);
+      expect(result.result).not.toBeNull();
+      expect(result.meta?.logMessages).toContainEqual(
+        expect.stringContaining(`Error Code: ${error}.`)
+      );
+    }
+  );
+
+  it.each(
+    configureRootTestData.filter(
+      ({error, voterWeightExpiryTolerance}) =>
+        error && voterWeightExpiryTolerance !== undefined
+    )
+  )(
+    'Fails to run set_voter_weight_expiry_tolerance instruction with $error',
+    async ({
+      realm,
+      root,
+      admin,
+      voterWeightExpiryTolerance,
+      error,
+    }: ConfigureRootTestData) => {
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+        ],
+      });
+
+      const tx = await program.methods
+        .setVoterWeightExpiryTolerance(voterWeightExpiryTolerance!)
+        .accountsStrict({
+          root: rootTester.rootAddress[0],
+          realm: rootTester.root.realm,
+          admin: admin!.publicKey,
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer, admin!);
+
+      const result = await testContext.banksClient.tryProcessTransaction(tx);
+      expect(result.result).not.toBeNull();
+      expect(result.meta?.logMessages).toContainEqual(
+        expect.stringContaining(`Error Code: ${error}.`)
+      );
+    }
+  );
+
+  it.each(
+    configureRootTestData.filter(
+      ({error, staleMemberGracePeriod}) =>
+        error && staleMemberGracePeriod !== undefined
+    )
+  )(
+    'Fails to run set_stale_member_grace_period instruction with $error',
+    async ({
+      realm,
+      root,
+      admin,
+      staleMemberGracePeriod,
+      error,
+    }: ConfigureRootTestData) => {
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+        ],
+      });
+
+      const tx = await program.methods
+        .setStaleMemberGracePeriod(staleMemberGracePeriod!)
+        .accountsStrict({
+          root: rootTester.rootAddress[0],
+          realm: rootTester.root.realm,
+          admin: admin!.publicKey,
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer, admin!);
+
+      const result = await testContext.banksClient.tryProcessTransaction(tx);
+      expect(result.result).not.toBeNull();
+      expect(result.meta?.logMessages).toContainEqual(
+        expect.stringContaining(`Error Code: ${error}.`)
+      );
+    }
+  );
 });
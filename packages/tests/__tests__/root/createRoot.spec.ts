@@ -44,23 +44,15 @@ describe('create_root instruction', () => {
       );
 
       const communityTokenConfigArgs = {
-        voterWeightAddin: new PublicKey(
-          'VoTaGDreyne7jk59uwbgRRbaAzxvNbyNipaJMrRXhjT'
-        ),
-        maxVoterWeightAddin:
-          realmTester.config.communityTokenConfig.maxVoterWeightAddin ||
-          undefined,
+        voterWeightAddin: program.programId,
+        maxVoterWeightAddin: program.programId,
         tokenType: realmTester.config.communityTokenConfig.tokenType.liquid
           ? GoverningTokenType.Liquid
           : realmTester.config.communityTokenConfig.tokenType.membership
           ? GoverningTokenType.Membership
           : GoverningTokenType.Dormant,
-        useVoterWeightAddin: Boolean(
-          realmTester.config.communityTokenConfig.voterWeightAddin
-        ),
-        useMaxVoterWeightAddin: Boolean(
-          realmTester.config.communityTokenConfig.maxVoterWeightAddin
-        ),
+        useVoterWeightAddin: true,
+        useMaxVoterWeightAddin: true,
       };
       const councilTokenConfigArgs = realmTester.realm.config.councilMint
         ? {
@@ -177,7 +169,21 @@ describe('create_root instruction', () => {
           PublicKey.default,
         maxProposalLifetime,
         voterWeightReset: null,
-        paused: false,
+        pauseFlags: 0,
+        voterWeightExpiryTolerance: new BN(0),
+        staleMemberGracePeriod: null,
+        admin: realmTester.authorityAddress!,
+        pendingAdmin: null,
+        pauseGuardian: null,
+        windDown: false,
+        memberLockDuration: new BN(2592000),
+        installed: false,
+        pluginDrift: false,
+        verifiedVotingWeightPlugin: PublicKey.default,
+        membershipWarmup: new BN(0),
+        maxLoyaltyBonusBp: 0,
+        weightTransform: {linear: {}},
+        recallThresholdBp: 0,
         bumps: {
           root: rootBump,
           maxVoterWeight: maxVwrBump,
@@ -198,6 +204,7 @@ describe('create_root instruction', () => {
         communityTokenConfig: {
           ...realmTester.config.communityTokenConfig,
           voterWeightAddin: program.programId,
+          maxVoterWeightAddin: program.programId,
           lockAuthorities: [lockAuthority],
         },
       });
@@ -260,21 +267,15 @@ describe('create_root instruction', () => {
         ),
       };
       const councilTokenConfigArgs = {
-        voterWeightAddin: new PublicKey(
-          'VoTaGDreyne7jk59uwbgRRbaAzxvNbyNipaJMrRXhjT'
-        ),
-        maxVoterWeightAddin:
-          realmTester.config.councilTokenConfig.maxVoterWeightAddin ||
-          undefined,
+        voterWeightAddin: program.programId,
+        maxVoterWeightAddin: program.programId,
         tokenType: realmTester.config.councilTokenConfig.tokenType.liquid
           ? GoverningTokenType.Liquid
           : realmTester.config.councilTokenConfig.tokenType.membership
           ? GoverningTokenType.Membership
           : GoverningTokenType.Dormant,
         useVoterWeightAddin: true,
-        useMaxVoterWeightAddin: Boolean(
-          realmTester.config.councilTokenConfig.maxVoterWeightAddin
-        ),
+        useMaxVoterWeightAddin: true,
       };
 
       const [lockAuthority, lockAuthorityBump] =
@@ -372,7 +373,21 @@ describe('create_root instruction', () => {
           PublicKey.default,
         maxProposalLifetime,
         voterWeightReset: null,
-        paused: false,
+        pauseFlags: 0,
+        voterWeightExpiryTolerance: new BN(0),
+        staleMemberGracePeriod: null,
+        admin: realmTester.authorityAddress!,
+        pendingAdmin: null,
+        pauseGuardian: null,
+        windDown: false,
+        memberLockDuration: new BN(2592000),
+        installed: false,
+        pluginDrift: false,
+        verifiedVotingWeightPlugin: PublicKey.default,
+        membershipWarmup: new BN(0),
+        maxLoyaltyBonusBp: 0,
+        weightTransform: {linear: {}},
+        recallThresholdBp: 0,
         bumps: {
           root: rootBump,
           maxVoterWeight: maxVwrBump,
@@ -393,6 +408,7 @@ describe('create_root instruction', () => {
         councilTokenConfig: {
           ...realmTester.config.councilTokenConfig,
           voterWeightAddin: program.programId,
+          maxVoterWeightAddin: program.programId,
           lockAuthorities: [lockAuthority],
         },
       });
@@ -408,4 +424,52 @@ describe('create_root instruction', () => {
       });
     }
   );
+
+  it.each(createRootTestData.filter(({error}) => !error))(
+    'Works without switching the realm config in the same transaction',
+    async ({realm, maxProposalLifetime = new BN(0)}: CreateRootTestData) => {
+      const realmTester = new RealmTester(realm);
+      const {program, testContext} = await startTest({
+        splGovernanceId: realmTester.splGovernanceId,
+        accounts: await realmTester.accounts(),
+      });
+      const [rootAddress] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('root', 'utf-8'),
+          realmTester.realmAddress.toBuffer(),
+          realmTester.realm.communityMint.toBuffer(),
+        ],
+        program.programId
+      );
+      const [maxVwr] = PublicKey.findProgramAddressSync(
+        [Buffer.from('max-voter-weight', 'utf-8'), rootAddress.toBuffer()],
+        program.programId
+      );
+
+      const tx = await program.methods
+        .createRoot(maxProposalLifetime)
+        .accountsStrict({
+          root: rootAddress,
+          realm: realmTester.realmAddress,
+          realmConfig: await realmTester.realmConfigId(),
+          governingTokenMint: realmTester.realm.communityMint,
+          realmAuthority: realmTester.realm.authority!,
+          maxVwr: maxVwr,
+          payer: program.provider.publicKey!,
+          governanceProgram: realmTester.splGovernanceId,
+          systemProgram: SystemProgram.programId,
+          voteAggregatorProgram: program.programId,
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer, realmTester.authority as Keypair);
+
+      // The realm config may be switched later, e.g. by a DAO proposal
+      await testContext.banksClient.processTransaction(tx);
+      await expect(
+        program.account.root.fetch(rootAddress)
+      ).resolves.toMatchObject({installed: false});
+    }
+  );
 });
@@ -0,0 +1,338 @@
+import {startTest} from '../../dev/startTest';
+import {Keypair, PublicKey, SystemProgram} from '@solana/web3.js';
+import {
+  RealmTester,
+  RootConfigTestData,
+  parseLogsEvent,
+  rootConfigTestData,
+} from '../../src';
+import {RootAccount, RootTester} from '../../src/VoteAggregator';
+import BN from 'bn.js';
+
+const pendingRootConfigAddress = (rootTester: RootTester) =>
+  PublicKey.findProgramAddressSync(
+    [
+      Buffer.from('pending-root-config', 'utf-8'),
+      rootTester.rootAddress[0].toBuffer(),
+    ],
+    rootTester.voteAggregatorId
+  );
+
+describe('Root config timelock', () => {
+  it.each(rootConfigTestData.filter(({error}) => !error))(
+    'Runs queue_root_config and apply_root_config instructions',
+    async ({realm, root, change}: RootConfigTestData) => {
+      const realmTester = new RealmTester(realm);
+      if (!(realmTester.authority instanceof Keypair)) {
+        throw new Error('Realm authority keypair is required');
+      }
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+        ],
+      });
+      const [pendingRootConfig, bump] = pendingRootConfigAddress(rootTester);
+
+      const queueTx = await program.methods
+        .queueRootConfig(change)
+        .accountsStrict({
+          configureRoot: {
+            root: rootTester.rootAddress[0],
+            realm: rootTester.root.realm,
+            admin: realmTester.authorityAddress!,
+          },
+          pendingRootConfig,
+          payer: testContext.payer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .transaction();
+      queueTx.recentBlockhash = testContext.lastBlockhash;
+      queueTx.feePayer = testContext.payer.publicKey;
+      queueTx.sign(testContext.payer, realmTester.authority! as Keypair);
+
+      const clock = await testContext.banksClient.getClock();
+      const activationTime = new BN(clock.unixTimestamp.toString()).add(
+        rootTester.root.maxProposalLifetime
+      );
+      await expect(
+        testContext.banksClient
+          .processTransaction(queueTx)
+          .then(meta => parseLogsEvent(program, meta.logMessages))
+      ).resolves.toStrictEqual([
+        {
+          name: 'RootConfigQueued',
+          data: {
+            root: rootTester.rootAddress[0],
+            activationTime,
+            change,
+          },
+        },
+      ]);
+
+      await expect(
+        program.account.pendingRootConfig.fetch(pendingRootConfig)
+      ).resolves.toStrictEqual({
+        root: rootTester.rootAddress[0],
+        rentPayer: testContext.payer.publicKey,
+        activationTime,
+        change,
+        bump,
+      });
+
+      const applyTx = await program.methods
+        .applyRootConfig()
+        .accountsStrict({
+          root: rootTester.rootAddress[0],
+          pendingRootConfig,
+          maxVwr: rootTester.maxVoterWeightAddress[0],
+          rentPayer: testContext.payer.publicKey,
+        })
+        .transaction();
+      applyTx.recentBlockhash = testContext.lastBlockhash;
+      applyTx.feePayer = testContext.payer.publicKey;
+      applyTx.sign(testContext.payer);
+
+      await expect(
+        testContext.banksClient
+          .processTransaction(applyTx)
+          .then(meta => parseLogsEvent(program, meta.logMessages))
+      ).resolves.toStrictEqual([
+        {
+          name: 'MembershipWarmupChanged',
+          data: {
+            root: rootTester.rootAddress[0],
+            oldMembershipWarmup: rootTester.root.membershipWarmup,
+            newMembershipWarmup: change.membershipWarmup,
+          },
+        },
+        {
+          name: 'MaxLoyaltyBonusChanged',
+          data: {
+            root: rootTester.rootAddress[0],
+            oldMaxLoyaltyBonusBp: rootTester.root.maxLoyaltyBonusBp,
+            newMaxLoyaltyBonusBp: change.maxLoyaltyBonusBp,
+          },
+        },
+        {
+          name: 'WeightTransformChanged',
+          data: {
+            root: rootTester.rootAddress[0],
+            oldWeightTransform: rootTester.root.weightTransform,
+            newWeightTransform: change.weightTransform,
+          },
+        },
+        {
+          name: 'RecallThresholdChanged',
+          data: {
+            root: rootTester.rootAddress[0],
+            oldRecallThresholdBp: rootTester.root.recallThresholdBp,
+            newRecallThresholdBp: change.recallThresholdBp,
+          },
+        },
+        {
+          name: 'VoterWeightExpiryToleranceChanged',
+          data: {
+            root: rootTester.rootAddress[0],
+            oldVoterWeightExpiryTolerance:
+              rootTester.root.voterWeightExpiryTolerance,
+            newVoterWeightExpiryTolerance: change.voterWeightExpiryTolerance,
+          },
+        },
+        {
+          name: 'StaleMemberGracePeriodChanged',
+          data: {
+            root: rootTester.rootAddress[0],
+            oldStaleMemberGracePeriod: rootTester.root.staleMemberGracePeriod,
+            newStaleMemberGracePeriod: change.staleMemberGracePeriod,
+          },
+        },
+        {
+          name: 'RootConfigApplied',
+          data: {
+            root: rootTester.rootAddress[0],
+            change,
+          },
+        },
+      ]);
+
+      await expect(
+        program.account.root.fetch(rootTester.rootAddress[0])
+      ).resolves.toStrictEqual<RootAccount>({
+        ...rootTester.root,
+        membershipWarmup: change.membershipWarmup!,
+        maxLoyaltyBonusBp: change.maxLoyaltyBonusBp!,
+        weightTransform: change.weightTransform!,
+        recallThresholdBp: change.recallThresholdBp!,
+        voterWeightExpiryTolerance: change.voterWeightExpiryTolerance!,
+        staleMemberGracePeriod: change.staleMemberGracePeriod,
+      });
+      await expect(
+        testContext.banksClient.getAccount(pendingRootConfig)
+      ).resolves.toBeNull();
+    }
+  );
+
+  it.each(rootConfigTestData.filter(({error}) => !error))(
+    'Runs queue_root_config and cancel_root_config instructions',
+    async ({realm, root, change}: RootConfigTestData) => {
+      const realmTester = new RealmTester(realm);
+      if (!(realmTester.authority instanceof Keypair)) {
+        throw new Error('Realm authority keypair is required');
+      }
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+        ],
+      });
+      const [pendingRootConfig] = pendingRootConfigAddress(rootTester);
+      const configureRoot = {
+        root: rootTester.rootAddress[0],
+        realm: rootTester.root.realm,
+        admin: realmTester.authorityAddress!,
+      };
+
+      const queueTx = await program.methods
+        .queueRootConfig(change)
+        .accountsStrict({
+          configureRoot,
+          pendingRootConfig,
+          payer: testContext.payer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .transaction();
+      queueTx.recentBlockhash = testContext.lastBlockhash;
+      queueTx.feePayer = testContext.payer.publicKey;
+      queueTx.sign(testContext.payer, realmTester.authority! as Keypair);
+      await testContext.banksClient.processTransaction(queueTx);
+
+      const cancelTx = await program.methods
+        .cancelRootConfig()
+        .accountsStrict({
+          configureRoot,
+          pendingRootConfig,
+          rentPayer: testContext.payer.publicKey,
+        })
+        .transaction();
+      cancelTx.recentBlockhash = testContext.lastBlockhash;
+      cancelTx.feePayer = testContext.payer.publicKey;
+      cancelTx.sign(testContext.payer, realmTester.authority! as Keypair);
+
+      await expect(
+        testContext.banksClient
+          .processTransaction(cancelTx)
+          .then(meta => parseLogsEvent(program, meta.logMessages))
+      ).resolves.toStrictEqual([
+        {
+          name: 'RootConfigCanceled',
+          data: {
+            root: rootTester.rootAddress[0],
+            change,
+          },
+        },
+      ]);
+
+      await expect(
+        program.account.root.fetch(rootTester.rootAddress[0])
+      ).resolves.toStrictEqual<RootAccount>(rootTester.root);
+      await expect(
+        testContext.banksClient.getAccount(pendingRootConfig)
+      ).resolves.toBeNull();
+    }
+  );
+
+  it.each(rootConfigTestData.filter(({error}) => !error))(
+    'Fails to run set_membership_warmup instruction once members joined',
+    async ({realm, root, change}: RootConfigTestData) => {
+      const realmTester = new RealmTester(realm);
+      if (!(realmTester.authority instanceof Keypair)) {
+        throw new Error('Realm authority keypair is required');
+      }
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+        ],
+      });
+
+      const tx = await program.methods
+        .setMembershipWarmup(change.membershipWarmup!)
+        .accountsStrict({
+          root: rootTester.rootAddress[0],
+          realm: rootTester.root.realm,
+          admin: realmTester.authorityAddress!,
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer, realmTester.authority! as Keypair);
+
+      const result = await testContext.banksClient.tryProcessTransaction(tx);
+      expect(result.result).not.toBeNull();
+      expect(result.meta?.logMessages).toContainEqual(
+        expect.stringContaining('Error Code: RootConfigChangeMustBeQueued.')
+      );
+    }
+  );
+
+  it.each(rootConfigTestData.filter(({error}) => error))(
+    'Fails to run queue_root_config instruction with $error',
+    async ({realm, root, change, admin, error}: RootConfigTestData) => {
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const signer = admin || realmTester.authority;
+      if (!(signer instanceof Keypair)) {
+        throw new Error('Admin keypair is required');
+      }
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+        ],
+      });
+
+      const tx = await program.methods
+        .queueRootConfig(change)
+        .accountsStrict({
+          configureRoot: {
+            root: rootTester.rootAddress[0],
+            realm: rootTester.root.realm,
+            admin: signer.publicKey,
+          },
+          pendingRootConfig: pendingRootConfigAddress(rootTester)[0],
+          payer: testContext.payer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer, signer);
+
+      const result = await testContext.banksClient.tryProcessTransaction(tx);
+      expect(result.result).not.toBeNull();
+      expect(result.meta?.logMessages).toContainEqual(
+        expect.stringContaining(`Error Code: ${error}.`)
+      );
+    }
+  );
+});
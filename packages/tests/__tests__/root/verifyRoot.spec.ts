@@ -0,0 +1,116 @@
+import {startTest} from '../../dev/startTest';
+import {
+  RealmTester,
+  VerifyRootTestData,
+  parseLogsEvent,
+  verifyRootTestData,
+} from '../../src';
+import {RootAccount, RootTester} from '../../src/VoteAggregator';
+
+describe('verify_root instruction', () => {
+  it.each(verifyRootTestData.filter(({error}) => !error))(
+    'Works',
+    async ({realm, root}: VerifyRootTestData) => {
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+        ],
+      });
+
+      const tx = await program.methods
+        .verifyRoot()
+        .accountsStrict({
+          root: rootTester.rootAddress[0],
+          realm: realmTester.realmAddress,
+          realmConfig: await realmTester.realmConfigId(),
+          governanceProgram: rootTester.splGovernanceId,
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer);
+
+      const events = [];
+      if (
+        !rootTester.root.verifiedVotingWeightPlugin.equals(
+          rootTester.root.votingWeightPlugin
+        )
+      ) {
+        events.push({
+          name: 'VotingWeightPluginVerified',
+          data: {
+            root: rootTester.rootAddress[0],
+            oldVerifiedVotingWeightPlugin:
+              rootTester.root.verifiedVotingWeightPlugin,
+            newVerifiedVotingWeightPlugin: rootTester.root.votingWeightPlugin,
+          },
+        });
+      }
+      if (!rootTester.root.installed) {
+        events.push({
+          name: 'RootInstalled',
+          data: {
+            root: rootTester.rootAddress[0],
+          },
+        });
+      }
+      await expect(
+        testContext.banksClient
+          .processTransaction(tx)
+          .then(meta => parseLogsEvent(program, meta.logMessages))
+      ).resolves.toStrictEqual(events);
+
+      await expect(
+        program.account.root.fetch(rootTester.rootAddress[0])
+      ).resolves.toStrictEqual<RootAccount>({
+        ...rootTester.root,
+        installed: true,
+        verifiedVotingWeightPlugin: rootTester.root.votingWeightPlugin,
+      });
+    }
+  );
+
+  it.each(verifyRootTestData.filter(({error}) => error))(
+    'Fails with $error',
+    async ({realm, root, error}: VerifyRootTestData) => {
+      const realmTester = new RealmTester(realm);
+      const rootTester = new RootTester({
+        ...root,
+        realm: realmTester,
+      });
+      const {testContext, program} = await startTest({
+        splGovernanceId: rootTester.splGovernanceId,
+        accounts: [
+          ...(await realmTester.accounts()),
+          ...(await rootTester.accounts()),
+        ],
+      });
+
+      const tx = await program.methods
+        .verifyRoot()
+        .accountsStrict({
+          root: rootTester.rootAddress[0],
+          realm: realmTester.realmAddress,
+          realmConfig: await realmTester.realmConfigId(),
+          governanceProgram: rootTester.splGovernanceId,
+        })
+        .transaction();
+      tx.recentBlockhash = testContext.lastBlockhash;
+      tx.feePayer = testContext.payer.publicKey;
+      tx.sign(testContext.payer);
+
+      const result = await testContext.banksClient.tryProcessTransaction(tx);
+      expect(result.result).not.toBeNull();
+      expect(result.meta?.logMessages).toContainEqual(
+        expect.stringContaining(`Error Code: ${error}.`)
+      );
+    }
+  );
+});
@@ -0,0 +1,379 @@
+import {LAMPORTS_PER_SOL, PublicKey} from '@solana/web3.js';
+import {ClanTestData, buildKeypair} from '../..';
+import {RealmTestData} from '../../SplGovernance/realm';
+import {RootTestData} from '../../VoteAggregator/root';
+import {BN} from '@coral-xyz/anchor';
+import {ProposalTestData} from '../../SplGovernance';
+import {GovernanceTestData} from '../../SplGovernance/governance';
+
+export type CastVetoVoteTestData = {
+  realm: RealmTestData;
+  root: RootTestData;
+  clan: ClanTestData;
+  governance: GovernanceTestData;
+  proposal: ProposalTestData;
+  error?: string;
+};
+
+// Approvals of earlier proposals
+const voteHistoryEntries = (count: number) =>
+  Array.from({length: count}, (_, i) => ({
+    proposal: new PublicKey(i + 1),
+    vote: {approve: {}},
+    approvedOptions: new BN(1),
+    voterWeight: new BN(1000 + i),
+    votedAt: new BN(1700000000 + i),
+  }));
+
+export const castVetoVoteTestData: CastVetoVoteTestData[] = [
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '3xNbuehooXRpHEbntV4BCwaaMdLHrGcfvjdopTA6idRq'
+      ),
+      realmAddress: new PublicKey(
+        'ajKArho8NF8gQMj5dPtpGSexusxxFygmeCGfSyBaCM6'
+      ),
+      communityMint: new PublicKey(
+        '6aQt8CqhzhK65KsKAhYqjXPgwL5qK4oW3JQSQLPU1dBc'
+      ),
+      councilMint: new PublicKey(
+        'Csw2izY3W68Csv57vPZXHbACU1EAM3r6Fb2JZeNsgQHt'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(11),
+      },
+      minCommunityWeightToCreateGovernance: new BN(4),
+      name: 'Community vetoes council proposal',
+      communityMintAuthority: new PublicKey(
+        '8Uht9C3S3vCs5EoLwZbHNEXG3Z1ozmWB8uD6unKZphU6'
+      ),
+    },
+    root: {
+      side: 'community',
+    },
+    clan: {
+      address: new PublicKey('7oDiwr5jqPnsqhcxqySrZCiQgdLMC2isWVPDUKJQtFnK'),
+      owner: buildKeypair('4d7MpwrJiT19qUv3vbz1NRTNJFxY2sjgcXGxJNda3DBN', [
+        209, 230, 138, 172, 83, 61, 36, 120, 8, 129, 209, 107, 182, 175, 174,
+        253, 146, 133, 49, 180, 49, 28, 58, 204, 184, 113, 7, 169, 51, 146, 15,
+        48, 53, 211, 40, 254, 249, 238, 74, 62, 111, 121, 237, 157, 95, 130,
+        112, 94, 182, 139, 72, 179, 1, 13, 163, 76, 172, 85, 64, 168, 253, 232,
+        0, 169,
+      ]),
+      name: 'Marinade',
+    },
+    governance: {
+      address: new PublicKey('GfwLMedHpyPxHefc64b7GefTr24EnJyeG6dscKdb6tDr'),
+      config: {
+        communityVoteThreshold: {
+          yesVotePercentage: [60],
+        },
+        minCommunityWeightToCreateProposal: new BN(LAMPORTS_PER_SOL),
+        minTransactionHoldUpTime: 0,
+        votingBaseTime: 100000000,
+        communityVoteTipping: {early: {}},
+        councilVoteThreshold: {yesVotePercentage: [50]},
+        councilVetoVoteThreshold: {yesVotePercentage: [50]},
+        minCouncilWeightToCreateProposal: new BN(LAMPORTS_PER_SOL),
+        councilVoteTipping: {early: {}},
+        communityVetoVoteThreshold: {yesVotePercentage: [50]},
+        votingCoolOffTime: 0,
+        depositExemptProposalCount: 0,
+      },
+      activeProposalCount: new BN(1),
+    },
+    proposal: {
+      address: new PublicKey('7UKPUJUyA2XmiP9joBVFJphAGWC4Wixmq7nmU98tbAQ9'),
+      governingTokenMint: new PublicKey(
+        'Csw2izY3W68Csv57vPZXHbACU1EAM3r6Fb2JZeNsgQHt'
+      ),
+      state: {voting: {}},
+      owner: new PublicKey('DKLu1t8A1NDXpvygyQSRU3VCjiwTVvb6vzm9UD6uPJJn'),
+      signatoriesCount: 0,
+      signatoriesSignedOffCount: 0,
+      voteType: {singleChoice: {}},
+      options: [
+        {
+          label: 'First',
+          voteWeight: new BN(1000),
+          voteResult: {none: {}},
+          transactionsExecutedCount: 0,
+          transactionsCount: 0,
+          transactionsNextIndex: 0,
+        },
+      ],
+      draftAt: new BN(0),
+      votingAt: new BN(10000000000),
+      voteThreshold: {yesVotePercentage: [20]},
+      name: 'Council proposal',
+      descriptionLink: 'description',
+      vetoVoteWeight: new BN(0),
+    },
+  },
+  // Appends the veto to the vote history
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'Fm4jEWBUexEDNNUBccSjBPhdogB2JMCddhXpY7ztDaFZ'
+      ),
+      realmAddress: new PublicKey(
+        'BkeVy34a6uz5BVeaoucrXwyFczNfGNfTVrLsCUvd5BCc'
+      ),
+      communityMint: new PublicKey(
+        '2bszVfTfXsHjs7TED1iCCCdvLTwnKNXyqb5etGywjBRS'
+      ),
+      councilMint: new PublicKey(
+        '9u2zs6ReacN3qWzkuKfEP1aQLxD28UVNxL5kVf3U6Mw9'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(11),
+      },
+      minCommunityWeightToCreateGovernance: new BN(4),
+      name: 'Community vetoes council proposal',
+      communityMintAuthority: new PublicKey(
+        'CuB4qY2vH9zv16RhrPY5D7V1fnmLJtciVBUo9dcrxjNp'
+      ),
+    },
+    root: {
+      side: 'community',
+    },
+    clan: {
+      address: new PublicKey('792cMJpSWsm2N7hjbCRiBU7x1KfaqJn8QEEnZcfS2Y4s'),
+      owner: buildKeypair('38UsuHcqZga38NG31H18AKtTMUxQrdpHWU5v1rduhBJx', [
+        97, 195, 247, 82, 72, 181, 218, 99, 235, 149, 14, 218, 167, 100, 128,
+        238, 77, 98, 232, 50, 237, 183, 10, 128, 156, 65, 28, 64, 239, 96, 82,
+        136, 31, 161, 245, 249, 30, 208, 45, 74, 130, 24, 7, 187, 81, 28, 83,
+        121, 249, 249, 189, 22, 28, 177, 32, 183, 28, 237, 146, 235, 98, 120,
+        146, 185,
+      ]),
+      name: 'Marinade',
+      voterWeight: new BN(5000),
+      voteHistoryEntries: voteHistoryEntries(3),
+    },
+    governance: {
+      address: new PublicKey('D1QSDd9prEpHEr5q5RLYbBDgLzMMDVgFzs6mN1VoecMM'),
+      config: {
+        communityVoteThreshold: {
+          yesVotePercentage: [60],
+        },
+        minCommunityWeightToCreateProposal: new BN(LAMPORTS_PER_SOL),
+        minTransactionHoldUpTime: 0,
+        votingBaseTime: 100000000,
+        communityVoteTipping: {early: {}},
+        councilVoteThreshold: {yesVotePercentage: [50]},
+        councilVetoVoteThreshold: {yesVotePercentage: [50]},
+        minCouncilWeightToCreateProposal: new BN(LAMPORTS_PER_SOL),
+        councilVoteTipping: {early: {}},
+        communityVetoVoteThreshold: {yesVotePercentage: [50]},
+        votingCoolOffTime: 0,
+        depositExemptProposalCount: 0,
+      },
+      activeProposalCount: new BN(1),
+    },
+    proposal: {
+      address: new PublicKey('J292EtQXRiPKpfLrf47EwAy5wivkhCHMXehKh4QJfj1T'),
+      governingTokenMint: new PublicKey(
+        '9u2zs6ReacN3qWzkuKfEP1aQLxD28UVNxL5kVf3U6Mw9'
+      ),
+      state: {voting: {}},
+      owner: new PublicKey('6dWrgfWWcNxQuPbE6gKaEnG7Gke1DvCTRSzhCwmeQq78'),
+      signatoriesCount: 0,
+      signatoriesSignedOffCount: 0,
+      voteType: {singleChoice: {}},
+      options: [
+        {
+          label: 'First',
+          voteWeight: new BN(1000),
+          voteResult: {none: {}},
+          transactionsExecutedCount: 0,
+          transactionsCount: 0,
+          transactionsNextIndex: 0,
+        },
+      ],
+      draftAt: new BN(0),
+      votingAt: new BN(10000000000),
+      voteThreshold: {yesVotePercentage: [20]},
+      name: 'Council proposal',
+      descriptionLink: 'description',
+      vetoVoteWeight: new BN(0),
+    },
+  },
+  // Overwrites the oldest vote once the vote history is full
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '64YzC26tcUEwRaSvLjzQPcYYujUxZbptnQ4UXbFyTqop'
+      ),
+      realmAddress: new PublicKey(
+        '3qws4ytDgPVdry1QHEiuryNK7UKTLesZP1P2xjwz7E1Q'
+      ),
+      communityMint: new PublicKey(
+        '7TPpGNsiWazKodKc2LXvbPvWumZFuMN8zeSneiKJKrEg'
+      ),
+      councilMint: new PublicKey(
+        '7MN9s87PfAvUaMkUd5jXRvgmjoXU9GfW3tsgDFiTV3c4'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(11),
+      },
+      minCommunityWeightToCreateGovernance: new BN(4),
+      name: 'Community vetoes council proposal',
+      communityMintAuthority: new PublicKey(
+        'CzKszm8DzwX19GfQvZnj7vXHPxdRRdwwSKzykDmsEtfM'
+      ),
+    },
+    root: {
+      side: 'community',
+    },
+    clan: {
+      address: new PublicKey('37dK27q9WZab6ANJjhNM1KasgX3tGP5mhRFHeLnwmPzh'),
+      owner: buildKeypair('E6pkPFeugQUjK1W2shwYJNfZnCSSa39KyeCksikZrQVZ', [
+        238, 31, 211, 49, 101, 194, 220, 228, 250, 44, 102, 147, 72, 212, 72,
+        40, 69, 196, 192, 23, 117, 98, 96, 39, 8, 128, 151, 75, 69, 172, 172,
+        64, 194, 165, 210, 170, 179, 31, 215, 77, 204, 172, 241, 188, 103, 107,
+        90, 166, 134, 203, 66, 194, 216, 225, 81, 187, 214, 129, 185, 199, 178,
+        188, 4, 124,
+      ]),
+      name: 'Marinade',
+      voterWeight: new BN(5000),
+      voteHistoryEntries: voteHistoryEntries(32),
+      voteHistoryNextIndex: 5,
+    },
+    governance: {
+      address: new PublicKey('AJQogg2w2mGcvNWXek9RqPJSf1n5exRP2wssA5PM4tw1'),
+      config: {
+        communityVoteThreshold: {
+          yesVotePercentage: [60],
+        },
+        minCommunityWeightToCreateProposal: new BN(LAMPORTS_PER_SOL),
+        minTransactionHoldUpTime: 0,
+        votingBaseTime: 100000000,
+        communityVoteTipping: {early: {}},
+        councilVoteThreshold: {yesVotePercentage: [50]},
+        councilVetoVoteThreshold: {yesVotePercentage: [50]},
+        minCouncilWeightToCreateProposal: new BN(LAMPORTS_PER_SOL),
+        councilVoteTipping: {early: {}},
+        communityVetoVoteThreshold: {yesVotePercentage: [50]},
+        votingCoolOffTime: 0,
+        depositExemptProposalCount: 0,
+      },
+      activeProposalCount: new BN(1),
+    },
+    proposal: {
+      address: new PublicKey('3PBfj7yF4TENXSnCw49yNF7JRpakejdmbzPjETp5uv2s'),
+      governingTokenMint: new PublicKey(
+        '7MN9s87PfAvUaMkUd5jXRvgmjoXU9GfW3tsgDFiTV3c4'
+      ),
+      state: {voting: {}},
+      owner: new PublicKey('BqqkboSuU4D86VdPtHShxtGDbZMAjjaAkRTNyGGQk6wa'),
+      signatoriesCount: 0,
+      signatoriesSignedOffCount: 0,
+      voteType: {singleChoice: {}},
+      options: [
+        {
+          label: 'First',
+          voteWeight: new BN(1000),
+          voteResult: {none: {}},
+          transactionsExecutedCount: 0,
+          transactionsCount: 0,
+          transactionsNextIndex: 0,
+        },
+      ],
+      draftAt: new BN(0),
+      votingAt: new BN(10000000000),
+      voteThreshold: {yesVotePercentage: [20]},
+      name: 'Council proposal',
+      descriptionLink: 'description',
+      vetoVoteWeight: new BN(0),
+    },
+  },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'EVgwqmqf8vyDkBBRDe1wQTxoSfHVp1BCEaLhgEyVRtuF'
+      ),
+      realmAddress: new PublicKey(
+        '2uf8mRCjV1tiTQVBaPRkvs65HsZX7WMJiJDL1Dkdp8LU'
+      ),
+      communityMint: new PublicKey(
+        'CKXdbcJmSPLmc82eoQ89T7vTpCUxdpvxyyaWFwo6YFwu'
+      ),
+      councilMint: new PublicKey(
+        '3sK6VVdMcoUwYwF5x2Lozr8QjH7ZeKwQdzn9Mku6kmDJ'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(11),
+      },
+      minCommunityWeightToCreateGovernance: new BN(4),
+      name: 'Community vetoes council proposal',
+      communityMintAuthority: new PublicKey(
+        '2JPZm3oMtg8UGYQ5BMds4NgPHrYwT5akG5N93PcUCc51'
+      ),
+    },
+    root: {
+      side: 'community',
+    },
+    clan: {
+      address: new PublicKey('F5dJRxbR9evRHCEuejZ19j19UT2bRvDtA4itqJzJkoKY'),
+      owner: buildKeypair('498dcTPN5cR1ZE7ANvCC2F8Ene2dqERdg7RCCfQN8u6c', [
+        152, 166, 215, 50, 148, 57, 107, 181, 52, 111, 183, 41, 14, 98, 13, 163,
+        75, 225, 149, 21, 239, 44, 216, 130, 139, 31, 125, 229, 241, 209, 171,
+        17, 46, 168, 85, 254, 50, 105, 101, 161, 225, 143, 239, 197, 199, 61,
+        250, 206, 198, 1, 219, 173, 190, 135, 192, 46, 214, 1, 236, 117, 15,
+        250, 83, 93,
+      ]),
+      name: 'Marinade',
+      // The clan votes with the weight checkpointed at the voting start
+      snapshotVoting: true,
+    },
+    governance: {
+      address: new PublicKey('2wEYpq41v5f5gEBxv9JqNaQa5e2puHmCDgUgMfkDTxb9'),
+      config: {
+        communityVoteThreshold: {
+          yesVotePercentage: [60],
+        },
+        minCommunityWeightToCreateProposal: new BN(LAMPORTS_PER_SOL),
+        minTransactionHoldUpTime: 0,
+        votingBaseTime: 100000000,
+        communityVoteTipping: {early: {}},
+        councilVoteThreshold: {yesVotePercentage: [50]},
+        councilVetoVoteThreshold: {yesVotePercentage: [50]},
+        minCouncilWeightToCreateProposal: new BN(LAMPORTS_PER_SOL),
+        councilVoteTipping: {early: {}},
+        communityVetoVoteThreshold: {yesVotePercentage: [50]},
+        votingCoolOffTime: 0,
+        depositExemptProposalCount: 0,
+      },
+      activeProposalCount: new BN(1),
+    },
+    proposal: {
+      address: new PublicKey('8t7A5ASSxAmoNJgZoW2X1vHzHAvB9XC7zAUw3yLZHgTf'),
+      governingTokenMint: new PublicKey(
+        '3sK6VVdMcoUwYwF5x2Lozr8QjH7ZeKwQdzn9Mku6kmDJ'
+      ),
+      state: {voting: {}},
+      owner: new PublicKey('HmRu4W7zAuqyvU5q9pCyCLuDGRXLKEjoFjxBKWro7isf'),
+      signatoriesCount: 0,
+      signatoriesSignedOffCount: 0,
+      voteType: {singleChoice: {}},
+      options: [
+        {
+          label: 'First',
+          voteWeight: new BN(1000),
+          voteResult: {none: {}},
+          transactionsExecutedCount: 0,
+          transactionsCount: 0,
+          transactionsNextIndex: 0,
+        },
+      ],
+      draftAt: new BN(0),
+      votingAt: new BN(10000000000),
+      voteThreshold: {yesVotePercentage: [20]},
+      name: 'Council proposal',
+      descriptionLink: 'description',
+      vetoVoteWeight: new BN(0),
+    },
+    error: 'ClanSnapshotVoting',
+  },
+];
@@ -0,0 +1,274 @@
+import {Keypair, PublicKey} from '@solana/web3.js';
+import {ClanTestData, RootTestData} from '../../VoteAggregator';
+import {RealmTestData} from '../../SplGovernance/realm';
+import {buildKeypair} from '../..';
+import BN from 'bn.js';
+
+export type ClanCommissionTestData = {
+  realm: RealmTestData;
+  root: RootTestData;
+  clan: ClanTestData & {owner: Keypair};
+  clanAuthority: 'owner' | 'delegate';
+  newCommissionBp: number;
+  claimDestination: PublicKey;
+  error?: string;
+};
+
+export const clanCommissionTestData: ClanCommissionTestData[] = [
+  // A decrease is applied right away
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '2co2yJcrJhX4X1xDvRz9WnWkQVuXhsnZRxmNxzN5MZSP'
+      ),
+      realmAddress: new PublicKey(
+        'fRicxTpbupxD6tZqrzzfTQD4GWQthoEiMtbqjPgAbaV'
+      ),
+      communityMint: new PublicKey(
+        'DC7GXEk7VWfg9diWcs5acQzXmEzn635HAhW8TA2vQRUC'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(32),
+      },
+      minCommunityWeightToCreateGovernance: new BN(4),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        '2vsHhkQrbDbKyTZPfQTM695uM8B2vNPuKhQ1GGToZDYf'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+    },
+    clan: {
+      address: new PublicKey('3g1EtpEv1G9CACEvfjqUE3gi9c2ZcChP3ytq2PWQxuiq'),
+      owner: buildKeypair(
+        '3STusyzwPU7Tj2HyHgbpUjfiwdEWXYCp73myGzRXAEdf',
+        [
+          41, 51, 184, 185, 142, 24, 81, 54, 27, 144, 213, 19, 78, 15, 187, 173,
+          16, 239, 173, 148, 189, 254, 104, 244, 34, 142, 11, 245, 93, 216, 92,
+          7, 36, 61, 82, 192, 27, 130, 153, 33, 82, 28, 7, 157, 213, 87, 69,
+          206, 231, 156, 247, 124, 121, 87, 23, 216, 30, 196, 166, 195, 12, 153,
+          71, 42,
+        ]
+      ),
+      name: 'Marinade',
+      size: 1000,
+      rewardMint: new PublicKey('4EeRPxE4u6Rr4DYFveUREwoYVWVNxPVG9sv6jmmdrRb8'),
+      commissionBp: 500,
+      unclaimedCommission: new BN(300),
+      rewardVaultAmount: new BN(1000),
+    },
+    clanAuthority: 'owner',
+    newCommissionBp: 200,
+    claimDestination: new PublicKey(
+      'APWRVf3PAPgQbFYbVpPnyenrBSWe4pMTiryf7SgxFpJw'
+    ),
+  },
+  // An increase waits for the exit cooldown
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'FnHyDCr64cywbFjc6q92m8CFuvCt8VdVRpcLAadMCG2m'
+      ),
+      realmAddress: new PublicKey(
+        'G9gpzhrWvD2Rod4KBZzDgb5e8CCwb1o7GsgwBySJZpw5'
+      ),
+      communityMint: new PublicKey(
+        'Cu5uTG43np1wmRvn74bXaeu6HW4FCdNdGKQTduuzQMTd'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(32),
+      },
+      minCommunityWeightToCreateGovernance: new BN(4),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'ESRvXgYHjY7nNGp4kEkcLrFR7z5SA2Xqp7Sk1c2o9tLA'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+    },
+    clan: {
+      address: new PublicKey('GCGW3VgaLLXYNSMBch25S2BicKrEpPw3g6bheaCNWxAZ'),
+      owner: buildKeypair(
+        'FTwtQkK4f6Lm5cihGhheVcWUGUi4cjeNY27cg3nABgjN',
+        [
+          125, 196, 139, 203, 34, 34, 102, 80, 217, 209, 169, 40, 146, 174, 131,
+          30, 228, 146, 170, 209, 39, 153, 45, 31, 79, 10, 29, 167, 216, 173,
+          47, 82, 214, 234, 199, 57, 119, 221, 51, 176, 167, 12, 229, 56, 137,
+          160, 62, 48, 229, 191, 241, 175, 27, 161, 49, 171, 241, 186, 113, 17,
+          26, 48, 99, 85,
+        ]
+      ),
+      name: 'Marinade',
+      size: 1000,
+      rewardMint: new PublicKey('BKqVxyM7wrxtaxGXCw6QtoL67awNoTcekythCANGms9x'),
+      commissionBp: 500,
+      unclaimedCommission: new BN(50),
+      rewardVaultAmount: new BN(50),
+    },
+    clanAuthority: 'owner',
+    newCommissionBp: 800,
+    claimDestination: new PublicKey(
+      '4o7tGGskhr3hHmhTpSPD8NVFDJZgAzehRkwVrKbWtpSC'
+    ),
+  },
+  // Requesting the pending increase again keeps its notice period
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'd5fTME7Skbtuyp7Sz4DGDGTv78mNCbmfmSuEirsmExs'
+      ),
+      realmAddress: new PublicKey(
+        '7Hw1JgV4wwKL6xT5ha2gAnvuJdqc5gZKDHA9oVRJmjf1'
+      ),
+      communityMint: new PublicKey(
+        'CVSXTB41R2tKhCXNvLo2F9fMbUrW14s1HrmJZDJup1Uv'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(32),
+      },
+      minCommunityWeightToCreateGovernance: new BN(4),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'EytGqk3b8PFEVnMgHmSNM3mHeFzSWQh8Es1A9jEboC2k'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+    },
+    clan: {
+      address: new PublicKey('7vJdaWedzfsCxVPJrXqTiiC7UPmNRvXUZJeDkd9CV7G4'),
+      owner: buildKeypair(
+        'EPHQFmazFXK8dpdbE7gnnTKNimLZUtERpVkGmDqgykSp',
+        [
+          218, 33, 75, 227, 157, 244, 254, 182, 21, 88, 172, 132, 145, 224, 77,
+          72, 154, 87, 82, 104, 90, 21, 149, 194, 45, 86, 40, 162, 95, 178, 182,
+          196, 198, 221, 64, 160, 84, 17, 205, 16, 85, 225, 99, 46, 26, 151,
+          109, 217, 58, 173, 12, 153, 207, 93, 177, 5, 57, 94, 131, 32, 46, 177,
+          141, 21,
+        ]
+      ),
+      name: 'Marinade',
+      size: 1000,
+      rewardMint: new PublicKey('9bZqN54NDFHDjHGqVwbxZGCX4eoft6qBzUbhPW5RWCdv'),
+      commissionBp: 500,
+      pendingCommission: {
+        commissionBp: 800,
+        effectiveAt: new BN(10000000000),
+      },
+    },
+    clanAuthority: 'owner',
+    newCommissionBp: 800,
+    claimDestination: new PublicKey(
+      '5qQswXEJw56FiJaB2jstsjPLaPA1SFeXaga5jX5q2oAB'
+    ),
+  },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'CgDn5KQbDP2fpQReLGmux9PJgKeK1kCZGg25Z1Ebfy5r'
+      ),
+      realmAddress: new PublicKey(
+        'GTiAwQte1ND9KZp4ZbCr45Q3NwwEunvjkdxJMAnF4bCC'
+      ),
+      communityMint: new PublicKey(
+        '87britD8yBSJnPivbRjFBQpdD48AWdCrL1ENfjAZ1gGT'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(32),
+      },
+      minCommunityWeightToCreateGovernance: new BN(4),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        '8qQB6EqBK5bi84tW5iDrjgiPB8kWH4RZCeJvfUULAXbQ'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+    },
+    clan: {
+      address: new PublicKey('As16GP5AHybvNpeUhobHcacLcxvKtCPEggqY42JgBHAL'),
+      owner: buildKeypair(
+        'CuhTPtqfM7eSNdC6243TR2RqpH64fe8x16hNzx82uXXg',
+        [
+          45, 204, 223, 201, 104, 168, 129, 102, 213, 214, 185, 43, 13, 220, 97,
+          72, 74, 115, 199, 180, 81, 188, 131, 238, 2, 113, 168, 84, 128, 25,
+          98, 239, 176, 240, 128, 63, 114, 131, 100, 186, 125, 250, 83, 204, 5,
+          226, 160, 52, 149, 145, 87, 220, 112, 36, 105, 43, 242, 150, 187, 51,
+          147, 111, 60, 251,
+        ]
+      ),
+      delegate: buildKeypair(
+        'Gig1GJuUDfb2TcfDBhokYyoNY6vVMzKDcjts6LPmzk6F',
+        [
+          223, 243, 199, 228, 183, 221, 75, 143, 135, 28, 172, 148, 170, 201,
+          151, 187, 146, 90, 68, 100, 163, 37, 116, 82, 162, 79, 62, 84, 175,
+          123, 246, 134, 233, 140, 56, 59, 54, 8, 152, 25, 64, 189, 104, 254,
+          67, 172, 187, 207, 44, 230, 46, 70, 74, 52, 115, 195, 94, 193, 37, 37,
+          243, 165, 206, 228,
+        ]
+      ),
+      name: 'Marinade',
+      size: 1000,
+      rewardMint: new PublicKey('DQR1Md1kiW6VS2H1Uo98PUTDVjtQej4nG3bEKHgi5uND'),
+      commissionBp: 500,
+    },
+    clanAuthority: 'delegate',
+    newCommissionBp: 200,
+    claimDestination: new PublicKey(
+      '8VMJbZBoCKQzRF6uChuEWbdzHGGTiYKXeiEvJ1v67cTv'
+    ),
+    error: 'WrongClanAuthority',
+  },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'EyRgV7AHvcA9bKySv3PnRJEGsnb6erGscx8Hs7um7gR6'
+      ),
+      realmAddress: new PublicKey(
+        'CV16pfoYYWGJ8msG5gYq6feLGL5VkE9gGEcuFPUuEySH'
+      ),
+      communityMint: new PublicKey(
+        '21pskKjoZrVkuMrnHwUazeB2mX1Q4gHWZgYAfphyA3zj'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(32),
+      },
+      minCommunityWeightToCreateGovernance: new BN(4),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'H8s3JikGik3TcM4SK62Hzvbs3eVxZ3KwhN4Da1stm22v'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+    },
+    clan: {
+      address: new PublicKey('G18FbpQk7aTbSBVSxV5ssjAb4GiXGYdodNPCtGpL7cab'),
+      owner: buildKeypair(
+        '8w3mqXDMkyyLYrAktudbir9v8xqycXpd3gZVWd2Tep1D',
+        [
+          88, 71, 91, 131, 149, 172, 105, 110, 72, 242, 124, 254, 242, 113, 68,
+          12, 3, 56, 144, 237, 76, 2, 250, 56, 23, 54, 93, 100, 16, 239, 98, 48,
+          117, 218, 78, 195, 45, 93, 195, 68, 127, 34, 226, 39, 91, 54, 134, 28,
+          30, 92, 58, 46, 13, 99, 57, 187, 207, 87, 190, 127, 61, 228, 212, 48,
+        ]
+      ),
+      name: 'Marinade',
+      size: 1000,
+      rewardMint: new PublicKey('DP1VM4RQgX8JXY9CrxFpkRAwa9rGmATsAznsfeAxBTeK'),
+    },
+    clanAuthority: 'owner',
+    newCommissionBp: 10001,
+    claimDestination: new PublicKey(
+      '7ic7kdEKY8QYVQuv6uSfkKsMidaGRCC8BuowcdSxvK1P'
+    ),
+    error: 'InvalidCommissionBp',
+  },
+];
@@ -0,0 +1,451 @@
+import BN from 'bn.js';
+import {ClanTestData, MemberTestData, RootTestData} from '../../VoteAggregator';
+import {RealmTestData} from '../../SplGovernance/realm';
+import {Keypair, LAMPORTS_PER_SOL, PublicKey} from '@solana/web3.js';
+import {buildKeypair} from '../..';
+
+export type ClanTreasuryTestData = {
+  realm: RealmTestData;
+  root: RootTestData;
+  // Approves the withdrawal from its first clan
+  member: MemberTestData & {owner: Keypair};
+  amount: BN;
+  destination: PublicKey;
+  error?: string;
+};
+
+export const clanTreasuryTestData: ClanTreasuryTestData[] = [
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'GCvkavaLFGdbYEL8gt6KNR3cPBbGNMJiUtYgMxdzdrUs'
+      ),
+      realmAddress: new PublicKey(
+        'Eju1Dri3R3aDutTG7HSYk7m4kGYLVQwYSVWA1dYMEtcX'
+      ),
+      communityMint: new PublicKey(
+        '8yZDxfjFh4GyMgFse2jMmVQ8XkaprjQkh7L192vxVZXH'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        '2ouwQifz38deKzfN3g89WKKKxKR9Mw5wwxcdZazb7xzQ'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+    },
+    member: {
+      owner: buildKeypair(
+        'EFSVkHshoBzvC7cxZoDQ7MnWfdQwuM9SinmhCkiAXZ8v',
+        [
+          31, 192, 62, 85, 229, 140, 130, 205, 232, 217, 190, 27, 63, 115, 68,
+          37, 175, 219, 66, 43, 254, 121, 43, 123, 130, 231, 211, 206, 167, 56,
+          10, 91, 196, 218, 227, 173, 151, 164, 95, 7, 209, 123, 73, 40, 132,
+          114, 13, 120, 149, 241, 141, 190, 133, 245, 232, 43, 29, 187, 38, 4,
+          213, 152, 79, 107,
+        ]
+      ),
+      voterWeight: new BN(1000),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              'HzCRLQ9uPXjKJXLJbp7DEPNSDF7LETA6zhSDfQJouuZp'
+            ),
+            owner: buildKeypair(
+              'D6AFR7fPVRCC2hNp1oGugyth2L7c8SD5EjCaHC98ga5P',
+              [
+                29, 215, 73, 105, 230, 64, 58, 36, 95, 114, 252, 203, 87, 167,
+                148, 27, 58, 124, 119, 109, 115, 229, 87, 210, 80, 22, 180, 8,
+                124, 121, 63, 63, 179, 158, 154, 194, 88, 42, 52, 113, 119, 198,
+                232, 101, 95, 143, 157, 88, 255, 168, 219, 250, 117, 86, 141,
+                220, 165, 198, 9, 239, 67, 20, 242, 234,
+              ]
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(2),
+            voterWeight: new BN(3000),
+            permanentVoterWeight: new BN(3000),
+            rewardWeight: new BN(1000),
+            treasuryPolicy: {
+              spendingLimit: new BN(LAMPORTS_PER_SOL),
+              spendingPeriod: new BN(86400),
+              approvalThresholdBp: 5000,
+            },
+            treasuryAmount: new BN(10 * LAMPORTS_PER_SOL),
+          },
+          shareBp: 10000,
+          rewardWeight: new BN(600),
+        },
+      ],
+    },
+    amount: new BN(5 * LAMPORTS_PER_SOL),
+    destination: new PublicKey('Ctmqb8BBQaHUd9uLcXsccDZMxGHndicAKKDXdDfAi6aC'),
+  },
+  // The approvals are below the threshold
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'APQJR9SVgkDRLWL4mfAv4GNPfery2XF8foNxP96LjB57'
+      ),
+      realmAddress: new PublicKey(
+        'B3YeMeUPrYg1VRpjs2bDRz6NiXJgRRceCzrDa7qSAPfz'
+      ),
+      communityMint: new PublicKey(
+        'yeHv3ZJxXnzgW5xYK9VRvCSX5y2pxSkv63cNpYVZ8eE'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        '7awU2FkmW4P7YNjMbVWLZNyGizeRTXMU8rHdDJimhFJz'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+    },
+    member: {
+      owner: buildKeypair(
+        'wDWud2Aj6hnMrDacG7xyfiHLKZNvqGwNpwHzfD7aKkV',
+        [
+          164, 203, 220, 235, 130, 100, 123, 76, 237, 4, 70, 111, 73, 131, 144,
+          2, 189, 198, 104, 95, 245, 209, 121, 76, 161, 98, 2, 66, 244, 220,
+          224, 86, 13, 227, 131, 226, 80, 111, 83, 89, 48, 132, 50, 200, 116,
+          45, 35, 255, 77, 113, 143, 166, 68, 177, 98, 116, 2, 246, 75, 208,
+          179, 77, 219, 106,
+        ]
+      ),
+      voterWeight: new BN(1000),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              'EArSQkHVYC3ESahgG7ye8G5WGdjYFM6HB2e64XNu7u2U'
+            ),
+            owner: buildKeypair(
+              '4jBwBy8Z8U2i1tv2B9vfQ13ZH6F6Hd3yv9gXLDrNqQnF',
+              [
+                4, 44, 218, 0, 135, 64, 87, 74, 108, 18, 120, 123, 216, 188, 81,
+                161, 203, 162, 102, 95, 53, 134, 209, 128, 84, 167, 87, 132,
+                191, 87, 205, 112, 55, 97, 209, 117, 255, 196, 63, 216, 105,
+                206, 215, 170, 243, 62, 51, 84, 29, 109, 53, 184, 29, 244, 140,
+                223, 48, 189, 164, 196, 104, 248, 164, 108,
+              ]
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(2),
+            voterWeight: new BN(3000),
+            permanentVoterWeight: new BN(3000),
+            rewardWeight: new BN(1000),
+            treasuryPolicy: {
+              spendingLimit: new BN(LAMPORTS_PER_SOL),
+              spendingPeriod: new BN(86400),
+              approvalThresholdBp: 5000,
+            },
+            treasuryAmount: new BN(10 * LAMPORTS_PER_SOL),
+          },
+          shareBp: 10000,
+          rewardWeight: new BN(400),
+        },
+      ],
+    },
+    amount: new BN(5 * LAMPORTS_PER_SOL),
+    destination: new PublicKey('2MyRUSsbuvDaNNUV6wTdoUfMVNzijgznGyzCBg8H1CPi'),
+    error: 'TreasuryWithdrawalNotApproved',
+  },
+  // Only the clans with a policy take approvals
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'MSHFu9zS9nisCmLRA2msZ17eW9Tjy8BGX86gq23Y9iB'
+      ),
+      realmAddress: new PublicKey(
+        'Hm7jZmGBKRL1FZjrfbN7xhUCwUDk8Zs43vFP1CpFJTE8'
+      ),
+      communityMint: new PublicKey(
+        'BUSmj3bFPzyw5TsvWC9rECRR5BtdDtSXgbVtLXhjmQtn'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'BBQZPzwAoJkXuAeEkmWwJQyAfFUBrJHj4pAaWwyuBdDY'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+    },
+    member: {
+      owner: buildKeypair(
+        'AzfJUoithPZ7kxkKnuRP5wGRtoaJTt1ECGGRhwMZpky5',
+        [
+          164, 119, 61, 39, 170, 186, 38, 211, 26, 70, 51, 52, 98, 127, 68, 61,
+          70, 219, 191, 194, 19, 251, 198, 196, 157, 140, 237, 119, 213, 153,
+          70, 101, 148, 126, 152, 144, 54, 172, 15, 147, 101, 147, 69, 54, 22,
+          169, 35, 239, 149, 73, 239, 120, 58, 183, 106, 150, 35, 12, 93, 179,
+          165, 168, 217, 24,
+        ]
+      ),
+      voterWeight: new BN(1000),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              'HFnwzJHFvfwdnnR2vAjyKd3CvKdsyMGnbTKkRWZUZGUi'
+            ),
+            owner: buildKeypair(
+              '2AZAVWqnM3wedcR8mygGqqw8kk2QaqZ6tdgUPEuctByi',
+              [
+                25, 44, 150, 72, 181, 215, 206, 27, 227, 16, 210, 167, 237, 131,
+                247, 192, 230, 111, 4, 96, 207, 121, 152, 52, 221, 135, 156,
+                243, 56, 194, 28, 97, 17, 78, 72, 33, 136, 1, 218, 123, 34, 92,
+                29, 152, 236, 131, 70, 89, 12, 228, 193, 169, 95, 31, 251, 162,
+                73, 49, 138, 196, 223, 177, 245, 41,
+              ]
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(2),
+            voterWeight: new BN(3000),
+            permanentVoterWeight: new BN(3000),
+            rewardWeight: new BN(1000),
+            treasuryAmount: new BN(10 * LAMPORTS_PER_SOL),
+          },
+          shareBp: 10000,
+          rewardWeight: new BN(600),
+        },
+      ],
+    },
+    amount: new BN(5 * LAMPORTS_PER_SOL),
+    destination: new PublicKey('H8nWWcVkz2adjmVzBXbxw49CfiqitWQmib2R5gNMBNmV'),
+    error: 'TreasuryPolicyRequired',
+  },
+];
+
+export type TreasurySpendingTestData = {
+  realm: RealmTestData;
+  root: RootTestData;
+  clan: ClanTestData & {owner: Keypair};
+  // Withdrawn one by one without approvals
+  withdrawals: BN[];
+  // Withdraws the tokens of the treasury if defined
+  treasuryTokens?: {
+    address: PublicKey;
+    mint: PublicKey;
+  };
+  destination: PublicKey;
+  error?: string;
+};
+
+export const treasurySpendingTestData: TreasurySpendingTestData[] = [
+  // The withdrawals of the period stay within the limit
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'HXzqMdZhGHD6K6j9Ecqt5u8jhfbB7WfxmmhAPKLcKD28'
+      ),
+      realmAddress: new PublicKey(
+        '38MGvDn7Cd1KUs4XmefZZxZEZ6NBf7wVL76ATrPUk6mv'
+      ),
+      communityMint: new PublicKey(
+        'Haj6oyrLyxMgjUHUcyVD7H7DRMZ6qW5cb68pcAh4Qvou'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'ATcXLjyhsr3MnknzJrUqSovLKgRm4oGDC7THXDDe86Jo'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+    },
+    clan: {
+      address: new PublicKey('84WwecCMrvL8EU6XJFZxatfbVgc64kpoPrPxzbAAvCJV'),
+      owner: buildKeypair(
+        'CPBDwnTxi855z8i2jt99AMfp8Mgd6EKCy11JLZfECeuG',
+        [
+          74, 240, 202, 156, 49, 237, 193, 118, 182, 88, 162, 81, 128, 12, 206,
+          228, 148, 69, 228, 8, 60, 192, 23, 5, 101, 170, 44, 191, 191, 108,
+          163, 162, 169, 30, 228, 180, 110, 12, 178, 44, 173, 41, 185, 168, 147,
+          139, 227, 127, 249, 182, 158, 212, 124, 96, 129, 223, 6, 148, 87, 6,
+          177, 99, 1, 211,
+        ]
+      ),
+      name: 'Marinade',
+      treasuryPolicy: {
+        spendingLimit: new BN(LAMPORTS_PER_SOL),
+        spendingPeriod: new BN(86400),
+        approvalThresholdBp: 5000,
+      },
+      treasuryAmount: new BN(10 * LAMPORTS_PER_SOL),
+    },
+    withdrawals: [new BN(400000000), new BN(600000000)],
+    destination: new PublicKey('Dq2ZtrM9Mfiodxc42Mpm1XMYZ37yLc2Zvaz6xy6QKiWQ'),
+  },
+  // The limit was spent in the previous period
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'Fw9BhQoA3WEK7mWbVM54K8nsazo775yUKzJ1tE34VKq3'
+      ),
+      realmAddress: new PublicKey(
+        '4wGdnz48zdeshNoRCgeCRq2z6pTzURzsJgBtwwiiFbLC'
+      ),
+      communityMint: new PublicKey(
+        '9Pwg6d9ncDTfDoxd7b9payL5ZT1dZdSQAz7rizUHXFjo'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'J6LTB2BLps1XNqdZocU2qTVjz5T5HSYsEfNvXjHT9U2t'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+    },
+    clan: {
+      address: new PublicKey('A16t3JL7o76oNMPdNQDgpmvFS8SsrYR8uTjBN1KV9qPr'),
+      owner: buildKeypair(
+        '21xq8Ehq8aLtBfYwy8thFc6GuGjUzBtRBFvBmcXd5zAL',
+        [
+          147, 211, 112, 114, 48, 1, 88, 15, 16, 42, 44, 233, 181, 149, 254, 58,
+          157, 99, 200, 81, 225, 245, 186, 134, 125, 182, 0, 134, 6, 151, 244,
+          29, 15, 26, 208, 85, 244, 120, 88, 149, 126, 255, 12, 81, 164, 106,
+          203, 146, 79, 35, 12, 104, 14, 58, 180, 123, 98, 25, 240, 56, 219, 42,
+          115, 129,
+        ]
+      ),
+      name: 'Marinade',
+      treasuryPolicy: {
+        spendingLimit: new BN(LAMPORTS_PER_SOL),
+        spendingPeriod: new BN(86400),
+        approvalThresholdBp: 5000,
+      },
+      treasurySpent: new BN(LAMPORTS_PER_SOL),
+      treasuryPeriodStart: new BN(0),
+      treasuryAmount: new BN(10 * LAMPORTS_PER_SOL),
+    },
+    withdrawals: [new BN(LAMPORTS_PER_SOL)],
+    destination: new PublicKey('FHwsGNw4sTAdEZKL3rikWU6Lg2zBdTaMtVCpjEStrMUZ'),
+  },
+  // The limit is checked against the spending of the period
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '9WAb5wgz3FX6JVx6avPnJtyGbNJFxAfSthb6b8n8Rxjt'
+      ),
+      realmAddress: new PublicKey(
+        'Dz8X9ZLPmEkg7A2vsxAYGJ5FezBMu71KyzUrR2e53R1t'
+      ),
+      communityMint: new PublicKey(
+        'HeKsd5JC13SA3jCRfzhyoNbY1MTv1KC6kK2D9qMxKv7B'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'EEHzMvGgLyNmCrdN2HVVZAvKGTiSFFE1LwFZdLRXJWpt'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+    },
+    clan: {
+      address: new PublicKey('5jjyRZ9JGt8Gb8Xtnyn4yVJrRj5HgNbXrqGQhx7cuuZ1'),
+      owner: buildKeypair(
+        '2PBnLqTZRMoLkLmSpwpKaF68tejzNbC3p4bxMmDy7tiT',
+        [
+          254, 13, 215, 80, 32, 135, 13, 132, 205, 129, 30, 201, 218, 133, 161,
+          107, 185, 125, 16, 185, 212, 193, 194, 148, 186, 87, 84, 63, 132, 255,
+          64, 91, 20, 138, 167, 219, 230, 141, 99, 68, 33, 178, 188, 149, 196,
+          69, 174, 148, 81, 221, 37, 156, 70, 202, 228, 182, 75, 207, 152, 7,
+          242, 234, 215, 128,
+        ]
+      ),
+      name: 'Marinade',
+      treasuryPolicy: {
+        spendingLimit: new BN(LAMPORTS_PER_SOL),
+        spendingPeriod: new BN(86400),
+        approvalThresholdBp: 5000,
+      },
+      treasuryAmount: new BN(10 * LAMPORTS_PER_SOL),
+    },
+    withdrawals: [new BN(600000000), new BN(600000000)],
+    destination: new PublicKey('CnmXXMyvh72g7igkHPsM5ckGtJPxdLDTGEhSVyDNsV7Q'),
+    error: 'TreasuryWithdrawalRequired',
+  },
+  // The limit is in lamports so the tokens are always approved
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'Gjvnj1C842xSvZFPUdFx8V3GNsV2wwtfiP6C4UsAtiCc'
+      ),
+      realmAddress: new PublicKey(
+        '9gVQXWKgRtQATuA3D51ZzFpGEzL7MHy6iGDkLbe11j54'
+      ),
+      communityMint: new PublicKey(
+        '6XAyopTy1UvW64fM5Tnxuxii7PSMbiBHE2YcSpeQUipu'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'HfMHxSMfMZeArQvLL5x9Vvp7bHYpwzUbe54mJFTg8oNt'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+    },
+    clan: {
+      address: new PublicKey('53M7RgjvLjDe1hHogQXLAkm8VR7YWuetSqFDswScdEjp'),
+      owner: buildKeypair(
+        '6PLuvyz1e81Ezi3WaTmbowFAjVVaL1iGXu95GujhAHRX',
+        [
+          85, 220, 234, 29, 98, 206, 35, 0, 226, 56, 55, 50, 56, 222, 144, 140,
+          93, 85, 234, 152, 246, 118, 104, 17, 70, 249, 157, 102, 120, 221, 209,
+          134, 80, 3, 186, 73, 38, 142, 196, 4, 155, 193, 175, 126, 196, 176,
+          251, 157, 138, 173, 10, 183, 43, 127, 234, 221, 4, 183, 83, 184, 153,
+          34, 160, 118,
+        ]
+      ),
+      name: 'Marinade',
+      treasuryPolicy: {
+        spendingLimit: new BN(LAMPORTS_PER_SOL),
+        spendingPeriod: new BN(86400),
+        approvalThresholdBp: 5000,
+      },
+      treasuryAmount: new BN(10 * LAMPORTS_PER_SOL),
+    },
+    withdrawals: [new BN(1)],
+    treasuryTokens: {
+      address: new PublicKey('64f9QFk5jh4qvC4mJq3DX4f7TcmiYdTXsLerHWEg6e3V'),
+      mint: new PublicKey('9dfH8fBysM9TL7rsBspS1AeQwk2kqJMKph7jTKTjaTGc'),
+    },
+    destination: new PublicKey('75NLmnzi2toGoshXjosrdUU8MkDH8nBmdb9QvwmwwpNV'),
+    error: 'TreasuryWithdrawalRequired',
+  },
+];
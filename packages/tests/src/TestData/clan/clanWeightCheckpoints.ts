@@ -0,0 +1,239 @@
+import {Keypair, LAMPORTS_PER_SOL, PublicKey} from '@solana/web3.js';
+import {MemberTestData, RootTestData} from '../../VoteAggregator';
+import {RealmTestData} from '../../SplGovernance/realm';
+import {GovernanceTestData} from '../../SplGovernance/governance';
+import {ProposalTestData} from '../../SplGovernance';
+import {buildKeypair} from '../..';
+import BN from 'bn.js';
+
+export type ClanWeightCheckpointsTestData = {
+  realm: RealmTestData;
+  root: RootTestData;
+  // Member of a single clan with the weight checkpoints
+  member: MemberTestData & {owner: Keypair};
+  governance: GovernanceTestData;
+  proposal: ProposalTestData;
+  // The member starts leaving the clan at this slot
+  slot: BN;
+};
+
+export const clanWeightCheckpointsTestData: ClanWeightCheckpointsTestData[] = [
+  // The voting started before the member left
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'CChgHdUP586uCG8myYG8aPyi1tmwUhH2CQRtrtej6SJh'
+      ),
+      realmAddress: new PublicKey(
+        'HqJ2Mk7h9NiDqFg2AACdsYmDEMyySfFAHrNw8yrUHNvV'
+      ),
+      communityMint: new PublicKey(
+        '2t6PurtMgqH6qXrcCqaSomUijEBXfeK3buyABAjDvufP'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'GRWmU4N4kDKBe8gmoZvKFVW7NDJPapJ4QedejC36E1X3'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+    },
+    member: {
+      owner: buildKeypair(
+        '6MfLP9gyR9shxdbAjuJqgAXXaNPG7sMh2gVEqxQNmWKC',
+        [
+          239, 24, 84, 217, 108, 41, 199, 211, 201, 194, 60, 32, 192, 226, 183,
+          52, 126, 111, 59, 233, 149, 23, 224, 99, 157, 125, 124, 195, 72, 223,
+          137, 151, 79, 149, 101, 56, 207, 154, 202, 72, 248, 51, 133, 98, 107,
+          80, 88, 242, 47, 224, 6, 217, 243, 78, 52, 48, 120, 246, 92, 78, 155,
+          96, 220, 131,
+        ]
+      ),
+      voterWeight: new BN(2000),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              'AywGyyVewQ2ptjoepJcAcsMVVg8XfC8YTqsAtBp5xEPR'
+            ),
+            owner: new PublicKey(
+              '6nyJbY5khKw2w3JqubMLu6xtKS25e7UrsonXvCgcxsXe'
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(2),
+            voterWeight: new BN(5000),
+            permanentVoterWeight: new BN(5000),
+            weightCheckpointEntries: [
+              {slot: new BN(100), voterWeight: new BN(3000)},
+              {slot: new BN(200), voterWeight: new BN(5000)},
+            ],
+          },
+          shareBp: 10000,
+        },
+      ],
+    },
+    governance: {
+      address: new PublicKey('7FDBgshgHw4snYeP9NmnFN57G3DKtfkYji8ASKNreETb'),
+      config: {
+        communityVoteThreshold: {
+          yesVotePercentage: [60],
+        },
+        minCommunityWeightToCreateProposal: new BN(LAMPORTS_PER_SOL),
+        minTransactionHoldUpTime: 0,
+        votingBaseTime: 100000000,
+        communityVoteTipping: {early: {}},
+        councilVoteThreshold: {yesVotePercentage: [50]},
+        councilVetoVoteThreshold: {yesVotePercentage: [50]},
+        minCouncilWeightToCreateProposal: new BN(LAMPORTS_PER_SOL),
+        councilVoteTipping: {early: {}},
+        communityVetoVoteThreshold: {yesVotePercentage: [50]},
+        votingCoolOffTime: 0,
+        depositExemptProposalCount: 0,
+      },
+      activeProposalCount: new BN(1),
+    },
+    proposal: {
+      address: new PublicKey('7WDJHaebnVybzEJ5WkZvAMdxBsKN5vD1FyTiG8YCWH1J'),
+      governingTokenMint: new PublicKey(
+        '2t6PurtMgqH6qXrcCqaSomUijEBXfeK3buyABAjDvufP'
+      ),
+      state: {voting: {}},
+      owner: new PublicKey('HRTP5PhQsh9ysfWJ1JmBdWCwGCww65avSeJptLurcMqx'),
+      signatoriesCount: 0,
+      signatoriesSignedOffCount: 0,
+      voteType: {singleChoice: {}},
+      options: [
+        {
+          label: 'First',
+          voteWeight: new BN(0),
+          voteResult: {none: {}},
+          transactionsExecutedCount: 0,
+          transactionsCount: 0,
+          transactionsNextIndex: 0,
+        },
+      ],
+      draftAt: new BN(0),
+      votingAt: new BN(0),
+      votingAtSlot: new BN(250),
+      voteThreshold: {yesVotePercentage: [20]},
+      name: 'Community proposal',
+      descriptionLink: 'description',
+      vetoVoteWeight: new BN(0),
+    },
+    slot: new BN(300),
+  },
+  // The voting started after the member left
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'AayNjEcvmHJZkQbQhaWGpv8ZGthudGFRRRfAV31cGdFq'
+      ),
+      realmAddress: new PublicKey(
+        'HL7KbxBHhrc7d9UQqKGXQWifFdCxFSbkeicLoCk82rVi'
+      ),
+      communityMint: new PublicKey(
+        'HUGn9GpmV2fRfFeFHJDrCh9TnYg6nqofRRU1HhtjTL5c'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'G3emHhGm8Fe1Be9jxe11Td8yRRyksjHCWqm8NzpkdHFb'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+    },
+    member: {
+      owner: buildKeypair(
+        '7SkzYZRNQai6wugYv6RuKsjVPuTt5oJPpfZaNgCx6ZWn',
+        [
+          176, 213, 87, 250, 200, 237, 1, 103, 218, 140, 66, 232, 80, 19, 58,
+          57, 191, 183, 156, 239, 127, 53, 238, 28, 199, 27, 50, 70, 248, 42,
+          58, 42, 95, 191, 98, 68, 103, 147, 218, 213, 83, 212, 162, 26, 104,
+          173, 218, 138, 69, 20, 63, 206, 230, 253, 240, 238, 81, 82, 47, 77,
+          17, 79, 11, 151,
+        ]
+      ),
+      voterWeight: new BN(2000),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              'B9VSdUNEZsbE5Gvs3QKtbWdYpCzVsWq1U8x9GgtNjT2n'
+            ),
+            owner: new PublicKey(
+              'Fv1yHzvTwz3RXbXiHn7mwhmPn7CkNTLz4QhUM11ge4Pv'
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(2),
+            voterWeight: new BN(5000),
+            permanentVoterWeight: new BN(5000),
+            weightCheckpointEntries: [
+              {slot: new BN(100), voterWeight: new BN(3000)},
+              {slot: new BN(200), voterWeight: new BN(5000)},
+            ],
+          },
+          shareBp: 10000,
+        },
+      ],
+    },
+    governance: {
+      address: new PublicKey('uTE1aaVjyKf7FemidEP1ACSwqQUB5QUQRB1YRSDVzuz'),
+      config: {
+        communityVoteThreshold: {
+          yesVotePercentage: [60],
+        },
+        minCommunityWeightToCreateProposal: new BN(LAMPORTS_PER_SOL),
+        minTransactionHoldUpTime: 0,
+        votingBaseTime: 100000000,
+        communityVoteTipping: {early: {}},
+        councilVoteThreshold: {yesVotePercentage: [50]},
+        councilVetoVoteThreshold: {yesVotePercentage: [50]},
+        minCouncilWeightToCreateProposal: new BN(LAMPORTS_PER_SOL),
+        councilVoteTipping: {early: {}},
+        communityVetoVoteThreshold: {yesVotePercentage: [50]},
+        votingCoolOffTime: 0,
+        depositExemptProposalCount: 0,
+      },
+      activeProposalCount: new BN(1),
+    },
+    proposal: {
+      address: new PublicKey('EQ2bvA7hbswzicvReapMiPe1gZDCsJ7s5oaELg5vdqXS'),
+      governingTokenMint: new PublicKey(
+        'HUGn9GpmV2fRfFeFHJDrCh9TnYg6nqofRRU1HhtjTL5c'
+      ),
+      state: {voting: {}},
+      owner: new PublicKey('5EQMBP7nGpWc7KVRwmuiJKYBAKrF2gbgienvPMwAXPim'),
+      signatoriesCount: 0,
+      signatoriesSignedOffCount: 0,
+      voteType: {singleChoice: {}},
+      options: [
+        {
+          label: 'First',
+          voteWeight: new BN(0),
+          voteResult: {none: {}},
+          transactionsExecutedCount: 0,
+          transactionsCount: 0,
+          transactionsNextIndex: 0,
+        },
+      ],
+      draftAt: new BN(0),
+      votingAt: new BN(0),
+      votingAtSlot: new BN(301),
+      voteThreshold: {yesVotePercentage: [20]},
+      name: 'Community proposal',
+      descriptionLink: 'description',
+      vetoVoteWeight: new BN(0),
+    },
+    slot: new BN(300),
+  },
+];
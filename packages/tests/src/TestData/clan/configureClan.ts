@@ -1,23 +1,53 @@
 import {Keypair, PublicKey} from '@solana/web3.js';
-import {ClanTestData} from '../../VoteAggregator';
+import {
+  ClanTestData,
+  RootTestData,
+  TreasuryPolicy,
+} from '../../VoteAggregator';
+import {RealmTestData} from '../../SplGovernance/realm';
 import {buildKeypair} from '../..';
 import BN from 'bn.js';
 
 export type ConfigureClanTestData = {
-  clan: ClanTestData & {root: PublicKey};
+  realm: RealmTestData;
+  root: RootTestData;
+  clan: ClanTestData;
   clanAuthority: Keypair | 'owner' | 'delegate';
   newDelegate?: PublicKey;
   newName?: string;
   newDescription?: string;
   newMinVotingWeightToJoin?: BN;
   newAcceptTemporaryMembers?: boolean;
+  newSnapshotVoting?: boolean;
+  newTreasuryPolicy?: TreasuryPolicy;
   error?: string;
 };
 
 export const configureClanTestData: ConfigureClanTestData[] = [
   {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'CEjqGGzQpYfy4byAWousMUpViZk5sNSiHZp53Bx8895Z'
+      ),
+      realmAddress: new PublicKey(
+        '4SoW27wTBY8UEnUbLxAL7VhHFxBCWsYatjusBbc7eRrq'
+      ),
+      communityMint: new PublicKey(
+        '8t71a1v3R3MkXr4at278brvtGd2FUhukZezZptmM7AMm'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(32),
+      },
+      minCommunityWeightToCreateGovernance: new BN(4),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'ENXnos1U8X49CvX8dx9QRwwfr9wZuziWyeiBfn63vbdr'
+      ),
+    },
+    root: {
+      side: 'community',
+    },
     clan: {
-      root: new PublicKey('7yM5fXw8M64eF73SXqnU4Cbs1QsNUM2sRiwCjvmropJ6'),
       address: new PublicKey('4vK9oDnDgPbFGtCwdJvm3hHay9EueHDxJfNoSZMnQEZw'),
       owner: buildKeypair(
         '4NGov9AUKpbbkQU9AaS5ZqhEEzgWG3MSZrziDsLuWQBa',
@@ -40,4 +70,183 @@ export const configureClanTestData: ConfigureClanTestData[] = [
     newMinVotingWeightToJoin: new BN(100),
     newAcceptTemporaryMembers: false,
   },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'BD4Md58dCx2hvjaDHeuisRpwbDCMGwPnciqQYsxBn8Vm'
+      ),
+      realmAddress: new PublicKey(
+        '3zctTy1NMWPXt7eE5SjT3Uej6aSXRGAgiN6MhAFwSmDg'
+      ),
+      communityMint: new PublicKey(
+        '7AKsBNz7j4vtoQGiX9SrfXFGhpEom9z1Ec8kaHh8uKf3'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(32),
+      },
+      minCommunityWeightToCreateGovernance: new BN(4),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'Cub4uqX15WSwzfhnbugndJbWmWhDqL375wDqcqzpHwTZ'
+      ),
+    },
+    root: {
+      side: 'community',
+      pauseFlags: 0b100000, // clan config
+    },
+    clan: {
+      address: new PublicKey('ATvDwcii2mt6x4hxVPxznKkvcHPwz8PNPntqFjkkW7r2'),
+      owner: buildKeypair(
+        'HwuntZsc5bhT9hCmT45jH8jnKamCunRC2QV6htrZxTHt',
+        [
+          186, 213, 227, 66, 131, 158, 45, 54, 67, 24, 8, 23, 227, 199, 28, 192,
+          70, 17, 70, 83, 204, 1, 33, 147, 164, 115, 133, 60, 120, 53, 60, 62,
+          251, 204, 11, 179, 27, 222, 42, 95, 37, 94, 9, 48, 164, 158, 48, 188,
+          247, 203, 211, 3, 5, 6, 139, 1, 117, 141, 94, 217, 208, 224, 145, 115,
+        ]
+      ),
+      name: 'Marinade',
+      description: 'Marinade is a test clan',
+      size: 1000,
+    },
+    clanAuthority: 'owner',
+    newName: 'LIDO',
+    error: 'Paused',
+  },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'Hpxz1yjZvQkPeuwoMPreR1BUyJXmshU7A7nRGwMoPBAB'
+      ),
+      realmAddress: new PublicKey(
+        '8PufuqbMcStPLtVgJ4d3qKwsh58bZsajRjrMVvRNJgDh'
+      ),
+      communityMint: new PublicKey(
+        'CKGqj4D8MX8ddMzG9vrosDBjjvcC26YrwMXe7Kgcmxj9'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(32),
+      },
+      minCommunityWeightToCreateGovernance: new BN(4),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'H2tjAqmmaFGpaLBsDKwJcdvqpBzXU86obbBTEcZY7VBr'
+      ),
+    },
+    root: {
+      side: 'community',
+    },
+    clan: {
+      address: new PublicKey('4xaoEDkp3E1QBWBqd8HY8kB6u2NW9NRXhCyByEEXqqy4'),
+      owner: buildKeypair(
+        'GHiGnV4MSgUALpinpg2pkzkiXZdNgUprvJdfFDrKkfAm',
+        [
+          204, 252, 251, 104, 143, 139, 24, 188, 55, 61, 33, 44, 147, 178, 44,
+          150, 76, 120, 183, 195, 230, 220, 53, 133, 155, 244, 224, 54, 241,
+          226, 198, 24, 227, 39, 67, 110, 39, 129, 60, 123, 185, 254, 150, 15,
+          200, 130, 182, 104, 147, 29, 197, 35, 46, 96, 160, 48, 15, 185, 223,
+          37, 22, 42, 113, 6,
+        ]
+      ),
+      name: 'Marinade',
+      description: 'Marinade is a test clan',
+      size: 1000,
+      weightCheckpoints: new PublicKey(
+        'GuMWYeihEMannMdeKUSTD58WpR7sHGsiXkSyXeNoq5NQ'
+      ),
+    },
+    clanAuthority: 'owner',
+    newSnapshotVoting: true,
+  },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '6Eiow4tfvp3Yfb9XBxpmzc4kjBWrQnds65UfvZbaPnUL'
+      ),
+      realmAddress: new PublicKey(
+        'My1TtXGJHhmLLq9y4Xf3AExQ2z7Mt56SAKa9Vnx15PG'
+      ),
+      communityMint: new PublicKey(
+        '95MMdMCz42jFfjd95Ae74dz6Ys8ZqcNZBNV6HodcGBUY'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(32),
+      },
+      minCommunityWeightToCreateGovernance: new BN(4),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'BcrwmfyxQGviYYLphrv9AEMqBwBzsQomxBMpPMRRaS9L'
+      ),
+    },
+    root: {
+      side: 'community',
+    },
+    clan: {
+      address: new PublicKey('2CiQphR1pXonaLsYTUqjnvVFo1vRTDXDddZSagjJ8hPh'),
+      owner: buildKeypair(
+        '9NugGbauPKUkSCyRiWP8aPw8XLxJkhg8fgPMXMEZPy8G',
+        [
+          40, 143, 250, 151, 201, 119, 219, 25, 24, 122, 99, 136, 53, 95, 110,
+          152, 49, 9, 60, 79, 159, 33, 113, 87, 41, 57, 237, 121, 161, 138, 93,
+          223, 124, 122, 65, 40, 254, 19, 104, 243, 248, 116, 125, 3, 63, 36,
+          236, 174, 231, 38, 200, 72, 116, 140, 76, 133, 23, 84, 135, 28, 49,
+          125, 0, 21,
+        ]
+      ),
+      name: 'Marinade',
+      description: 'Marinade is a test clan',
+      size: 1000,
+    },
+    clanAuthority: 'owner',
+    newSnapshotVoting: true,
+    error: 'ClanWeightCheckpointsRequired',
+  },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '2FC5KBVAKNL5dERt7BQGmp4H8oz13i6D4ZumX1ZfGesn'
+      ),
+      realmAddress: new PublicKey(
+        '8AqkcZxdYGgbchdk6PG1pKquYWCd9bN5Lyajoees2q5s'
+      ),
+      communityMint: new PublicKey(
+        'DVTUfu34pufA2vtAJg7RUUQFrGqi29WeSUyedrVJgsgp'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(32),
+      },
+      minCommunityWeightToCreateGovernance: new BN(4),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'H1ZmAtRXJyRUhkZN4PajTzjHEQakuxgUMXhk3T5KKvdk'
+      ),
+    },
+    root: {
+      side: 'community',
+    },
+    clan: {
+      address: new PublicKey('BV9YsmNRDyU3H9UeudEsR7rjXL4Tj43zuepwZyfx6Zdc'),
+      owner: buildKeypair(
+        '4u8QdaW7K4f2hFXQbtGMpRNjpAEgv7Z5AGNMZWsLe3JZ',
+        [
+          244, 217, 96, 166, 34, 4, 74, 180, 71, 70, 100, 191, 109, 173, 99,
+          236, 167, 255, 136, 12, 181, 225, 255, 134, 127, 49, 165, 206, 254,
+          96, 189, 174, 57, 237, 163, 113, 169, 63, 244, 2, 5, 255, 9, 194, 177,
+          57, 46, 175, 111, 181, 5, 148, 103, 3, 0, 98, 92, 202, 48, 51, 125,
+          236, 22, 186,
+        ]
+      ),
+      name: 'Marinade',
+      description: 'Marinade is a test clan',
+      size: 1000,
+    },
+    clanAuthority: 'owner',
+    // The spending period is longer than a year
+    newTreasuryPolicy: {
+      spendingLimit: new BN(1000000000),
+      spendingPeriod: new BN(365 * 24 * 60 * 60 + 1),
+      approvalThresholdBp: 5000,
+    },
+    error: 'InvalidTreasuryPolicy',
+  },
 ];
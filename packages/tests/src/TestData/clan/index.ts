@@ -5,3 +5,11 @@ export * from './resizeClan';
 export * from './setClanOwner';
 export * from './forcedCancelProposal';
 export * from './updateProposalVote';
+export * from './castVetoVote';
+export * from './reviseClanVwr';
+export * from './linkSiblingClan';
+export * from './clanCommission';
+export * from './clanTreasury';
+export * from './migrateClan';
+export * from './updateClan';
+export * from './clanWeightCheckpoints';
@@ -0,0 +1,277 @@
+import {Keypair, PublicKey} from '@solana/web3.js';
+import {ClanTestData} from '../../VoteAggregator';
+import {RealmTestData} from '../../SplGovernance/realm';
+import {buildKeypair} from '../..';
+import BN from 'bn.js';
+
+export type LinkSiblingClanTestData = {
+  realm: RealmTestData;
+  // In the community root
+  clan: ClanTestData & {owner: Keypair};
+  // In the council root
+  siblingClan: ClanTestData;
+  unlink: boolean;
+  // Renames both linked clans
+  newName?: string;
+  error?: string;
+};
+
+export const linkSiblingClanTestData: LinkSiblingClanTestData[] = [
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '9gGyAiDoKATfa6U1ajQd1yFMXbevnMQLDBNBefkDb3zM'
+      ),
+      realmAddress: new PublicKey(
+        '7UzYNAADEEFycTegG3Pw1k5DRDHo5yLLgUeETnYs1NbH'
+      ),
+      communityMint: new PublicKey(
+        '4XJQHYyffM2mo9izDsN5hkkuYywsXNULaYUJsGdZPi3D'
+      ),
+      councilMint: new PublicKey(
+        '4mKww6QW4YL3rRQLgrh7n4kocm92XRBS3gDRNLndUBJi'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(32),
+      },
+      minCommunityWeightToCreateGovernance: new BN(4),
+      name: 'Community + council side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'GQVg9p9owTjHUghur1LPWdVW6WnyHUDWETKyNQrCBrjx'
+      ),
+      councilMintAuthority: new PublicKey(
+        '8W1UV7r9Zxnhv5eVhW7qhAB3Vorcdsse8Cqstv97pTBu'
+      ),
+    },
+    clan: {
+      address: new PublicKey('4cqny9zgw2FhgFMVnKLXJtWiANKi2kJCtxhViF87F2kM'),
+      owner: buildKeypair(
+        '3m9HWeE1d22X7hdn3oAokr4pQ8qu6ejqLdM4w9YZxZwG',
+        [
+          207, 52, 161, 41, 221, 11, 34, 194, 165, 25, 167, 253, 253, 140, 245,
+          69, 195, 203, 35, 10, 69, 145, 189, 254, 89, 239, 144, 90, 171, 41,
+          194, 146, 41, 6, 76, 5, 183, 189, 153, 136, 236, 113, 217, 141, 127,
+          102, 92, 219, 187, 44, 48, 162, 89, 254, 244, 43, 113, 189, 149, 159,
+          147, 183, 43, 195,
+        ]
+      ),
+      name: 'Marinade',
+      size: 1000,
+    },
+    siblingClan: {
+      address: new PublicKey('A6DepmEsrwHvbC3oz677GdZyqccviYcHwCotWMMY4a45'),
+      owner: new PublicKey('3m9HWeE1d22X7hdn3oAokr4pQ8qu6ejqLdM4w9YZxZwG'),
+      name: 'Marinade',
+      size: 1000,
+    },
+    unlink: false,
+  },
+  // A frozen clan does not hold its sibling
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '68VUfPao1mm3uttZ3k7NLoCAPEBWGnjaUMQuZ6RebZHZ'
+      ),
+      realmAddress: new PublicKey(
+        '8KF4SnPAPSfeaPXtT5mvLsfAjdrewwmuHxupDgAq4hqK'
+      ),
+      communityMint: new PublicKey(
+        '9Ld46kARe6k9nGkwpBfudcD2Cj3rBzsE8dTNMHVg7ubJ'
+      ),
+      councilMint: new PublicKey(
+        '8kt8crjeJUYkHKzVJrBB5VEyXomYRFsjwyvQ9BXmMG5F'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(32),
+      },
+      minCommunityWeightToCreateGovernance: new BN(4),
+      name: 'Community + council side, no plugins',
+      communityMintAuthority: new PublicKey(
+        '2wh6y1s7iAX2BJuXMHQYSjj49UKYbzz9gJRyQNL6VVVC'
+      ),
+      councilMintAuthority: new PublicKey(
+        '45kc7Ltf7m2KzDHptTgwqQT5D42wwYSw17vtAXA7fFJy'
+      ),
+    },
+    clan: {
+      address: new PublicKey('7tw4eyAY2oz3nhHkg1e18fLtcZ9aNdPdeXtydtf2KUmb'),
+      owner: buildKeypair(
+        '8sLu57KCyDPv9qBfWGDizGKtrmrwC6xD9jcr7ggAK96U',
+        [
+          69, 57, 108, 241, 39, 9, 246, 229, 22, 6, 149, 146, 4, 116, 49, 168,
+          179, 233, 9, 13, 97, 135, 90, 241, 107, 120, 218, 104, 138, 49, 245,
+          75, 116, 231, 88, 240, 166, 156, 231, 63, 42, 40, 20, 90, 53, 48, 31,
+          130, 89, 17, 8, 187, 171, 66, 36, 186, 170, 237, 69, 254, 72, 17, 155,
+          221,
+        ]
+      ),
+      name: 'Marinade',
+      size: 1000,
+      sibling: new PublicKey('8jfW8ookGYivFcUQdWUa4kTPB7q6U571K5ssz7PhXYh9'),
+    },
+    siblingClan: {
+      address: new PublicKey('8jfW8ookGYivFcUQdWUa4kTPB7q6U571K5ssz7PhXYh9'),
+      owner: new PublicKey('8sLu57KCyDPv9qBfWGDizGKtrmrwC6xD9jcr7ggAK96U'),
+      name: 'Marinade',
+      size: 1000,
+      sibling: new PublicKey('7tw4eyAY2oz3nhHkg1e18fLtcZ9aNdPdeXtydtf2KUmb'),
+      frozen: true,
+    },
+    unlink: true,
+  },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '6KGy8Ro94MWFWMf6by12AZzNRC3wrYS7yNMkh9Gk13Dk'
+      ),
+      realmAddress: new PublicKey(
+        'BxxLTEvkq13V5D6SNB8ykod7rFzSgyCLtqeM6kz62pus'
+      ),
+      communityMint: new PublicKey(
+        '3HR1kAgtfV7LKan93Hg82iDocA1ocVJNjiRTP3Er5j9L'
+      ),
+      councilMint: new PublicKey(
+        '9n9AuA789LYkYZTPyvxkmJKD6sLe9xeYE6ipQHMFJ8dg'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(32),
+      },
+      minCommunityWeightToCreateGovernance: new BN(4),
+      name: 'Community + council side, no plugins',
+      communityMintAuthority: new PublicKey(
+        '5s8RVPjh5qbms3kdjejVaWxeozcBaTByjGzi71YH7kva'
+      ),
+      councilMintAuthority: new PublicKey(
+        '58v7Dvks7YWBvFwTPXqr8L2RPS4DJ7ZrEES1h5BHub7X'
+      ),
+    },
+    clan: {
+      address: new PublicKey('BCdW9e9KthRHKm5gXdx8qwLKm2MwSFqAcofUYXBH4DKp'),
+      owner: buildKeypair(
+        'Ev6zEXdPbmqidsS85sj92yrJanQ6S7q2tfUQeovrcvnj',
+        [
+          255, 193, 178, 97, 221, 28, 217, 137, 17, 33, 215, 252, 226, 249, 211,
+          106, 233, 158, 83, 10, 23, 253, 231, 116, 4, 182, 91, 193, 72, 202,
+          47, 37, 206, 194, 123, 113, 72, 200, 252, 0, 23, 199, 69, 158, 132,
+          222, 245, 90, 40, 166, 246, 124, 118, 192, 29, 191, 127, 241, 205,
+          151, 56, 134, 105, 120,
+        ]
+      ),
+      name: 'Marinade',
+      size: 1000,
+      sibling: new PublicKey('ERXyk5HWt8wXitNjuVRGpAPtrRUTJrHtSgXqekv1ftft'),
+    },
+    siblingClan: {
+      address: new PublicKey('ERXyk5HWt8wXitNjuVRGpAPtrRUTJrHtSgXqekv1ftft'),
+      owner: new PublicKey('Ev6zEXdPbmqidsS85sj92yrJanQ6S7q2tfUQeovrcvnj'),
+      name: 'Marinade',
+      size: 1000,
+      sibling: new PublicKey('BCdW9e9KthRHKm5gXdx8qwLKm2MwSFqAcofUYXBH4DKp'),
+    },
+    unlink: true,
+    newName: 'Marinade DAO',
+  },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '6CqyQg4ftN59K2rYTcY4pjJqE1BW2gJw85g4ibwuszwS'
+      ),
+      realmAddress: new PublicKey(
+        '3B9trixkHKYmVd1o5HkHCoH1ZMYr4tr3U63rSs8EYGRt'
+      ),
+      communityMint: new PublicKey(
+        'Cfw74CFpDrdcD2E4HLbsDWvhbmAZGg3a1RKhNBiZPYT6'
+      ),
+      councilMint: new PublicKey(
+        '4EB7ZuPqGNtvmCcjAAdTBfENykAQXKsoJQdZiHQ3t1k5'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(32),
+      },
+      minCommunityWeightToCreateGovernance: new BN(4),
+      name: 'Community + council side, no plugins',
+      communityMintAuthority: new PublicKey(
+        '2mQHYDwiEMpDSDYvvprMjyAVDPKyXcBr75NCeRtVZ4t9'
+      ),
+      councilMintAuthority: new PublicKey(
+        'FKfGZDemeTuZhaa1M6rHqepVjqQZz61GTSqM6mvtEFEG'
+      ),
+    },
+    clan: {
+      address: new PublicKey('HpyJ6x2aRxHxn5ZVBFNmeQHq1EGKEypW5o82ZLuX9Ydf'),
+      owner: buildKeypair(
+        'E76QeNfKnr6Bvjjeecvu6Y3NHd36BTeEvqVKv2Xdje5r',
+        [
+          153, 183, 7, 190, 0, 83, 236, 55, 30, 165, 246, 81, 16, 0, 255, 48,
+          76, 25, 15, 181, 70, 207, 250, 133, 240, 198, 89, 60, 58, 102, 157,
+          118, 194, 183, 135, 132, 25, 176, 104, 120, 122, 9, 49, 110, 234, 127,
+          167, 127, 186, 85, 159, 165, 115, 121, 131, 151, 152, 87, 125, 5, 108,
+          48, 37, 157,
+        ]
+      ),
+      name: 'Marinade',
+      size: 1000,
+    },
+    siblingClan: {
+      address: new PublicKey('EqyMMqenfwzFmPaHZeY8mLx8NZdh3bNh87GybUvq6qXx'),
+      owner: new PublicKey('E76QeNfKnr6Bvjjeecvu6Y3NHd36BTeEvqVKv2Xdje5r'),
+      name: 'Marinade',
+      size: 1000,
+      frozen: true,
+    },
+    unlink: false,
+    error: 'ClanFrozen',
+  },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'CnQcpvtKGnqyVj9TH5dPoaMqt6zFhUsRJzT4K8NduqWf'
+      ),
+      realmAddress: new PublicKey(
+        '8gYCJQ28faPXDStVQPqYR5ycmM3HSB5dNJrCNux6rwWw'
+      ),
+      communityMint: new PublicKey(
+        '3DtyWar8pHUeWSxJqRrCDW6nTnTRg2dD4sX3CTokZukh'
+      ),
+      councilMint: new PublicKey(
+        '41TZn9hmx6A3AhsvnAAsdiD5R3q4Xj7WvD2aJ5ZZZE3N'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(32),
+      },
+      minCommunityWeightToCreateGovernance: new BN(4),
+      name: 'Community + council side, no plugins',
+      communityMintAuthority: new PublicKey(
+        '3Cto6jqgbMmx2E5ifgXjK5WzoTjxCB2CYhiSWJmJPcn8'
+      ),
+      councilMintAuthority: new PublicKey(
+        '74BfMLBWdwVfV8FdQJj7uVw1yyuAZXShSx9ziu5ft8RN'
+      ),
+    },
+    clan: {
+      address: new PublicKey('Edcf83s66DvyhgDRt3A2oxcNyVj54jVS4LiyewFcCmNj'),
+      owner: buildKeypair(
+        'DurKYzAWqsZok1wKBgfPunW43UQYC2JQy1mSdLuDZJ3o',
+        [
+          201, 89, 116, 193, 8, 183, 155, 117, 250, 233, 197, 142, 171, 215,
+          214, 211, 172, 131, 150, 151, 159, 140, 39, 117, 246, 255, 53, 138,
+          206, 187, 54, 126, 191, 214, 54, 25, 79, 242, 186, 212, 59, 110, 197,
+          125, 202, 82, 253, 26, 204, 43, 251, 126, 140, 112, 214, 95, 6, 81,
+          116, 51, 243, 108, 56, 6,
+        ]
+      ),
+      name: 'Marinade',
+      size: 1000,
+      // Linked to another council clan
+      sibling: new PublicKey('5d5Zw4eCeULoTLqCRFN9g2jdBTGob2NrhWBaKXV5CTpv'),
+    },
+    siblingClan: {
+      address: new PublicKey('4hbsDpRWmfEcitfjbUjgB4QKqufmvVrGieWQAXDzjf87'),
+      owner: new PublicKey('DurKYzAWqsZok1wKBgfPunW43UQYC2JQy1mSdLuDZJ3o'),
+      name: 'Marinade',
+      size: 1000,
+    },
+    unlink: false,
+    error: 'ClanAlreadyLinked',
+  },
+];
@@ -0,0 +1,95 @@
+import BN from 'bn.js';
+import {ClanTestData, RootTestData} from '../../VoteAggregator';
+import {RealmTestData} from '../../SplGovernance/realm';
+import {PublicKey} from '@solana/web3.js';
+
+export type MigrateClanTestData = {
+  realm: RealmTestData;
+  root: RootTestData;
+  clan: ClanTestData;
+  // Encoded with the layout before the voter weight decay
+  legacy: boolean;
+  error?: string;
+};
+
+export const migrateClanTestData: MigrateClanTestData[] = [
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'Gksp1oAj1T8ScP92M8AGc6aiHyF2tZkkWSbizwoWXv1X'
+      ),
+      realmAddress: new PublicKey(
+        'EcLVqTAojVtHGukeDiiaQCYjKLA8qpX1DTFmjfQXrGTD'
+      ),
+      communityMint: new PublicKey(
+        'EquGPTB7o9vbssFfbbeVMmWKT1yHbDjFVhUFF4xXDiHT'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'AuWHDhqtSQThySGVjNZhcb3TN4qGaC3wFjSJcRYm23JK'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+    },
+    clan: {
+      address: new PublicKey('Cd9XuZ5uEVmrARzZpt7Ugxh9VPFvYs977fwN9w6rVtvC'),
+      owner: new PublicKey('KWWqduQMR4vWNJZz5SnKQbyKopu4CM8BN148uRBfREP'),
+      delegate: new PublicKey('BtTg9DExWJx45afxLr1wdypPLr2Mz3P2UG9u5BmKCJKy'),
+      name: 'Marinade',
+      description: 'Legacy clan',
+      minVotingWeightToJoin: new BN(100),
+      permanentMembers: new BN(2),
+      temporaryMembers: new BN(1),
+      updatedTemporaryMembers: new BN(1),
+      leavingMembers: new BN(1),
+      acceptTemporaryMembers: false,
+      voterWeight: new BN(3500),
+      permanentVoterWeight: new BN(3000),
+      nextVoterWeightResetTime: new BN(5000),
+    },
+    legacy: true,
+  },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '42vjvuFrahc3WukLsWdrcJeA5BAosuTPQCudNePqDznU'
+      ),
+      realmAddress: new PublicKey(
+        '58xeZyyrTxrDgZ1gC9ChVD1q1NXNihpX4boenhNghU6J'
+      ),
+      communityMint: new PublicKey(
+        '8BnfKTBHS7U9LGU9SnjDiPPCJbV3F5FNCUJpJmyRQimT'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'F1qWeAtaZWNcaNaQ1YDZn9CuPWHdvxvsD5BMAH5wqSfY'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+    },
+    clan: {
+      address: new PublicKey('BbKjKELuEPwtiXZ8aEJR9Lr2JEdQmR8RjYjNeD2YwAbK'),
+      owner: new PublicKey('94XuicSpd8m8a28je4TNngzqMEdoXsh1D2kgDJF9mSjx'),
+      // At least Clan::SPACE as created by create_clan
+      size: 10240,
+      name: 'Marinade',
+      permanentMembers: new BN(1),
+      voterWeight: new BN(1000),
+      permanentVoterWeight: new BN(1000),
+    },
+    legacy: false,
+    error: 'ClanAlreadyMigrated',
+  },
+];
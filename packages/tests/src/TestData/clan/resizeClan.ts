@@ -30,4 +30,25 @@ export const resizeClanTestData: ResizeClanTestData[] = [
     clanAuthority: 'owner',
     newSize: 1200,
   },
+  // Members of a frozen clan still need the space to be updated and leave
+  {
+    clan: {
+      root: new PublicKey('4BeqHLYdez7QyZd7HnmNe3Lcy4nEuPCAiZkfPxDhBpNA'),
+      address: new PublicKey('2tUuHvTFJw4S1kjK6PL3Ne8vXPaSzHZDehWu172YcFo1'),
+      owner: buildKeypair(
+        'FJwZ31ffvGsLjZCfGHkcV4k9PDaHt27wLvURpHkAVCyp',
+        [
+          43, 191, 237, 120, 100, 19, 143, 109, 35, 179, 79, 70, 171, 123, 171,
+          10, 182, 48, 45, 43, 110, 206, 159, 117, 157, 164, 98, 129, 247, 98,
+          225, 209, 212, 156, 44, 88, 212, 122, 34, 238, 243, 202, 103, 202,
+          219, 254, 154, 17, 156, 47, 190, 193, 252, 191, 4, 63, 95, 210, 150,
+          247, 67, 209, 132, 187,
+        ]
+      ),
+      name: 'Marinade',
+      frozen: true,
+    },
+    clanAuthority: 'owner',
+    newSize: 1200,
+  },
 ];
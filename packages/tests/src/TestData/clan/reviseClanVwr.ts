@@ -0,0 +1,228 @@
+import BN from 'bn.js';
+import {ClanTestData, RootTestData} from '../../VoteAggregator';
+import {RealmTestData} from '../../SplGovernance/realm';
+import {PublicKey} from '@solana/web3.js';
+
+export type ReviseClanVwrTestData = {
+  realm: RealmTestData;
+  root: RootTestData;
+  clan: ClanTestData;
+  slot?: bigint;
+  error?: string;
+};
+
+export const reviseClanVwrTestData: ReviseClanVwrTestData[] = [
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '4id91pS36hXT6KSC2df9E7GYRba5ppejQrX6c7uEwXy6'
+      ),
+      realmAddress: new PublicKey(
+        '72KSetHcrzxNK3DavYf81nhmzitqhQraYwH5M5RmnTSo'
+      ),
+      communityMint: new PublicKey(
+        '7gK2EcCSJWExVi2nuULovKBVS3tvvKFyrSw9twP3CFT9'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, expiring plugin',
+      communityMintAuthority: new PublicKey(
+        '5UfCFpEe6n4tevMPRxRRkE6GEw4NBNhgYnfYKhfPrRWQ'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      voterWeightReset: {
+        nextResetTime: new BN('1893456000'),
+        step: new BN(86400),
+      },
+    },
+    clan: {
+      address: new PublicKey('6UMno61pfXbfbYa7Xgm3Fg3i9qR9yrwb4NvdxQA88bis'),
+      owner: new PublicKey('HYgRCf9JUmidceK17LrmxDXmqDU7v747x2DXewTs4DCq'),
+      name: 'Marinade',
+      permanentMembers: new BN(1),
+      temporaryMembers: new BN(1),
+      updatedTemporaryMembers: new BN(1),
+      voterWeight: new BN(1500),
+      voterWeightExpiry: new BN(10),
+      permanentVoterWeight: new BN(1000),
+      nextVoterWeightResetTime: new BN('1893456000'),
+    },
+    slot: BigInt(100),
+  },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'BgCZKKHnDE8nx9mpComLLWnR6hTQq54aDrkUdizSKB6Z'
+      ),
+      realmAddress: new PublicKey(
+        '2hSruEjVK5weDfyCGEamx3EwBkRA5e2MmFEJVkiCVvdL'
+      ),
+      communityMint: new PublicKey(
+        '3CxaMC2A54iPj1rkeYjR1wtqY1rfLnpH5i2nxiTMTLj9'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, expiring plugin',
+      communityMintAuthority: new PublicKey(
+        'psK2C1uj8DGM7SrPDhndrYijmEWJRVts4NLuKQB8NVn'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      voterWeightReset: {
+        nextResetTime: new BN('1893456000'),
+        step: new BN(86400),
+      },
+    },
+    clan: {
+      address: new PublicKey('HkdyksWwibJwFmK1HUDBQWaaN2NoCKG8ihpNw1YqBcC2'),
+      owner: new PublicKey('3X984SMek2fgErTJ3nyGGM8LXPACX8fsMRZzXbE8g2Pa'),
+      name: 'Marinade',
+      permanentMembers: new BN(1),
+      temporaryMembers: new BN(1),
+      // One of the temporary members missed the current reset
+      updatedTemporaryMembers: new BN(0),
+      voterWeight: new BN(1500),
+      voterWeightExpiry: new BN(10),
+      permanentVoterWeight: new BN(1000),
+      nextVoterWeightResetTime: new BN('1893456000'),
+    },
+    slot: BigInt(100),
+    error: 'TemporaryMembersNotUpdated',
+  },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'GpcM1Fn56vhpzKzHij963kWbftCo6PVeC4zFooLAf1xD'
+      ),
+      realmAddress: new PublicKey(
+        'EmrrkQYtseg7YaL7BWvwxqmEpTzigUccYouRkbkWY5PF'
+      ),
+      communityMint: new PublicKey(
+        'EKgyQRiGdLwvPEivzRkHvBhmqyXUwBNtLPMuCaqy1UGN'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, expiring plugin',
+      communityMintAuthority: new PublicKey(
+        'FfgSYCKiT86xMD7bgqsXeh1rRC31x68s3ADNecBs3iiz'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      voterWeightReset: {
+        nextResetTime: new BN('1893456000'),
+        step: new BN(86400),
+      },
+    },
+    clan: {
+      address: new PublicKey('4pAzrPTkshBZX3BPtLeFudn21R3nKrCxLgsfGsqMFJFe'),
+      owner: new PublicKey('BNckqgCYcVt4jUojze4bDK6uf3FsqgvPC6vgSCK4pawP'),
+      name: 'Marinade',
+      permanentMembers: new BN(1),
+      temporaryMembers: new BN(1),
+      updatedTemporaryMembers: new BN(1),
+      voterWeight: new BN(1500),
+      voterWeightExpiry: new BN(10),
+      permanentVoterWeight: new BN(1000),
+      nextVoterWeightResetTime: new BN('1893456000'),
+      unmigratedMembers: new BN(1),
+    },
+    slot: BigInt(100),
+    error: 'WeightTransformNotMigrated',
+  },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '9U11RvrstT3P4CzPSg2FPVAgtjoz8YEELVKPEYKe3Rt4'
+      ),
+      realmAddress: new PublicKey(
+        '74sWUkR2GL77LPAZeqQuSG5XLezEU4kxZZnMPcux1izN'
+      ),
+      communityMint: new PublicKey(
+        'CyHWyyabKdvwgRLae9wL79PxPyLGwodZ2QKgVTxAo8XK'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, expiring plugin',
+      communityMintAuthority: new PublicKey(
+        'GwgRZrbWpvaTvtcmabC1M5KwRKa4h3JRYA2z51bV6wMZ'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+    },
+    clan: {
+      address: new PublicKey('C6G1BVcaUmMmBvEYjHMN3MGzY4hpf6UPL6W3XfU4eXWi'),
+      owner: new PublicKey('DGoPbbyevZVgKJXKLMp3aXCRNpmfbvq9N2xbwzoDzoWb'),
+      name: 'Marinade',
+      permanentMembers: new BN(1),
+      temporaryMembers: new BN(1),
+      updatedTemporaryMembers: new BN(1),
+      voterWeight: new BN(1500),
+      voterWeightExpiry: new BN(10),
+      permanentVoterWeight: new BN(1000),
+      // The temporary weight was not dropped since the resets were disabled
+      nextVoterWeightResetTime: new BN('1893456000'),
+    },
+    slot: BigInt(100),
+    error: 'TemporaryMembersNotUpdated',
+  },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '5ro2KKED6gkTcmMJx2QaZ85NJQ2AGpDwxJRvbXYRFXJ'
+      ),
+      realmAddress: new PublicKey(
+        'ZfcfY26oGFp9rDPQjBpGB4WxzFfwKYeG25R2rQKjbxi'
+      ),
+      communityMint: new PublicKey(
+        '3M2NcLeLHwXnqPcYEgGssCpdogTmVg7nnD7k9gxVcemn'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, decaying plugin',
+      communityMintAuthority: new PublicKey(
+        'E2gLF5P5eSUvN6puNaBFWgY5kcDg8pmrLEpHZA8PRfVv'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      voterWeightReset: {
+        nextResetTime: new BN('1893456000'),
+        step: new BN(86400),
+      },
+    },
+    clan: {
+      address: new PublicKey('5JZbDm4HdzHkAyvPi8PGG1fEDNeky48KqseAJDkc3J59'),
+      owner: new PublicKey('FxPySAf243v8ZX14PJKzv1ZbnhsV89EA2jaZZf4WyRQw'),
+      name: 'Marinade',
+      permanentMembers: new BN(1),
+      // The decaying member missed the current reset
+      decayingMembers: new BN(1),
+      updatedTemporaryMembers: new BN(0),
+      voterWeight: new BN(1000),
+      permanentVoterWeight: new BN(1000),
+      nextVoterWeightResetTime: new BN('1893456000'),
+    },
+    slot: BigInt(100),
+    error: 'TemporaryMembersNotUpdated',
+  },
+];
@@ -1,9 +1,13 @@
 import {Keypair, PublicKey} from '@solana/web3.js';
-import {ClanTestData} from '../../VoteAggregator';
+import {ClanTestData, RootTestData} from '../../VoteAggregator';
+import {RealmTestData} from '../../SplGovernance/realm';
 import {buildKeypair} from '../..';
+import BN from 'bn.js';
 
 export type SetClanOwnerTestData = {
-  clan: ClanTestData & {root: PublicKey};
+  realm: RealmTestData;
+  root: RootTestData;
+  clan: ClanTestData;
   clanAuthority: Keypair | 'owner';
   newOwner: PublicKey;
   error?: string;
@@ -11,8 +15,29 @@ export type SetClanOwnerTestData = {
 
 export const setClanOwnerTestData: SetClanOwnerTestData[] = [
   {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'AsFykQEAhSZ88gFWB2Unhiq8QCn82hLeBGxRmABAd2hW'
+      ),
+      realmAddress: new PublicKey(
+        '7poT4XY26wRBrGr298kgZTFmyL5G59koD4uMusShJkUC'
+      ),
+      communityMint: new PublicKey(
+        'D5AhZQEKwmWW9x82zzAvWsrrhzxKnBvY4yZqrouxFgNS'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(32),
+      },
+      minCommunityWeightToCreateGovernance: new BN(4),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'Hv6vWbMpDWfGdFvLizATSZCzgVZbDg4j2R5jqP8PwgsJ'
+      ),
+    },
+    root: {
+      side: 'community',
+    },
     clan: {
-      root: new PublicKey('8qKxmU6GpvTjKu3pyBF5bLwZpaX431y3pmCnrkjuC2hj'),
       address: new PublicKey('7GYCtRSpMmTMgxWvg7NtW5ESw9yX3jXBmNMw7wvn7D41'),
       owner: buildKeypair(
         '4P4rFuYLKjF3WPLiof8XYXEzdHoiT6ofVaxU2YP8u5hR',
@@ -31,4 +56,91 @@ export const setClanOwnerTestData: SetClanOwnerTestData[] = [
     clanAuthority: 'owner',
     newOwner: new PublicKey('7gBxbecBEj5k9EN4Yao6fT75AVuyz2irKoqKgxgRCYMh'),
   },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'DZLJtF3n5CbcpYnqGsG3PAV5cazLjHefk1KWYm3ezVWD'
+      ),
+      realmAddress: new PublicKey(
+        'E6uiZY9rURNZ9EYQJuZL2ft4YZxwkZoh3f5koYSpJN8w'
+      ),
+      communityMint: new PublicKey(
+        'EGR5vcwct6RvMdM8NKeCQDruc7a9YZTSvbajebSLHUt2'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(32),
+      },
+      minCommunityWeightToCreateGovernance: new BN(4),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'CLUrBj6FVqrM3A6kbvvey4yuncmtJ9zVCXsfXXJrNqde'
+      ),
+    },
+    root: {
+      side: 'community',
+      pauseFlags: 0b100000, // clan config
+    },
+    clan: {
+      address: new PublicKey('9LgoUmkYf2p5aL2Mr84LL5wbyDvseQDeY3xdFPRsF3cA'),
+      owner: buildKeypair(
+        'GtAkfGKftPsRiy8wSYA4FwHWLs6fPXk3WJftVxpJxBWC',
+        [
+          108, 152, 53, 53, 184, 184, 160, 77, 82, 25, 118, 42, 88, 89, 146,
+          255, 205, 177, 251, 109, 164, 36, 245, 246, 183, 251, 33, 0, 36, 191,
+          161, 217, 235, 250, 243, 223, 178, 220, 8, 35, 99, 131, 93, 194, 30,
+          237, 86, 49, 184, 180, 15, 63, 68, 158, 163, 98, 212, 207, 92, 130,
+          133, 131, 151, 205,
+        ]
+      ),
+      name: 'Marinade',
+      description: 'Marinade is a test clan',
+      size: 1000,
+    },
+    clanAuthority: 'owner',
+    newOwner: new PublicKey('7gBxbecBEj5k9EN4Yao6fT75AVuyz2irKoqKgxgRCYMh'),
+    error: 'Paused',
+  },
+  // Setting the same owner changes nothing
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'Ernfa8BfJJD76RvMwvrXgMdNCe7rnBjVuxD2P6YP7wDU'
+      ),
+      realmAddress: new PublicKey(
+        'FTwJ9LTL2QWC2116RncWQupEaw5xhCc1WFgDTpHgJsPK'
+      ),
+      communityMint: new PublicKey(
+        '9QWbjJR9FJSwynsih15v5wyAB11efhQUTP3fxZ6S7Xr2'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(32),
+      },
+      minCommunityWeightToCreateGovernance: new BN(4),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        '3Cod1HuNGZjTXX33EsJ6wULSd2hp6DMFFNzDeM5H3hmg'
+      ),
+    },
+    root: {
+      side: 'community',
+    },
+    clan: {
+      address: new PublicKey('A4p7AdJdCPM18bkxVQYrMMLUkcUjgSsbB3cW8QnCEZ2K'),
+      owner: buildKeypair(
+        '3xn3FeNvVd6AJ2n3hWUShvASeAA2ukut8xUdM4jtKHaX',
+        [
+          190, 248, 197, 81, 183, 204, 119, 5, 194, 226, 64, 145, 144, 26, 187,
+          135, 129, 32, 249, 9, 199, 85, 161, 223, 226, 224, 115, 131, 142, 136,
+          144, 241, 44, 1, 62, 113, 240, 217, 124, 229, 82, 28, 100, 230, 55,
+          40, 152, 160, 15, 204, 219, 128, 233, 237, 200, 239, 194, 217, 38, 42,
+          165, 251, 193, 216,
+        ]
+      ),
+      name: 'Marinade',
+      description: 'Marinade is a test clan',
+      size: 1000,
+    },
+    clanAuthority: 'owner',
+    newOwner: new PublicKey('3xn3FeNvVd6AJ2n3hWUShvASeAA2ukut8xUdM4jtKHaX'),
+  },
 ];
@@ -0,0 +1,234 @@
+import BN from 'bn.js';
+import {ClanAccount, ClanTestData, RootTestData} from '../../VoteAggregator';
+import {RealmTestData} from '../../SplGovernance/realm';
+import {PublicKey} from '@solana/web3.js';
+
+export type UpdateClanTestData = {
+  realm: RealmTestData;
+  root: RootTestData;
+  clan: ClanTestData;
+  clanChanges: Partial<ClanAccount>;
+  // The clan VWR is reset if set
+  resetVoterWeight?: BN;
+  // The clan belongs to this root of the realm if set
+  clanRoot?: RootTestData;
+  // The VWR of this clan is passed if set
+  otherClan?: ClanTestData;
+  error?: string;
+};
+
+export const updateClanTestData: UpdateClanTestData[] = [
+  // Rescheduled before the current reset
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '3SB1Atdi7FA3gfYoc4Uxj1XPd1fLbqR6sXgjzxcjUnjV'
+      ),
+      realmAddress: new PublicKey(
+        '5t6LVGhy91bUF3N63VqAhe8x5DwDFHwB6qr5r445wJb7'
+      ),
+      communityMint: new PublicKey(
+        'F2Wj3XmKrj5QZb9zfv1fnmuiRTUyL5Vr8JgyiK9dRrdC'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, expiring plugin',
+      communityMintAuthority: new PublicKey(
+        '24kc4aHxXqpNP7QxWfmbp8hUiLGP6FzHsZy4bvjQuAy7'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      voterWeightReset: {
+        nextResetTime: new BN('1893542400'),
+        step: new BN(86400),
+      },
+    },
+    clan: {
+      address: new PublicKey('B4JFWNDbWjcpny6GJHnfLncQpFWjksmZaM2PSJkrr3z5'),
+      owner: new PublicKey('5ouBNthegtFhJooe4gv34FJUqWkuWhGxmoGPeorpcVhU'),
+      name: 'Marinade',
+      permanentMembers: new BN(1),
+      temporaryMembers: new BN(1),
+      updatedTemporaryMembers: new BN(1),
+      voterWeight: new BN(1500),
+      voterWeightExpiry: new BN(10),
+      permanentVoterWeight: new BN(1000),
+      nextVoterWeightResetTime: new BN('1893456000'),
+    },
+    clanChanges: {
+      nextVoterWeightResetTime: new BN('1893542400'),
+      rescheduledVoterWeightResetTime: new BN('1893456000'),
+    },
+  },
+  // Disabled before the current reset
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '6v5dMHXf6frjqcNMosnT7rUcp9ZhgqbUM5jDFgECFNZq'
+      ),
+      realmAddress: new PublicKey(
+        'kxynP68MHBZPZwUGiGv1xXVnikitV41qVndg3zCfqDu'
+      ),
+      communityMint: new PublicKey(
+        '2U7xTucFVkdSZf5qbGzPeSa2BLmvFJpytfZS4pgxdtcL'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, expiring plugin',
+      communityMintAuthority: new PublicKey(
+        '9uU3ph1HV5KPm7uaEovyFhg56YBdF1tTyQzYtddBVDCo'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+    },
+    clan: {
+      address: new PublicKey('AhQX9AV3vGsphfUHHd34VDnCG9ipywUGZKCbtUR8j9Nr'),
+      owner: new PublicKey('DfPYhj3WRPVwM9Z8mP4fnd5jkNYfaWru6S4W847wo7cg'),
+      name: 'Marinade',
+      permanentMembers: new BN(1),
+      temporaryMembers: new BN(1),
+      updatedTemporaryMembers: new BN(1),
+      voterWeight: new BN(1500),
+      voterWeightExpiry: new BN(10),
+      permanentVoterWeight: new BN(1000),
+      nextVoterWeightResetTime: new BN('1893456000'),
+    },
+    clanChanges: {
+      nextVoterWeightResetTime: null,
+      rescheduledVoterWeightResetTime: new BN('1893456000'),
+    },
+  },
+  // The current reset is over
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '5Jyha4dCL7AHe2ciKKaaxKszV1Cp6CsyUYNPxQbuNCNt'
+      ),
+      realmAddress: new PublicKey(
+        'BkPQBNA5ENWhpAFtV3fzL7TSCavpwsKYn3urkfXkT2Qg'
+      ),
+      communityMint: new PublicKey(
+        'BzZPf9XWxrki2A4uwAoNA4AUHHgu8kwoq1CpYUGYsGtQ'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, expiring plugin',
+      communityMintAuthority: new PublicKey(
+        '2fecbhGjNB5rWG4YJvwwGo3mCoWXdTHNuR4eY4jM8C4S'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      voterWeightReset: {
+        nextResetTime: new BN('1893456000'),
+        step: new BN(86400),
+      },
+    },
+    clan: {
+      address: new PublicKey('CuMyWuZ4W9gjab8J7Q7yaHoxRSRSqvLbtJ3fZ7w4s6Bh'),
+      owner: new PublicKey('9x5KEDMkS8eCMBCey6tTqQAWuwJZBZzGdd1fE2cHw6Hf'),
+      name: 'Marinade',
+      permanentMembers: new BN(1),
+      temporaryMembers: new BN(1),
+      updatedTemporaryMembers: new BN(1),
+      voterWeight: new BN(1500),
+      voterWeightExpiry: new BN(10),
+      permanentVoterWeight: new BN(1000),
+      nextVoterWeightResetTime: new BN('1700000000'),
+    },
+    clanChanges: {
+      nextVoterWeightResetTime: new BN('1893456000'),
+      updatedTemporaryMembers: new BN(0),
+    },
+    resetVoterWeight: new BN(1000),
+  },
+  // The clan of the other root of the realm
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'CT7NJkoPQ61h7W8JSBop8VvJf6h3wV1gYPm79wa5iaCm'
+      ),
+      realmAddress: new PublicKey(
+        'AzLUMVw2C7LWo4LKFWZSbJTJXxT3jJqhAFD1ym4dTean'
+      ),
+      communityMint: new PublicKey(
+        '8VSxV7hubbTDQrLmZWi9cBjDfGBx5Kk4u5TKNfwFdjgP'
+      ),
+      councilMint: new PublicKey(
+        '7pCut3RkQPNJsGuy5McWJH8UJzvCjDcSvWu7CXRxszt2'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community and council roots',
+      communityMintAuthority: new PublicKey(
+        'DDWjfxexyBvPf9A43NzJt3bQ4Q1L7qG4H1L4APef6jyV'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+    },
+    clanRoot: {
+      side: 'council',
+      maxProposalLifetime: new BN(172800),
+    },
+    clan: {
+      address: new PublicKey('E7s7bzihrPmBRrbfoATois3fwBw1NgpXtgfbrnb8at3R'),
+      owner: new PublicKey('XnSZJW8CXaywaQkUXSmqVfrRjsaGZeiJN5w6ogufjD9'),
+      name: 'Marinade',
+    },
+    clanChanges: {},
+    error: 'ConstraintHasOne',
+  },
+  // The VWR of another clan
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '8Z2sZ3e1Ancz27hgD4S7uX9zLjErBLGjXQkwjXSfWY9L'
+      ),
+      realmAddress: new PublicKey(
+        '712vHbhVwhaP9VJ8sMXERxz1MCThFEDuSR188QwR4pCX'
+      ),
+      communityMint: new PublicKey(
+        'Bo4EcLGRQXKDRJ1eB7MN9itKdvHsVjPzsKCQJ6KojaSN'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'EycwNzkUBRaKAHSnqaqwfXYmoCwvv979jfUcj8ohJcrU'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+    },
+    clan: {
+      address: new PublicKey('CCcQTJJukGi3nzA5g1vLaLk46KsdFKzdhCbiEbyYS1PZ'),
+      owner: new PublicKey('5W9PGA6WnGRgj4jNYuasWAwCoAexV4FEMTtT8EDHzU1V'),
+      name: 'Marinade',
+    },
+    otherClan: {
+      address: new PublicKey('7ZZhVmgjbZLZiTFy1ynjdY3JKTnLSDccUg5nCU7NiKZs'),
+      owner: new PublicKey('4rBwierj5je8TE5G8DbQaBquPqSuvn3VB4V25mNL2pmj'),
+      name: 'Other',
+    },
+    clanChanges: {},
+    error: 'ConstraintSeeds',
+  },
+];
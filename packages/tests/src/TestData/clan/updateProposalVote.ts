@@ -101,4 +101,89 @@ export const updateProposalVoteTestData: UpdateProposalVoteTestData[] = [
       vote: {approve: [{rank: 0, weightPercentage: 100} as any]},
     },
   },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '3xNbuehooXRpHEbntV4BCwaaMdLHrGcfvjdopTA6idRq'
+      ),
+      realmAddress: new PublicKey(
+        '7cCDBt2cTcyvv9q4kaHG5h81kAJUGaFaWkuR32jAyNq9'
+      ),
+      communityMint: new PublicKey(
+        '8qeVzQTvSdZeuChHHTZKiFsQJSEnZoVGrTJQ5XQDKzhn'
+      ),
+      councilMint: new PublicKey(
+        '6M6ghTmKcGBaDav2YhEtb1TPEbZ9DBXQGAavNXbKqWnT'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(11),
+      },
+      minCommunityWeightToCreateGovernance: new BN(4),
+      name: 'Community vetoes council proposal',
+      communityMintAuthority: new PublicKey(
+        '8Uht9C3S3vCs5EoLwZbHNEXG3Z1ozmWB8uD6unKZphU6'
+      ),
+    },
+    root: {
+      side: 'community',
+    },
+    clan: {
+      address: new PublicKey('2QcGc6xMvKsUJ7fUm5ZnQQSeWtdBpVATNAqmeASnHdtT'),
+      owner: new PublicKey('BSwbVWvLWo1xC9TJVoc6fPXccQLzGKYBoTicdqtncoj8'),
+      name: 'Marinade',
+      unrelinquishedVotesCount: new BN(1),
+    },
+    governance: {
+      address: new PublicKey('BR52wDadLYbLZm3uaptwb71q9oASedhXuoRFPE1wv8xz'),
+      config: {
+        communityVoteThreshold: {
+          yesVotePercentage: [60],
+        },
+        minCommunityWeightToCreateProposal: new BN(LAMPORTS_PER_SOL),
+        minTransactionHoldUpTime: 0,
+        votingBaseTime: 100000000,
+        communityVoteTipping: {early: {}},
+        councilVoteThreshold: {yesVotePercentage: [50]},
+        councilVetoVoteThreshold: {yesVotePercentage: [50]},
+        minCouncilWeightToCreateProposal: new BN(LAMPORTS_PER_SOL),
+        councilVoteTipping: {early: {}},
+        communityVetoVoteThreshold: {yesVotePercentage: [50]},
+        votingCoolOffTime: 0,
+        depositExemptProposalCount: 0,
+      },
+      activeProposalCount: new BN(1),
+    },
+    proposal: {
+      address: new PublicKey('EJMrBDPNtLnNMEQJVoz2AwP1w1pWuh8BjUjXDF9d4e1n'),
+      governingTokenMint: new PublicKey(
+        '6M6ghTmKcGBaDav2YhEtb1TPEbZ9DBXQGAavNXbKqWnT'
+      ),
+      state: {voting: {}},
+      owner: new PublicKey('DKLu1t8A1NDXpvygyQSRU3VCjiwTVvb6vzm9UD6uPJJn'),
+      signatoriesCount: 0,
+      signatoriesSignedOffCount: 0,
+      voteType: {singleChoice: {}},
+      options: [
+        {
+          label: 'First',
+          voteWeight: new BN(1000),
+          voteResult: {none: {}},
+          transactionsExecutedCount: 0,
+          transactionsCount: 0,
+          transactionsNextIndex: 0,
+        },
+      ],
+      draftAt: new BN(0),
+      votingAt: new BN(10000000000),
+      voteThreshold: {yesVotePercentage: [20]},
+      name: 'Council proposal',
+      descriptionLink: 'description',
+      vetoVoteWeight: new BN(100),
+    },
+    vote: {
+      isRelinquished: false,
+      voterWeight: new BN(100),
+      vote: {veto: {}},
+    },
+  },
 ];
@@ -0,0 +1,379 @@
+import BN from 'bn.js';
+import {MemberTestData, RootTestData} from '../../VoteAggregator';
+import {RealmTestData} from '../../SplGovernance/realm';
+import {Keypair, PublicKey} from '@solana/web3.js';
+import {buildKeypair} from '../..';
+
+export type ClanRewardsTestData = {
+  realm: RealmTestData;
+  root: RootTestData;
+  member: MemberTestData & {owner: Keypair};
+  // Funded with the deposit amount
+  depositSource: PublicKey;
+  depositAmount: BN;
+  claimDestination: PublicKey;
+  // The member owner if undefined
+  memberAuthority?: Keypair;
+  error?: string;
+};
+
+export const clanRewardsTestData: ClanRewardsTestData[] = [
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'GYiUKRyxjEy7bgtEYYHRxmPfh9emSQhhGMGTzYdN15fq'
+      ),
+      realmAddress: new PublicKey(
+        '9SGNaPUXGZWi2jfV7Qcyyob7p7a7CZ845Gf6RiuZs2ZG'
+      ),
+      communityMint: new PublicKey(
+        '5VQfqDJngDAhfwY6e4hg7zkWQHbMFbQxh9ZnubFi69Lk'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        '2byASUyii73S6dbx8cZcuwNkgW6a7uAnB9H5i8buRWGR'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+    },
+    member: {
+      owner: buildKeypair(
+        '76VpkZZPwkFAT1NraSmnYrN3zHhd5FySE3zsjBLfiyKk',
+        [
+          224, 250, 177, 245, 170, 240, 66, 140, 14, 38, 39, 90, 249, 79, 157,
+          220, 248, 111, 154, 168, 192, 31, 190, 160, 54, 39, 82, 1, 235, 69,
+          126, 90, 90, 142, 157, 191, 173, 168, 57, 116, 136, 200, 137, 244, 95,
+          14, 181, 228, 221, 198, 10, 98, 155, 12, 32, 85, 25, 248, 178, 79, 7,
+          137, 19, 71,
+        ]
+      ),
+      voterWeight: new BN(1000),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              '5otq98pMey583kvc3JTS8r4cqJrh6wdmWJLSY5VxcnEv'
+            ),
+            owner: new PublicKey(
+              'APwKPCk1ASgNogcEFTsVCSbeYmfh7C9arz7yJmLMVpsY'
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(2),
+            voterWeight: new BN(3000),
+            permanentVoterWeight: new BN(3000),
+            rewardMint: new PublicKey(
+              'Fr99AoRRs7vBrJrTjdRzcXd1eeeNB3R6Q3iCZ8NnuXDc'
+            ),
+            rewardWeight: new BN(4000),
+          },
+          shareBp: 10000,
+          rewardWeight: new BN(1000),
+        },
+      ],
+    },
+    depositSource: new PublicKey(
+      '5vMBY8s6TfEknrYXLLuNgjvFpcQtNdtA6W2VYsWpHRrt'
+    ),
+    depositAmount: new BN(2000),
+    claimDestination: new PublicKey(
+      '4TBCAVWaERcw18QBtAKtGo4ZK77FSRru6oZ2DS2wYgA8'
+    ),
+  },
+  // Accrues on top of the unclaimed rewards and keeps the commission
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '6fQMBMJkyvTKswkPsKBNb5qhK5u22UAY8s4u8a2o2upD'
+      ),
+      realmAddress: new PublicKey(
+        '3e5MaGpG1rThzwAqX7t48JbmbjSs9i86CGae9jTb7Fi5'
+      ),
+      communityMint: new PublicKey(
+        '221heHgYBL1hYsV9fLqi9XdEC6XKFjgCmJWLnFbdEYz2'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'Gd91BJbtg5bSwJ2mXDWXjTE8wdMznS2CSeZ7wxqf8iYK'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+    },
+    member: {
+      owner: buildKeypair(
+        'GJBU9oYQRUTG42v91CXFMaEqq5evHJcv2ydzZr9zpkRF',
+        [
+          36, 218, 172, 231, 19, 249, 250, 124, 173, 183, 244, 247, 35, 197, 18,
+          78, 97, 33, 187, 138, 162, 190, 78, 66, 40, 101, 208, 239, 197, 62,
+          184, 76, 227, 70, 3, 149, 234, 170, 56, 119, 125, 87, 179, 150, 221,
+          6, 36, 137, 69, 247, 3, 71, 146, 120, 177, 144, 221, 219, 161, 199,
+          102, 131, 120, 178,
+        ]
+      ),
+      voterWeight: new BN(1000),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              '2Do5Lp768AaqJj18AqyKM5taH4z7t2KCpi3m7yJivJ9S'
+            ),
+            owner: new PublicKey(
+              '3GeqvKpY4FtoneqBMzxAPbGdxUqboK26HKckxdo5mavG'
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(2),
+            voterWeight: new BN(3000),
+            permanentVoterWeight: new BN(3000),
+            rewardMint: new PublicKey(
+              'HkED7FDwxaPemVsuLqJBPZ9itec7KujhTnJ8Nueythi7'
+            ),
+            rewardWeight: new BN(3000),
+            commissionBp: 1000,
+            rewardPerWeight: new BN('2000000000000'),
+            rewardVaultAmount: new BN(100),
+          },
+          shareBp: 10000,
+          rewardWeight: new BN(1500),
+          rewardPerWeightPaid: new BN('2000000000000'),
+          unclaimedRewards: new BN(100),
+        },
+      ],
+    },
+    depositSource: new PublicKey(
+      'gserXaQ86FYcMuWTZA8GVC4VjN9JbADoztpTysZsoUL'
+    ),
+    depositAmount: new BN(3000),
+    claimDestination: new PublicKey(
+      '3vXA2yjUAyJNjdkpVPPNvyj1Wrn429A8rowJBx9j3iYp'
+    ),
+  },
+  // Charges the rewards distributed before the increase at the old commission
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '7ZEJuJ4TUqLRR5fGaTYDj4gFCqLbupmbvKuwHdaecc3M'
+      ),
+      realmAddress: new PublicKey(
+        'GFgWQcGZTmibYiBQHJauUG1BoUoQxwYAYyiXQbY8VHmu'
+      ),
+      communityMint: new PublicKey(
+        '3YJpKUt2gTkQ1BnQBqNyFPa9MW6QgkisZqVG3x9MSSxh'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        '9b5rnvEZ4Qke7GNnaFnn6RT3Ma5H9v727GPSFk2RnJAw'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+    },
+    member: {
+      owner: buildKeypair(
+        '5mnhH1r7PTa22ct61YW5QRUPPXHoa8fb2mbNruuLmGgS',
+        [
+          254, 13, 50, 167, 118, 5, 68, 101, 245, 143, 144, 246, 122, 28, 17,
+          103, 2, 224, 162, 157, 178, 96, 17, 172, 43, 117, 193, 0, 236, 183,
+          233, 120, 70, 231, 248, 91, 113, 157, 19, 77, 245, 198, 24, 7, 130,
+          32, 74, 58, 226, 160, 122, 41, 177, 48, 61, 171, 157, 226, 254, 71,
+          187, 112, 210, 27,
+        ]
+      ),
+      voterWeight: new BN(1000),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              '2Lh6eaSn8VH8D3RdMDixid2Sqj7KxswzkBw1Wpt7y5xC'
+            ),
+            owner: new PublicKey(
+              '48ZgHiH4EUfnMx2jCRPaPcmFB84ivpn7txNsNwEbr8M4'
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(2),
+            voterWeight: new BN(3000),
+            permanentVoterWeight: new BN(3000),
+            rewardMint: new PublicKey(
+              'BcR8ZSggUpBkwMyCcvbDRVKTEv3mQZsg46uAPSVEpJux'
+            ),
+            rewardWeight: new BN(2000),
+            commissionBp: 500,
+            // The notice period is over before the deposit
+            pendingCommission: {commissionBp: 2000, effectiveAt: new BN(1)},
+            rewardPerWeight: new BN('3000000000000'),
+            rewardVaultAmount: new BN(2000),
+          },
+          shareBp: 10000,
+          rewardWeight: new BN(1000),
+          rewardPerWeightPaid: new BN('1000000000000'),
+        },
+      ],
+    },
+    depositSource: new PublicKey(
+      '6HpepH2ydmeYhsRoXinwMd88RmiCZsxz6vWURM2mPPFS'
+    ),
+    depositAmount: new BN(4000),
+    claimDestination: new PublicKey(
+      'E44tYT3EHiyiYTk1jfkwUDopZY8Ng24pw12qFT1ZTKhx'
+    ),
+  },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'CYWWJAfhE5B1Kzz49Shm6omdBrBY3R47Aka65rbin4Mr'
+      ),
+      realmAddress: new PublicKey(
+        'FqG1AxuACGEhUqSk2gtfU95oXBA9no99Vsfp8DALZAmX'
+      ),
+      communityMint: new PublicKey(
+        '93ii7qvCr2eimfWMG8koShAPPynKanEC1mbT14fTcUY5'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'HejkGTbLcCywyGHobAJawb6Rjy4w2KSXJVtoVnnsh8XL'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+    },
+    member: {
+      owner: buildKeypair(
+        'DPii3JbFwCexvR4ZqdfuxQyDhedknjRXuRdCGhjwaqqF',
+        [
+          168, 20, 148, 62, 71, 74, 97, 196, 30, 217, 234, 68, 26, 67, 7, 8,
+          168, 210, 92, 4, 21, 252, 227, 199, 28, 50, 216, 29, 1, 231, 155, 172,
+          184, 30, 46, 9, 58, 192, 237, 197, 16, 58, 218, 133, 18, 194, 21, 199,
+          32, 159, 117, 200, 167, 20, 104, 121, 205, 9, 10, 69, 231, 242, 120,
+          246,
+        ]
+      ),
+      voterWeight: new BN(1000),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              'GTtT9NFKLLRPB7ByckGjvrgBELdGV8tmjWm41bNpAf2y'
+            ),
+            owner: new PublicKey(
+              '9iCjwcVT9jk9XfaAcBQknk34gCXqCxAPwqvS45ezJJMr'
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(2),
+            voterWeight: new BN(3000),
+            permanentVoterWeight: new BN(3000),
+            rewardMint: new PublicKey(
+              '3CjWd9oertj6ZJNnV5ku46zPAcwckRfcfZxjTfNjXGMi'
+            ),
+          },
+          shareBp: 10000,
+        },
+      ],
+    },
+    depositSource: new PublicKey(
+      'AWN8cggdEffnqwPXUNqFcBKda9ZKznk2pLWzt283kkf7'
+    ),
+    depositAmount: new BN(1000),
+    claimDestination: new PublicKey(
+      '98LqksJpChQ3n4ZUWM3piwEL9pd1Q32uyacthb5k6wjH'
+    ),
+    error: 'NoClanRewardWeight',
+  },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'EdJbuA3fg5CsdtRFMQvt9YD2zaEkfvEytSi63YNTGLTt'
+      ),
+      realmAddress: new PublicKey(
+        '2WNFsCQVwPCrDSTDxoM7gafvvjDXoaDhVaRSY5xEtdBg'
+      ),
+      communityMint: new PublicKey(
+        'GLMdrkchU4poLGNrUCKiUQH73CZ9YDwaQDevdcTG3bqi'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        '29Ck3H3HnXsD6tYkfhFzyVPyXx7tXsRBTxpcdWhREejc'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+    },
+    member: {
+      owner: buildKeypair(
+        'E2j2tMDe4yLa3kk1YuMHM6NzcByuiyA4hjALRuCv3Jc2',
+        [
+          63, 17, 181, 44, 231, 75, 214, 176, 9, 249, 89, 4, 193, 84, 33, 244,
+          99, 24, 244, 13, 31, 180, 143, 218, 38, 106, 17, 189, 14, 224, 228,
+          69, 193, 153, 9, 131, 33, 217, 99, 1, 222, 26, 143, 158, 204, 140, 21,
+          176, 72, 53, 240, 246, 106, 135, 129, 3, 193, 44, 138, 174, 230, 123,
+          115, 211,
+        ]
+      ),
+      voterWeight: new BN(1000),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              '6tgJpaYTb8hVwnUFSPZ4PnHpKyZMiMNpSuSedaJA17zv'
+            ),
+            owner: new PublicKey(
+              'Hzqr29CY64b45oHJDvWQjQBhv4bXt1wQFRyr7sRTNh5w'
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(2),
+            voterWeight: new BN(3000),
+            permanentVoterWeight: new BN(3000),
+            rewardMint: new PublicKey(
+              '6kLtsuTyGgaZbQyrbHn2Hf6FjGh2aTR8CUF5h5WnS81Y'
+            ),
+            rewardWeight: new BN(4000),
+          },
+          shareBp: 10000,
+          rewardWeight: new BN(1000),
+        },
+      ],
+    },
+    depositSource: new PublicKey(
+      '9x9GYnWgFtVnWhbhMchoT94eKkfdUp3ACWW41Y75oeEa'
+    ),
+    depositAmount: new BN(1000),
+    claimDestination: new PublicKey(
+      '7xsxcF6NxvYVMh5gFbTmzYNc9YJn4hRU9C2YocWFXBkQ'
+    ),
+    memberAuthority: buildKeypair(
+      '2qK953ZEqLAb1Fmw6WJjje4npUZqoXFFYx3s5H24Eebc',
+      [
+        76, 208, 89, 148, 140, 72, 80, 46, 189, 58, 236, 253, 33, 79, 190,
+        107, 152, 179, 252, 236, 19, 208, 169, 186, 210, 0, 222, 204, 1, 231,
+        250, 28, 27, 60, 19, 100, 10, 82, 179, 133, 232, 9, 168, 48, 161, 62,
+        19, 216, 222, 100, 196, 118, 248, 191, 37, 196, 70, 253, 135, 1, 234,
+        205, 134, 227,
+      ]
+    ),
+    error: 'WrongMemberAuthority',
+  },
+];
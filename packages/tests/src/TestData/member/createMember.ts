@@ -50,4 +50,43 @@ export const createMemberTestData: CreateMemberTestData[] = [
       ),
     },
   },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'FkiM9YTzaZxprmncWo12BqHTzgajpoi7e3GdGa3ie3Cy'
+      ),
+      realmAddress: new PublicKey(
+        '3Uo7zL2YL9q96EzDBNs1SiEG6Nu396BqL8JrnDNGXysa'
+      ),
+      communityMint: new PublicKey(
+        '9msqAwCjo6AvbYkGWA6nZ7sVXoXf57TuV46hd8UuCzxV'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(32),
+      },
+      minCommunityWeightToCreateGovernance: new BN(4),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        '7BCoVwzgb3TQQi41JfXxxhGwP5BhJZyAFQiQx4y7KRRH'
+      ),
+    },
+    root: {
+      side: 'community',
+      // The realm config was not verified yet
+      installed: false,
+    },
+    member: {
+      owner: buildKeypair(
+        '4Gr7ZfP99S9JRp8GVSDUbNS4XwHb7oLE3euLGVACyaAD',
+        [
+          229, 17, 175, 95, 245, 198, 0, 243, 63, 20, 192, 19, 71, 194, 205, 28,
+          214, 46, 105, 220, 1, 252, 73, 196, 193, 191, 196, 244, 53, 206, 213,
+          232, 48, 162, 78, 42, 184, 198, 236, 246, 120, 157, 88, 36, 228, 204,
+          20, 39, 57, 215, 225, 232, 197, 176, 95, 127, 100, 232, 28, 170, 4,
+          85, 218, 74,
+        ]
+      ),
+    },
+    error: 'RootNotInstalled',
+  },
 ];
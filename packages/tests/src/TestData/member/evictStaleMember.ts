@@ -0,0 +1,371 @@
+import BN from 'bn.js';
+import {MemberTestData, RootTestData} from '../../VoteAggregator';
+import {RealmTestData} from '../../SplGovernance/realm';
+import {PublicKey} from '@solana/web3.js';
+
+export type EvictStaleMemberTestData = {
+  realm: RealmTestData;
+  root: RootTestData;
+  member: MemberTestData;
+  error?: string;
+};
+
+export const evictStaleMemberTestData: EvictStaleMemberTestData[] = [
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '4GBFVbdHcq9HqJmSNey7bmVvwYTzVbAdpvTGAFFb3XjN'
+      ),
+      realmAddress: new PublicKey(
+        '4QWgzEy2WgndxEJuCYGb4yqeuyR6vM7vwLgSeSavxBKt'
+      ),
+      communityMint: new PublicKey(
+        'AtgaZoatAwATYZKRkaiW2mEqQSmQcESc12rbPY63akc1'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, expiring plugin',
+      communityMintAuthority: new PublicKey(
+        'Dn8g5APndHywxZGDT66rKxFYERH3LQynma84NGNKfCY4'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      voterWeightReset: {
+        nextResetTime: new BN('1893456000'),
+        step: new BN(86400),
+      },
+      staleMemberGracePeriod: new BN(86400),
+    },
+    member: {
+      owner: new PublicKey('oPrgjFxASE918x7RuuSouWcoYAbDeDapuVZxrA4FLuA'),
+      voterWeight: new BN(500),
+      voterWeightExpiry: new BN(1000),
+      // Missed the reset long before the grace period
+      nextVoterWeightResetTime: new BN('1700000000'),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              'Givy9kfJhLmfEQjUPstXDcLVuZbg4LNApJxgEPPsTFe1'
+            ),
+            owner: new PublicKey(
+              'EGzeRpBZVYt4Jm5npMPdcguzjtiWTNxQZp96pLLLA8Ki'
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(1),
+            temporaryMembers: new BN(2),
+            updatedTemporaryMembers: new BN(1),
+            voterWeight: new BN(1500),
+            permanentVoterWeight: new BN(1000),
+            nextVoterWeightResetTime: new BN('1700000000'),
+          },
+          shareBp: 10000,
+        },
+      ],
+    },
+  },
+  // The decaying weight was dropped by the reset like the other temporary one
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '32msBk8ygKLpHJcpX5LAeHknRZVhg4tdeV3D6wcaxQcX'
+      ),
+      realmAddress: new PublicKey(
+        'dXvGNp3DEGe7Hgh9njZ14vXJ7rTnyRdNRVoPDGFZiVn'
+      ),
+      communityMint: new PublicKey(
+        'HKHP5MmLVzJVa8DaM9eygpLy1xK8NKxegJ7CziFNuRAs'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, decaying plugin',
+      communityMintAuthority: new PublicKey(
+        '8yVWoQKuZtVKKqhfAJuaX2DEXCwKq5yGLSCGtczcQRMa'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      voterWeightReset: {
+        nextResetTime: new BN('1893456000'),
+        step: new BN(86400),
+      },
+      staleMemberGracePeriod: new BN(86400),
+    },
+    member: {
+      owner: new PublicKey('8daEbLLqYnAweARJSt9J8sSKrqcAfDy6uQiLYQQEfarY'),
+      voterWeight: new BN(500),
+      voterWeightExpiry: new BN(1000),
+      voterWeightDecay: {
+        startTime: new BN('1699999500'),
+        rate: new BN(1),
+        endTime: new BN('1700000000'),
+      },
+      nextVoterWeightResetTime: new BN('1700000000'),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              '9qmnsLG8ASsBNsvFmcdvqahDEbgTRTw4eQcLe5dTKKBw'
+            ),
+            owner: new PublicKey(
+              'G7ktP8TS6yWpteYQbANBPivS1Wxc3ZMEXuC1psHhKTBf'
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(1),
+            decayingMembers: new BN(1),
+            updatedTemporaryMembers: new BN(1),
+            voterWeight: new BN(1500),
+            permanentVoterWeight: new BN(1000),
+            decayingVoterWeight: new BN(500),
+            voterWeightDecayRateBp: new BN(10000),
+            voterWeightDecayUpdatedAt: new BN('1699999500'),
+            voterWeightDecayEnds: [
+              {endTime: new BN('1700000000'), rateBp: new BN(10000)},
+            ],
+            nextVoterWeightResetTime: new BN('1700000000'),
+          },
+          shareBp: 10000,
+        },
+      ],
+    },
+  },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'HWwfz6pVoTbABGuodoXXqqSsuGju8DEgzqUiEfdfGbn5'
+      ),
+      realmAddress: new PublicKey(
+        'EVPMCLcQth73pw3MrCoj7hFthzGvz8khjNTRgQJDmKFi'
+      ),
+      communityMint: new PublicKey(
+        'FormNigmr7nYUZ8FghrJttCt8vchF9LRravJnSfgEn5i'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, expiring plugin',
+      communityMintAuthority: new PublicKey(
+        'E5kRNFP5NLsMnEtZQFaE8SKuF8B7tTTzHkEMNSaxAT6g'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      voterWeightReset: {
+        nextResetTime: new BN('1893456000'),
+        step: new BN(86400),
+      },
+      // The grace period after the missed reset is not over yet
+      staleMemberGracePeriod: new BN('10000000000'),
+    },
+    member: {
+      owner: new PublicKey('66qFHHLwkWViKkWZscsHh8KVGSnaJz2vgTRqxX2o1dv8'),
+      voterWeight: new BN(500),
+      voterWeightExpiry: new BN(1000),
+      nextVoterWeightResetTime: new BN('1700000000'),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              '5WWzTEivZtzXzoqPeoKHUu2K2zX5YoYS9ctmVjuytyDp'
+            ),
+            owner: new PublicKey(
+              '3S33TnJB1qHLWvdeEq8hqiKpP1yGSiu6E6w4ix9Qmkgr'
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(1),
+            temporaryMembers: new BN(2),
+            updatedTemporaryMembers: new BN(1),
+            voterWeight: new BN(1500),
+            permanentVoterWeight: new BN(1000),
+            nextVoterWeightResetTime: new BN('1700000000'),
+          },
+          shareBp: 10000,
+        },
+      ],
+    },
+    error: 'TooEarlyToEvictMember',
+  },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'w3nXoFDdrFTgV7Gbud1XrAQMkK2H6qnN6qCay1kDnnv'
+      ),
+      realmAddress: new PublicKey(
+        'FRqRSXpd4qsdJMmDY4rqvxU93Fj3DeH8tCz1wqhKwJ2b'
+      ),
+      communityMint: new PublicKey(
+        '4MSsmYLnWe1QPio1iCCFnTU8W6PEuEDv2dw8vUaUfpyf'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, expiring plugin',
+      communityMintAuthority: new PublicKey(
+        'AMEednRgRFsnunvtKu2P3o9tC8hZM4P1oXAaVR58dcSm'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      voterWeightReset: {
+        nextResetTime: new BN('1893456000'),
+        step: new BN(86400),
+      },
+      staleMemberGracePeriod: new BN(86400),
+    },
+    member: {
+      owner: new PublicKey('G1tsLqp9o1z6EKxqLTc3ZG6E64DrtBMBxERC2qtiHrdF'),
+      voterWeight: new BN(500),
+      voterWeightExpiry: new BN(1000),
+      // Updated in the current reset period
+      nextVoterWeightResetTime: new BN('1893456000'),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              'Fxwg7Pav4dQKCm6ff95391P76fMWBhJvUjqXJBLxSK1H'
+            ),
+            owner: new PublicKey(
+              '3S8APDtzrx36P846RJa3XHtAUQ6H8cFbL5XQhgLSUEdW'
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(1),
+            temporaryMembers: new BN(1),
+            updatedTemporaryMembers: new BN(1),
+            voterWeight: new BN(1500),
+            permanentVoterWeight: new BN(1000),
+            nextVoterWeightResetTime: new BN('1893456000'),
+          },
+          shareBp: 10000,
+        },
+      ],
+    },
+    error: 'MemberIsNotStale',
+  },
+  // Never reset members wait for the grace period after the last root reset
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'FoE5ueTovtrMAKzUR3NkE9mYyskdwDfmPwhCwXZQoJfu'
+      ),
+      realmAddress: new PublicKey(
+        '2Ux239CZU89swJJ7tLz2Ch8hStyQaPbp82VFDbwZnUrK'
+      ),
+      communityMint: new PublicKey(
+        'EzpeNN3P6k31dnE4apiRYCTRzD3rx28XFBZAoh2mfXxP'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, expiring plugin',
+      communityMintAuthority: new PublicKey(
+        'PWwYMjJe1TngGyXyf3YpuMz2vGu9PhfnQ9uVNsK7URP'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      voterWeightReset: {
+        nextResetTime: new BN('1893456000'),
+        step: new BN(86400),
+      },
+      staleMemberGracePeriod: new BN('86400'),
+    },
+    member: {
+      owner: new PublicKey('GLmEicHmsYTujsi1vxHx1vsDN8Rc1eEZPvtovdro7tQe'),
+      voterWeight: new BN(500),
+      voterWeightExpiry: new BN(1000),
+      nextVoterWeightResetTime: null,
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              'BQmGFuWF34CRDaxnQHcvAErBGote8td9h9sFaQCqyUjp'
+            ),
+            owner: new PublicKey(
+              'ChMMgDcEpTP1wu21cJiS3Lun5wsQzWYJyC68YcFiRD7x'
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(1),
+            temporaryMembers: new BN(2),
+            updatedTemporaryMembers: new BN(1),
+            voterWeight: new BN(1500),
+            permanentVoterWeight: new BN(1000),
+            nextVoterWeightResetTime: new BN('1700000000'),
+          },
+          shareBp: 10000,
+        },
+      ],
+    },
+    error: 'TooEarlyToEvictMember',
+  },
+  // The grace period does not overflow
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'FD2j3hkF2MELhupX3eXUZpsg2dwZM79Ws2LpiPHKYvYJ'
+      ),
+      realmAddress: new PublicKey(
+        'DPGWq7ag3LiQPutqBsN4tiNWKdkFsVRbMwwCaenuAFGi'
+      ),
+      communityMint: new PublicKey(
+        'CtUQvuDUzA9NK225btgUnHq9U1xFLUn34gwjJYzX8RdA'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, expiring plugin',
+      communityMintAuthority: new PublicKey(
+        '8kEJB6vTLK48YpnbTynxY1emvhvnD6221djNNm8tgHFM'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      voterWeightReset: {
+        nextResetTime: new BN('1893456000'),
+        step: new BN(86400),
+      },
+      staleMemberGracePeriod: new BN('18446744073709551615'),
+    },
+    member: {
+      owner: new PublicKey('62STDX7zJqfr2zsEZhpTTWhFPoBxhiXymf3PzsFcNLMR'),
+      voterWeight: new BN(500),
+      voterWeightExpiry: new BN(1000),
+      nextVoterWeightResetTime: new BN('1700000000'),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              'h9en5HAdht9win7taKbZH9hidfHt7cJbVY82dV4YBdD'
+            ),
+            owner: new PublicKey(
+              'H7PRUq4221BnEVjWtUxZYU8z8fzTdtY3aRxNzGvHUs8B'
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(1),
+            temporaryMembers: new BN(2),
+            updatedTemporaryMembers: new BN(1),
+            voterWeight: new BN(1500),
+            permanentVoterWeight: new BN(1000),
+            nextVoterWeightResetTime: new BN('1700000000'),
+          },
+          shareBp: 10000,
+        },
+      ],
+    },
+    error: 'TooEarlyToEvictMember',
+  },
+];
@@ -0,0 +1,160 @@
+import BN from 'bn.js';
+import {MemberTestData, RootTestData} from '../../VoteAggregator';
+import {RealmTestData} from '../../SplGovernance/realm';
+import {PublicKey} from '@solana/web3.js';
+
+export type EvictUnlockedMemberTestData = {
+  realm: RealmTestData;
+  root: RootTestData;
+  member: MemberTestData;
+  // The member TOR lock expiry. No lock if undefined
+  lockExpiry?: BN | null;
+  error?: string;
+};
+
+export const evictUnlockedMemberTestData: EvictUnlockedMemberTestData[] = [
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '45wKu8fbKL8eVPNU6yy5bcGUB3wfCR9aKNJLaNETvZ9p'
+      ),
+      realmAddress: new PublicKey(
+        'HMyRmnGN2LHbfj5JYKnbhPdrwqoNtpiDA82aKREWCbfP'
+      ),
+      communityMint: new PublicKey(
+        'EmVzD4zRkrqutK7mNRfQj9frqqPfENUYQVhgFa4Km6Ya'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'Gfosynxocm3P95FQWpBb7jVxhouWdjLLjdf9qdZg11Wo'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+    },
+    member: {
+      owner: new PublicKey('9ypXSu7XSp8doWjAU77H28Emp68PVnb8nAZ5iqe5oHJn'),
+      voterWeight: new BN(1000),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              'Bgg2SqXE3qxX7mJvDvX9K7ALqeuJDWD3DQtEU84orAK'
+            ),
+            owner: new PublicKey(
+              'X7CjPwMsp3hM7Urqmag4mB69LQ73PfQakZE56i4JCqp'
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(2),
+            voterWeight: new BN(3000),
+            permanentVoterWeight: new BN(3000),
+          },
+          shareBp: 10000,
+        },
+      ],
+    },
+    // The lock expired but was not removed from the TOR yet
+    lockExpiry: new BN(1000),
+  },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '8WxyE7Z3eciWesr2RQ2KGo6ER8Jt7DnZcuxKxsteXv1t'
+      ),
+      realmAddress: new PublicKey(
+        'H5SZkkQA94QJAANJhDXe1H7BiQjMLyBNfSuAmbsm3qAP'
+      ),
+      communityMint: new PublicKey(
+        'pvxpzo1obqDg9UvyT4Bc9i3rYZJnjeyWjZcwJn6oRkb'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'GsaMt1NWvGgAcdK5SJomG8vSrk1ZNnbgGu9mxZ4roBYv'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+    },
+    member: {
+      owner: new PublicKey('D1ucFdWi8VWHSGLK7htWdfERVssJzt1NR7dLXorD2B1h'),
+      voterWeight: new BN(1000),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              'Bz1nw6TwhcTUe6MomgWURJR2YFx99d8ZFs6BoCWqfdCA'
+            ),
+            owner: new PublicKey(
+              '8iqXnf9PmnWkxDkmEumhFDPciA5UiE4Suyzen9bvUHYS'
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(2),
+            voterWeight: new BN(3000),
+            permanentVoterWeight: new BN(3000),
+          },
+          shareBp: 10000,
+        },
+      ],
+    },
+      // The expired lock was already removed from the TOR
+  },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'C38ZEiLEBdqAwtEk4brVSN7NBCXjFUshnSKS88EKa2Y5'
+      ),
+      realmAddress: new PublicKey(
+        'AbLcpDuogrftv85Q9ZXUnBWvhEe8Wimb7Mvd435X7SwM'
+      ),
+      communityMint: new PublicKey(
+        '8jhAtzHo9FfRzBNthFcNxeHwnYeNWEAajpdxVPFAggz'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'GLHCWGqcmAz4bGyGP2R6gmWjnpomQ3GW964C4fqAcTVj'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+    },
+    member: {
+      owner: new PublicKey('9xFBRSbvQ4kstTnvGgfKuwHLVWUrrUYsTVK9rx33seAW'),
+      voterWeight: new BN(1000),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              '4rrNf9hdk7buyvR5HtTBUd8dFcTx9vtcFSaiXMJMThXN'
+            ),
+            owner: new PublicKey(
+              'FkvPAF2DHPxmsqXH9wZGypFg6huUop2yib7RdriLCQAL'
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(2),
+            voterWeight: new BN(3000),
+            permanentVoterWeight: new BN(3000),
+          },
+          shareBp: 10000,
+        },
+      ],
+    },
+    // Locked until the member leaves the clan
+    lockExpiry: null,
+    error: 'MemberStillLocked',
+  },
+];
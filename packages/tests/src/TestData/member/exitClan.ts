@@ -8,6 +8,12 @@ export type ExitClanTestData = {
   member: MemberTestData;
   clanIndex?: number;
   clanLeavingTimeOffset?: BN;
+  // Relative to the current time. None if the clan control never changed
+  controlChangedAtOffset?: BN;
+  // Passes the clan TOR to show the clan has no live votes
+  clanTor?: boolean;
+  // The clan owner sets this delegate and back right before the exit
+  flippedDelegate?: PublicKey;
   error?: string;
 };
 
@@ -68,4 +74,194 @@ export const exitClanTestData: ExitClanTestData[] = [
     },
     clanLeavingTimeOffset: new BN(-200),
   },
+  // The owner changed after the member joined. The cooldown is skipped
+  // while the clan has no live votes
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '8V6THGdeQQ6c2UsHNh7WssMKtj6oF4VhdRfhdwnAdVsa'
+      ),
+      realmAddress: new PublicKey(
+        '8HstZG4XA3LWSJp2WCMs3U1hGwcHqHjsdNBrGaByBNj2'
+      ),
+      communityMint: new PublicKey(
+        'EnjfNkkfiXz3x45HYcNrjTpGUaxTHkYuscasjtcYDJHC'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        '5nGsxryh5T5X3GJipsLkxS8hJE5h7FQS1wvac5mwGxgJ'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      maxVoterWeight: new BN('107428734639842'),
+    },
+    member: {
+      owner: buildKeypair(
+        '4MmKQ7LsTAxZM8rRjMUcxLL6F2XFE5QSRpBM4VTw1fzp',
+        [
+          149, 115, 155, 188, 120, 33, 237, 159, 254, 146, 1, 3, 243, 185, 135,
+          116, 49, 73, 79, 138, 19, 11, 57, 167, 98, 194, 83, 69, 246, 236, 224,
+          0, 49, 228, 201, 94, 68, 175, 220, 163, 163, 187, 234, 182, 50, 37,
+          124, 198, 215, 160, 114, 36, 197, 210, 53, 121, 193, 179, 145, 36, 12,
+          81, 198, 145,
+        ]
+      ),
+      voterWeight: new BN('3829473823'),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              'HD4zxGtpY9cjSa5Z3mDHHAVeqJrzCimo9aJwNGnWkNLF'
+            ),
+            owner: new PublicKey(
+              'AY4Gq8wxMvTn43y2DqjsdyT8BLpH5a47M568vcjNtVRz'
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(1),
+            leavingMembers: new BN(1),
+            outstandingProposalCount: 1,
+          },
+          shareBp: 10000,
+        },
+      ],
+    },
+    clanLeavingTimeOffset: new BN(100000),
+    controlChangedAtOffset: new BN(-100),
+    clanTor: true,
+  },
+  // The free exit window is over
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '4r4oDQxvvbumFqC9VTk9QuD4ez35d2ucCnQmXDwA7Bhb'
+      ),
+      realmAddress: new PublicKey(
+        '342EazBCfV2g1WDTGk9hnzpmfpoyrvEBcXMDsTpG7WY5'
+      ),
+      communityMint: new PublicKey(
+        'EoyGJdJUvUFPaJrRqd5auTCPwbQgKU5pF9AQoCq7WSP7'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        '9Htr8eCWxNN3AsRBzTFcBTdQoFtTUp7GUTCwmf6DZQTY'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      maxVoterWeight: new BN('107428734639842'),
+    },
+    member: {
+      owner: buildKeypair(
+        '2to7b1zdj3GNEXPwxpFyGdenc6WvyHjUqQMN9nbPHJPx',
+        [
+          69, 216, 224, 180, 224, 215, 182, 0, 242, 52, 158, 25, 136, 75, 70,
+          223, 49, 96, 86, 220, 224, 177, 73, 164, 139, 194, 173, 50, 203, 175,
+          108, 115, 28, 32, 114, 238, 235, 147, 242, 53, 149, 198, 168, 111, 64,
+          10, 219, 236, 69, 224, 11, 227, 20, 55, 240, 86, 117, 130, 51, 118, 3,
+          184, 123, 247,
+        ]
+      ),
+      voterWeight: new BN('3829473823'),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              '95sDvuteMkk2wkn69ih5ox5FCr3u3isqMQQeGz9GDz88'
+            ),
+            owner: new PublicKey(
+              '9NgaxcY3j5QzPbmaL322491mrrMzenEzaAu3A9G7QEL7'
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(1),
+            leavingMembers: new BN(1),
+          },
+          shareBp: 10000,
+        },
+      ],
+    },
+    clanLeavingTimeOffset: new BN(100000),
+    controlChangedAtOffset: new BN(-200000),
+    error: 'TooEarlyToExitClan',
+  },
+  // Flipping the delegate does not free the weight of the live votes
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '7xnYfgoXS6mZGTFGNTzE6FxVA7RcEgyBs7UCgMfdzB1p'
+      ),
+      realmAddress: new PublicKey(
+        '7RSDnjHND9hfR51X48vYqWvG8DdPpe7xmfsKjJHJ9eeK'
+      ),
+      communityMint: new PublicKey(
+        '79vBVC2W7TdHU99BS4hYokrMzhnwPUhtLF1qt91BFc3G'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'H18d4gwLjAL5bQEZvZEMcVfSr72seQhUmn4uGxKtUTbv'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      maxVoterWeight: new BN('107428734639842'),
+    },
+    member: {
+      owner: buildKeypair(
+        'GUsXtqr2P6sYNwnenDdEmNCATNbRMctVdJSi9twcrdfM',
+        [
+          109, 47, 135, 177, 220, 74, 208, 255, 88, 7, 187, 94, 198, 25, 194,
+          121, 29, 233, 48, 156, 11, 103, 127, 137, 181, 129, 211, 36, 10, 93,
+          11, 44, 230, 3, 31, 137, 136, 233, 162, 135, 73, 147, 225, 162, 247,
+          244, 7, 97, 72, 97, 177, 147, 151, 218, 40, 135, 44, 182, 222, 239,
+          159, 184, 70, 24,
+        ]
+      ),
+      voterWeight: new BN('3829473823'),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              'EMPMY3pX7FeQ4HWycyjaf4pKTSyx56q1D3Tp4uPTaUTG'
+            ),
+            owner: buildKeypair(
+              '8UotCCk6rdZcF5u4iLYfmRH72hh78xCqwtTtjSM6ekLu',
+              [
+                59, 248, 66, 15, 193, 147, 250, 153, 221, 133, 212, 158, 40,
+                196, 146, 1, 127, 131, 145, 97, 156, 96, 123, 94, 112, 243, 205,
+                120, 46, 135, 181, 143, 111, 33, 128, 45, 224, 216, 56, 160,
+                101, 99, 172, 242, 49, 96, 9, 132, 123, 13, 76, 201, 249, 110,
+                97, 156, 243, 230, 108, 3, 175, 32, 91, 102,
+              ]
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(1),
+            leavingMembers: new BN(1),
+            unrelinquishedVotesCount: new BN(1),
+          },
+          shareBp: 10000,
+        },
+      ],
+    },
+    clanLeavingTimeOffset: new BN(100000),
+    clanTor: true,
+    flippedDelegate: new PublicKey(
+      'BCvccniM81L1BctoeuroWTYm2SZGBZ4EGuv23uVpkk7P'
+    ),
+    error: 'TooEarlyToExitClan',
+  },
 ];
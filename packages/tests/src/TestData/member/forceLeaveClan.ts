@@ -0,0 +1,139 @@
+import BN from 'bn.js';
+import {MemberTestData, RootTestData} from '../../VoteAggregator';
+import {RealmTestData} from '../../SplGovernance/realm';
+import {PublicKey} from '@solana/web3.js';
+import {buildKeypair} from '../..';
+
+export type ForceLeaveClanTestData = {
+  realm: RealmTestData;
+  root: RootTestData;
+  member: MemberTestData;
+  immediate: boolean;
+  releaseLock: boolean;
+  reason: number;
+  error?: string;
+};
+
+export const forceLeaveClanTestData: ForceLeaveClanTestData[] = [
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '6Zr1DGjQ9EANCtsXcL9f6sohNVkSXWGMFBSCmZ7Wa5pT'
+      ),
+      realmAddress: new PublicKey(
+        '4oRDPXyBNihxyAkPJCAGVpY3kjya6N7JQzWycxSb384B'
+      ),
+      authority: buildKeypair(
+        'FuKbCvw55bQvHAWn1hqYWhq4pHHqaDYQmDmsUbRUv7a7',
+        [
+          177, 143, 39, 255, 233, 27, 184, 215, 77, 107, 92, 219, 134, 175, 30,
+          175, 66, 254, 232, 148, 52, 75, 226, 130, 40, 166, 177, 30, 220, 143,
+          124, 207, 221, 106, 214, 170, 173, 78, 131, 196, 103, 133, 81, 176,
+          87, 247, 221, 28, 90, 148, 202, 73, 76, 105, 244, 240, 93, 124, 26,
+          175, 239, 17, 194, 80,
+        ]
+      ),
+      communityMint: new PublicKey(
+        'AD6uW9eq15RokUMWD67kTrDW2ArFQsT1J4Q8nygn3djd'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        '5ymmDLCcvEGmAJNfquY4N7AkHBmCWtXTHwq52JsTPTVn'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+    },
+    member: {
+      owner: new PublicKey('8d4eGSNoxrt9XyzWWHesq8cuPP5VvMwCp6w5W8GEftzR'),
+      voterWeight: new BN(1000),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              'F7TGZZsvAENdnyknbzREbf8cxCCkUEPYyBEZspDqPwvH'
+            ),
+            owner: new PublicKey(
+              '8ieK7STjfesH7BZBKVLMoLUFW2kxKk5Je1ZjbLukGuT2'
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(2),
+            voterWeight: new BN(3000),
+            permanentVoterWeight: new BN(3000),
+          },
+          shareBp: 10000,
+        },
+      ],
+    },
+    immediate: true,
+    releaseLock: true,
+    reason: 1,
+  },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '6v4WDjtrMM6byZf26GMy2LQqPyt7kUvJuFAK6s7Nk7NM'
+      ),
+      realmAddress: new PublicKey(
+        'XBCd1RtPX4LnsKwYcVPuZCadVLCwXY25TPS59mryprf'
+      ),
+      authority: buildKeypair(
+        'J15YW3zMrNj7vsqhHnKL5vN613kwYRNR4XgnQsS6TaA3',
+        [
+          221, 76, 145, 254, 184, 227, 29, 81, 231, 193, 166, 229, 208, 77, 162,
+          129, 6, 224, 79, 110, 51, 149, 120, 100, 220, 226, 147, 192, 214, 48,
+          158, 35, 252, 155, 208, 142, 33, 182, 171, 111, 152, 33, 26, 154, 196,
+          178, 140, 17, 99, 224, 216, 32, 182, 41, 80, 68, 226, 76, 25, 233,
+          137, 0, 54, 176,
+        ]
+      ),
+      communityMint: new PublicKey(
+        'ARTyfMjUUGNdyrjyYhX8KMGnBtJDnVEu84UKcnSvwjaZ'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'Foc3J7sTy8iQECw9byvsb3JqqTw6RSJj21hw3CKnEXkB'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+    },
+    member: {
+      owner: new PublicKey('5S1ch3pJvRBh8PKYgMSxGvyEn3Bx6Xfbx6Bzbn4nzbcZ'),
+      voterWeight: new BN(1000),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              '7yH8kDyyRBJYxZb6RctBUuqrGkNL6dyZG7oWyMzV6vPr'
+            ),
+            owner: new PublicKey(
+              '9PrarE4Tk7jzpqvrzTq5sDr8GtUPrLPwmCXZK8UkFMfG'
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(2),
+            voterWeight: new BN(3000),
+            permanentVoterWeight: new BN(3000),
+          },
+          shareBp: 10000,
+          // Must be claimed before the member is removed
+          unclaimedRewards: new BN(25),
+        },
+      ],
+    },
+    immediate: true,
+    releaseLock: true,
+    reason: 1,
+    error: 'UnclaimedClanRewards',
+  },
+];
@@ -4,3 +4,14 @@ export * from './startLeavingClan';
 export * from './exitClan';
 export * from './updateVoterWeight';
 export * from './setVoterWeightRecord';
+export * from './setVoterWeightDecay';
+export * from './evictStaleMember';
+export * from './evictUnlockedMember';
+export * from './windDownExit';
+export * from './forceLeaveClan';
+export * from './clanRewards';
+export * from './migrateMember';
+export * from './joinClanWarmup';
+export * from './migrateMemberTransform';
+export * from './recallProposal';
+export * from './loyaltyBonus';
@@ -0,0 +1,264 @@
+import {PublicKey} from '@solana/web3.js';
+import {
+  RealmTestData,
+  MemberTestData,
+  RootTestData,
+  buildKeypair,
+  VoterWeightRecordTestData,
+} from '../..';
+import {BN} from '@coral-xyz/anchor';
+
+export type JoinClanWarmupTestData = {
+  realm: RealmTestData;
+  root: RootTestData;
+  // Already in the first clan of the membership
+  member: MemberTestData;
+  memberVoterWeight: VoterWeightRecordTestData;
+  shareBp: number;
+  // The part of the new share in warmup
+  warmupShareBp: number;
+};
+
+export const joinClanWarmupTestData: JoinClanWarmupTestData[] = [
+  // Only the added share warms up once the old share is counted
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '2m4n9ckYASoBe5cJTc3r75dA1uH7m7BghbEJF9839hbt'
+      ),
+      realmAddress: new PublicKey(
+        'D5GgU8JcU1UP2ofrBfX5mmToijnV4UnehdtYLvVgHkiv'
+      ),
+      communityMint: new PublicKey(
+        '8tvH2jGLGt78TznzLa13kQFpbkqX37KH7g7zhkh9Fp9V'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(32),
+      },
+      minCommunityWeightToCreateGovernance: new BN(4),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        '9eu3XhZAsFGaVNKzPMpoLWqbMRkjNCHZyAuiFZggDiCx'
+      ),
+    },
+    root: {
+      side: 'community',
+      votingWeightPlugin: new PublicKey(
+        'A9Gii6weJceK2JsGxR57mbg3HXCPRWTqoFyuycLynsu6'
+      ),
+      membershipWarmup: new BN(3600),
+    },
+    member: {
+      owner: buildKeypair(
+        'GijvvkJhtyakjDjk17dCA2MuQMcMUC7mNEd5nbKu7Bzo',
+        [
+          190, 66, 143, 115, 120, 193, 48, 238, 210, 178, 13, 80, 139, 119, 138,
+          57, 109, 77, 193, 69, 8, 117, 113, 215, 84, 160, 3, 97, 98, 75, 160,
+          6, 233, 144, 168, 108, 255, 109, 112, 87, 115, 75, 121, 152, 43, 54,
+          166, 157, 238, 69, 215, 9, 230, 43, 158, 155, 108, 18, 235, 106, 166,
+          162, 110, 240,
+        ]
+      ),
+      voterWeight: new BN(1000),
+      voterWeightRecord: new PublicKey(
+        'BVzDPsjPe552VDbEncuhyeXfYusZymJt3fAbmtxg4Pgh'
+      ),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              'GXQwr2PNgfsB8tvCUeZ2tz1yKBAzcmtpEUh16WBmV3XP'
+            ),
+            owner: new PublicKey(
+              '2TNondGFHPAtXQ6ak6xxiqAUzFPaMJhtHvGxVDBiebr1'
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(1),
+            voterWeight: new BN(500),
+            permanentVoterWeight: new BN(500),
+            rewardWeight: new BN(500),
+          },
+          shareBp: 5000,
+          rewardWeight: new BN(500),
+          warmupEnd: new BN(1000),
+          warmupShareBp: 5000,
+          joinedAt: new BN(500),
+        },
+      ],
+    },
+    memberVoterWeight: {
+      address: new PublicKey(
+        'BVzDPsjPe552VDbEncuhyeXfYusZymJt3fAbmtxg4Pgh'
+      ),
+      voterWeight: new BN(1000),
+      plugin: new PublicKey(
+        'A9Gii6weJceK2JsGxR57mbg3HXCPRWTqoFyuycLynsu6'
+      ),
+    },
+    shareBp: 10000,
+    warmupShareBp: 5000,
+  },
+  // The added share restarts the warmup of the share still warming up
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'DEx16BrhpfCWbBB6zEv4i6QhzFhM5t6GKQg4qKDN6dZd'
+      ),
+      realmAddress: new PublicKey(
+        '5CDK1gHGxBtMWZ8kq4hDaZyU4j3v1RuxuYHVMyfStDVH'
+      ),
+      communityMint: new PublicKey(
+        '5UTFSYbiE433MgCgHTgswAsCAN1FqLQkL6eshnxN7ECw'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(32),
+      },
+      minCommunityWeightToCreateGovernance: new BN(4),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'ANNjxjMHTmXYZ83dcCXGKyR9zsT6xkgWdk6Zm6LWKFiL'
+      ),
+    },
+    root: {
+      side: 'community',
+      votingWeightPlugin: new PublicKey(
+        'Erx1MwvZCcSDtQVyZfzBcqWHfCPhb9FPgqSwyr4Ne9Ce'
+      ),
+      membershipWarmup: new BN(3600),
+    },
+    member: {
+      owner: buildKeypair(
+        '3anYT7PT3Ncgfd1XoG9ZdwaTNU7i66Zwzdp3nd3Rqk8e',
+        [
+          2, 245, 161, 145, 93, 230, 63, 60, 188, 34, 8, 239, 171, 250, 245,
+          184, 156, 106, 69, 185, 129, 184, 182, 115, 216, 74, 1, 185, 248, 54,
+          39, 57, 38, 95, 9, 11, 133, 234, 215, 151, 95, 32, 248, 115, 44, 164,
+          142, 182, 61, 177, 213, 49, 149, 146, 144, 210, 200, 92, 122, 219, 75,
+          103, 220, 135,
+        ]
+      ),
+      voterWeight: new BN(1000),
+      voterWeightRecord: new PublicKey(
+        '4WnZUpxFQU7JL8DX5kDi7RmXXJpwjApMNXJ5M7XTJ66o'
+      ),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              '5X6cTEk4CXW1SqVf8q6DdbjKGJ3chXQCMNS7jpzFiYTj'
+            ),
+            owner: new PublicKey(
+              'gpFw9qDoRFfBuCZ9E9G4bCZhJbdQhpAA2wE1QwpBdAj'
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(1),
+            permanentVoterWeight: new BN(500),
+            rewardWeight: new BN(500),
+            warmingUpVoterWeight: new BN(500),
+            warmupEnds: [
+              {
+                endTime: new BN(10000000000),
+                voterWeight: new BN(500),
+                isPermanent: true,
+              },
+            ],
+          },
+          shareBp: 5000,
+          rewardWeight: new BN(500),
+          warmupEnd: new BN(10000000000),
+          warmupShareBp: 5000,
+          heldWarmup: {
+            endTime: new BN(10000000000),
+            voterWeight: new BN(500),
+            isPermanent: true,
+          },
+          joinedAt: new BN(500),
+        },
+      ],
+    },
+    memberVoterWeight: {
+      address: new PublicKey(
+        '4WnZUpxFQU7JL8DX5kDi7RmXXJpwjApMNXJ5M7XTJ66o'
+      ),
+      voterWeight: new BN(1000),
+      plugin: new PublicKey(
+        'Erx1MwvZCcSDtQVyZfzBcqWHfCPhb9FPgqSwyr4Ne9Ce'
+      ),
+    },
+    shareBp: 8000,
+    warmupShareBp: 8000,
+  },
+  // Rejoining while leaving warms up the whole share again
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '71VphNzukmsFVQY96czxGHrKy1TyDTwfHUXDjU9RZTfw'
+      ),
+      realmAddress: new PublicKey(
+        'FzSw7Bg79ygSbco9o35eaWw6AxgjMCJ3aQ7rGckGPski'
+      ),
+      communityMint: new PublicKey(
+        'fkBqtXUsW6G1f1XJjT6zxToSwrvzLTT3vU7s94QyozF'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(32),
+      },
+      minCommunityWeightToCreateGovernance: new BN(4),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'C5bB6LMn9aRTKqvFNnGS6eXYhUZvLRpFKuDN1X5Kehis'
+      ),
+    },
+    root: {
+      side: 'community',
+      votingWeightPlugin: new PublicKey(
+        'CsJJq6VDeaxxn77YSXXymUdbKDhqdB57PqBDyU4DHGRD'
+      ),
+      membershipWarmup: new BN(3600),
+    },
+    member: {
+      owner: buildKeypair(
+        'FJZZvPkbNGf55zCeuBtK1HDZNraqsRJwD2CtN6w5afc9',
+        [
+          229, 107, 250, 106, 7, 240, 94, 78, 179, 193, 254, 25, 84, 171, 129,
+          144, 81, 170, 158, 41, 230, 215, 9, 37, 159, 239, 87, 125, 126, 82,
+          197, 90, 212, 131, 80, 165, 58, 84, 222, 73, 101, 166, 182, 181, 113,
+          60, 145, 224, 87, 227, 177, 86, 240, 30, 32, 120, 184, 245, 207, 86,
+          82, 155, 207, 54,
+        ]
+      ),
+      voterWeight: new BN(1000),
+      voterWeightRecord: new PublicKey(
+        'DpXsLPm6HGkogdPjkcHfmXsMwMYe6vemFiQc6mYca5uH'
+      ),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              'YbuaZMk4B8S41m66AhJW7EpyF6PTTzEKMjXkTh2MaWG'
+            ),
+            owner: new PublicKey(
+              'FMjsU6Td3599Wrftz8NmjGXUG1njeJic2Kyv2F6ofrmA'
+            ),
+            name: 'Marinade',
+            leavingMembers: new BN(1),
+          },
+          shareBp: 10000,
+          exitableAt: new BN(10000000000),
+          joinedAt: new BN(500),
+        },
+      ],
+    },
+    memberVoterWeight: {
+      address: new PublicKey(
+        'DpXsLPm6HGkogdPjkcHfmXsMwMYe6vemFiQc6mYca5uH'
+      ),
+      voterWeight: new BN(1000),
+      plugin: new PublicKey(
+        'CsJJq6VDeaxxn77YSXXymUdbKDhqdB57PqBDyU4DHGRD'
+      ),
+    },
+    shareBp: 10000,
+    warmupShareBp: 10000,
+  },
+];
@@ -0,0 +1,289 @@
+import BN from 'bn.js';
+import {MemberTestData, RootTestData} from '../../VoteAggregator';
+import {
+  RealmTestData,
+  VoterWeightRecordTestData,
+} from '../../SplGovernance/realm';
+import {PublicKey} from '@solana/web3.js';
+
+export type LoyaltyBonusTestData = {
+  realm: RealmTestData;
+  root: RootTestData;
+  // Member of a single clan
+  member: MemberTestData;
+  memberVoterWeightRecord: VoterWeightRecordTestData;
+  // Seconds since the member joined the clan at the update
+  tenure: BN;
+  error?: string;
+};
+
+export const loyaltyBonusTestData: LoyaltyBonusTestData[] = [
+  // Grows the bonus over the curve period
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'JDxL3ZJq6d2N2pg6T7HH2aicH2eUTkjCJi3kfcdpMn1y'
+      ),
+      realmAddress: new PublicKey(
+        '5zkU9xzX1QyGqcTJYXcwdUdyP99c6C7ZPcNfrG8kBCrV'
+      ),
+      communityMint: new PublicKey(
+        'B4gpDKEXj1EqWSoYWDsxmTzU5Y3pFaPNgvP8wzTdS2Aa'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'ArAbpbPfX88ZJQRsjDg5FrKjKnP87tYTasGiTdsrkZum'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      votingWeightPlugin: new PublicKey(
+        'EUo2L19zcwkN8TyPitZrBaMFg1qfGgWwAjDXw9SvWm7X'
+      ),
+      maxVoterWeight: new BN(1000000),
+      maxLoyaltyBonusBp: 2000,
+    },
+    member: {
+      owner: new PublicKey('7DK95AGv82Dznw1nzLQgEByjNed93d6D2noCCXjoVbjW'),
+      voterWeight: new BN(1000),
+      voterWeightRecord: new PublicKey(
+        'AUXduNSvvMqcdKBzA79PFBegcDQoXwWNuB5d9VDMPNuT'
+      ),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              'DmyFtrSVVNi7PQ9m4aanW67dMkafYDN4w9aQeupDg88C'
+            ),
+            owner: new PublicKey('a9rxBVGGi8hj96Lqf3zA56mXsYHAA4wue318hZMH3Cz'),
+            name: 'Marinade',
+            permanentMembers: new BN(3),
+            voterWeight: new BN(3000),
+            permanentVoterWeight: new BN(3000),
+            rewardWeight: new BN(3000),
+            loyaltyCurve: {maxBonusBp: 2000, period: new BN(1000)},
+          },
+          shareBp: 10000,
+          rewardWeight: new BN(1000),
+          joinedAt: new BN(1700000000),
+        },
+      ],
+    },
+    memberVoterWeightRecord: {
+      voterWeight: new BN(1000),
+      voterWeightExpiry: null,
+      address: new PublicKey('AUXduNSvvMqcdKBzA79PFBegcDQoXwWNuB5d9VDMPNuT'),
+      plugin: new PublicKey('EUo2L19zcwkN8TyPitZrBaMFg1qfGgWwAjDXw9SvWm7X'),
+    },
+    tenure: new BN(250),
+  },
+  // Caps the bonus at the curve maximum after the period
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'Bidf9BPNZepXGUEGLmoejRWZ8hXpm5DtJk6iUzCqhaZg'
+      ),
+      realmAddress: new PublicKey(
+        'EzyF1FhPQT1XoCDTo3pwhh6N1roHoPrizJWkxtRy5rSG'
+      ),
+      communityMint: new PublicKey(
+        'FWAqiK7N6KG9CDaCck7wJc8pUcSrDKbrUfBDpJRKDPVR'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        '8qb5U6RmvoFsEXtRkcFAzDetiV3JtwWwetaoLTRU8fzv'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      votingWeightPlugin: new PublicKey(
+        'C4Q2XEmTavsNowYTUtvy3qtGTeRbNAdH18GCufP5KJtL'
+      ),
+      maxVoterWeight: new BN(1000000),
+      maxLoyaltyBonusBp: 2000,
+    },
+    member: {
+      owner: new PublicKey('5PfZ5B75x7su5yoxdwLpKzQfVRjzVAQxDt8vLXMV1AZg'),
+      voterWeight: new BN(1000),
+      voterWeightRecord: new PublicKey(
+        'HVt89ruaievvForU2Jrma2aL21VJGdusY4JWh3ZBJWXm'
+      ),
+      loyaltyBonusWeight: new BN(100),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              'J7F4rbfp5onjinz5JY2DNpN776Q2rMJQCuSTzYD45TdP'
+            ),
+            owner: new PublicKey(
+              'B1rKzARWitFeNnUF8G2TRdYSYkpa7AWhTs3qsvKagRvk'
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(3),
+            voterWeight: new BN(3100),
+            permanentVoterWeight: new BN(3100),
+            rewardWeight: new BN(3100),
+            loyaltyCurve: {maxBonusBp: 2000, period: new BN(1000)},
+          },
+          shareBp: 10000,
+          rewardWeight: new BN(1100),
+          joinedAt: new BN(1700000000),
+          loyaltyBonusBp: 1000,
+        },
+      ],
+    },
+    memberVoterWeightRecord: {
+      voterWeight: new BN(2000),
+      voterWeightExpiry: null,
+      address: new PublicKey('HVt89ruaievvForU2Jrma2aL21VJGdusY4JWh3ZBJWXm'),
+      plugin: new PublicKey('C4Q2XEmTavsNowYTUtvy3qtGTeRbNAdH18GCufP5KJtL'),
+    },
+    tenure: new BN(5000),
+  },
+  // Applies the changed curve on the next update
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'Dhs3dBFN5k3ovu4sVZacqW6vsojyY1jQZ29movjG9sW5'
+      ),
+      realmAddress: new PublicKey(
+        '6qtVmNsWeA9zHptFXgaCLRP23irXCHWpmq8oPMSMmvyp'
+      ),
+      communityMint: new PublicKey(
+        '8sit3CkPhzESRTe4kXRCpPcVnWm8vB7DaYEnpEogTwCM'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'HFqSdER7zCpJG1G5TRBj8U7TccceJff821AZTLm8QuM5'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      votingWeightPlugin: new PublicKey(
+        '6bhHY85neKSUSuMXeGaYbn71kb9pusYsHqQGf5nTaeNo'
+      ),
+      maxVoterWeight: new BN(1000000),
+      maxLoyaltyBonusBp: 3000,
+    },
+    member: {
+      owner: new PublicKey('J54FWshg8kGH8hwSarQpmXrJTFNjdgmsrcUsjH7dggcs'),
+      voterWeight: new BN(1000),
+      voterWeightRecord: new PublicKey(
+        'BoKC1DP3W4U6CbXXHouMD8YpSRef6vF9v4vFZMtxb7cx'
+      ),
+      loyaltyBonusWeight: new BN(150),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              '67DEpGvRqJK78GBQh482BGqGguwX1ei3cyo26aMyFa6Q'
+            ),
+            owner: new PublicKey(
+              'By4tcvacSUVHCuSGkLB9KeyKFrsppH4m62XYgEuVgjQm'
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(3),
+            voterWeight: new BN(3150),
+            permanentVoterWeight: new BN(3150),
+            rewardWeight: new BN(3150),
+            loyaltyCurve: {maxBonusBp: 1000, period: new BN(1000)},
+          },
+          shareBp: 10000,
+          rewardWeight: new BN(1150),
+          joinedAt: new BN(1700000000),
+          loyaltyBonusBp: 1500,
+        },
+      ],
+    },
+    memberVoterWeightRecord: {
+      voterWeight: new BN(1000),
+      voterWeightExpiry: null,
+      address: new PublicKey('BoKC1DP3W4U6CbXXHouMD8YpSRef6vF9v4vFZMtxb7cx'),
+      plugin: new PublicKey('6bhHY85neKSUSuMXeGaYbn71kb9pusYsHqQGf5nTaeNo'),
+    },
+    tenure: new BN(2000),
+  },
+  // Drops the bonus of a removed curve
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '9KjkeSBWGkizC4hhct6fDfdTTiGnB6WzoBm3keHiPWF4'
+      ),
+      realmAddress: new PublicKey(
+        'qv5kdemMEaprJ8WKgkivJMYEtLis2nJNLrixJYg6TFa'
+      ),
+      communityMint: new PublicKey(
+        '8vUoHkACrZQaupok2NS7YVCbZ1581TGAk8WB2nfPorDm'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'GT92aa5fubf774wYYhgdTnk9yf4XB1qfqYdeMMkfm2QY'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      votingWeightPlugin: new PublicKey(
+        '5PfjURJSBaUqDRkPtLg115cQBzL7uvjwFozWXtKkNNmF'
+      ),
+      maxVoterWeight: new BN(1000000),
+      maxLoyaltyBonusBp: 2000,
+    },
+    member: {
+      owner: new PublicKey('2K7tUfKwPzGZC7a4aLLpbpDayKVjPga77JSokLrEcPTo'),
+      voterWeight: new BN(1000),
+      voterWeightRecord: new PublicKey(
+        'Gvz47k31W5spA5n2kdQWczfcoZsmpJkhmm9LqT46TrL'
+      ),
+      loyaltyBonusWeight: new BN(50),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              '2gJqqv6bE1j65i8sAdTQhtfqsr2F93eTkZVBdNEkob1R'
+            ),
+            owner: new PublicKey(
+              '5Z7QLrSbF6JrSBqxwoc4fKQVMvciKkgHFkkHAenZsg3U'
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(3),
+            voterWeight: new BN(3050),
+            permanentVoterWeight: new BN(3050),
+            rewardWeight: new BN(3050),
+            loyaltyCurve: null,
+          },
+          shareBp: 10000,
+          rewardWeight: new BN(1050),
+          joinedAt: new BN(1700000000),
+          loyaltyBonusBp: 500,
+        },
+      ],
+    },
+    memberVoterWeightRecord: {
+      voterWeight: new BN(1000),
+      voterWeightExpiry: null,
+      address: new PublicKey('Gvz47k31W5spA5n2kdQWczfcoZsmpJkhmm9LqT46TrL'),
+      plugin: new PublicKey('5PfjURJSBaUqDRkPtLg115cQBzL7uvjwFozWXtKkNNmF'),
+    },
+    tenure: new BN(2000),
+  },
+];
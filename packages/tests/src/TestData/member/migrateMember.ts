@@ -0,0 +1,205 @@
+import BN from 'bn.js';
+import {MemberTestData, RootTestData} from '../../VoteAggregator';
+import {RealmTestData} from '../../SplGovernance/realm';
+import {buildKeypair} from '../..';
+import {PublicKey} from '@solana/web3.js';
+
+export type MigrateMemberTestData = {
+  realm: RealmTestData;
+  root: RootTestData;
+  member: MemberTestData;
+  // Encoded with the layout before the clan rewards
+  legacy: boolean;
+  error?: string;
+};
+
+export const migrateMemberTestData: MigrateMemberTestData[] = [
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '3jcZmf9MY1XHgv6hCtENyf2yWSehU9xjgbwNSrJR76kF'
+      ),
+      realmAddress: new PublicKey(
+        'HqPti9JCb8j1zZ39obDH26Tjuk2TBURtF3Jy5xrLy3VY'
+      ),
+      communityMint: new PublicKey(
+        '2W7b4D85N7BTmJmtxQuGBgSBEn2KRFU1wJCm9Xxbrkny'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        '4gAbR4YumerSFGWQEo6G3J7dksFEjS6nj58BrEwp9tW6'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+    },
+    member: {
+      owner: new PublicKey('8BiHZ5EJXR1hWvSCnQJfkByERisPpMWFBkqDr83J7fYc'),
+      voterWeight: new BN(1000),
+      membership: [
+        {
+          clan: new PublicKey('2e95x3bHhRY8SQWWJhno64xfEAk1QNNAf35YJBbgMrsv'),
+          shareBp: 10000,
+        },
+      ],
+    },
+    legacy: true,
+  },
+  // Leaving one of the clans
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'AnsJ2oKwBq1eUuiir7sgyhhTN6rPnfKdRZ9XXDYsb52'
+      ),
+      realmAddress: new PublicKey(
+        'AkRVLE3NUsoTZnYLWX2YHCjC37aXsXLGHqeQmXXyiw7m'
+      ),
+      communityMint: new PublicKey(
+        '9MU2uwR9nTnxZNMtBqMoDGM313aQzXaEizNRs8Yk79VT'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'CkNrFDaSypzxtENNnHo6LPDkmEddXgoNW6jbfEa4unfA'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+    },
+    member: {
+      owner: new PublicKey('Hy4GQTz99ckqxdSPw1TyU3v3XJ7w7hXHqa924L6P67h'),
+      voterWeight: new BN(1000),
+      voterWeightExpiry: new BN(1000),
+      nextVoterWeightResetTime: new BN(5000),
+      membership: [
+        {
+          clan: new PublicKey('52wnpXB1BwjggGFaxLjKeisXg1CHSR6E5Ux1MTmZxLSh'),
+          shareBp: 5000,
+        },
+        {
+          clan: new PublicKey('GYdcnq4ucwWpexg9yxyANgd3Ay4SgNRG8XJwaxCea6UR'),
+          shareBp: 5000,
+          exitableAt: new BN(2000),
+        },
+      ],
+    },
+    legacy: true,
+  },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '6jmn52Re9KakhLSmt6vLD1Gq9R4awqLc26XRy4KgREp4'
+      ),
+      realmAddress: new PublicKey(
+        'BeTZoFUEPeQHM39CavRqwiSv5jHLt69rLBYr5vdfX8Qb'
+      ),
+      communityMint: new PublicKey(
+        'BbaHokNncQgfR5WZG6brVMbGtwzvUPz7YKFMCXYsj6bf'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'FauYspHnBoiEibMm5H8XYUnWWA6pvMNESLAd3eSn9Eyi'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+    },
+    member: {
+      owner: new PublicKey('Ch2ZthN1M5sCxUVbLKqac4JkXwmPxeLrB79Ec6Puw2mY'),
+      // At least Member::SPACE as created by create_member
+      size: 10240,
+      voterWeight: new BN(1000),
+      membership: [
+        {
+          clan: new PublicKey('7vFBs5AedxEax9MbXqCen4jqt98BHBPYVSnRJqVN5zRm'),
+          shareBp: 10000,
+        },
+      ],
+    },
+    legacy: false,
+    error: 'MemberAlreadyMigrated',
+  },
+];
+
+// The member was in a clan with a loyalty curve and in a clan whose control
+// changed before the migration
+export const migratedMemberTenureTestData: MigrateMemberTestData = {
+  realm: {
+    splGovernanceId: new PublicKey(
+      '4XZLZY9fE1ozdXJnWECXaYddgj6fnduZXXFeFGfzjM8Z'
+    ),
+    realmAddress: new PublicKey('6snYm7LRpHLrNRPKvF8Z9HLhv56tDe37VnWgRnR4twHT'),
+    communityMint: new PublicKey(
+      'FZdkNcGKHzXWJ6XwVRyKm35kfnF9EXhxHA2K5WBVzbHG'
+    ),
+    communityMintMaxVoterWeightSource: {
+      supplyFraction: new BN(25),
+    },
+    minCommunityWeightToCreateGovernance: new BN(6),
+    name: 'Community side, no plugins',
+    communityMintAuthority: new PublicKey(
+      'J1zQJgvrv2axwVCqK28Pk2X74iCXX2kw5UVQoY9RB9L3'
+    ),
+  },
+  root: {
+    side: 'community',
+    maxProposalLifetime: new BN(172800),
+    // Lets update_voter_weight run without the member VWR
+    pauseFlags: 1 << 3,
+  },
+  member: {
+    owner: buildKeypair('8X9YciYXcH89FVKK5CYRvLpviegFUszD3DTgicwsGdVc', [
+      56, 92, 105, 149, 8, 143, 36, 79, 190, 26, 157, 251, 95, 197, 7, 209, 247,
+      81, 137, 91, 251, 52, 190, 23, 90, 177, 186, 152, 193, 35, 43, 23, 111,
+      186, 229, 13, 40, 206, 203, 142, 134, 29, 245, 1, 122, 187, 55, 103, 209,
+      59, 222, 226, 108, 214, 238, 219, 197, 103, 94, 174, 151, 166, 121, 3,
+    ]),
+    voterWeight: new BN(1000),
+    membership: [
+      {
+        clan: {
+          address: new PublicKey(
+            '2hpbgbDAq7xuxCHJtZgypyQaLmBEz1dwXq3ghFLbUbds'
+          ),
+          owner: new PublicKey('5FWy5NkmUKDmiQbCdbDsfDAQuEHLYuyvGAr8ye7GT3gx'),
+          name: 'Marinade',
+          permanentMembers: new BN(1),
+          voterWeight: new BN(500),
+          permanentVoterWeight: new BN(500),
+          loyaltyCurve: {
+            maxBonusBp: 5000,
+            period: new BN(1000),
+          },
+        },
+        shareBp: 5000,
+      },
+      {
+        clan: {
+          address: new PublicKey(
+            'A5r6QVFdL7duEKwuorEAv81MGcxaRLupn3Y7UTeAsbE1'
+          ),
+          owner: new PublicKey('EcZ9aivwqn6qBwPXSa7c9oQswpjY4vNojS2knpJfowMq'),
+          name: 'Marinade',
+          leavingMembers: new BN(1),
+        },
+        shareBp: 5000,
+        exitableAt: new BN(1893456000),
+      },
+    ],
+  },
+  legacy: true,
+};
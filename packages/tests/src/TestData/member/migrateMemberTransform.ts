@@ -0,0 +1,113 @@
+import BN from 'bn.js';
+import {MemberTestData, RootTestData} from '../../VoteAggregator';
+import {RealmTestData} from '../../SplGovernance/realm';
+import {PublicKey} from '@solana/web3.js';
+
+export type MigrateMemberTransformTestData = {
+  realm: RealmTestData;
+  root: RootTestData;
+  member: MemberTestData;
+  error?: string;
+};
+
+export const migrateMemberTransformTestData: MigrateMemberTransformTestData[] =
+  [
+    // The member weight was aggregated before the last transform sync
+    {
+      realm: {
+        splGovernanceId: new PublicKey(
+          '9wNzayA1L159iLnUxZeyem8E2eEkvFzFi6WWx1LDB6vp'
+        ),
+        realmAddress: new PublicKey(
+          '4tb8DTPDJZrrUeiV5Yz8jUjqieRSQSsHgC12JgrrADLR'
+        ),
+        communityMint: new PublicKey(
+          '3c67pjTpbzbZhg4KoL3S5hLMSnx7QQTp6kM6T5kWyFsj'
+        ),
+        communityMintMaxVoterWeightSource: {
+          supplyFraction: new BN(25),
+        },
+        minCommunityWeightToCreateGovernance: new BN(6),
+        name: 'Community side, no plugins',
+        communityMintAuthority: new PublicKey(
+          'JDTzhGuAsd2Q6cFmv3CEUeyPPKH1X2334y3JZWW2ffF6'
+        ),
+      },
+      root: {
+        side: 'community',
+      },
+      member: {
+        owner: new PublicKey('JE8wPuM48YAywqXVsSATgzLBhh35dd9g7gx7Bwyz7B4j'),
+        voterWeight: new BN(500),
+        membership: [
+          {
+            clan: {
+              address: new PublicKey(
+                'H4gB7VewN9Qx71F61f2uFRKhjrQ2DRyjK6heLe9JDNj'
+              ),
+              owner: new PublicKey(
+                'FB9rExRyZSGz3yRcPKWHxjK6kZv9KpDC2oBVkDZ5thSd'
+              ),
+              name: 'Marinade',
+              permanentMembers: new BN(2),
+              voterWeight: new BN(1500),
+              permanentVoterWeight: new BN(1500),
+              weightTransformEpoch: new BN(1),
+              unmigratedMembers: new BN(1),
+            },
+            shareBp: 10000,
+          },
+        ],
+      },
+    },
+    {
+      realm: {
+        splGovernanceId: new PublicKey(
+          'FcQxSxJHDimJ3ehhUjtsdM53hpJ2ZPwajp8yBctf2Sjc'
+        ),
+        realmAddress: new PublicKey(
+          'GXnNHBydE89vvAtsvSoDEBhNpx9tZQouHMHauQJCDVfN'
+        ),
+        communityMint: new PublicKey(
+          'GTynwqDKheHYKrP8cPKFzJin9VqaeRFq2PzgdztiUp7e'
+        ),
+        communityMintMaxVoterWeightSource: {
+          supplyFraction: new BN(25),
+        },
+        minCommunityWeightToCreateGovernance: new BN(6),
+        name: 'Community side, no plugins',
+        communityMintAuthority: new PublicKey(
+          'GNdfEHKjQu6FQan5A1oykzBNoQrU7UfeZF7ReZYnXwXi'
+        ),
+      },
+      root: {
+        side: 'community',
+      },
+      member: {
+        owner: new PublicKey('AKRZLf6NBbQPVzG8gdg4RXZTRinh9oUB75K5LjiprtZT'),
+        voterWeight: new BN(500),
+        membership: [
+          {
+            clan: {
+              address: new PublicKey(
+                '4xrFoPiC9P3c2ysYdMF5cs9D2kymaAD9QuYBSvb7bcM9'
+              ),
+              owner: new PublicKey(
+                '6MQRCNCbv39JFmFaJQhmd9H4h7FSAPB86mA1GAPvggX6'
+              ),
+              name: 'Marinade',
+              permanentMembers: new BN(2),
+              voterWeight: new BN(1500),
+              permanentVoterWeight: new BN(1500),
+              weightTransformEpoch: new BN(1),
+              unmigratedMembers: new BN(1),
+            },
+            shareBp: 10000,
+            // Already aggregated with the clan transform
+            weightTransformEpoch: new BN(1),
+          },
+        ],
+      },
+      error: 'MemberTransformAlreadyMigrated',
+    },
+  ];
@@ -0,0 +1,325 @@
+import BN from 'bn.js';
+import {MemberTestData, RootTestData} from '../../VoteAggregator';
+import {RealmTestData} from '../../SplGovernance/realm';
+import {Keypair, PublicKey} from '@solana/web3.js';
+import {buildKeypair} from '../..';
+
+export type RecallProposalTestData = {
+  realm: RealmTestData;
+  root: RootTestData;
+  // Opens and signs the recall of the owner of its first clan
+  member: MemberTestData & {owner: Keypair};
+  newOwner: PublicKey;
+  // The recall with the member signature exists already
+  signed?: boolean;
+  error?: string;
+};
+
+export const recallProposalTestData: RecallProposalTestData[] = [
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '5MTtkzrTmDUrwYaXvx6p9RbCxJoa171p5TBreGz2GNmg'
+      ),
+      realmAddress: new PublicKey(
+        '2owvD6ecBRHbqfZYFepeSVPAZ7WSBboHBoiWRK14JJCb'
+      ),
+      communityMint: new PublicKey(
+        'EJgNbQ6HXwN69SEb3Tcvf8M58Q7guWoMgUGcNkCaHvxY'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        '422oKjngR2QbkCTXrLQrD3r9XCd2iJtiGhbTNDzxkYg2'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      recallThresholdBp: 6000,
+    },
+    member: {
+      owner: buildKeypair(
+        'Aq7ZQCSRfaWnH3MCcX9y3RQnQ4DaLD8mNdx7MZBEbdkw',
+        [
+          135, 119, 36, 230, 36, 189, 226, 101, 147, 114, 233, 70, 171, 151, 67,
+          78, 93, 76, 166, 59, 237, 166, 209, 247, 80, 86, 160, 46, 62, 239, 61,
+          69, 146, 12, 122, 30, 127, 253, 242, 169, 229, 221, 24, 202, 45, 210,
+          101, 132, 21, 120, 6, 206, 248, 117, 196, 114, 60, 129, 39, 60, 187,
+          135, 12, 100,
+        ]
+      ),
+      voterWeight: new BN(700),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              '8drrBmnDh9RTapsySefLMPRBv8kchG3veJCeRXcATsNR'
+            ),
+            owner: new PublicKey(
+              'ATkpEFhyaS5JoSpjJABcmj9ouhtSKu2pxcbknyHQ3gKN'
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(2),
+            voterWeight: new BN(1000),
+            permanentVoterWeight: new BN(1000),
+            rewardWeight: new BN(1000),
+          },
+          shareBp: 10000,
+          rewardWeight: new BN(700),
+        },
+      ],
+    },
+    newOwner: new PublicKey('E3FHhhX2q2gvc3bwSAGkyHnrf8vfAyfnHNoK2vgJ73JR'),
+  },
+  // The signatures are below the threshold
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'BCitE1pmVtCiMmtvrVojHvGghynQp3qgA9fW7ZpAEr7T'
+      ),
+      realmAddress: new PublicKey(
+        'Dk2LWUnpsQrbwyB1KHZoVv8YaSoehrcqxfUbfCEwdp4w'
+      ),
+      communityMint: new PublicKey(
+        '5HLT5NBBJRpZ1MTjrLuSjhavL9Wy3w3wYArqx1LyBttu'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'AKj7yaVFoK7LxcMkvdi84xHy6sKmZj5QzBZzkvGqPgLp'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      recallThresholdBp: 6000,
+    },
+    member: {
+      owner: buildKeypair(
+        'BYUNSNC5T4344HFSyShcNsLFPLoUNkCg1ZisPZq2RBja',
+        [
+          233, 94, 195, 188, 237, 172, 84, 207, 80, 67, 195, 140, 146, 228, 107,
+          145, 91, 5, 18, 27, 54, 248, 133, 73, 164, 47, 26, 130, 103, 84, 33,
+          59, 156, 164, 210, 62, 35, 26, 48, 216, 66, 54, 242, 121, 45, 186,
+          131, 236, 117, 39, 253, 123, 23, 52, 18, 240, 29, 188, 233, 232, 204,
+          239, 54, 221,
+        ]
+      ),
+      voterWeight: new BN(500),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              'DGxwfgzBsdtLGV1K3F3TG7rW27mEycxeXEKAPAxyYZCS'
+            ),
+            owner: new PublicKey(
+              '7hfTWA8fcfDTbGDJQhMGUVCdD75aeSSBnoHJ7CMfanJQ'
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(2),
+            voterWeight: new BN(1000),
+            permanentVoterWeight: new BN(1000),
+            rewardWeight: new BN(1000),
+          },
+          shareBp: 10000,
+          rewardWeight: new BN(500),
+        },
+      ],
+    },
+    newOwner: new PublicKey('AcCxR3bizbpu7WzxcypuzWdVjN8MzaSXZB2dmTEsro2w'),
+    error: 'RecallProposalNotSupported',
+  },
+  // The member weight is still warming up
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '3hX41MjP3iPqEcFRFkAjsW7itrzrPJBEomRtKcAdpyY6'
+      ),
+      realmAddress: new PublicKey(
+        '6mBUvNxTSwCEMYqXqpaoeQPGmGvSy7Hi3wbiAukgUqKe'
+      ),
+      communityMint: new PublicKey(
+        'DY3ZYMRjmBMPzGAnVVAt4ZsWrzCJBpjErv9E7XojTJFP'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'Aaa4vsTtEJpEeWyGqGntWsV6Bd4N7Ydo3Fq5XWqsnLPV'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      recallThresholdBp: 6000,
+    },
+    member: {
+      owner: buildKeypair(
+        '7UbeUcLJVt8n4idmaLtht5TRG3HjjEqArqVvrNEEk6WL',
+        [
+          2, 175, 247, 189, 118, 250, 150, 30, 28, 78, 85, 221, 50, 209, 189,
+          45, 105, 162, 179, 131, 187, 100, 128, 91, 170, 63, 222, 28, 24, 235,
+          87, 107, 96, 55, 250, 88, 189, 211, 183, 46, 10, 95, 172, 179, 48,
+          188, 210, 237, 62, 17, 114, 227, 211, 9, 104, 62, 121, 120, 87, 32,
+          60, 92, 122, 193,
+        ]
+      ),
+      voterWeight: new BN(700),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              'SYLGForik1eM6sUj2BHfwyiS2oqdpLdTcfDLNnxF7jn'
+            ),
+            owner: new PublicKey(
+              'D871Bboh8NoT8PhrcdnfX1xHRYQcxK86dYF43vf6JdZu'
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(2),
+            voterWeight: new BN(1000),
+            permanentVoterWeight: new BN(1000),
+            rewardWeight: new BN(1000),
+          },
+          shareBp: 10000,
+          rewardWeight: new BN(700),
+          warmupEnd: new BN('10000000000'),
+        },
+      ],
+    },
+    newOwner: new PublicKey('2hgatfazSk3KpBwkFxQFWgz6g1p4TiSd61FSF9aAuLFj'),
+    error: 'MemberInWarmup',
+  },
+  // The root does not allow the recalls
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'ECAGqYU9WkUJSp8FfiHemHNAXrcWhwLKaHfYL7yUzvUk'
+      ),
+      realmAddress: new PublicKey(
+        '82yRkJbiEnn5F9g2DcLbpvUqMNzevfC27s8rmSbmBWhB'
+      ),
+      communityMint: new PublicKey(
+        '92Be3or6SZzSQ87A2mZv9AKbpYr9eD8pzv8oqfsA18MP'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'A4wCHLqoXuw6tUJr3G5cBNv8XseTRjoWSbBFyFbkNNgH'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+    },
+    member: {
+      owner: buildKeypair(
+        'B7H6eVD1uRXo99P6VxqhCqXEEiu29uuTr5GLekFCZCkw',
+        [
+          48, 186, 43, 227, 40, 76, 43, 217, 243, 87, 192, 152, 154, 49, 46, 23,
+          167, 203, 251, 185, 58, 140, 104, 21, 138, 197, 120, 42, 100, 10, 92,
+          68, 150, 48, 142, 49, 247, 238, 122, 250, 147, 150, 136, 190, 196,
+          166, 150, 38, 43, 130, 255, 135, 42, 193, 242, 212, 169, 3, 240, 114,
+          85, 225, 192, 48,
+        ]
+      ),
+      voterWeight: new BN(700),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              'BJ71CLwrhQGTm9JE9p5mgc1sfmKaseSJaAiEz4QAhANJ'
+            ),
+            owner: new PublicKey(
+              'GXH4u11XDdy7fGg2R5TmXbqGDgRMynur6EeH6DZmxHKh'
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(2),
+            voterWeight: new BN(1000),
+            permanentVoterWeight: new BN(1000),
+            rewardWeight: new BN(1000),
+          },
+          shareBp: 10000,
+          rewardWeight: new BN(700),
+        },
+      ],
+    },
+    newOwner: new PublicKey('Ba5gxxd1XvnpntpnXQnzdbcg9cCWK8hDDSvvALP7cWj8'),
+    error: 'RecallDisabled',
+  },
+  // The signer started leaving the clan after signing
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'BksqdNCDKPRaLWH4aWfHLPv5W5bnxo4R66222J6RCkXd'
+      ),
+      realmAddress: new PublicKey(
+        'EfvQWm4z83XxtUE34ZD4brJLjr66kavZAtYKfZabWUFQ'
+      ),
+      communityMint: new PublicKey(
+        'E3oqjg134ViKEAZK9dSFR4zbMtFxxobYrGWzhfSae7KZ'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        '3MKLEJhpc4io1nyjLSArRBXuBfVANkt8Yiw9XTTR9jBb'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      recallThresholdBp: 6000,
+    },
+    member: {
+      owner: buildKeypair(
+        '9zSFuKoPJxN9hfagb7c81HuxDw1x5kDQ6tkS6SsddddF',
+        [
+          207, 80, 80, 101, 15, 23, 84, 84, 124, 199, 202, 79, 101, 111, 213,
+          169, 185, 254, 9, 63, 28, 171, 126, 250, 167, 217, 14, 56, 217, 104,
+          90, 168, 133, 148, 40, 221, 194, 171, 37, 97, 58, 179, 178, 36, 184,
+          232, 246, 175, 154, 50, 236, 130, 78, 232, 70, 8, 255, 15, 50, 138,
+          182, 170, 74, 38,
+        ]
+      ),
+      voterWeight: new BN(700),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              '7rFdXcZJgLYwXRSkVRCeNnb8ogfN5buPV1b3ZPreZBU8'
+            ),
+            owner: new PublicKey(
+              '8vWKHqmsoThVQLwUTGRgkKXmr3bpgc9KwGmb414sogQW'
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(2),
+            voterWeight: new BN(1000),
+            permanentVoterWeight: new BN(1000),
+            rewardWeight: new BN(1000),
+            recallProposals: new BN(1),
+          },
+          shareBp: 10000,
+          rewardWeight: new BN(700),
+          exitableAt: new BN('10000000000'),
+        },
+      ],
+    },
+    newOwner: new PublicKey('9LdM4FzwwgJdgW8rPsqpGSbXZjcubsH4fmT2QggEQjrY'),
+    signed: true,
+    error: 'RecallProposalNotSupported',
+  },
+];
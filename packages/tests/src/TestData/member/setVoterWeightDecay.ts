@@ -0,0 +1,187 @@
+import BN from 'bn.js';
+import {MemberTestData, RootTestData} from '../../VoteAggregator';
+import {
+  RealmTestData,
+  VoterWeightRecordTestData,
+} from '../../SplGovernance/realm';
+import {PublicKey} from '@solana/web3.js';
+import {buildKeypair} from '../../utils';
+
+export type SetVoterWeightDecayTestData = {
+  realm: RealmTestData;
+  root: RootTestData;
+  member: MemberTestData;
+  memberVoterWeightRecord: VoterWeightRecordTestData;
+  rate: BN;
+  endTime: BN;
+  error?: string;
+};
+
+export const setVoterWeightDecayTestData: SetVoterWeightDecayTestData[] = [
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '4gJEd9zpJwVGHytKE5ZuyRg5R32N6ZHpK5EnEHuxVaVg'
+      ),
+      realmAddress: new PublicKey(
+        '9VRbuBNooxUHPgQutdzGHfjxJGGfD49cUEDNEYkYUpWg'
+      ),
+      communityMint: new PublicKey(
+        '8bJ7brqQr1SQPFpfHbuQV6AW44NgDt2Q14VP7hbGBci9'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, decaying plugin',
+      communityMintAuthority: new PublicKey(
+        '5sdEKiLHDpKNTzDM7t3SYqbai4WCnvbSj2GrtZHStYZH'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      votingWeightPlugin: new PublicKey(
+        'Eckxye4H3d1ZMUPrCFRTMXvUbmqVvt1QiN8EzXL2aM9'
+      ),
+      maxVoterWeight: new BN('107428734639842'),
+      voterWeightReset: {
+        nextResetTime: new BN('1893456000'),
+        step: new BN(86400),
+      },
+    },
+    member: {
+      owner: buildKeypair(
+        'A8y6SGKr7k3BGaJUYQggAcWdcaPFL4YZCbvv6jEPRc3L',
+        [
+          205, 147, 56, 231, 134, 75, 9, 124, 185, 7, 76, 227, 47, 191, 37, 169,
+          209, 8, 4, 119, 95, 77, 219, 121, 66, 186, 195, 249, 102, 135, 185,
+          12, 135, 195, 172, 97, 229, 167, 133, 6, 222, 242, 10, 27, 225, 114,
+          149, 219, 193, 4, 146, 240, 205, 241, 226, 47, 86, 173, 197, 185, 101,
+          115, 42, 179,
+        ]
+      ),
+      voterWeight: new BN('7296622000'),
+    },
+    memberVoterWeightRecord: {
+      voterWeight: new BN('7489342343'),
+      voterWeightExpiry: new BN(1000),
+      address: new PublicKey('5k2hMxPem2cyC4yQLPKV93KU9t9MMpsHuzBqPhg3oq4x'),
+      plugin: new PublicKey('Eckxye4H3d1ZMUPrCFRTMXvUbmqVvt1QiN8EzXL2aM9'),
+    },
+    rate: new BN(10),
+    endTime: new BN('1893456000'),
+  },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '4NE8MhHLegrNmLgcaicEv9pMz5A1cNSHWjjXDfimCghH'
+      ),
+      realmAddress: new PublicKey(
+        '7aNRUnHCQ7bwnTxySnNuVuDdNSHk5CyHRasW65dBDTuz'
+      ),
+      communityMint: new PublicKey(
+        'BLutobHsm52GnYkggfvSWs1K3nxicRTNyVwWZFEwL5nz'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, decaying plugin',
+      communityMintAuthority: new PublicKey(
+        '7T4tCDMXSgkGzB131BdXA8zurjNUZiWjvfnq4md2YjbA'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      votingWeightPlugin: new PublicKey(
+        'HxQRr5KihqQdfP7T2qyMqH7H6o89rW4vr8VvxBQ2RCh1'
+      ),
+      maxVoterWeight: new BN('107428734639842'),
+      voterWeightReset: {
+        nextResetTime: new BN('1893456000'),
+        step: new BN(86400),
+      },
+    },
+    member: {
+      owner: buildKeypair(
+        'A5jWbyjZKsY6Bsq5cKSU94Cu7usSThavp6h14jLt2qsW',
+        [
+          57, 18, 252, 60, 168, 187, 77, 28, 148, 151, 61, 102, 122, 116, 231,
+          73, 55, 120, 162, 183, 111, 151, 85, 159, 180, 108, 114, 103, 123, 52,
+          3, 193, 134, 239, 146, 125, 228, 232, 55, 7, 178, 244, 51, 76, 82, 14,
+          233, 206, 206, 197, 10, 231, 47, 43, 43, 157, 103, 170, 181, 57, 11,
+          170, 195, 105,
+        ]
+      ),
+      voterWeight: new BN('7296622000'),
+    },
+    memberVoterWeightRecord: {
+      voterWeight: new BN('7489342343'),
+      voterWeightExpiry: new BN(1000),
+      address: new PublicKey('CGsV6TZPiS33euJgz2o9i9FHRS5Ayv8XjxVP1twn9Cus'),
+      plugin: new PublicKey('HxQRr5KihqQdfP7T2qyMqH7H6o89rW4vr8VvxBQ2RCh1'),
+    },
+    // The decay must not outlive the next reset
+    rate: new BN(1),
+    endTime: new BN('1893542400'),
+    error: 'InvalidVoterWeightDecay',
+  },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'FydapEVKHfN9R296sdDRPYWey5pGnCoPSgFS9rL1Ta1J'
+      ),
+      realmAddress: new PublicKey(
+        '6XWEkJGhS7XkCPuW34rQeVQm96C2n1U7rmYxnh3KXqWM'
+      ),
+      communityMint: new PublicKey(
+        'GsMmZUx58X29374XYm12eDgNssx62ywaruWcdSBmkTrM'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        '5X3Cqxoe3vMEEHGWk2Ti6jnptUXJ1Lh6xWKYbZBqP2U2'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      votingWeightPlugin: new PublicKey(
+        '7Wd4uekGrYJYeKHhJXCUV29ATBNAyPcdfHfyGpgbs6ux'
+      ),
+      maxVoterWeight: new BN('107428734639842'),
+      voterWeightReset: {
+        nextResetTime: new BN('1893456000'),
+        step: new BN(86400),
+      },
+    },
+    member: {
+      owner: buildKeypair(
+        '7wEXdNJ1XiGtqaZS1S8orcNRGkRwMfvEZMYFs367pRtq',
+        [
+          116, 83, 220, 56, 59, 165, 208, 15, 194, 213, 244, 115, 197, 88, 204,
+          162, 96, 254, 144, 33, 209, 148, 231, 43, 91, 204, 209, 105, 96, 202,
+          12, 182, 103, 10, 200, 14, 29, 183, 104, 58, 32, 226, 155, 3, 67, 5,
+          67, 108, 107, 7, 104, 122, 110, 68, 163, 249, 249, 4, 111, 226, 65,
+          223, 188, 118,
+        ]
+      ),
+      voterWeight: new BN('7296622000'),
+    },
+    // Not expiring weight can not decay
+    memberVoterWeightRecord: {
+      voterWeight: new BN('7489342343'),
+      voterWeightExpiry: null,
+      address: new PublicKey('7eK2Q4kQVRbTguqMoeMrha9gfDHTfJ6P1jhfQJfhvkzB'),
+      plugin: new PublicKey('7Wd4uekGrYJYeKHhJXCUV29ATBNAyPcdfHfyGpgbs6ux'),
+    },
+    rate: new BN(10),
+    endTime: new BN('1893456000'),
+    error: 'InvalidVoterWeightDecay',
+  },
+];
@@ -12,6 +12,7 @@ export type SetVoterWeightRecordTestData = {
   root: RootTestData;
   member: MemberTestData;
   memberVoterWeightRecord: VoterWeightRecordTestData;
+  slot?: bigint;
   error?: string;
 };
 
@@ -204,4 +205,108 @@ export const setVoterWeightRecordTestData: SetVoterWeightRecordTestData[] = [
       plugin: new PublicKey('7tD4nQi7eaivX8JPQ8XCKAQT4QREKWmRYnjXcV1JB9ZN'),
     },
   },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'FagWkTCgxgiz97eHe6NmSq2S8xada4PrZXgryQjJAL67'
+      ),
+      realmAddress: new PublicKey(
+        '53KUwVY4Kt4g5fs6y6KfmvZcwx4eoTNGs7gmmr51rzpo'
+      ),
+      communityMint: new PublicKey(
+        'GuCzV4WmQsU9h3FQjkjH6pz8Zq3Z2vVZTyohYvu6ZkrV'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, decaying plugin',
+      communityMintAuthority: new PublicKey(
+        'CphN6czk23Ytbs4G9ReYeTFRsDCRA65e5DSXeCpin9Mh'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      votingWeightPlugin: new PublicKey(
+        'FTRQNrpYKzDaNq4ecrq7DPTPPj1G3fXatjoww6pKZoor'
+      ),
+      maxVoterWeight: new BN('107428734639842'),
+      voterWeightReset: {
+        nextResetTime: new BN('1893456000'),
+        step: new BN(86400),
+      },
+    },
+    member: {
+      owner: buildKeypair(
+        'ETXNXE8oiRMVms7QLZocoiZpu7q5Y5gVLLBoU1i7jyD8',
+        [
+          58, 238, 240, 122, 86, 189, 188, 183, 1, 137, 109, 16, 40, 1, 129,
+          229, 61, 155, 163, 227, 192, 133, 208, 151, 49, 159, 66, 67, 109, 126,
+          186, 243, 199, 243, 95, 42, 74, 40, 9, 185, 77, 24, 56, 205, 246, 241,
+          2, 179, 176, 234, 222, 242, 90, 196, 103, 187, 84, 58, 154, 10, 22, 8,
+          3, 175,
+        ]
+      ),
+      voterWeight: new BN('7296622000'),
+    },
+    memberVoterWeightRecord: {
+      voterWeight: new BN('7489342343'),
+      voterWeightExpiry: new BN(1000),
+      address: new PublicKey('GNL4D5Xw1LsS3P3dvEwtn9ACYv9CGGG7Eq8qLotJUdto'),
+      plugin: new PublicKey('FTRQNrpYKzDaNq4ecrq7DPTPPj1G3fXatjoww6pKZoor'),
+    },
+    slot: BigInt(1200),
+    error: 'VoterWeightExpired',
+  },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '2vtYJTuUHxj6XDAWUtxJgZoQ7zujHQCpqwPfET7Z2vSz'
+      ),
+      realmAddress: new PublicKey(
+        'EyhpT2nqFLRihnyRDTquhk5xPq3BkzKspwAKk4NNU69w'
+      ),
+      communityMint: new PublicKey(
+        '79h9CgivdNzwWjtkUrjCHXvemJ7tbxjUVucRWMaYWzUJ'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, decaying plugin',
+      communityMintAuthority: new PublicKey(
+        '2aTaXHbXwV2RgXcP5mjxS3oKJjjv8cd5n8QNuBv5uVit'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      votingWeightPlugin: new PublicKey(
+        '5DFnaYtiy3bnbkpNSWHzERLhAFFTqwe353C3VjATAMrQ'
+      ),
+      maxVoterWeight: new BN('107428734639842'),
+    },
+    member: {
+      owner: buildKeypair(
+        '2Jeyz7JeD49eQSpBeQvKZB6AKTwDqUc8WB8ZBVgnvcYZ',
+        [
+          173, 197, 238, 190, 123, 99, 82, 16, 189, 22, 193, 250, 80, 241, 214,
+          61, 214, 137, 166, 217, 70, 130, 71, 19, 239, 47, 86, 68, 236, 126,
+          174, 8, 19, 97, 129, 243, 150, 244, 90, 144, 242, 255, 240, 11, 51,
+          75, 72, 72, 172, 130, 167, 114, 249, 19, 171, 250, 156, 240, 147, 10,
+          76, 56, 130, 138,
+        ]
+      ),
+      voterWeight: new BN('7296622000'),
+    },
+    memberVoterWeightRecord: {
+      voterWeight: new BN('7489342343'),
+      voterWeightExpiry: new BN(1200),
+      address: new PublicKey('BnF64afHfyDaj7Gp9yc7pkpgi5Jg6fjEcdjtm1NNPiFi'),
+      plugin: new PublicKey('5DFnaYtiy3bnbkpNSWHzERLhAFFTqwe353C3VjATAMrQ'),
+    },
+    slot: BigInt(1200),
+    error: 'VoterWeightExpiryIsNotImplemented',
+  },
 ];
@@ -0,0 +1,132 @@
+import {PublicKey} from '@solana/web3.js';
+import {RealmTestData, MemberTestData, RootTestData, buildKeypair} from '../..';
+import {BN} from '@coral-xyz/anchor';
+
+export type WindDownExitTestData = {
+  realm: RealmTestData;
+  root: RootTestData;
+  member: MemberTestData;
+  error?: string;
+};
+
+export const windDownExitTestData: WindDownExitTestData[] = [
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'Gw1g6pETVGTmP4bov9yMHcFj5Po7fCyU7fNS6vXzaRbz'
+      ),
+      realmAddress: new PublicKey(
+        '9QjHMMhqeXWm1eCVSWwdjyrrzJ7Y3gYoFSXJbtXKBKbf'
+      ),
+      communityMint: new PublicKey(
+        'DzUCEyt3zoedbFhhujTbJTX1Y3W6ZWwEm5TZ8jmjFGhi'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(23),
+      },
+      minCommunityWeightToCreateGovernance: new BN(8),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        '9HhrtkxVYWUxsNABWh7cRXbZVhuX1saE6eZFVmPanFHz'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      maxVoterWeight: new BN(1000),
+      windDown: true,
+      // The leave pause does not trap the members of a retired root
+      pauseFlags: 0b100,
+    },
+    member: {
+      owner: buildKeypair(
+        '4WL36zojMaANvAtkWsRfceHgCtT1QU7N37mYgfDHRczC',
+        [
+          65, 163, 64, 235, 173, 31, 245, 136, 154, 145, 228, 230, 181, 196,
+          102, 144, 23, 39, 57, 38, 100, 12, 9, 217, 179, 173, 42, 157, 7, 239,
+          27, 203, 52, 22, 109, 185, 200, 183, 81, 249, 222, 64, 198, 214, 102,
+          68, 225, 154, 34, 117, 8, 31, 134, 74, 116, 74, 5, 230, 76, 211, 246,
+          73, 55, 33,
+        ]
+      ),
+      voterWeight: new BN(1000),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              '8kyeFDXkpMWuYnWYCsb8pjHRxr6X3fmVAtsa5trnv4cT'
+            ),
+            owner: new PublicKey(
+              '8VNMhhP9AqWddQWPBVrobtcYDAQtkMuFvvkidRkwTuVh'
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(1),
+            voterWeight: new BN(1000),
+            permanentVoterWeight: new BN(1000),
+          },
+          shareBp: 10000,
+        },
+      ],
+    },
+  },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'HE4g5sJu9RMpwCcvhgLUb1SKFkZ9K4p716pSMTctmGRR'
+      ),
+      realmAddress: new PublicKey(
+        'FgsPqEwBY4CNethB3Y6MPnomfWFsDo8uMi4FGEQ5US2o'
+      ),
+      communityMint: new PublicKey(
+        'Diq723Sb274xBfyhA2GSDkhcB2mtNYoP4gkwfjPGAzCo'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(23),
+      },
+      minCommunityWeightToCreateGovernance: new BN(8),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'DdE6TBG5yuGXXBkSiB9q75aYBfNnptBZR2Njj6xsK5X7'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      maxVoterWeight: new BN(1000),
+      windDown: true,
+    },
+    member: {
+      owner: buildKeypair(
+        'G7XxTDXHSzBhTJU4UTAyuGMs7C3Qzzcg6HzEAa6AVHFD',
+        [
+          202, 37, 233, 38, 187, 85, 63, 125, 164, 232, 106, 232, 121, 60, 242,
+          248, 207, 115, 174, 205, 220, 5, 166, 54, 132, 59, 135, 135, 35, 202,
+          231, 249, 224, 139, 202, 119, 170, 176, 176, 169, 103, 182, 148, 82,
+          232, 135, 21, 194, 11, 167, 3, 213, 62, 71, 1, 89, 95, 48, 41, 233,
+          26, 60, 175, 72,
+        ]
+      ),
+      voterWeight: new BN(1000),
+      membership: [
+        {
+          clan: {
+            address: new PublicKey(
+              'Cr86RfHbnVY5eywmqhbk4eNxdukjm5WoCUsL1YfUGaQH'
+            ),
+            owner: new PublicKey(
+              'A8yvGpKi4yWcG1ckbHzeGmSYATpW7VMyFaNhKDRN3bDJ'
+            ),
+            name: 'Marinade',
+            permanentMembers: new BN(1),
+            voterWeight: new BN(1000),
+            permanentVoterWeight: new BN(1000),
+          },
+          shareBp: 10000,
+          // Must be claimed before the exit
+          unclaimedRewards: new BN(25),
+        },
+      ],
+    },
+    error: 'UnclaimedClanRewards',
+  },
+];
@@ -0,0 +1,183 @@
+import {PublicKey} from '@solana/web3.js';
+import {RealmTestData} from '../../SplGovernance/realm';
+import {BN} from '@coral-xyz/anchor';
+import {RootTestData} from '../../VoteAggregator/root';
+
+export type CheckPluginConfigTestData = {
+  realm: RealmTestData;
+  root: RootTestData;
+  // The drift status expected after the check
+  drift?: boolean;
+  error?: string;
+};
+
+const voteAggregatorId = new PublicKey(
+  'VoTaGDreyne7jk59uwbgRRbaAzxvNbyNipaJMrRXhjT'
+);
+
+export const checkPluginConfigTestData: CheckPluginConfigTestData[] = [
+  // The realm stopped using the aggregator
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '5TecHsuuuQkCauwQAmE6BgywWoSrcykZVyVzRmAWo2Ny'
+      ),
+      realmAddress: new PublicKey(
+        'DhRSZWBVivhF3N2mmzyvcpVGvv4PrBsdK7MBzoyfentE'
+      ),
+      communityMint: new PublicKey(
+        'w613HDzRZpG4zmLRkfXSKssaMSdBrMrhMbkrXwGAwuv'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        '54AJo9Q1LMKHQbBJHQLFWaSYykZSWYx8WC5LKeFe6ivP'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      pluginDrift: false,
+    },
+    drift: true,
+  },
+  // The realm uses the aggregator again
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'CqJEXtmTKo9qZRDqDY1HjASxuaJmLD8eHduqWS1vFj5D'
+      ),
+      realmAddress: new PublicKey(
+        'JBcegLYhDcy3WC43tjXxm88Mwzyq9twTRdTjdukugVxQ'
+      ),
+      communityMint: new PublicKey(
+        'CWpNdNAfvZzaj7MTwUDLpUDy1p8HVXrVV9uM8vik7BxK'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, switched to the aggregator',
+      communityMintAuthority: new PublicKey(
+        '69rJpu98ML2zu65hgvejPtRMWeXx9GDQivd5wkZz4RG2'
+      ),
+      communityTokenConfig: {
+        voterWeightAddin: voteAggregatorId,
+        maxVoterWeightAddin: voteAggregatorId,
+      },
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      pluginDrift: true,
+    },
+    drift: false,
+  },
+  // The aggregator reads from a plugin which is gone
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'EDR3TB3xNvKHDVQHgmXh1NmNWpxQRyCE5eN3AM8pvx65'
+      ),
+      realmAddress: new PublicKey(
+        'Q5GvYe9it7p2ZufQ1kUyXLjJMmyPbzcPKwKv7a2zW8P'
+      ),
+      communityMint: new PublicKey(
+        '7eAb1dVeTDKZwE1wsQ3bYSV3W21cRrCmNapLWKYGznwo'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, switched to the aggregator',
+      communityMintAuthority: new PublicKey(
+        'AMAJRCUJxQtdtnkBySGuGYPyVeFQNcf4MgBWDZrBDkx9'
+      ),
+      communityTokenConfig: {
+        voterWeightAddin: voteAggregatorId,
+        maxVoterWeightAddin: voteAggregatorId,
+      },
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      // Not deployed anymore
+      votingWeightPlugin: new PublicKey(
+        'EVfTqeDNb6gxVxvZURoZhsKsK3qot79ArX542hh8gBpM'
+      ),
+      pluginDrift: false,
+    },
+    drift: true,
+  },
+  // The plugin was removed without verifying the realm config again
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'o1BKUMwnMStAJWjvFMbFVXcDPZGqyMPDzRmeNdEinz4'
+      ),
+      realmAddress: new PublicKey(
+        'E44udJpHs3YLqs1c4hBvSv2ru2f9whUrGWDzoGjomdxd'
+      ),
+      communityMint: new PublicKey(
+        '3RGrCyKTENkmFtEouAbE93T1QvSsKXFF7hPt5DRcNXRE'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, switched to the aggregator',
+      communityMintAuthority: new PublicKey(
+        'HVYa5tqfuDd3F4uw8zySGBWZf84YZKwcegw1UryFG6n5'
+      ),
+      communityTokenConfig: {
+        voterWeightAddin: voteAggregatorId,
+        maxVoterWeightAddin: voteAggregatorId,
+      },
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      verifiedVotingWeightPlugin: new PublicKey(
+        '7nDVhCWsq6gae1MxuGTGukLi5boxZyJs9qgt6AdaVAWZ'
+      ),
+      pluginDrift: false,
+    },
+    drift: true,
+  },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'BEKeS63G4TTr5rvYZBh1wW57tM9uQxwbccTJ9ySDYjt1'
+      ),
+      realmAddress: new PublicKey(
+        '5xngPQdtwmQFUv3j464LvnZtwQhAcAGMjhtMjZZQuVYh'
+      ),
+      communityMint: new PublicKey(
+        'GVfa2Xu6yBDUqcVTTsz2zwUCGuV1KVmCXGPLMyJxHS4v'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, switched to the aggregator',
+      communityMintAuthority: new PublicKey(
+        'D5sM8SNx9sQZPBsU7SiNavHzYixReEmyFHFxwoDFA4jC'
+      ),
+      communityTokenConfig: {
+        voterWeightAddin: voteAggregatorId,
+        maxVoterWeightAddin: voteAggregatorId,
+      },
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      votingWeightPlugin: new PublicKey(
+        'w23aN1t65Jmk6in9G293myR1HBzjRvzqfvBCy33u6Pd'
+      ),
+    },
+    error: 'VotingWeightPluginRequired',
+  },
+];
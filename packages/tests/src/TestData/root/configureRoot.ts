@@ -1,7 +1,8 @@
-import {PublicKey} from '@solana/web3.js';
+import {Keypair, PublicKey} from '@solana/web3.js';
 import {RealmTestData} from '../../SplGovernance/realm';
 import {BN} from '@coral-xyz/anchor';
 import {RootTestData} from '../../VoteAggregator/root';
+import {WeightTransform} from '../../VoteAggregator/accounts';
 import {buildKeypair} from '../..';
 
 export type ConfigureRootTestData = {
@@ -10,8 +11,16 @@ export type ConfigureRootTestData = {
   maxProposalLifetime?: BN;
   voterWeightResetStep?: BN;
   nextVoterWeightResetOffset?: BN | null;
-  paused?: boolean;
+  pauseFlags?: number;
+  resumeFlags?: number;
+  voterWeightExpiryTolerance?: BN;
+  disableVoterWeightReset?: boolean;
   votingWeightPlugin?: PublicKey;
+  newAdmin?: Keypair;
+  // Signs instead of the realm authority
+  admin?: Keypair;
+  weightTransform?: WeightTransform;
+  staleMemberGracePeriod?: BN | null;
   error?: string;
 };
 
@@ -52,7 +61,18 @@ export const configureRootTestData: ConfigureRootTestData[] = [
     maxProposalLifetime: new BN(10230),
     voterWeightResetStep: new BN(3452353),
     nextVoterWeightResetOffset: new BN(237),
-    paused: true,
+    pauseFlags: 0b10010, // joins and proposal votes
+    voterWeightExpiryTolerance: new BN(2),
+    newAdmin: buildKeypair(
+      'FW7QFAodzLvmdxGvRT3b9zka74dpGNQ8nHyiYnNKExpm',
+      [
+        71, 15, 138, 84, 188, 19, 126, 187, 64, 129, 244, 181, 202, 46, 206, 89,
+        194, 165, 148, 47, 244, 45, 141, 179, 146, 141, 217, 214, 145, 96, 147,
+        194, 215, 120, 178, 162, 239, 50, 167, 6, 232, 112, 40, 211, 43, 250,
+        64, 102, 153, 223, 176, 11, 248, 122, 40, 53, 218, 24, 215, 138, 102,
+        91, 210, 150,
+      ]
+    ),
   },
   {
     realm: {
@@ -86,7 +106,11 @@ export const configureRootTestData: ConfigureRootTestData[] = [
     },
     root: {
       side: 'community',
-      paused: true,
+      pauseFlags: 0xffffffff,
+      voterWeightReset: {
+        nextResetTime: new BN('1893456000'),
+        step: new BN(604800),
+      },
       votingWeightPlugin: new PublicKey(
         'CjLrEkUWo7en4DAFfpmMjTz9PGzC7xGQ6sdZTQBpbKiy'
       ),
@@ -94,9 +118,132 @@ export const configureRootTestData: ConfigureRootTestData[] = [
     maxProposalLifetime: new BN(10230),
     voterWeightResetStep: new BN(3452353),
     nextVoterWeightResetOffset: new BN(237),
-    paused: false,
+    resumeFlags: 0b1000, // voter weight updates
+    voterWeightExpiryTolerance: new BN(0),
+    disableVoterWeightReset: true,
     votingWeightPlugin: new PublicKey(
       'FWFrQ1sAq9JAEuyGdN2T6oEkGLVdKPmYBt5g2eNgNgjr'
     ),
   },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '8mwnTAWr5qDmpBHJUfX7DHphRiV2V3R4mhg8mWzdGDMt'
+      ),
+      realmAddress: new PublicKey(
+        '7szyMsVv8sc9QX5dx3pkf6VS6FALhuscZkUW6AQpYGNX'
+      ),
+      authority: new PublicKey('9VzUcjtJ4dRVw76238mtc62nj2THgHktSp9NouARUWgo'),
+      communityMint: new PublicKey(
+        'F1z4vh2Laa1bo91iQzjNpGGiPEqFvkY7PsKqD3PEemSA'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(32),
+      },
+      minCommunityWeightToCreateGovernance: new BN(4),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        '2dwEUv3MyULkCocJnCWQc3NFLiuwdsMDNEDqdLiD6LZL'
+      ),
+    },
+    root: {
+      side: 'community',
+      admin: new PublicKey('EegnhUtaY4SUcRDqkUPwGdNs53aS6QC4gx7BeuMStc6Y'),
+    },
+    // The voting rules stay with the realm authority
+    admin: buildKeypair(
+      'EegnhUtaY4SUcRDqkUPwGdNs53aS6QC4gx7BeuMStc6Y',
+      [
+        109, 250, 150, 104, 173, 219, 222, 235, 89, 155, 147, 120, 186, 254,
+        165, 237, 145, 125, 209, 220, 220, 169, 117, 213, 58, 82, 122, 39, 51,
+        237, 137, 12, 202, 207, 104, 134, 11, 91, 82, 184, 6, 209, 7, 70, 249,
+        10, 201, 24, 226, 167, 48, 8, 123, 31, 201, 124, 168, 77, 249, 8, 166,
+        161, 237, 181,
+      ]
+    ),
+    weightTransform: {squareRoot: {}},
+    error: 'WrongRealmAuthority',
+  },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'DApkiyAtyLnMfPbHsL7Aj9u43vx5Ac9EGDinjfJhMM8b'
+      ),
+      realmAddress: new PublicKey(
+        'G8P13ev8bSWV3dikM1fd9Y1yZRGnBs3MSLoj8JzXmpwZ'
+      ),
+      authority: new PublicKey('6VptXqSKvFRrPmekyLi6einAZs8APdwpSFFFp7vpBZGp'),
+      communityMint: new PublicKey(
+        'AsJJrpN1KPdcAyeoDkRiMwHHddVGa45fjD3uitaRZgd2'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(32),
+      },
+      minCommunityWeightToCreateGovernance: new BN(4),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        '2dwEUv3MyULkCocJnCWQc3NFLiuwdsMDNEDqdLiD6LZL'
+      ),
+    },
+    root: {
+      side: 'community',
+      admin: new PublicKey('3UsLfNwLCXnzbDwVnaJycho7vkxWBnYqy2eYDgjCjz3v'),
+      memberCount: new BN(3),
+      voterWeightExpiryTolerance: new BN(10),
+    },
+    admin: buildKeypair(
+      '3UsLfNwLCXnzbDwVnaJycho7vkxWBnYqy2eYDgjCjz3v',
+      [
+        74, 69, 192, 134, 56, 82, 171, 109, 175, 37, 228, 227, 97, 33, 203,
+        45, 41, 219, 51, 75, 13, 80, 135, 153, 111, 211, 149, 50, 68, 245,
+        197, 56, 36, 218, 249, 99, 228, 230, 158, 66, 188, 120, 157, 211, 35,
+        38, 218, 71, 218, 254, 168, 116, 190, 251, 81, 62, 54, 13, 37, 153,
+        179, 72, 42, 237,
+      ]
+    ),
+    // A shorter tolerance may reject the member voter weights
+    voterWeightExpiryTolerance: new BN(2),
+    error: 'RootConfigChangeMustBeQueued',
+  },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '4RxLrffka4jG1LnHgaHcQc5LULSHLKX6mjsY8ZT7Wry1'
+      ),
+      realmAddress: new PublicKey(
+        'Bos4XNKiiirgT1oH3vwASx5f5kMewWjXnmyXQXgJY1LY'
+      ),
+      authority: new PublicKey('5ht8kpG2tEB5C4T1C8NSGjDuNLFnSey2b8cd1FHd5H5x'),
+      communityMint: new PublicKey(
+        '57xsdDLe1J5icZ6Exgj98hQZD7eFCtsjSTXygQRhgVeP'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(32),
+      },
+      minCommunityWeightToCreateGovernance: new BN(4),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        '2dwEUv3MyULkCocJnCWQc3NFLiuwdsMDNEDqdLiD6LZL'
+      ),
+    },
+    root: {
+      side: 'community',
+      admin: new PublicKey('2nGBB9qontpnfkR9CsS6j3RqAikqxguiE2sgmBcXB5LF'),
+      memberCount: new BN(3),
+      staleMemberGracePeriod: new BN(86400),
+    },
+    admin: buildKeypair(
+      '2nGBB9qontpnfkR9CsS6j3RqAikqxguiE2sgmBcXB5LF',
+      [
+        76, 114, 175, 140, 157, 202, 205, 97, 16, 113, 60, 162, 51, 165, 92,
+        189, 79, 36, 132, 200, 105, 28, 65, 5, 75, 179, 23, 122, 126, 54, 94,
+        135, 26, 115, 251, 116, 190, 124, 140, 107, 166, 45, 169, 219, 247,
+        117, 21, 232, 31, 171, 186, 80, 85, 105, 231, 124, 57, 251, 115, 179,
+        6, 133, 190, 188,
+      ]
+    ),
+    // A shorter grace period may evict the members earlier
+    staleMemberGracePeriod: new BN(3600),
+    error: 'RootConfigChangeMustBeQueued',
+  },
 ];
@@ -1,2 +1,5 @@
 export * from './createRoot';
 export * from './configureRoot';
+export * from './rootConfig';
+export * from './verifyRoot';
+export * from './checkPluginConfig';
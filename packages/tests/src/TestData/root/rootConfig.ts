@@ -0,0 +1,160 @@
+import {Keypair, PublicKey} from '@solana/web3.js';
+import {RealmTestData} from '../../SplGovernance/realm';
+import {BN} from '@coral-xyz/anchor';
+import {RootTestData} from '../../VoteAggregator/root';
+import {RootConfigChange} from '../../VoteAggregator/accounts';
+import {buildKeypair} from '../..';
+
+export type RootConfigTestData = {
+  realm: RealmTestData;
+  root: RootTestData;
+  change: RootConfigChange;
+  // Signs instead of the realm authority
+  admin?: Keypair;
+  error?: string;
+};
+
+const emptyChange: RootConfigChange = {
+  maxProposalLifetime: null,
+  votingWeightPlugin: null,
+  voterWeightReset: null,
+  memberLockDuration: null,
+  membershipWarmup: null,
+  maxLoyaltyBonusBp: null,
+  weightTransform: null,
+  recallThresholdBp: null,
+  voterWeightExpiryTolerance: null,
+  staleMemberGracePeriod: null,
+  windDown: false,
+};
+
+export const rootConfigTestData: RootConfigTestData[] = [
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '2dxEdjE5RqZCA2iLLmzBzCvX3DVpdMje43UTL227BDJa'
+      ),
+      realmAddress: new PublicKey(
+        'DyeAKcGEx5EafXRU3wb5MVfdfJdgHw9GZPZTFGzBhetU'
+      ),
+      authority: buildKeypair(
+        '3EAEB34QVysJN2hELEzh6SWYKx9BxTG23CtyciUFi5pA',
+        [
+          79, 187, 23, 3, 201, 163, 206, 67, 240, 189, 90, 254, 122, 219, 250,
+          131, 22, 87, 204, 59, 145, 82, 117, 228, 116, 96, 64, 33, 84, 251, 44,
+          119, 33, 22, 91, 133, 195, 69, 52, 110, 41, 39, 11, 32, 220, 44, 157,
+          163, 77, 184, 78, 26, 17, 21, 77, 197, 119, 31, 48, 36, 38, 77, 48,
+          39,
+        ]
+      ),
+      communityMint: new PublicKey(
+        '4ufPiRQCz5wcrMW1WcxVv5znRoCsNP5kJ22pgP2RBUji'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(32),
+      },
+      minCommunityWeightToCreateGovernance: new BN(4),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        '92ksZcAMJbCLKjMGVuEMtt3PJ2d5uEBiELvTwUEvW6QF'
+      ),
+    },
+    root: {
+      side: 'community',
+      // Activates right away
+      maxProposalLifetime: new BN(0),
+      memberCount: new BN(3),
+    },
+    change: {
+      ...emptyChange,
+      membershipWarmup: new BN(3600),
+      maxLoyaltyBonusBp: 500,
+      weightTransform: {squareRoot: {}},
+      recallThresholdBp: 6000,
+      voterWeightExpiryTolerance: new BN(10),
+      staleMemberGracePeriod: new BN(86400),
+    },
+  },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'GrWhdBn447Cx5iobm3e1sJzaqmPZ655cbuvkeM6WurNh'
+      ),
+      realmAddress: new PublicKey(
+        '7hqvXrb1mGAqwRFMkscGApjr6WSg9KccspcMk6rRpBXQ'
+      ),
+      authority: buildKeypair(
+        '5YGbALzbpL1Fb7VVqPQ39Qqzgc5sQ4g5GFwadpehgRB7',
+        [
+          125, 9, 21, 81, 81, 189, 164, 219, 167, 3, 254, 197, 48, 218, 2, 62,
+          163, 88, 131, 216, 207, 82, 32, 2, 207, 183, 163, 10, 28, 248, 87, 58,
+          67, 113, 97, 26, 84, 20, 205, 120, 101, 173, 152, 177, 53, 201, 113,
+          234, 102, 116, 104, 137, 1, 240, 54, 44, 100, 56, 165, 110, 103, 12,
+          209, 34,
+        ]
+      ),
+      communityMint: new PublicKey(
+        'CbsZ1RDPDVb7FZeivZdBqGHsRSE4rcN1N7BqzQJ44tMT'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(32),
+      },
+      minCommunityWeightToCreateGovernance: new BN(4),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        '5e6tYC22164mbWW7Pm9uUJERviTUUVBfWiaocGx13pfx'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(0),
+      memberCount: new BN(3),
+    },
+    change: emptyChange,
+    error: 'EmptyRootConfigChange',
+  },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'Eg5gqphaLHWTZALSvqu5v8M9nc9XQPzCJ3d9ZUtVRqtq'
+      ),
+      realmAddress: new PublicKey(
+        'ByW8irSpWn4aBwWG5c576iDJjrxbkWeqjKQr851aeV2f'
+      ),
+      authority: new PublicKey('C1kzRwEzM6Wk31uYNh3BauK5MnXgu5dB3Zhu2XHsfH8h'),
+      communityMint: new PublicKey(
+        'CamWw3nyuLXQstxwP9AiNw84xkrsHA6RrvxPFFzWxxh9'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(32),
+      },
+      minCommunityWeightToCreateGovernance: new BN(4),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        '5e6tYC22164mbWW7Pm9uUJERviTUUVBfWiaocGx13pfx'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(0),
+      memberCount: new BN(3),
+      admin: new PublicKey('DfhKmnm2DVN5YFGRqBF6vDgMuyVPrHZfwZbzSXfG63Rh'),
+    },
+    // The root admin can not change the voting rules
+    change: {
+      ...emptyChange,
+      recallThresholdBp: 6000,
+    },
+    admin: buildKeypair(
+      'DfhKmnm2DVN5YFGRqBF6vDgMuyVPrHZfwZbzSXfG63Rh',
+      [
+        135, 205, 141, 29, 94, 184, 112, 25, 126, 233, 125, 47, 39, 128, 127,
+        143, 63, 210, 108, 18, 166, 177, 188, 163, 182, 108, 181, 57, 11, 0,
+        191, 117, 188, 53, 232, 112, 146, 22, 188, 249, 62, 199, 117, 207, 37,
+        114, 88, 18, 102, 13, 6, 20, 138, 45, 50, 206, 57, 65, 86, 255, 71, 2,
+        27, 88,
+      ]
+    ),
+    error: 'WrongRealmAuthority',
+  },
+];
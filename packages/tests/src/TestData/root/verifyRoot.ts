@@ -0,0 +1,108 @@
+import {PublicKey} from '@solana/web3.js';
+import {RealmTestData} from '../../SplGovernance/realm';
+import {BN} from '@coral-xyz/anchor';
+import {RootTestData} from '../../VoteAggregator/root';
+
+export type VerifyRootTestData = {
+  realm: RealmTestData;
+  root: RootTestData;
+  error?: string;
+};
+
+const voteAggregatorId = new PublicKey(
+  'VoTaGDreyne7jk59uwbgRRbaAzxvNbyNipaJMrRXhjT'
+);
+
+export const verifyRootTestData: VerifyRootTestData[] = [
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        'C79JpgzabQcrHb83w7tFkXSE8KxkRg5UFtjMATvSXXes'
+      ),
+      realmAddress: new PublicKey(
+        '24htPMQWVVKRK45B5VEZEwztSeUBV7QyekrCmxCJKzYG'
+      ),
+      communityMint: new PublicKey(
+        '5LByXvaEekgMMnduiNWKVz5C9iu4p2jXYjrBib6Xv5V5'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, switched to the aggregator',
+      communityMintAuthority: new PublicKey(
+        '9Nw6dz9aUSWjLqs77Uvw7JdzYvkFgivvgjrxEcX7i2sk'
+      ),
+      communityTokenConfig: {
+        voterWeightAddin: voteAggregatorId,
+        maxVoterWeightAddin: voteAggregatorId,
+      },
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      installed: false,
+    },
+  },
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '3oK1NvULNqvmjKXB5uev1ipyUtySCAV1rwisqM18dyej'
+      ),
+      realmAddress: new PublicKey(
+        '67DJMCcXvf3UyYRtxfe1gDYWsqh7f9YRJtX6rsxXJMKJ'
+      ),
+      communityMint: new PublicKey(
+        'HPdwGXWi5pm7xdeJ7oNC8T4d49wSUoFg9cgFqddTqqMj'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, no plugins',
+      communityMintAuthority: new PublicKey(
+        'CW93j5LxSHqiMcJ6xQwrYKWEoQ7Y4BZCW1nsMHQ5XLa6'
+      ),
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      installed: false,
+    },
+    error: 'EmptyCommunityVoterWeightAddin',
+  },
+  // The voting weight plugin was changed since the last verification
+  {
+    realm: {
+      splGovernanceId: new PublicKey(
+        '4t5hK47tZxLvNWv7fRqJvfcc6jhNWuzqUohnsgFPE7zJ'
+      ),
+      realmAddress: new PublicKey(
+        '72rN3PDgNF1ZM7ws7saHPdFugrayM7rv9Xz6E5LUeJod'
+      ),
+      communityMint: new PublicKey(
+        'Cye4jRp8ho2AsBjrtKatxPNmLtfkRJaDmg1uDkszoDXq'
+      ),
+      communityMintMaxVoterWeightSource: {
+        supplyFraction: new BN(25),
+      },
+      minCommunityWeightToCreateGovernance: new BN(6),
+      name: 'Community side, switched to the aggregator',
+      communityMintAuthority: new PublicKey(
+        'BL75ZuRgse3QMgdSAmYaHXJE7LA7ZTU5wXSmviNB9Gdz'
+      ),
+      communityTokenConfig: {
+        voterWeightAddin: voteAggregatorId,
+        maxVoterWeightAddin: voteAggregatorId,
+      },
+    },
+    root: {
+      side: 'community',
+      maxProposalLifetime: new BN(172800),
+      votingWeightPlugin: new PublicKey(
+        '5N4Zy57Qe3AWTfebUMvGiPKjJWkbBZvEMdswEhhkZEQn'
+      ),
+      verifiedVotingWeightPlugin: PublicKey.default,
+    },
+  },
+];
@@ -7,6 +7,22 @@ export type RootAccount = IdlAccounts<VoteAggregator>['root'];
 export type ClanAccount = IdlAccounts<VoteAggregator>['clan'];
 export type MemberAccount = IdlAccounts<VoteAggregator>['member'];
 export type MembershipEntry = IdlTypes<VoteAggregator>['MembershipEntry'];
+export type VoterWeightDecay = IdlTypes<VoteAggregator>['VoterWeightDecay'];
+export type VoterWeightDecayEnd =
+  IdlTypes<VoteAggregator>['VoterWeightDecayEnd'];
+export type WarmupEnd = IdlTypes<VoteAggregator>['WarmupEnd'];
+export type LoyaltyCurve = IdlTypes<VoteAggregator>['LoyaltyCurve'];
+export type WeightTransform = IdlTypes<VoteAggregator>['WeightTransform'];
+export type TreasuryPolicy = IdlTypes<VoteAggregator>['TreasuryPolicy'];
+export type RootConfigChange = IdlTypes<VoteAggregator>['RootConfigChange'];
+export type ClanVoteHistoryAccount =
+  IdlAccounts<VoteAggregator>['clanVoteHistory'];
+export type ClanVoteHistoryEntry =
+  IdlTypes<VoteAggregator>['ClanVoteHistoryEntry'];
+export type ClanWeightCheckpointsAccount =
+  IdlAccounts<VoteAggregator>['clanWeightCheckpoints'];
+export type ClanWeightCheckpoint =
+  IdlTypes<VoteAggregator>['ClanWeightCheckpoint'];
 export type VoterWeightRecordAccount =
   IdlAccounts<VoteAggregator>['voterWeightRecord'];
 export type MaxVoterWeightRecordAccount =
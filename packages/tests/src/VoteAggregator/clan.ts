@@ -1,12 +1,25 @@
-import {Keypair, PublicKey} from '@solana/web3.js';
+import {Keypair, PublicKey, SystemProgram} from '@solana/web3.js';
 import {getMinimumBalanceForRentExemption} from '../utils';
 import {BN} from '@coral-xyz/anchor';
-import {ClanAccount, VoterWeightRecordAccount} from './accounts';
+import {
+  ClanAccount,
+  ClanVoteHistoryAccount,
+  ClanVoteHistoryEntry,
+  ClanWeightCheckpoint,
+  ClanWeightCheckpointsAccount,
+  LoyaltyCurve,
+  VoterWeightDecayEnd,
+  TreasuryPolicy,
+  VoterWeightRecordAccount,
+  WarmupEnd,
+  WeightTransform,
+} from './accounts';
 import {RootTester} from './root';
 import {AddedAccount} from 'solana-bankrun';
 import {buildVoteAggregatorProgram} from './program';
 import {buildSplGovernanceProgram} from '../SplGovernance/program';
 import {TokenOwnerRecordAccount} from '../SplGovernance/accounts';
+import {tokenAccount} from '../splToken';
 
 export type ClanTestData = {
   address: PublicKey;
@@ -21,22 +34,79 @@ export type ClanTestData = {
   name: string;
   description?: string;
   voterWeight?: BN;
+  voterWeightExpiry?: BN | null;
   acceptTemporaryMembers?: boolean;
   permanentVoterWeight?: BN;
   nextVoterWeightResetTime?: BN | null;
+  rescheduledVoterWeightResetTime?: BN | null;
+  decayingMembers?: BN;
+  decayingVoterWeight?: BN;
+  voterWeightDecayRateBp?: BN;
+  voterWeightDecayUpdatedAt?: BN;
+  voterWeightDecayEnds?: VoterWeightDecayEnd[];
+  frozen?: boolean;
+  sibling?: PublicKey | null;
+  rewardMint?: PublicKey | null;
+  rewardWeight?: BN;
+  rewardPerWeight?: BN;
+  commissionBp?: number;
+  pendingCommission?: {commissionBp: number; effectiveAt: BN} | null;
+  unclaimedCommission?: BN;
+  previousCommissionBp?: number;
+  commissionChangedRewardPerWeight?: BN;
+  voteHistory?: PublicKey | null;
+  vetoVotesCast?: BN;
+  voteUpdates?: BN;
+  lastVotedAt?: BN | null;
+  warmingUpVoterWeight?: BN;
+  warmupEnds?: WarmupEnd[];
+  loyaltyCurve?: LoyaltyCurve | null;
+  weightTransform?: WeightTransform;
+  weightTransformEpoch?: BN;
+  unmigratedMembers?: BN;
+  weightCheckpoints?: PublicKey | null;
+  snapshotVoting?: boolean;
+  treasuryPolicy?: TreasuryPolicy | null;
+  pendingTreasuryPolicy?: {
+    policy: TreasuryPolicy | null;
+    effectiveAt: BN;
+  } | null;
+  treasurySpent?: BN;
+  treasuryPeriodStart?: BN;
+  treasuryWithdrawals?: BN;
+  recallProposals?: BN;
+  controlChangedAt?: BN | null;
   governingTokenDepositAmount?: BN;
   unrelinquishedVotesCount?: BN;
   outstandingProposalCount?: number;
   governanceDelegate?: PublicKey | null;
+  rewardVaultAmount?: BN;
+  // Lamports of the treasury
+  treasuryAmount?: BN;
+  // Creates the vote history with these entries when given
+  voteHistoryEntries?: ClanVoteHistoryEntry[];
+  voteHistoryNextIndex?: number;
+  // Creates the weight checkpoints with these entries when given
+  weightCheckpointEntries?: ClanWeightCheckpoint[];
+  weightCheckpointsNextIndex?: number;
 };
 
 export class ClanTester {
+  // ClanVoteHistory::SPACE for the 32 entries
+  static readonly VOTE_HISTORY_SPACE = 8 + 64 + 32 * 64;
+  // ClanWeightCheckpoints::SPACE for the 64 checkpoints
+  static readonly WEIGHT_CHECKPOINTS_SPACE = 8 + 64 + 64 * 16;
+
   public owner?: Keypair;
   public delegate?: Keypair;
   public root: RootTester;
   public clanAddress: PublicKey;
   public clan: ClanAccount;
   public clanSize?: number;
+  public rewardVaultAmount: BN;
+  public treasuryAmount: BN;
+  public voteHistory?: ClanVoteHistoryAccount;
+  public weightCheckpoints?: ClanWeightCheckpointsAccount;
   public tokenOwnerRecord: TokenOwnerRecordAccount;
   public voterWeightRecord: VoterWeightRecordAccount;
 
@@ -54,6 +124,37 @@ export class ClanTester {
     );
   }
 
+  get rewardVaultAddress(): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from('reward-vault', 'utf-8'), this.clanAddress.toBuffer()],
+      this.root.voteAggregatorId
+    );
+  }
+
+  get treasuryAddress(): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from('treasury', 'utf-8'), this.clanAddress.toBuffer()],
+      this.root.voteAggregatorId
+    );
+  }
+
+  get voteHistoryAddress(): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from('vote-history', 'utf-8'), this.clanAddress.toBuffer()],
+      this.root.voteAggregatorId
+    );
+  }
+
+  get weightCheckpointsAddress(): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('weight-checkpoints', 'utf-8'),
+        this.clanAddress.toBuffer(),
+      ],
+      this.root.voteAggregatorId
+    );
+  }
+
   get tokenOwnerRecordAddress(): [PublicKey, number] {
     return PublicKey.findProgramAddressSync(
       [
@@ -119,6 +220,41 @@ export class ClanTester {
     acceptTemporaryMembers = true,
     permanentVoterWeight = new BN(0),
     nextVoterWeightResetTime = null,
+    rescheduledVoterWeightResetTime = null,
+    decayingMembers = new BN(0),
+    decayingVoterWeight = new BN(0),
+    voterWeightDecayRateBp = new BN(0),
+    voterWeightDecayUpdatedAt = new BN(0),
+    voterWeightDecayEnds = [],
+    frozen = false,
+    sibling = null,
+    rewardMint = null,
+    rewardWeight = new BN(0),
+    rewardPerWeight = new BN(0),
+    commissionBp = 0,
+    pendingCommission = null,
+    unclaimedCommission = new BN(0),
+    previousCommissionBp = 0,
+    commissionChangedRewardPerWeight = new BN(0),
+    voteHistory = null,
+    vetoVotesCast = new BN(0),
+    voteUpdates = new BN(0),
+    lastVotedAt = null,
+    warmingUpVoterWeight = new BN(0),
+    warmupEnds = [],
+    loyaltyCurve = null,
+    weightTransform = {linear: {}},
+    weightTransformEpoch = new BN(0),
+    unmigratedMembers = new BN(0),
+    weightCheckpoints = null,
+    snapshotVoting = false,
+    treasuryPolicy = null,
+    pendingTreasuryPolicy = null,
+    treasurySpent = new BN(0),
+    treasuryPeriodStart = new BN(0),
+    treasuryWithdrawals = new BN(0),
+    recallProposals = new BN(0),
+    controlChangedAt = null,
     name,
     description = '',
   }: ClanTestData & {root: PublicKey}): ClanAccount {
@@ -136,10 +272,47 @@ export class ClanTester {
       acceptTemporaryMembers,
       permanentVoterWeight,
       nextVoterWeightResetTime,
+      rescheduledVoterWeightResetTime,
+      decayingMembers,
+      decayingVoterWeight,
+      voterWeightDecayRateBp,
+      voterWeightDecayUpdatedAt,
+      voterWeightDecayEnds,
+      frozen,
+      sibling,
+      rewardMint,
+      rewardWeight,
+      rewardPerWeight,
+      commissionBp,
+      pendingCommission,
+      unclaimedCommission,
+      previousCommissionBp,
+      commissionChangedRewardPerWeight,
+      voteHistory,
+      vetoVotesCast,
+      voteUpdates,
+      lastVotedAt,
+      warmingUpVoterWeight,
+      warmupEnds,
+      loyaltyCurve,
+      weightTransform,
+      weightTransformEpoch,
+      unmigratedMembers,
+      weightCheckpoints,
+      snapshotVoting,
+      treasuryPolicy,
+      pendingTreasuryPolicy,
+      treasurySpent,
+      treasuryPeriodStart,
+      treasuryWithdrawals,
+      recallProposals,
+      controlChangedAt,
       bumps: {
         voterAuthority: 0,
         tokenOwnerRecord: 0,
         voterWeightRecord: 0,
+        rewardVault: 0,
+        treasury: 0,
       },
       permanentMembers,
       temporaryMembers,
@@ -164,16 +337,60 @@ export class ClanTester {
     name,
     description = '',
     voterWeight = new BN(0),
+    voterWeightExpiry = null,
     acceptTemporaryMembers = true,
     permanentVoterWeight = new BN(0),
     nextVoterWeightResetTime = null,
+    rescheduledVoterWeightResetTime = null,
+    decayingMembers = new BN(0),
+    decayingVoterWeight = new BN(0),
+    voterWeightDecayRateBp = new BN(0),
+    voterWeightDecayUpdatedAt = new BN(0),
+    voterWeightDecayEnds = [],
+    frozen = false,
+    sibling = null,
+    rewardMint = null,
+    rewardWeight = new BN(0),
+    rewardPerWeight = new BN(0),
+    commissionBp = 0,
+    pendingCommission = null,
+    unclaimedCommission = new BN(0),
+    previousCommissionBp = 0,
+    commissionChangedRewardPerWeight = new BN(0),
+    voteHistory = null,
+    vetoVotesCast = new BN(0),
+    voteUpdates = new BN(0),
+    lastVotedAt = null,
+    warmingUpVoterWeight = new BN(0),
+    warmupEnds = [],
+    loyaltyCurve = null,
+    weightTransform = {linear: {}},
+    weightTransformEpoch = new BN(0),
+    unmigratedMembers = new BN(0),
+    weightCheckpoints = null,
+    snapshotVoting = false,
+    treasuryPolicy = null,
+    pendingTreasuryPolicy = null,
+    treasurySpent = new BN(0),
+    treasuryPeriodStart = new BN(0),
+    treasuryWithdrawals = new BN(0),
+    recallProposals = new BN(0),
+    controlChangedAt = null,
     governingTokenDepositAmount = new BN(0),
     unrelinquishedVotesCount = new BN(0),
     outstandingProposalCount = 0,
     governanceDelegate = null,
+    rewardVaultAmount = new BN(0),
+    treasuryAmount = new BN(0),
+    voteHistoryEntries,
+    voteHistoryNextIndex,
+    weightCheckpointEntries,
+    weightCheckpointsNextIndex,
   }: ClanTestData & {root: RootTester}) {
     this.clanAddress = address;
     this.clanSize = size;
+    this.rewardVaultAmount = rewardVaultAmount;
+    this.treasuryAmount = treasuryAmount;
     if (owner instanceof Keypair) {
       this.owner = owner;
       owner = owner.publicKey;
@@ -207,6 +424,46 @@ export class ClanTester {
         root.voteAggregatorId
       );
 
+    const [, treasuryBump] = PublicKey.findProgramAddressSync(
+      [Buffer.from('treasury', 'utf-8'), address.toBuffer()],
+      root.voteAggregatorId
+    );
+
+    const [, rewardVaultBump] = PublicKey.findProgramAddressSync(
+      [Buffer.from('reward-vault', 'utf-8'), address.toBuffer()],
+      root.voteAggregatorId
+    );
+
+    if (voteHistoryEntries) {
+      const [voteHistoryAddress, voteHistoryBump] =
+        PublicKey.findProgramAddressSync(
+          [Buffer.from('vote-history', 'utf-8'), address.toBuffer()],
+          root.voteAggregatorId
+        );
+      this.voteHistory = {
+        clan: address,
+        nextIndex: voteHistoryNextIndex ?? voteHistoryEntries.length,
+        entries: voteHistoryEntries,
+        bump: voteHistoryBump,
+      };
+      voteHistory = voteHistoryAddress;
+    }
+
+    if (weightCheckpointEntries) {
+      const [weightCheckpointsAddress, weightCheckpointsBump] =
+        PublicKey.findProgramAddressSync(
+          [Buffer.from('weight-checkpoints', 'utf-8'), address.toBuffer()],
+          root.voteAggregatorId
+        );
+      this.weightCheckpoints = {
+        clan: address,
+        nextIndex: weightCheckpointsNextIndex ?? weightCheckpointEntries.length,
+        checkpoints: weightCheckpointEntries,
+        bump: weightCheckpointsBump,
+      };
+      weightCheckpoints = weightCheckpointsAddress;
+    }
+
     this.clan = {
       root: root.rootAddress[0],
       owner,
@@ -218,10 +475,47 @@ export class ClanTester {
       acceptTemporaryMembers,
       permanentVoterWeight,
       nextVoterWeightResetTime,
+      rescheduledVoterWeightResetTime,
+      decayingMembers,
+      decayingVoterWeight,
+      voterWeightDecayRateBp,
+      voterWeightDecayUpdatedAt,
+      voterWeightDecayEnds,
+      frozen,
+      sibling,
+      rewardMint,
+      rewardWeight,
+      rewardPerWeight,
+      commissionBp,
+      pendingCommission,
+      unclaimedCommission,
+      previousCommissionBp,
+      commissionChangedRewardPerWeight,
+      voteHistory,
+      vetoVotesCast,
+      voteUpdates,
+      lastVotedAt,
+      warmingUpVoterWeight,
+      warmupEnds,
+      loyaltyCurve,
+      weightTransform,
+      weightTransformEpoch,
+      unmigratedMembers,
+      weightCheckpoints,
+      snapshotVoting,
+      treasuryPolicy,
+      pendingTreasuryPolicy,
+      treasurySpent,
+      treasuryPeriodStart,
+      treasuryWithdrawals,
+      recallProposals,
+      controlChangedAt,
       bumps: {
         voterAuthority: voterAuthorityBump,
         tokenOwnerRecord: tokenOwnerRecordBump,
         voterWeightRecord: voterWeightRecordBump,
+        rewardVault: rewardMint ? rewardVaultBump : 0,
+        treasury: treasuryBump,
       },
       permanentMembers,
       temporaryMembers,
@@ -236,7 +530,7 @@ export class ClanTester {
       governingTokenMint: root.governingTokenMint,
       governingTokenOwner: this.voterAuthority[0],
       voterWeight,
-      voterWeightExpiry: null,
+      voterWeightExpiry,
       weightAction: null,
       weightActionTarget: null,
       reserved: [0, 0, 0, 0, 0, 0, 0, 0],
@@ -316,6 +610,75 @@ export class ClanTester {
       });
     }
 
+    if (this.clan.rewardMint) {
+      accounts.push(
+        tokenAccount({
+          address: this.rewardVaultAddress[0],
+          mint: this.clan.rewardMint,
+          owner: this.voterAuthority[0],
+          amount: this.rewardVaultAmount,
+        })
+      );
+    }
+
+    if (this.voteHistory) {
+      const voteHistoryData =
+        await program.coder.accounts.encode<ClanVoteHistoryAccount>(
+          'clanVoteHistory',
+          this.voteHistory
+        );
+      // Room for the entries up to the capacity
+      const data = Buffer.concat([
+        voteHistoryData,
+        Buffer.alloc(ClanTester.VOTE_HISTORY_SPACE - voteHistoryData.length),
+      ]);
+      accounts.push({
+        address: this.voteHistoryAddress[0],
+        info: {
+          executable: false,
+          owner: this.root.voteAggregatorId,
+          lamports: getMinimumBalanceForRentExemption(data.length),
+          data,
+        },
+      });
+    }
+
+    if (this.weightCheckpoints) {
+      const weightCheckpointsData =
+        await program.coder.accounts.encode<ClanWeightCheckpointsAccount>(
+          'clanWeightCheckpoints',
+          this.weightCheckpoints
+        );
+      // Room for the checkpoints up to the capacity
+      const data = Buffer.concat([
+        weightCheckpointsData,
+        Buffer.alloc(
+          ClanTester.WEIGHT_CHECKPOINTS_SPACE - weightCheckpointsData.length
+        ),
+      ]);
+      accounts.push({
+        address: this.weightCheckpointsAddress[0],
+        info: {
+          executable: false,
+          owner: this.root.voteAggregatorId,
+          lamports: getMinimumBalanceForRentExemption(data.length),
+          data,
+        },
+      });
+    }
+
+    if (!this.treasuryAmount.isZero()) {
+      accounts.push({
+        address: this.treasuryAddress[0],
+        info: {
+          executable: false,
+          owner: SystemProgram.programId,
+          lamports: this.treasuryAmount.toNumber(),
+          data: Buffer.alloc(0),
+        },
+      });
+    }
+
     return accounts;
   }
 }
@@ -1,7 +1,12 @@
 import {Keypair, PublicKey} from '@solana/web3.js';
 import {getMinimumBalanceForRentExemption} from '../utils';
 import {BN} from '@coral-xyz/anchor';
-import {MemberAccount} from './accounts';
+import {
+  MemberAccount,
+  VoterWeightDecay,
+  WarmupEnd,
+  WeightTransform,
+} from './accounts';
 import {RootTester} from './root';
 import {AddedAccount} from 'solana-bankrun';
 import {buildVoteAggregatorProgram} from './program';
@@ -16,21 +21,45 @@ export type MembershipTester = {
   clan: PublicKey | ClanTester;
   shareBp: number;
   exitableAt?: BN | null;
+  rewardWeight?: BN;
+  rewardPerWeightPaid?: BN;
+  unclaimedRewards?: BN;
+  warmupEnd?: BN | null;
+  warmupShareBp?: number;
+  heldWarmup?: WarmupEnd | null;
+  joinedAt?: BN;
+  loyaltyBonusBp?: number;
+  weightTransform?: WeightTransform;
+  weightTransformEpoch?: BN;
 };
 
 export type MembershipTestData = {
   clan: PublicKey | ClanTestData;
   shareBp: number;
   exitableAt?: BN | null;
+  rewardWeight?: BN;
+  rewardPerWeightPaid?: BN;
+  unclaimedRewards?: BN;
+  warmupEnd?: BN | null;
+  warmupShareBp?: number;
+  heldWarmup?: WarmupEnd | null;
+  joinedAt?: BN;
+  loyaltyBonusBp?: number;
+  weightTransform?: WeightTransform;
+  weightTransformEpoch?: BN;
 };
 
 export type MemberTestData = {
   owner: PublicKey | Keypair;
+  size?: number;
   delegate?: PublicKey | Keypair;
   voterWeightRecord?: PublicKey;
   voterWeight?: BN;
   voterWeightExpiry?: BN | null;
   nextVoterWeightResetTime?: BN | null;
+  voterWeightDecay?: VoterWeightDecay | null;
+  transformedVoterWeight?: BN;
+  loyaltyBonusWeight?: BN;
   membership?: MembershipTestData[];
   governingTokenDepositAmount?: BN;
   unrelinquishedVotesCount?: BN;
@@ -46,6 +75,7 @@ export class MemberTester {
   public member: MemberAccount;
   public membership: MembershipTester[];
   public tokenOwnerRecord: TokenOwnerRecordAccount;
+  public memberSize?: number;
 
   get ownerAddress(): PublicKey {
     return this.owner instanceof Keypair ? this.owner.publicKey : this.owner;
@@ -81,21 +111,24 @@ export class MemberTester {
     membership: MembershipTestData[];
     root: RootTester;
   }) {
-    return membership.map(({clan, shareBp, exitableAt}) => ({
+    return membership.map(({clan, ...entry}) => ({
+      ...entry,
       clan: clan instanceof PublicKey ? clan : new ClanTester({...clan, root}),
-      shareBp,
-      exitableAt,
     }));
   }
 
   constructor({
     owner,
+    size,
     delegate,
     root,
     voterWeightRecord = PublicKey.default,
     voterWeight = new BN(0),
     voterWeightExpiry = null,
     nextVoterWeightResetTime = null,
+    voterWeightDecay = null,
+    transformedVoterWeight = voterWeight,
+    loyaltyBonusWeight = new BN(0),
     membership = [],
     governingTokenDepositAmount = new BN(0),
     unrelinquishedVotesCount = new BN(0),
@@ -107,16 +140,43 @@ export class MemberTester {
     membership?: MembershipTester[];
   }) {
     this.owner = owner;
+    this.memberSize = size;
     if (delegate instanceof Keypair) {
       this.delegate = delegate;
       delegate = delegate.publicKey;
     }
     this.membership = membership;
-    const membershipData = membership.map(({clan, shareBp, exitableAt}) => ({
-      clan: clan instanceof ClanTester ? clan.clanAddress : clan,
-      shareBp,
-      exitableAt: exitableAt || null,
-    }));
+    const membershipData = membership.map(
+      ({
+        clan,
+        shareBp,
+        exitableAt,
+        rewardWeight = new BN(0),
+        rewardPerWeightPaid = new BN(0),
+        unclaimedRewards = new BN(0),
+        warmupEnd = null,
+        warmupShareBp = 0,
+        heldWarmup = null,
+        joinedAt = new BN(0),
+        loyaltyBonusBp = 0,
+        weightTransform = {linear: {}},
+        weightTransformEpoch = new BN(0),
+      }) => ({
+        clan: clan instanceof ClanTester ? clan.clanAddress : clan,
+        shareBp,
+        exitableAt: exitableAt || null,
+        rewardWeight,
+        rewardPerWeightPaid,
+        unclaimedRewards,
+        warmupEnd,
+        warmupShareBp,
+        heldWarmup,
+        joinedAt,
+        loyaltyBonusBp,
+        weightTransform,
+        weightTransformEpoch,
+      })
+    );
     this.root = root;
 
     const [, addressBump] = PublicKey.findProgramAddressSync(
@@ -148,6 +208,9 @@ export class MemberTester {
       tokenOwnerRecord,
       voterWeightRecord,
       nextVoterWeightResetTime,
+      voterWeightDecay,
+      transformedVoterWeight,
+      loyaltyBonusWeight,
       membership: membershipData,
       bumps: {
         address: addressBump,
@@ -184,7 +247,20 @@ export class MemberTester {
         'member',
         this.member
       );
-      memberData = Buffer.concat([memberData, Buffer.alloc(1024)]);
+      const size = this.memberSize;
+      if (size !== undefined) {
+        if (size < memberData.length) {
+          throw new Error(
+            `Member size ${size} is too small for ${memberData.length} bytes`
+          );
+        }
+        memberData = Buffer.concat([
+          memberData,
+          Buffer.alloc(size - memberData.length),
+        ]);
+      } else {
+        memberData = Buffer.concat([memberData, Buffer.alloc(1024)]);
+      }
 
       accounts.push({
         address: this.memberAddress[0],
@@ -3,6 +3,7 @@ import {
   MaxVoterWeightRecordAccount,
   RootAccount,
   VoterWeightReset,
+  WeightTransform,
 } from './accounts';
 import {RealmTester} from '../SplGovernance/realm';
 import {BN} from '@coral-xyz/anchor';
@@ -17,7 +18,21 @@ export type RootTestData = {
   maxVoterWeight?: BN;
   maxProposalLifetime?: BN;
   voterWeightReset?: VoterWeightReset | null;
-  paused?: boolean;
+  pauseFlags?: number;
+  voterWeightExpiryTolerance?: BN;
+  staleMemberGracePeriod?: BN | null;
+  admin?: PublicKey;
+  pendingAdmin?: PublicKey | null;
+  pauseGuardian?: PublicKey | null;
+  windDown?: boolean;
+  memberLockDuration?: BN;
+  installed?: boolean;
+  pluginDrift?: boolean;
+  verifiedVotingWeightPlugin?: PublicKey;
+  membershipWarmup?: BN;
+  maxLoyaltyBonusBp?: number;
+  weightTransform?: WeightTransform;
+  recallThresholdBp?: number;
   clanCount?: BN;
   memberCount?: BN;
 };
@@ -79,7 +94,21 @@ export class RootTester {
     maxVoterWeight = new BN(0),
     maxProposalLifetime = new BN(0),
     voterWeightReset = null,
-    paused = false,
+    pauseFlags = 0,
+    voterWeightExpiryTolerance = new BN(0),
+    staleMemberGracePeriod = null,
+    admin,
+    pendingAdmin = null,
+    pauseGuardian = null,
+    windDown = false,
+    memberLockDuration = new BN(2592000),
+    installed = true,
+    pluginDrift = false,
+    verifiedVotingWeightPlugin = votingWeightPlugin,
+    membershipWarmup = new BN(0),
+    maxLoyaltyBonusBp = 0,
+    weightTransform = {linear: {}},
+    recallThresholdBp = 0,
     clanCount = new BN(0),
     memberCount = new BN(0),
   }: RootTestData & {realm: RealmTester}) {
@@ -100,7 +129,21 @@ export class RootTester {
       maxProposalLifetime,
       votingWeightPlugin,
       voterWeightReset,
-      paused,
+      pauseFlags,
+      voterWeightExpiryTolerance,
+      staleMemberGracePeriod,
+      admin: admin || realm.authorityAddress || PublicKey.default,
+      pendingAdmin,
+      pauseGuardian,
+      windDown,
+      memberLockDuration,
+      installed,
+      pluginDrift,
+      verifiedVotingWeightPlugin,
+      membershipWarmup,
+      maxLoyaltyBonusBp,
+      weightTransform,
+      recallThresholdBp,
       clanCount,
       memberCount,
       bumps: {
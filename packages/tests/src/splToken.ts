@@ -2,6 +2,15 @@ import {AnchorProvider, IdlAccounts} from '@coral-xyz/anchor';
 // eslint-disable-next-line node/no-extraneous-import
 import {splTokenProgram} from '@coral-xyz/spl-token';
 import {Connection, PublicKey} from '@solana/web3.js';
+import {
+  ACCOUNT_SIZE,
+  AccountLayout,
+  AccountState,
+  TOKEN_PROGRAM_ID,
+} from '@solana/spl-token';
+import BN from 'bn.js';
+import {AddedAccount} from 'solana-bankrun';
+import {getMinimumBalanceForRentExemption} from './utils';
 
 // for types only
 const splTokenIdl = () => splTokenProgram().idl;
@@ -21,3 +30,45 @@ export const buildSplTokenProgram = () =>
       {}
     ),
   });
+
+export const tokenAccount = ({
+  address,
+  mint,
+  owner,
+  amount,
+}: {
+  address: PublicKey;
+  mint: PublicKey;
+  owner: PublicKey;
+  amount: BN;
+}): AddedAccount => {
+  const data = Buffer.alloc(ACCOUNT_SIZE);
+  AccountLayout.encode(
+    {
+      mint,
+      owner,
+      amount: BigInt(amount.toString()),
+      delegateOption: 0,
+      delegate: PublicKey.default,
+      state: AccountState.Initialized,
+      isNativeOption: 0,
+      isNative: BigInt(0),
+      delegatedAmount: BigInt(0),
+      closeAuthorityOption: 0,
+      closeAuthority: PublicKey.default,
+    },
+    data
+  );
+  return {
+    address,
+    info: {
+      executable: false,
+      owner: TOKEN_PROGRAM_ID,
+      lamports: getMinimumBalanceForRentExemption(data.length),
+      data,
+    },
+  };
+};
+
+export const tokenAccountAmount = (data: Uint8Array) =>
+  new BN(AccountLayout.decode(data).amount.toString());
@@ -74,4 +74,64 @@ pub enum Error {
     Paused,
     MemberVwrRequired,
     ResetAllVoterWeightsFirst,
-}
\ No newline at end of file
+    InvalidVoterWeightDecay,
+    StaleMemberEvictionDisabled,
+    MemberIsNotStale,
+    TooEarlyToEvictMember,
+    WrongRootAdmin,
+    WrongPendingRootAdmin,
+    RootConfigChangeMustBeQueued,
+    RootConfigNotMatured,
+    EmptyRootConfigChange,
+    ClanFrozen,
+    WindingDown,
+    WindDownNotStarted,
+    MemberHasNoClans,
+    MemberLockStillRequired,
+    PluginConfigDrifted,
+    ClanAlreadyLinked,
+    WrongSiblingClan,
+    InvalidSiblingRoot,
+    InvalidProposalMint,
+    NoClanRewardWeight,
+    UnclaimedClanRewards,
+    ClanRewardVaultExists,
+    InvalidCommissionBp,
+    RootIsRequired,
+    ClanVoteHistoryRequired,
+    ClanVoteHistoryExists,
+    InvalidLoyaltyCurve,
+    InvalidWeightTransform,
+    WeightTransformNotMigrated,
+    ClanWeightCheckpointsRequired,
+    ClanWeightCheckpointsExist,
+    ClanWeightNotCheckpointed,
+    ClanSnapshotVoting,
+    ProposalVotingNotStarted,
+    InvalidTreasuryPolicy,
+    TreasuryPolicyRequired,
+    TreasuryWithdrawalRequired,
+    TreasuryWithdrawalNotApproved,
+    TreasuryWithdrawalExpired,
+    TreasuryWithdrawalExecuted,
+    TreasuryWithdrawalMismatch,
+    WrongTreasuryAccount,
+    InvalidRecallThreshold,
+    RecallDisabled,
+    EmptyRecallProposal,
+    RecallProposalExpired,
+    RecallProposalExecuted,
+    RecallProposalNotSupported,
+    MemberInWarmup,
+    #[msg("Clan account is full. Must resize the clan first")]
+    ClanResizeRequired,
+    RootAlreadyMigrated,
+    MemberStillLocked,
+    RootNotInstalled,
+    VotingWeightPluginRequired,
+    MemberAlreadyMigrated,
+    MemberTransformAlreadyMigrated,
+    InvalidRecallSignatures,
+    ClanAlreadyMigrated,
+    MemberLockAccountsRequired,
+}
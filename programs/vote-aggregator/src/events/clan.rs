@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::state::{LoyaltyCurve, TreasuryPolicy, WeightTransform};
+
 #[event]
 pub struct ClanCreated {
     pub clan: Pubkey,
@@ -13,6 +15,8 @@ pub struct ClanOwnerChanged {
     pub clan: Pubkey,
     pub old_owner: Pubkey,
     pub new_owner: Pubkey,
+    /// Start of the free exit window
+    pub control_changed_at: i64,
 }
 
 #[event]
@@ -26,6 +30,8 @@ pub struct ClanDelegateChanged {
     pub clan: Pubkey,
     pub old_delegate: Pubkey,
     pub new_delegate: Pubkey,
+    /// Start of the free exit window
+    pub control_changed_at: i64,
 }
 
 #[event]
@@ -89,6 +95,8 @@ pub struct ClanVotingDelegateChanged {
     pub clan: Pubkey,
     pub new_voting_delegate: Option<Pubkey>,
     pub old_voting_delegate: Option<Pubkey>,
+    /// Start of the free exit window
+    pub control_changed_at: i64,
 }
 
 #[event]
@@ -99,8 +107,161 @@ pub struct ProposalVoteUpdated {
     pub new_voting_weight: u64,
 }
 
+#[event]
+pub struct ProposalVetoCast {
+    pub clan: Pubkey,
+    pub proposal: Pubkey,
+    pub voting_weight: u64,
+}
+
 #[event]
 pub struct ProposalCanceled {
     pub clan: Pubkey,
     pub proposal: Pubkey,
-}
\ No newline at end of file
+}
+
+#[event]
+pub struct ClanFrozenChanged {
+    pub clan: Pubkey,
+    pub root: Pubkey,
+    pub frozen: bool,
+}
+
+#[event]
+pub struct ClanSiblingLinked {
+    pub clan: Pubkey,
+    pub sibling_clan: Pubkey,
+}
+
+#[event]
+pub struct ClanSiblingUnlinked {
+    pub clan: Pubkey,
+    pub sibling_clan: Pubkey,
+}
+
+#[event]
+pub struct ClanRewardVaultCreated {
+    pub clan: Pubkey,
+    pub reward_mint: Pubkey,
+    pub reward_vault: Pubkey,
+}
+
+#[event]
+pub struct ClanRewardsDeposited {
+    pub clan: Pubkey,
+    pub depositor: Pubkey,
+    pub amount: u64,
+    pub reward_per_weight: u128,
+}
+
+#[event]
+pub struct ClanCommissionChanged {
+    pub clan: Pubkey,
+    pub old_commission_bp: u16,
+    pub new_commission_bp: u16,
+    pub effective_at: i64,
+}
+
+#[event]
+pub struct ClanCommissionClaimed {
+    pub clan: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ClanLoyaltyCurveChanged {
+    pub clan: Pubkey,
+    pub old_loyalty_curve: Option<LoyaltyCurve>,
+    pub new_loyalty_curve: Option<LoyaltyCurve>,
+}
+
+#[event]
+pub struct ClanWeightTransformSynced {
+    pub clan: Pubkey,
+    pub weight_transform: WeightTransform,
+    pub weight_transform_epoch: u64,
+    pub unmigrated_members: u64,
+}
+
+#[event]
+pub struct ClanVoteHistoryCreated {
+    pub clan: Pubkey,
+    pub vote_history: Pubkey,
+}
+
+#[event]
+pub struct ClanWeightCheckpointsCreated {
+    pub clan: Pubkey,
+    pub weight_checkpoints: Pubkey,
+}
+
+#[event]
+pub struct ClanSnapshotVotingChanged {
+    pub clan: Pubkey,
+    pub snapshot_voting: bool,
+}
+
+#[event]
+pub struct ClanSnapshotVwrCreated {
+    pub clan: Pubkey,
+    pub proposal: Pubkey,
+    pub snapshot_vwr: Pubkey,
+    pub voting_at_slot: u64,
+    pub voter_weight: u64,
+}
+
+#[event]
+pub struct ClanTreasuryPolicyChanged {
+    pub clan: Pubkey,
+    pub old_treasury_policy: Option<TreasuryPolicy>,
+    pub new_treasury_policy: Option<TreasuryPolicy>,
+    pub effective_at: i64,
+}
+
+#[event]
+pub struct ClanTreasuryDeposited {
+    pub clan: Pubkey,
+    /// None for SOL
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ClanTreasuryWithdrawn {
+    pub clan: Pubkey,
+    /// None for SOL
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+    pub destination: Pubkey,
+    /// The approved withdrawal if it was used
+    pub withdrawal: Option<Pubkey>,
+}
+
+#[event]
+pub struct TreasuryWithdrawalProposed {
+    pub clan: Pubkey,
+    pub withdrawal: Pubkey,
+    pub index: u64,
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+    pub destination: Pubkey,
+    pub required_weight: u64,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct RecallProposalExecuted {
+    pub recall: Pubkey,
+    pub clan: Pubkey,
+    pub new_owner: Option<Pubkey>,
+    pub new_delegate: Option<Pubkey>,
+    pub signed_weight: u64,
+}
+
+#[event]
+pub struct ClanMigrated {
+    pub clan: Pubkey,
+    pub root: Pubkey,
+    pub owner: Pubkey,
+}
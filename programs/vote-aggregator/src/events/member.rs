@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::state::VoterWeightDecay;
+
 #[event]
 pub struct MemberCreated {
     pub member: Pubkey,
@@ -17,10 +19,106 @@ pub struct MemberVoterWeightChanged {
     pub old_voter_weight_record: Pubkey,
     pub new_voter_weight_record: Pubkey,
 }
+
+#[event]
+pub struct MemberVoterWeightDecayChanged {
+    pub member: Pubkey,
+    pub root: Pubkey,
+    pub old_voter_weight_decay: Option<VoterWeightDecay>,
+    pub new_voter_weight_decay: Option<VoterWeightDecay>,
+}
+
 #[event]
 pub struct StartingLeavingClan {
     pub member: Pubkey,
     pub clan: Pubkey,
     pub root: Pubkey,
     pub owner: Pubkey,
-}
\ No newline at end of file
+}
+
+#[event]
+pub struct StaleMemberEvicted {
+    pub member: Pubkey,
+    pub clan: Pubkey,
+    pub root: Pubkey,
+    pub owner: Pubkey,
+    pub exitable_at: i64,
+}
+
+#[event]
+pub struct UnlockedMemberEvicted {
+    pub member: Pubkey,
+    pub clan: Pubkey,
+    pub root: Pubkey,
+    pub owner: Pubkey,
+    pub exitable_at: i64,
+}
+
+#[event]
+pub struct MemberMigrated {
+    pub member: Pubkey,
+    pub root: Pubkey,
+    pub owner: Pubkey,
+}
+
+#[event]
+pub struct MemberWeightTransformMigrated {
+    pub member: Pubkey,
+    pub clan: Pubkey,
+    pub root: Pubkey,
+    pub weight_transform_epoch: u64,
+    pub unmigrated_members: u64,
+}
+
+#[event]
+pub struct MemberForcedOut {
+    pub member: Pubkey,
+    pub clan: Pubkey,
+    pub root: Pubkey,
+    pub owner: Pubkey,
+    /// None if the member was removed without the exit cooldown
+    pub exitable_at: Option<i64>,
+    pub lock_released: bool,
+    pub reason: u16,
+}
+
+#[event]
+pub struct ClanRewardsClaimed {
+    pub member: Pubkey,
+    pub clan: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    /// Charged on the rewards accrued since the last update of the member.
+    /// The updates keep the commission of the rewards accrued before them
+    pub commission: u64,
+}
+
+#[event]
+pub struct TreasuryWithdrawalApproved {
+    pub withdrawal: Pubkey,
+    pub clan: Pubkey,
+    pub member: Pubkey,
+    pub voter_weight: u64,
+    pub approved_weight: u64,
+}
+
+#[event]
+pub struct RecallProposalOpened {
+    pub recall: Pubkey,
+    pub clan: Pubkey,
+    pub member: Pubkey,
+    pub index: u64,
+    pub new_owner: Option<Pubkey>,
+    pub new_delegate: Option<Pubkey>,
+    pub required_weight: u64,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct RecallProposalSigned {
+    pub recall: Pubkey,
+    pub clan: Pubkey,
+    pub member: Pubkey,
+    pub voter_weight: u64,
+    pub signed_weight: u64,
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::state::VoterWeightReset;
+use crate::state::{RootConfigChange, VoterWeightReset, WeightTransform};
 
 #[event]
 pub struct RootCreated {
@@ -32,14 +32,93 @@ pub struct VoterWeightResetChanged {
     pub new_voter_weight_reset: Option<VoterWeightReset>,
 }
 
+#[event]
+pub struct VoterWeightExpiryToleranceChanged {
+    pub root: Pubkey,
+    pub old_voter_weight_expiry_tolerance: u64,
+    pub new_voter_weight_expiry_tolerance: u64,
+}
+
+#[event]
+pub struct StaleMemberGracePeriodChanged {
+    pub root: Pubkey,
+    pub old_stale_member_grace_period: Option<u64>,
+    pub new_stale_member_grace_period: Option<u64>,
+}
+
+#[event]
+pub struct RootAdminProposed {
+    pub root: Pubkey,
+    pub admin: Pubkey,
+    pub pending_admin: Option<Pubkey>,
+}
+
+#[event]
+pub struct RootAdminChanged {
+    pub root: Pubkey,
+    pub old_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+#[event]
+pub struct PauseGuardianChanged {
+    pub root: Pubkey,
+    pub old_pause_guardian: Option<Pubkey>,
+    pub new_pause_guardian: Option<Pubkey>,
+}
+
+#[event]
+pub struct MemberLockDurationChanged {
+    pub root: Pubkey,
+    pub old_member_lock_duration: u64,
+    pub new_member_lock_duration: u64,
+}
+
+#[event]
+pub struct MembershipWarmupChanged {
+    pub root: Pubkey,
+    pub old_membership_warmup: u64,
+    pub new_membership_warmup: u64,
+}
+
+#[event]
+pub struct MaxLoyaltyBonusChanged {
+    pub root: Pubkey,
+    pub old_max_loyalty_bonus_bp: u16,
+    pub new_max_loyalty_bonus_bp: u16,
+}
+
+#[event]
+pub struct WeightTransformChanged {
+    pub root: Pubkey,
+    pub old_weight_transform: WeightTransform,
+    pub new_weight_transform: WeightTransform,
+}
+
+#[event]
+pub struct RecallThresholdChanged {
+    pub root: Pubkey,
+    pub old_recall_threshold_bp: u16,
+    pub new_recall_threshold_bp: u16,
+}
+
+#[event]
+pub struct WindDownStarted {
+    pub root: Pubkey,
+}
+
 #[event]
 pub struct Paused {
     pub root: Pubkey,
+    pub flags: u32, // newly paused
+    pub pause_flags: u32,
 }
 
 #[event]
 pub struct Resumed {
     pub root: Pubkey,
+    pub flags: u32, // newly resumed
+    pub pause_flags: u32,
 }
 
 #[event]
@@ -48,3 +127,57 @@ pub struct VoterWeightPluginChanged {
     pub old_voting_weight_plugin: Pubkey,
     pub new_voting_weight_plugin: Pubkey,
 }
+
+#[event]
+pub struct RootConfigQueued {
+    pub root: Pubkey,
+    pub activation_time: i64,
+    pub change: RootConfigChange,
+}
+
+#[event]
+pub struct RootConfigApplied {
+    pub root: Pubkey,
+    pub change: RootConfigChange,
+}
+
+#[event]
+pub struct RootConfigCanceled {
+    pub root: Pubkey,
+    pub change: RootConfigChange,
+}
+
+#[event]
+pub struct RootInstalled {
+    pub root: Pubkey,
+}
+
+#[event]
+pub struct VotingWeightPluginVerified {
+    pub root: Pubkey,
+    pub old_verified_voting_weight_plugin: Pubkey,
+    pub new_verified_voting_weight_plugin: Pubkey,
+}
+
+#[event]
+pub struct PluginConfigDrift {
+    pub root: Pubkey,
+    pub realm_voter_weight_addin: Option<Pubkey>,
+    pub realm_max_voter_weight_addin: Option<Pubkey>,
+    /// The plugin the realm pointed to before the aggregator
+    pub voting_weight_plugin: Pubkey,
+    /// The plugin registered by verify_root
+    pub verified_voting_weight_plugin: Pubkey,
+}
+
+#[event]
+pub struct PluginConfigRestored {
+    pub root: Pubkey,
+}
+
+#[event]
+pub struct RootMigrated {
+    pub root: Pubkey,
+    pub admin: Pubkey,
+    pub pause_flags: u32,
+}
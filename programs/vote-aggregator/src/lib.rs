@@ -6,6 +6,7 @@ pub mod processor;
 pub mod state;
 
 use processor::*;
+use state::{LoyaltyCurve, RootConfigChange, TreasuryPolicy, WeightTransform};
 
 declare_id!("VoTaGDreyne7jk59uwbgRRbaAzxvNbyNipaJMrRXhjT");
 /*
@@ -32,10 +33,22 @@ pub mod vote_aggregator {
         ctx.accounts.process(max_proposal_lifetime, ctx.bumps)
     }
 
+    pub fn verify_root(ctx: Context<VerifyRoot>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    pub fn check_plugin_config(ctx: Context<CheckPluginConfig>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
     pub fn update_root(ctx: Context<UpdateRoot>) -> Result<()> {
         ctx.accounts.process()
     }
 
+    pub fn migrate_root(ctx: Context<MigrateRoot>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
     pub fn set_max_proposal_lifetime(
         ctx: Context<ConfigureRoot>,
         new_max_proposal_lifetime: u64,
@@ -53,12 +66,121 @@ pub mod vote_aggregator {
             .set_voter_weight_reset(new_step, new_next_reset_time)
     }
 
-    pub fn pause(ctx: Context<ConfigureRoot>) -> Result<()> {
-        ctx.accounts.pause()
+    pub fn disable_voter_weight_reset(ctx: Context<ConfigureRoot>) -> Result<()> {
+        ctx.accounts.disable_voter_weight_reset()
+    }
+
+    pub fn align_voter_weight_reset(
+        ctx: Context<AlignVoterWeightReset>,
+        voting_windows: u64,
+        new_next_reset_time: Option<i64>,
+    ) -> Result<()> {
+        ctx.accounts.process(voting_windows, new_next_reset_time)
     }
 
-    pub fn resume(ctx: Context<ConfigureRoot>) -> Result<()> {
-        ctx.accounts.resume()
+    pub fn set_voter_weight_expiry_tolerance(
+        ctx: Context<ConfigureRoot>,
+        new_voter_weight_expiry_tolerance: u64,
+    ) -> Result<()> {
+        ctx.accounts
+            .set_voter_weight_expiry_tolerance(new_voter_weight_expiry_tolerance)
+    }
+
+    pub fn set_stale_member_grace_period(
+        ctx: Context<ConfigureRoot>,
+        new_stale_member_grace_period: Option<u64>,
+    ) -> Result<()> {
+        ctx.accounts
+            .set_stale_member_grace_period(new_stale_member_grace_period)
+    }
+
+    pub fn propose_root_admin(
+        ctx: Context<ConfigureRoot>,
+        new_pending_admin: Option<Pubkey>,
+    ) -> Result<()> {
+        ctx.accounts.propose_root_admin(new_pending_admin)
+    }
+
+    pub fn accept_root_admin(ctx: Context<AcceptRootAdmin>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    pub fn reclaim_root_admin(ctx: Context<ConfigureRoot>) -> Result<()> {
+        ctx.accounts.reclaim_root_admin()
+    }
+
+    pub fn set_pause_guardian(
+        ctx: Context<ConfigureRoot>,
+        new_pause_guardian: Option<Pubkey>,
+    ) -> Result<()> {
+        ctx.accounts.set_pause_guardian(new_pause_guardian)
+    }
+
+    pub fn set_member_lock_duration(
+        ctx: Context<ConfigureRoot>,
+        new_member_lock_duration: u64,
+    ) -> Result<()> {
+        ctx.accounts
+            .set_member_lock_duration(new_member_lock_duration)
+    }
+
+    pub fn set_membership_warmup(
+        ctx: Context<ConfigureRoot>,
+        new_membership_warmup: u64,
+    ) -> Result<()> {
+        ctx.accounts.set_membership_warmup(new_membership_warmup)
+    }
+
+    pub fn set_max_loyalty_bonus(
+        ctx: Context<ConfigureRoot>,
+        new_max_loyalty_bonus_bp: u16,
+    ) -> Result<()> {
+        ctx.accounts.set_max_loyalty_bonus(new_max_loyalty_bonus_bp)
+    }
+
+    pub fn set_weight_transform(
+        ctx: Context<ConfigureRoot>,
+        new_weight_transform: WeightTransform,
+    ) -> Result<()> {
+        ctx.accounts.set_weight_transform(new_weight_transform)
+    }
+
+    pub fn set_recall_threshold(
+        ctx: Context<ConfigureRoot>,
+        new_recall_threshold_bp: u16,
+    ) -> Result<()> {
+        ctx.accounts.set_recall_threshold(new_recall_threshold_bp)
+    }
+
+    pub fn start_wind_down(ctx: Context<ConfigureRoot>) -> Result<()> {
+        ctx.accounts.start_wind_down()
+    }
+
+    pub fn pause(ctx: Context<ConfigureRoot>, flags: u32) -> Result<()> {
+        ctx.accounts.pause(flags)
+    }
+
+    pub fn resume(ctx: Context<ConfigureRoot>, flags: u32) -> Result<()> {
+        ctx.accounts.resume(flags)
+    }
+
+    pub fn set_clan_frozen(ctx: Context<SetClanFrozen>, frozen: bool) -> Result<()> {
+        ctx.accounts.process(frozen)
+    }
+
+    pub fn queue_root_config(
+        ctx: Context<QueueRootConfig>,
+        change: RootConfigChange,
+    ) -> Result<()> {
+        ctx.accounts.process(change, ctx.bumps.pending_root_config)
+    }
+
+    pub fn apply_root_config(ctx: Context<ApplyRootConfig>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    pub fn cancel_root_config(ctx: Context<CancelRootConfig>) -> Result<()> {
+        ctx.accounts.process()
     }
 
     pub fn set_voter_weight_plugin(
@@ -80,6 +202,14 @@ pub mod vote_aggregator {
         ctx.accounts.process(owner)
     }
 
+    pub fn link_sibling_clan(ctx: Context<LinkSiblingClan>) -> Result<()> {
+        ctx.accounts.link()
+    }
+
+    pub fn unlink_sibling_clan(ctx: Context<LinkSiblingClan>) -> Result<()> {
+        ctx.accounts.unlink()
+    }
+
     pub fn resize_clan(ctx: Context<ResizeClan>, size: u32) -> Result<()> {
         ctx.accounts.process(size)
     }
@@ -112,10 +242,104 @@ pub mod vote_aggregator {
             .set_accept_temporary_members(accept_temporary_members)
     }
 
+    pub fn set_clan_commission(ctx: Context<ConfigureClan>, commission_bp: u16) -> Result<()> {
+        ctx.accounts.set_commission(commission_bp)
+    }
+
+    pub fn set_clan_loyalty_curve(
+        ctx: Context<ConfigureClan>,
+        loyalty_curve: Option<LoyaltyCurve>,
+    ) -> Result<()> {
+        ctx.accounts.set_loyalty_curve(loyalty_curve)
+    }
+
+    pub fn set_clan_snapshot_voting(
+        ctx: Context<ConfigureClan>,
+        snapshot_voting: bool,
+    ) -> Result<()> {
+        ctx.accounts.set_snapshot_voting(snapshot_voting)
+    }
+
+    pub fn set_clan_treasury_policy(
+        ctx: Context<ConfigureClan>,
+        treasury_policy: Option<TreasuryPolicy>,
+    ) -> Result<()> {
+        ctx.accounts.set_treasury_policy(treasury_policy)
+    }
+
     pub fn update_proposal_vote(ctx: Context<UpdateProposalVote>) -> Result<()> {
         ctx.accounts.process()
     }
 
+    pub fn cast_veto_vote(ctx: Context<CastVetoVote>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    pub fn create_clan_reward_vault(ctx: Context<CreateClanRewardVault>) -> Result<()> {
+        ctx.accounts.process(ctx.bumps)
+    }
+
+    pub fn deposit_clan_rewards(ctx: Context<DepositClanRewards>, amount: u64) -> Result<()> {
+        ctx.accounts.process(amount)
+    }
+
+    pub fn deposit_clan_treasury(ctx: Context<DepositClanTreasury>, amount: u64) -> Result<()> {
+        ctx.accounts.process(amount)
+    }
+
+    pub fn deposit_clan_treasury_tokens(
+        ctx: Context<DepositClanTreasuryTokens>,
+        amount: u64,
+    ) -> Result<()> {
+        ctx.accounts.process(amount)
+    }
+
+    pub fn propose_treasury_withdrawal(
+        ctx: Context<ProposeTreasuryWithdrawal>,
+        mint: Option<Pubkey>,
+        amount: u64,
+        destination: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.process(mint, amount, destination, ctx.bumps)
+    }
+
+    pub fn withdraw_clan_treasury(ctx: Context<WithdrawClanTreasury>, amount: u64) -> Result<()> {
+        ctx.accounts.process(amount)
+    }
+
+    pub fn withdraw_clan_treasury_tokens(
+        ctx: Context<WithdrawClanTreasuryTokens>,
+        amount: u64,
+    ) -> Result<()> {
+        ctx.accounts.process(amount)
+    }
+
+    pub fn claim_clan_commission(ctx: Context<ClaimClanCommission>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    pub fn create_clan_vote_history(ctx: Context<CreateClanVoteHistory>) -> Result<()> {
+        ctx.accounts.process(ctx.bumps)
+    }
+
+    pub fn create_clan_weight_checkpoints(ctx: Context<CreateClanWeightCheckpoints>) -> Result<()> {
+        ctx.accounts.process(ctx.bumps)
+    }
+
+    pub fn create_clan_snapshot_vwr(ctx: Context<CreateClanSnapshotVwr>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    pub fn revise_clan_vwr(ctx: Context<ReviseClanVwr>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    pub fn execute_recall_proposal<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, ExecuteRecallProposal<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.process(ctx.remaining_accounts)
+    }
+
     pub fn forced_cancel_proposal(ctx: Context<ForcedCancelProposal>) -> Result<()> {
         ctx.accounts.process()
     }
@@ -146,6 +370,65 @@ pub mod vote_aggregator {
         ctx.accounts.process()
     }
 
+    pub fn claim_clan_rewards(ctx: Context<ClaimClanRewards>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    pub fn approve_treasury_withdrawal(ctx: Context<ApproveTreasuryWithdrawal>) -> Result<()> {
+        ctx.accounts.process(ctx.bumps)
+    }
+
+    pub fn open_recall_proposal(
+        ctx: Context<OpenRecallProposal>,
+        new_owner: Option<Pubkey>,
+        new_delegate: Option<Pubkey>,
+    ) -> Result<()> {
+        ctx.accounts.process(new_owner, new_delegate, ctx.bumps)
+    }
+
+    pub fn sign_recall_proposal(ctx: Context<SignRecallProposal>) -> Result<()> {
+        ctx.accounts.process(ctx.bumps)
+    }
+
+    pub fn force_leave_clan(
+        ctx: Context<ForceLeaveClan>,
+        immediate: bool,
+        release_lock: bool,
+        reason: u16,
+    ) -> Result<()> {
+        ctx.accounts.process(immediate, release_lock, reason)
+    }
+
+    pub fn wind_down_exit<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, WindDownExit<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.process(ctx.remaining_accounts)
+    }
+
+    pub fn refresh_member_lock(ctx: Context<RefreshMemberLock>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    pub fn evict_stale_member(ctx: Context<EvictStaleMember>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    pub fn evict_unlocked_member(ctx: Context<EvictUnlockedMember>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    pub fn migrate_clan(ctx: Context<MigrateClan>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    pub fn migrate_member(ctx: Context<MigrateMember>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    pub fn migrate_member_transform(ctx: Context<MigrateMemberTransform>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
     pub fn update_voter_weight<'a, 'b, 'c: 'info, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, UpdateVoterWeight<'info>>,
     ) -> Result<()> {
@@ -158,6 +441,14 @@ pub mod vote_aggregator {
         ctx.accounts.process(ctx.remaining_accounts)
     }
 
+    pub fn set_voter_weight_decay<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, SetVoterWeightDecay<'info>>,
+        rate: u64,
+        end_time: i64,
+    ) -> Result<()> {
+        ctx.accounts.process(rate, end_time, ctx.remaining_accounts)
+    }
+
     /*
     pub fn destroy_account(ctx: Context<DestroyAccount>) -> Result<()> {
         let l = ctx.accounts.account.lamports();
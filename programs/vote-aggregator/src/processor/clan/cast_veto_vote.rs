@@ -0,0 +1,234 @@
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed, system_program};
+
+use anchor_spl::token::Mint;
+use spl_governance::{
+    instruction::cast_vote,
+    state::{
+        proposal::get_proposal_data_for_governance,
+        realm::get_realm_data_for_governing_token_mint, vote_record::Vote,
+    },
+    PROGRAM_AUTHORITY_SEED,
+};
+
+use crate::error::Error;
+use crate::events::clan::ProposalVetoCast;
+use crate::state::{Clan, ClanVoteHistory, MaxVoterWeightRecord, Root, VoterWeightRecord};
+
+/// Vetoes a proposal of the other realm mint with the clan voter weight
+#[derive(Accounts)]
+pub struct CastVetoVote<'info> {
+    #[account(
+        mut,
+        has_one = root,
+        constraint = clan.is_updated(&root) @ Error::TemporaryMembersNotUpdated,
+        constraint = clan.is_migrated(&root) @ Error::WeightTransformNotMigrated,
+        constraint = !clan.snapshot_voting @ Error::ClanSnapshotVoting,
+        constraint = !clan.frozen @ Error::ClanFrozen,
+    )]
+    clan: Box<Account<'info, Clan>>,
+    #[account(
+        constraint = clan_authority.key() == clan.owner ||
+            clan_authority.key() == clan.delegate
+            @ Error::WrongClanAuthority
+    )]
+    clan_authority: Signer<'info>,
+    #[account(
+        has_one = realm,
+        has_one = governing_token_mint,
+        has_one = governance_program,
+    )]
+    root: Box<Account<'info, Root>>,
+    /// CHECK: dynamic owner
+    #[account(
+        owner = governance_program.key(),
+    )]
+    realm: UncheckedAccount<'info>,
+    /// CHECK: dynamic owner
+    #[account(
+        owner = governance_program.key(),
+        seeds = [
+            b"realm-config",
+            &realm.key.to_bytes()
+        ],
+        bump,
+        seeds::program = governance_program.key(),
+    )]
+    realm_config: UncheckedAccount<'info>,
+    /// The veto mint
+    governing_token_mint: Box<Account<'info, Mint>>,
+    /// CHECK: dynamic owner
+    #[account(
+        mut,
+        owner = governance_program.key(),
+    )]
+    governance: UncheckedAccount<'info>,
+    /// CHECK: dynamic owner
+    #[account(
+        mut,
+        owner = governance_program.key(),
+    )]
+    proposal: UncheckedAccount<'info>,
+    /// CHECK: dynamic owner
+    #[account(
+        mut,
+        owner = governance_program.key(),
+    )]
+    proposal_owner_record: UncheckedAccount<'info>,
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            Clan::VOTER_AUTHORITY_SEED,
+            &clan.key().to_bytes()
+        ],
+        bump = clan.bumps.voter_authority,
+    )]
+    voter_authority: UncheckedAccount<'info>,
+    /// CHECK: dynamic owner
+    #[account(
+        mut,
+        owner = governance_program.key(),
+        seeds = [
+            PROGRAM_AUTHORITY_SEED,
+            &realm.key.to_bytes(),
+            &root.governing_token_mint.key().to_bytes(),
+            &voter_authority.key.to_bytes(),
+        ],
+        seeds::program = root.governance_program,
+        bump = clan.bumps.token_owner_record,
+        address = clan.token_owner_record,
+    )]
+    clan_tor: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [
+            VoterWeightRecord::ADDRESS_SEED,
+            &clan.key().to_bytes()
+        ],
+        bump = clan.bumps.voter_weight_record,
+        address = clan.voter_weight_record,
+    )]
+    clan_vwr: Box<Account<'info, VoterWeightRecord>>,
+    /// Max voter weight of the veto mint
+    #[account(
+        seeds = [
+            MaxVoterWeightRecord::ADDRESS_SEED,
+            &root.key().to_bytes()
+        ],
+        bump = root.bumps.max_voter_weight,
+    )]
+    max_vwr: Option<Box<Account<'info, MaxVoterWeightRecord>>>,
+    /// CHECK: created by the CPI
+    #[account(
+        mut,
+        seeds = [
+            PROGRAM_AUTHORITY_SEED,
+            &proposal.key.to_bytes(),
+            &clan_tor.key.to_bytes()],
+        bump,
+        seeds::program = governance_program.key(),
+    )]
+    vote_record: UncheckedAccount<'info>,
+    /// Required once the clan has a vote history
+    #[account(
+        mut,
+        seeds = [
+            ClanVoteHistory::ADDRESS_SEED,
+            &clan.key().to_bytes()
+        ],
+        bump = vote_history.bump,
+    )]
+    vote_history: Option<Box<Account<'info, ClanVoteHistory>>>,
+    #[account(
+        mut,
+        owner = system_program::ID,
+    )]
+    payer: Signer<'info>,
+
+    system_program: Program<'info, System>,
+    /// CHECK: program
+    #[account(executable)]
+    governance_program: UncheckedAccount<'info>,
+}
+
+impl<'info> CastVetoVote<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        self.root.check_not_paused(Root::PAUSE_PROPOSAL_VOTE)?;
+        self.root.check_no_plugin_drift()?;
+        let realm = get_realm_data_for_governing_token_mint(
+            self.governance_program.key,
+            &self.realm.to_account_info(),
+            &self.root.governing_token_mint,
+        )
+        .map_err(|e| ProgramErrorWithOrigin::from(e).with_account_name("realm"))?;
+        let proposal = get_proposal_data_for_governance(
+            self.governance_program.key,
+            &self.proposal.to_account_info(),
+            self.governance.key,
+        )
+        .map_err(|e| ProgramErrorWithOrigin::from(e).with_account_name("proposal"))?;
+        require_keys_eq!(
+            proposal.governing_token_mint,
+            self.root.proposal_mint_for_vote(&realm, true)?,
+            Error::InvalidProposalMint
+        );
+
+        let clock = Clock::get()?;
+        self.clan.revise_voter_weight(&mut self.clan_vwr, &clock);
+        let mut cast_vote_accounts = vec![
+            self.governance_program.to_account_info(),
+            self.realm.to_account_info(),
+            self.governance.to_account_info(),
+            self.proposal.to_account_info(),
+            self.proposal_owner_record.to_account_info(),
+            self.clan_tor.to_account_info(),
+            self.voter_authority.to_account_info(),
+            self.vote_record.to_account_info(),
+            self.governing_token_mint.to_account_info(),
+            self.payer.to_account_info(),
+            self.system_program.to_account_info(),
+            self.realm_config.to_account_info(),
+            self.clan_vwr.to_account_info(),
+        ];
+        if let Some(max_voter_weight) = self.max_vwr.as_ref() {
+            cast_vote_accounts.push(max_voter_weight.to_account_info());
+        }
+        invoke_signed(
+            &cast_vote(
+                self.governance_program.key,
+                self.realm.key,
+                self.governance.key,
+                self.proposal.key,
+                self.proposal_owner_record.key,
+                self.clan_tor.key,
+                self.voter_authority.key,
+                &self.governing_token_mint.key(),
+                self.payer.key,
+                Some(self.clan_vwr.key()),
+                self.max_vwr.as_ref().map(|max_vwr| max_vwr.key()),
+                Vote::Veto,
+            ),
+            &cast_vote_accounts,
+            &[&[
+                Clan::VOTER_AUTHORITY_SEED,
+                &self.clan.key().to_bytes(),
+                &[self.clan.bumps.voter_authority],
+            ]],
+        )?;
+        self.clan.record_vote(
+            self.vote_history
+                .as_deref_mut()
+                .map(|vote_history| &mut **vote_history),
+            self.proposal.key(),
+            &Vote::Veto,
+            self.clan_vwr.voter_weight,
+            clock.unix_timestamp,
+            false,
+        )?;
+        emit!(ProposalVetoCast {
+            clan: self.clan.key(),
+            proposal: self.proposal.key(),
+            voting_weight: self.clan_vwr.voter_weight,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::events::clan::ClanCommissionClaimed;
+use crate::state::Clan;
+
+/// The clan owner withdraws the commission kept from the member reward claims
+#[derive(Accounts)]
+pub struct ClaimClanCommission<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+    )]
+    clan: Account<'info, Clan>,
+
+    owner: Signer<'info>,
+
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            Clan::VOTER_AUTHORITY_SEED,
+            &clan.key().to_bytes()
+        ],
+        bump = clan.bumps.voter_authority,
+    )]
+    voter_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            Clan::REWARD_VAULT_SEED,
+            &clan.key().to_bytes()
+        ],
+        bump = clan.bumps.reward_vault,
+    )]
+    reward_vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    destination: Account<'info, TokenAccount>,
+
+    token_program: Program<'info, Token>,
+}
+
+impl<'info> ClaimClanCommission<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let amount = self.clan.unclaimed_commission;
+        self.clan.unclaimed_commission = 0;
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.reward_vault.to_account_info(),
+                    to: self.destination.to_account_info(),
+                    authority: self.voter_authority.to_account_info(),
+                },
+                &[&[
+                    Clan::VOTER_AUTHORITY_SEED,
+                    &self.clan.key().to_bytes(),
+                    &[self.clan.bumps.voter_authority],
+                ]],
+            ),
+            amount,
+        )?;
+        emit!(ClanCommissionClaimed {
+            clan: self.clan.key(),
+            owner: self.owner.key(),
+            amount,
+        });
+        Ok(())
+    }
+}
@@ -2,14 +2,20 @@ use anchor_lang::prelude::*;
 
 use crate::error::Error;
 use crate::events::clan::{
-    ClanAcceptTemporaryMembersChanged, ClanDelegateChanged, ClanDescriptionChanged,
-    ClanMinVotingWeightToJoinChanged, ClanNameChanged,
+    ClanAcceptTemporaryMembersChanged, ClanCommissionChanged, ClanDelegateChanged,
+    ClanDescriptionChanged, ClanLoyaltyCurveChanged, ClanMinVotingWeightToJoinChanged,
+    ClanNameChanged, ClanSnapshotVotingChanged, ClanTreasuryPolicyChanged,
+};
+use crate::state::{
+    Clan, LoyaltyCurve, PendingCommission, PendingTreasuryPolicy, Root, TreasuryPolicy,
 };
-use crate::state::Clan;
 
 #[derive(Accounts)]
 pub struct ConfigureClan<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = !clan.frozen @ Error::ClanFrozen,
+    )]
     clan: Account<'info, Clan>,
 
     #[account(
@@ -18,48 +24,80 @@ pub struct ConfigureClan<'info> {
         @ Error::WrongClanAuthority,
     )]
     clan_authority: Signer<'info>,
+
+    /// Required if the clan is linked
+    #[account(
+        mut,
+        constraint = !sibling_clan.frozen @ Error::ClanFrozen,
+    )]
+    sibling_clan: Option<Account<'info, Clan>>,
+
+    #[account(
+        constraint = root.key() == clan.root @ Error::UnexpectedClan,
+        constraint = !root.is_paused(Root::PAUSE_CLAN_CONFIG) @ Error::Paused,
+    )]
+    root: Account<'info, Root>,
 }
 
 impl<'info> ConfigureClan<'info> {
-    pub fn set_delegate(&mut self, new_delegate: Pubkey) -> Result<()> {
-        let old_delegate = self.clan.delegate;
-        self.clan.delegate = new_delegate;
-        if new_delegate != old_delegate {
-            emit!(ClanDelegateChanged {
-                clan: self.clan.key(),
-                old_delegate,
-                new_delegate,
-            });
+    /// Applies the shared configuration to the clan and its sibling
+    fn apply_to_clans(&mut self, mut f: impl FnMut(&mut Account<'info, Clan>)) -> Result<()> {
+        require!(
+            self.sibling_clan.as_ref().map(|c| c.key()) == self.clan.sibling,
+            Error::WrongSiblingClan
+        );
+        f(&mut self.clan);
+        if let Some(sibling_clan) = self.sibling_clan.as_mut() {
+            f(sibling_clan);
         }
         Ok(())
     }
 
+    pub fn set_delegate(&mut self, new_delegate: Pubkey) -> Result<()> {
+        let time = Clock::get()?.unix_timestamp;
+        self.apply_to_clans(|clan| {
+            let old_delegate = clan.delegate;
+            clan.delegate = new_delegate;
+            if new_delegate != old_delegate {
+                emit!(ClanDelegateChanged {
+                    clan: clan.key(),
+                    old_delegate,
+                    new_delegate,
+                    control_changed_at: clan.record_control_change(time),
+                });
+            }
+        })
+    }
+
     pub fn set_name(&mut self, new_name: String) -> Result<()> {
-        let old_name = self.clan.name.clone();
-        self.clan.name = new_name.clone();
-        if new_name != old_name {
-            emit!(ClanNameChanged {
-                clan: self.clan.key(),
-                old_name,
-                new_name,
-            });
-        }
-        Ok(())
+        self.apply_to_clans(|clan| {
+            let old_name = clan.name.clone();
+            clan.name = new_name.clone();
+            if new_name != old_name {
+                emit!(ClanNameChanged {
+                    clan: clan.key(),
+                    old_name,
+                    new_name: new_name.clone(),
+                });
+            }
+        })
     }
 
     pub fn set_description(&mut self, new_description: String) -> Result<()> {
-        let old_description = self.clan.description.clone();
-        self.clan.description = new_description.clone();
-        if new_description != old_description {
-            emit!(ClanDescriptionChanged {
-                clan: self.clan.key(),
-                old_description,
-                new_description,
-            });
-        }
-        Ok(())
+        self.apply_to_clans(|clan| {
+            let old_description = clan.description.clone();
+            clan.description = new_description.clone();
+            if new_description != old_description {
+                emit!(ClanDescriptionChanged {
+                    clan: clan.key(),
+                    old_description,
+                    new_description: new_description.clone(),
+                });
+            }
+        })
     }
 
+    /// Not shared with the sibling because the weights are in different tokens
     pub fn set_min_voting_weight_to_join(
         &mut self,
         new_min_voting_weight_to_join: u64,
@@ -80,15 +118,150 @@ impl<'info> ConfigureClan<'info> {
         &mut self,
         new_accept_temporary_members: bool,
     ) -> Result<()> {
-        let old_accept_temporary_members = self.clan.accept_temporary_members;
-        self.clan.accept_temporary_members = new_accept_temporary_members;
-        if new_accept_temporary_members != old_accept_temporary_members {
-            emit!(ClanAcceptTemporaryMembersChanged {
+        self.apply_to_clans(|clan| {
+            let old_accept_temporary_members = clan.accept_temporary_members;
+            clan.accept_temporary_members = new_accept_temporary_members;
+            if new_accept_temporary_members != old_accept_temporary_members {
+                emit!(ClanAcceptTemporaryMembersChanged {
+                    clan: clan.key(),
+                    old_accept_temporary_members,
+                    new_accept_temporary_members,
+                });
+            }
+        })
+    }
+
+    /// Not shared with the sibling because the clans have separate reward vaults.
+    /// Only the owner collects the commission. An increase takes effect after
+    /// the exit cooldown so the members can leave first
+    pub fn set_commission(&mut self, new_commission_bp: u16) -> Result<()> {
+        require_keys_eq!(
+            self.clan_authority.key(),
+            self.clan.owner,
+            Error::WrongClanAuthority
+        );
+        require_gte!(10000, new_commission_bp, Error::InvalidCommissionBp);
+        let clock = Clock::get()?;
+        self.clan.apply_pending_commission(clock.unix_timestamp);
+        let old_commission_bp = self.clan.commission_bp;
+        let pending_commission_bp = self
+            .clan
+            .pending_commission
+            .as_ref()
+            .map(|pending| pending.commission_bp);
+        // Requesting the pending increase again keeps its notice period
+        if new_commission_bp == pending_commission_bp.unwrap_or(old_commission_bp) {
+            return Ok(());
+        }
+        let effective_at = if new_commission_bp <= old_commission_bp {
+            self.clan.commission_bp = new_commission_bp;
+            self.clan.pending_commission = None;
+            clock.unix_timestamp
+        } else {
+            let effective_at = self.root.max_proposal_lifetime_end(clock.unix_timestamp);
+            self.clan.pending_commission = Some(PendingCommission {
+                commission_bp: new_commission_bp,
+                effective_at,
+            });
+            effective_at
+        };
+        emit!(ClanCommissionChanged {
+            clan: self.clan.key(),
+            old_commission_bp,
+            new_commission_bp,
+            effective_at,
+        });
+        Ok(())
+    }
+
+    /// Not shared with the sibling because each root bounds its own curves.
+    /// Applied to the members on their next update
+    pub fn set_loyalty_curve(&mut self, new_loyalty_curve: Option<LoyaltyCurve>) -> Result<()> {
+        if let Some(curve) = &new_loyalty_curve {
+            require_gt!(curve.period, 0, Error::InvalidLoyaltyCurve);
+            require_gte!(
+                self.root.max_loyalty_bonus_bp,
+                curve.max_bonus_bp,
+                Error::InvalidLoyaltyCurve
+            );
+        }
+        let old_loyalty_curve = self.clan.loyalty_curve.clone();
+        self.clan.loyalty_curve = new_loyalty_curve.clone();
+        if new_loyalty_curve != old_loyalty_curve {
+            emit!(ClanLoyaltyCurveChanged {
+                clan: self.clan.key(),
+                old_loyalty_curve,
+                new_loyalty_curve,
+            });
+        }
+        Ok(())
+    }
+
+    /// Not shared with the sibling clan which has its own checkpoints
+    pub fn set_snapshot_voting(&mut self, snapshot_voting: bool) -> Result<()> {
+        if snapshot_voting {
+            require!(
+                self.clan.weight_checkpoints.is_some(),
+                Error::ClanWeightCheckpointsRequired
+            );
+        }
+        if self.clan.snapshot_voting != snapshot_voting {
+            self.clan.snapshot_voting = snapshot_voting;
+            emit!(ClanSnapshotVotingChanged {
                 clan: self.clan.key(),
-                old_accept_temporary_members,
-                new_accept_temporary_members,
+                snapshot_voting,
             });
         }
         Ok(())
     }
+
+    /// Not shared with the sibling clan which has its own treasury.
+    /// Only the owner controls the treasury. A looser policy takes effect
+    /// after the exit cooldown so the members can leave first
+    pub fn set_treasury_policy(
+        &mut self,
+        new_treasury_policy: Option<TreasuryPolicy>,
+    ) -> Result<()> {
+        require_keys_eq!(
+            self.clan_authority.key(),
+            self.clan.owner,
+            Error::WrongClanAuthority
+        );
+        if let Some(policy) = &new_treasury_policy {
+            require!(
+                policy.approval_threshold_bp > 0
+                    && policy.approval_threshold_bp <= 10000
+                    && policy.spending_period > 0
+                    && policy.spending_period <= TreasuryPolicy::MAX_SPENDING_PERIOD,
+                Error::InvalidTreasuryPolicy
+            );
+        }
+        let clock = Clock::get()?;
+        self.clan
+            .apply_pending_treasury_policy(clock.unix_timestamp);
+        let old_treasury_policy = self.clan.treasury_policy.clone();
+        let is_stricter = match &old_treasury_policy {
+            Some(policy) => policy.is_stricter(&new_treasury_policy),
+            None => true,
+        };
+        let effective_at = if is_stricter {
+            self.clan.treasury_policy = new_treasury_policy.clone();
+            self.clan.pending_treasury_policy = None;
+            clock.unix_timestamp
+        } else {
+            let effective_at = self.root.max_proposal_lifetime_end(clock.unix_timestamp);
+            self.clan.pending_treasury_policy = Some(PendingTreasuryPolicy {
+                policy: new_treasury_policy.clone(),
+                effective_at,
+            });
+            effective_at
+        };
+        emit!(ClanTreasuryPolicyChanged {
+            clan: self.clan.key(),
+            old_treasury_policy,
+            new_treasury_policy,
+            effective_at,
+        });
+        Ok(())
+    }
 }
@@ -86,6 +86,9 @@ pub struct CreateClan<'info> {
 
 impl<'info> CreateClan<'info> {
     pub fn process(&mut self, owner: Pubkey, bumps: CreateClanBumps) -> Result<()> {
+        self.root.check_not_paused(Root::PAUSE_CREATE)?;
+        self.root.check_not_winding_down()?;
+        self.root.check_installed()?;
         let clock = Clock::get()?;
         self.root.update_next_voter_weight_reset_time(&clock);
 
@@ -104,12 +107,53 @@ impl<'info> CreateClan<'info> {
             accept_temporary_members: true,
             permanent_voter_weight: 0,
             next_voter_weight_reset_time: self.root.next_voter_weight_reset_time(),
+            rescheduled_voter_weight_reset_time: None,
+            decaying_members: 0,
+            decaying_voter_weight: 0,
+            voter_weight_decay_rate_bp: 0,
+            voter_weight_decay_updated_at: clock.unix_timestamp,
+            voter_weight_decay_ends: vec![],
+            frozen: false,
+            sibling: None,
+            reward_mint: None,
+            reward_weight: 0,
+            reward_per_weight: 0,
+            commission_bp: 0,
+            pending_commission: None,
+            unclaimed_commission: 0,
+            previous_commission_bp: 0,
+            commission_changed_reward_per_weight: 0,
+            vote_history: None,
+            veto_votes_cast: 0,
+            vote_updates: 0,
+            last_voted_at: None,
+            warming_up_voter_weight: 0,
+            warmup_ends: vec![],
+            loyalty_curve: None,
+            weight_transform: self.root.weight_transform.clone(),
+            weight_transform_epoch: 0,
+            unmigrated_members: 0,
+            weight_checkpoints: None,
+            snapshot_voting: false,
+            treasury_policy: None,
+            pending_treasury_policy: None,
+            treasury_spent: 0,
+            treasury_period_start: 0,
+            treasury_withdrawals: 0,
+            recall_proposals: 0,
+            control_changed_at: None,
             name: "".to_owned(),
             description: "".to_owned(),
             bumps: ClanBumps {
                 voter_authority: bumps.voter_authority,
                 token_owner_record: bumps.clan_tor,
                 voter_weight_record: bumps.clan_vwr,
+                reward_vault: 0,
+                treasury: Pubkey::find_program_address(
+                    &[Clan::TREASURY_SEED, &self.clan.key().to_bytes()],
+                    &crate::ID,
+                )
+                .1,
             },
         });
         invoke(
@@ -0,0 +1,71 @@
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::error::Error;
+use crate::events::clan::ClanRewardVaultCreated;
+use crate::state::Clan;
+
+/// Token account the clan passes the incentives to its members through
+#[derive(Accounts)]
+pub struct CreateClanRewardVault<'info> {
+    #[account(
+        mut,
+        constraint = !clan.frozen @ Error::ClanFrozen,
+        constraint = clan.reward_mint.is_none() @ Error::ClanRewardVaultExists,
+    )]
+    clan: Account<'info, Clan>,
+
+    #[account(
+        constraint = clan_authority.key() == clan.owner ||
+            clan_authority.key() == clan.delegate
+        @ Error::WrongClanAuthority,
+    )]
+    clan_authority: Signer<'info>,
+
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            Clan::VOTER_AUTHORITY_SEED,
+            &clan.key().to_bytes()
+        ],
+        bump = clan.bumps.voter_authority,
+    )]
+    voter_authority: UncheckedAccount<'info>,
+
+    reward_mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            Clan::REWARD_VAULT_SEED,
+            &clan.key().to_bytes()
+        ],
+        bump,
+        token::mint = reward_mint,
+        token::authority = voter_authority,
+    )]
+    reward_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        owner = system_program::ID
+    )]
+    payer: Signer<'info>,
+
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+}
+
+impl<'info> CreateClanRewardVault<'info> {
+    pub fn process(&mut self, bumps: CreateClanRewardVaultBumps) -> Result<()> {
+        self.clan.reward_mint = Some(self.reward_mint.key());
+        self.clan.bumps.reward_vault = bumps.reward_vault;
+        emit!(ClanRewardVaultCreated {
+            clan: self.clan.key(),
+            reward_mint: self.reward_mint.key(),
+            reward_vault: self.reward_vault.key(),
+        });
+        Ok(())
+    }
+}
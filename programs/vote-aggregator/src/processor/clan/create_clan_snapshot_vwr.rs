@@ -0,0 +1,104 @@
+use anchor_lang::{prelude::*, system_program};
+use spl_governance::state::proposal::get_proposal_data_for_governance;
+
+use crate::error::Error;
+use crate::events::clan::ClanSnapshotVwrCreated;
+use crate::state::{Clan, ClanWeightCheckpoints, Root, VoterWeightAction, VoterWeightRecord};
+
+/// Permissionless creation of the clan VWR fixed at the proposal voting start.
+/// Used by the voting delegate instead of the clan VWR
+#[derive(Accounts)]
+pub struct CreateClanSnapshotVwr<'info> {
+    #[account(
+        has_one = governance_program,
+    )]
+    root: Account<'info, Root>,
+
+    #[account(
+        has_one = root,
+        constraint = !clan.frozen @ Error::ClanFrozen,
+    )]
+    clan: Account<'info, Clan>,
+
+    #[account(
+        seeds = [
+            ClanWeightCheckpoints::ADDRESS_SEED,
+            &clan.key().to_bytes()
+        ],
+        bump = weight_checkpoints.bump,
+    )]
+    weight_checkpoints: Account<'info, ClanWeightCheckpoints>,
+
+    /// CHECK: dynamic owner
+    #[account(
+        owner = governance_program.key(),
+    )]
+    governance: UncheckedAccount<'info>,
+
+    /// CHECK: dynamic owner
+    #[account(
+        owner = governance_program.key(),
+    )]
+    proposal: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        seeds = [
+            VoterWeightRecord::SNAPSHOT_ADDRESS_SEED,
+            &clan.key().to_bytes(),
+            &proposal.key().to_bytes(),
+        ],
+        bump,
+        payer = payer,
+        space = VoterWeightRecord::SPACE,
+    )]
+    snapshot_vwr: Account<'info, VoterWeightRecord>,
+
+    #[account(
+        mut,
+        owner = system_program::ID
+    )]
+    payer: Signer<'info>,
+
+    system_program: Program<'info, System>,
+    /// CHECK: program
+    #[account(executable)]
+    governance_program: UncheckedAccount<'info>,
+}
+
+impl<'info> CreateClanSnapshotVwr<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let proposal = get_proposal_data_for_governance(
+            self.governance_program.key,
+            &self.proposal.to_account_info(),
+            self.governance.key,
+        )
+        .map_err(|e| ProgramErrorWithOrigin::from(e).with_account_name("proposal"))?;
+        let voting_at_slot = proposal
+            .voting_at_slot
+            .ok_or(error!(Error::ProposalVotingNotStarted))?;
+        let voter_weight = self
+            .weight_checkpoints
+            .voter_weight_before(voting_at_slot)
+            .ok_or(error!(Error::ClanWeightNotCheckpointed))?;
+
+        // Can only be used to vote on this proposal
+        self.snapshot_vwr.set_inner(VoterWeightRecord::new(
+            self.root.realm,
+            self.root.governing_token_mint,
+            self.clan.voter_authority,
+            voter_weight,
+            None,
+            Some(VoterWeightAction::CastVote),
+            Some(self.proposal.key()),
+        ));
+        emit!(ClanSnapshotVwrCreated {
+            clan: self.clan.key(),
+            proposal: self.proposal.key(),
+            snapshot_vwr: self.snapshot_vwr.key(),
+            voting_at_slot,
+            voter_weight,
+        });
+        Ok(())
+    }
+}
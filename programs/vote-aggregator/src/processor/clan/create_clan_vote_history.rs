@@ -0,0 +1,59 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::error::Error;
+use crate::events::clan::ClanVoteHistoryCreated;
+use crate::state::{Clan, ClanVoteHistory};
+
+/// Starts recording the clan votes
+#[derive(Accounts)]
+pub struct CreateClanVoteHistory<'info> {
+    #[account(
+        mut,
+        constraint = clan.vote_history.is_none() @ Error::ClanVoteHistoryExists,
+    )]
+    clan: Account<'info, Clan>,
+
+    #[account(
+        constraint = clan_authority.key() == clan.owner ||
+            clan_authority.key() == clan.delegate
+        @ Error::WrongClanAuthority,
+    )]
+    clan_authority: Signer<'info>,
+
+    #[account(
+        init,
+        seeds = [
+            ClanVoteHistory::ADDRESS_SEED,
+            &clan.key().to_bytes()
+        ],
+        bump,
+        payer = payer,
+        space = ClanVoteHistory::SPACE,
+    )]
+    vote_history: Account<'info, ClanVoteHistory>,
+
+    #[account(
+        mut,
+        owner = system_program::ID
+    )]
+    payer: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> CreateClanVoteHistory<'info> {
+    pub fn process(&mut self, bumps: CreateClanVoteHistoryBumps) -> Result<()> {
+        self.vote_history.set_inner(ClanVoteHistory {
+            clan: self.clan.key(),
+            next_index: 0,
+            entries: Vec::with_capacity(ClanVoteHistory::CAPACITY),
+            bump: bumps.vote_history,
+        });
+        self.clan.vote_history = Some(self.vote_history.key());
+        emit!(ClanVoteHistoryCreated {
+            clan: self.clan.key(),
+            vote_history: self.vote_history.key(),
+        });
+        Ok(())
+    }
+}
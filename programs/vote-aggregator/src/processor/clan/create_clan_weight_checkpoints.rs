@@ -0,0 +1,71 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::error::Error;
+use crate::events::clan::ClanWeightCheckpointsCreated;
+use crate::state::{Clan, ClanWeightCheckpoints, VoterWeightRecord};
+
+/// Starts recording the clan voter weight changes
+#[derive(Accounts)]
+pub struct CreateClanWeightCheckpoints<'info> {
+    #[account(
+        mut,
+        constraint = clan.weight_checkpoints.is_none() @ Error::ClanWeightCheckpointsExist,
+    )]
+    clan: Account<'info, Clan>,
+
+    #[account(
+        constraint = clan_authority.key() == clan.owner ||
+            clan_authority.key() == clan.delegate
+        @ Error::WrongClanAuthority,
+    )]
+    clan_authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            VoterWeightRecord::ADDRESS_SEED,
+            &clan.key().to_bytes()
+        ],
+        bump = clan.bumps.voter_weight_record,
+    )]
+    clan_vwr: Account<'info, VoterWeightRecord>,
+
+    #[account(
+        init,
+        seeds = [
+            ClanWeightCheckpoints::ADDRESS_SEED,
+            &clan.key().to_bytes()
+        ],
+        bump,
+        payer = payer,
+        space = ClanWeightCheckpoints::SPACE,
+    )]
+    weight_checkpoints: Account<'info, ClanWeightCheckpoints>,
+
+    #[account(
+        mut,
+        owner = system_program::ID
+    )]
+    payer: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> CreateClanWeightCheckpoints<'info> {
+    pub fn process(&mut self, bumps: CreateClanWeightCheckpointsBumps) -> Result<()> {
+        self.weight_checkpoints.set_inner(ClanWeightCheckpoints {
+            clan: self.clan.key(),
+            next_index: 0,
+            checkpoints: Vec::with_capacity(ClanWeightCheckpoints::CAPACITY),
+            bump: bumps.weight_checkpoints,
+        });
+        // The weight the next changes start from
+        self.weight_checkpoints
+            .record(Clock::get()?.slot, self.clan_vwr.voter_weight);
+        self.clan.weight_checkpoints = Some(self.weight_checkpoints.key());
+        emit!(ClanWeightCheckpointsCreated {
+            clan: self.clan.key(),
+            weight_checkpoints: self.weight_checkpoints.key(),
+        });
+        Ok(())
+    }
+}
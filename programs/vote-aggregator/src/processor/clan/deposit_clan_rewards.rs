@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::events::clan::ClanRewardsDeposited;
+use crate::state::Clan;
+
+/// Anyone can reward the clan members pro-rata to their current contributions
+#[derive(Accounts)]
+pub struct DepositClanRewards<'info> {
+    #[account(mut)]
+    clan: Account<'info, Clan>,
+
+    #[account(
+        mut,
+        seeds = [
+            Clan::REWARD_VAULT_SEED,
+            &clan.key().to_bytes()
+        ],
+        bump = clan.bumps.reward_vault,
+    )]
+    reward_vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    source: Account<'info, TokenAccount>,
+
+    depositor: Signer<'info>,
+
+    token_program: Program<'info, Token>,
+}
+
+impl<'info> DepositClanRewards<'info> {
+    pub fn process(&mut self, amount: u64) -> Result<()> {
+        self.clan
+            .distribute_rewards(amount, Clock::get()?.unix_timestamp)?;
+        token::transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.source.to_account_info(),
+                    to: self.reward_vault.to_account_info(),
+                    authority: self.depositor.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        emit!(ClanRewardsDeposited {
+            clan: self.clan.key(),
+            depositor: self.depositor.key(),
+            amount,
+            reward_per_weight: self.clan.reward_per_weight,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, Transfer},
+};
+
+use crate::events::clan::ClanTreasuryDeposited;
+use crate::state::Clan;
+
+/// The clan owner moves SOL into the clan treasury
+#[derive(Accounts)]
+pub struct DepositClanTreasury<'info> {
+    #[account(
+        has_one = owner,
+    )]
+    clan: Account<'info, Clan>,
+
+    #[account(mut)]
+    owner: Signer<'info>,
+
+    /// CHECK: PDA
+    #[account(
+        mut,
+        seeds = [
+            Clan::TREASURY_SEED,
+            &clan.key().to_bytes()
+        ],
+        bump = clan.bumps.treasury,
+    )]
+    treasury: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> DepositClanTreasury<'info> {
+    pub fn process(&mut self, amount: u64) -> Result<()> {
+        system_program::transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.owner.to_account_info(),
+                    to: self.treasury.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        emit!(ClanTreasuryDeposited {
+            clan: self.clan.key(),
+            mint: None,
+            amount,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::error::Error;
+use crate::events::clan::ClanTreasuryDeposited;
+use crate::state::Clan;
+
+/// The clan owner moves tokens into a token account of the clan treasury
+#[derive(Accounts)]
+pub struct DepositClanTreasuryTokens<'info> {
+    #[account(
+        has_one = owner,
+    )]
+    clan: Account<'info, Clan>,
+
+    owner: Signer<'info>,
+
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            Clan::TREASURY_SEED,
+            &clan.key().to_bytes()
+        ],
+        bump = clan.bumps.treasury,
+    )]
+    treasury: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = treasury_tokens.owner == treasury.key() @ Error::WrongTreasuryAccount,
+    )]
+    treasury_tokens: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    source: Account<'info, TokenAccount>,
+
+    token_program: Program<'info, Token>,
+}
+
+impl<'info> DepositClanTreasuryTokens<'info> {
+    pub fn process(&mut self, amount: u64) -> Result<()> {
+        token::transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.source.to_account_info(),
+                    to: self.treasury_tokens.to_account_info(),
+                    authority: self.owner.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        emit!(ClanTreasuryDeposited {
+            clan: self.clan.key(),
+            mint: Some(self.treasury_tokens.mint),
+            amount,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+
+use crate::error::Error;
+use crate::events::clan::{
+    ClanDelegateChanged, ClanOwnerChanged, ClanSiblingUnlinked, RecallProposalExecuted,
+};
+use crate::state::{Clan, Member, RecallProposal, RecallSignature};
+
+/// Anyone can replace the clan owner or delegate once the members support the recall.
+/// The rest accounts are the (signature, member) pairs of the recall. The signatures
+/// are counted with the current member weights so the signers who left have none
+#[derive(Accounts)]
+pub struct ExecuteRecallProposal<'info> {
+    #[account(
+        mut,
+        has_one = clan,
+        constraint = !recall.executed @ Error::RecallProposalExecuted,
+    )]
+    recall: Account<'info, RecallProposal>,
+
+    #[account(
+        mut,
+        constraint = !clan.frozen @ Error::ClanFrozen,
+    )]
+    clan: Account<'info, Clan>,
+
+    /// Required if the clan is linked. The recalled clan leaves its sibling
+    /// because linked clans must keep the same owner
+    #[account(mut)]
+    sibling_clan: Option<Account<'info, Clan>>,
+}
+
+impl<'info> ExecuteRecallProposal<'info> {
+    pub fn process<'c: 'info>(&mut self, rest: &'c [AccountInfo<'info>]) -> Result<()> {
+        let time = Clock::get()?.unix_timestamp;
+        require_gt!(self.recall.expires_at, time, Error::RecallProposalExpired);
+        require!(rest.len() % 2 == 0, Error::InvalidRecallSignatures);
+        let mut signers = Vec::with_capacity(rest.len() / 2);
+        let mut signed_weight = 0;
+        for pair in rest.chunks_exact(2) {
+            let signature = Account::<RecallSignature>::try_from(&pair[0])?;
+            let member = Account::<Member>::try_from(&pair[1])?;
+            require_keys_eq!(signature.recall, self.recall.key());
+            require_keys_eq!(signature.member, member.key());
+            require!(
+                !signers.contains(&member.key()),
+                Error::InvalidRecallSignatures
+            );
+            signers.push(member.key());
+            // The leaving members give no weight to the clan
+            if let Some(entry) = member
+                .membership
+                .iter()
+                .find(|entry| entry.clan == self.clan.key() && entry.exitable_at.is_none())
+            {
+                signed_weight += signature.voter_weight.min(entry.reward_weight);
+            }
+        }
+        self.recall.signed_weight = signed_weight;
+        require!(
+            self.recall.is_supported(),
+            Error::RecallProposalNotSupported
+        );
+        require!(
+            self.sibling_clan.as_ref().map(|c| c.key()) == self.clan.sibling,
+            Error::WrongSiblingClan
+        );
+        self.recall.executed = true;
+
+        if let Some(sibling_clan) = self.sibling_clan.as_mut() {
+            self.clan.sibling = None;
+            sibling_clan.sibling = None;
+            emit!(ClanSiblingUnlinked {
+                clan: self.clan.key(),
+                sibling_clan: sibling_clan.key(),
+            });
+        }
+        // The new owner resets the voting delegate with set_voting_delegate if needed
+        if let Some(new_owner) = self.recall.new_owner {
+            let old_owner = self.clan.owner;
+            self.clan.owner = new_owner;
+            emit!(ClanOwnerChanged {
+                clan: self.clan.key(),
+                old_owner,
+                new_owner,
+                control_changed_at: self.clan.record_control_change(time),
+            });
+        }
+        if let Some(new_delegate) = self.recall.new_delegate {
+            let old_delegate = self.clan.delegate;
+            self.clan.delegate = new_delegate;
+            emit!(ClanDelegateChanged {
+                clan: self.clan.key(),
+                old_delegate,
+                new_delegate,
+                control_changed_at: self.clan.record_control_change(time),
+            });
+        }
+        emit!(RecallProposalExecuted {
+            recall: self.recall.key(),
+            clan: self.clan.key(),
+            new_owner: self.recall.new_owner,
+            new_delegate: self.recall.new_delegate,
+            signed_weight: self.recall.signed_weight,
+        });
+        Ok(())
+    }
+}
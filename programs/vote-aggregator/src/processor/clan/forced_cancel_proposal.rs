@@ -20,7 +20,8 @@ use crate::error::Error;
 pub struct ForcedCancelProposal<'info> {
     #[account(
         has_one = root,
-        constraint = clan.is_updated(&root) @ Error::TemporaryMembersNotUpdated
+        constraint = clan.is_updated(&root) @ Error::TemporaryMembersNotUpdated,
+        constraint = !clan.frozen @ Error::ClanFrozen,
     )]
     clan: Box<Account<'info, Clan>>,
     #[account(
@@ -99,6 +100,7 @@ pub struct ForcedCancelProposal<'info> {
 
 impl<'info> ForcedCancelProposal<'info> {
     pub fn process(&mut self) -> Result<()> {
+        self.root.check_not_paused(Root::PAUSE_PROPOSAL_VOTE)?;
         let realm = get_realm_data_for_governing_token_mint(
             self.governance_program.key,
             &self.realm.to_account_info(),
@@ -125,7 +127,7 @@ impl<'info> ForcedCancelProposal<'info> {
             } else if Some(self.governing_token_mint.key()) == realm.config.council_mint {
                 governance.config.min_council_weight_to_create_proposal
             } else {
-                unreachable!("Mints changed")
+                return err!(Error::UnknownGoverningTokenMint);
             };
         require_gt!(
             min_weight_to_create_proposal,
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::error::Error;
+use crate::events::clan::{ClanSiblingLinked, ClanSiblingUnlinked};
+use crate::state::{Clan, Root};
+
+/// Links the clans of the same owner in the community and the council roots
+#[derive(Accounts)]
+pub struct LinkSiblingClan<'info> {
+    root: Account<'info, Root>,
+    #[account(
+        constraint = sibling_root.key() != root.key() &&
+            sibling_root.realm == root.realm &&
+            sibling_root.governance_program == root.governance_program
+        @ Error::InvalidSiblingRoot,
+    )]
+    sibling_root: Account<'info, Root>,
+
+    #[account(
+        mut,
+        has_one = root,
+        has_one = owner,
+    )]
+    clan: Account<'info, Clan>,
+    #[account(
+        mut,
+        constraint = sibling_clan.root == sibling_root.key() @ Error::UnexpectedClan,
+        constraint = sibling_clan.owner == owner.key() @ Error::WrongClanAuthority,
+    )]
+    sibling_clan: Account<'info, Clan>,
+
+    owner: Signer<'info>,
+}
+
+impl<'info> LinkSiblingClan<'info> {
+    pub fn link(&mut self) -> Result<()> {
+        require!(
+            !self.clan.frozen && !self.sibling_clan.frozen,
+            Error::ClanFrozen
+        );
+        require!(
+            self.clan.sibling.is_none() && self.sibling_clan.sibling.is_none(),
+            Error::ClanAlreadyLinked
+        );
+        self.clan.sibling = Some(self.sibling_clan.key());
+        self.sibling_clan.sibling = Some(self.clan.key());
+        emit!(ClanSiblingLinked {
+            clan: self.clan.key(),
+            sibling_clan: self.sibling_clan.key(),
+        });
+        Ok(())
+    }
+
+    /// Allowed for frozen clans, so a frozen clan does not hold its sibling
+    pub fn unlink(&mut self) -> Result<()> {
+        require!(
+            self.clan.sibling == Some(self.sibling_clan.key())
+                && self.sibling_clan.sibling == Some(self.clan.key()),
+            Error::WrongSiblingClan
+        );
+        self.clan.sibling = None;
+        self.sibling_clan.sibling = None;
+        emit!(ClanSiblingUnlinked {
+            clan: self.clan.key(),
+            sibling_clan: self.sibling_clan.key(),
+        });
+        Ok(())
+    }
+}
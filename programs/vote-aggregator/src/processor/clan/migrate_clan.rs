@@ -0,0 +1,76 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, Transfer},
+    Discriminator,
+};
+
+use crate::error::Error;
+use crate::events::clan::ClanMigrated;
+use crate::state::{Clan, LegacyClan};
+
+/// Grows a clan created with the legacy layout and converts it
+#[derive(Accounts)]
+pub struct MigrateClan<'info> {
+    /// CHECK: the legacy layout is parsed by the processor
+    #[account(
+        mut,
+        owner = crate::ID,
+    )]
+    clan: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        owner = system_program::ID,
+    )]
+    payer: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateClan<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let clan_info = self.clan.to_account_info();
+        require_gt!(
+            Clan::SPACE,
+            clan_info.data_len(),
+            Error::ClanAlreadyMigrated
+        );
+        let legacy = {
+            let data = clan_info.try_borrow_data()?;
+            require!(
+                data.len() >= 8 && data[..8] == Clan::DISCRIMINATOR,
+                ErrorCode::AccountDiscriminatorMismatch
+            );
+            LegacyClan::deserialize(&mut &data[8..])?
+        };
+        let (_, treasury_bump) = Pubkey::find_program_address(
+            &[Clan::TREASURY_SEED, &self.clan.key().to_bytes()],
+            &crate::ID,
+        );
+        let clan = Clan::from_legacy(legacy, treasury_bump, Clock::get()?.unix_timestamp);
+
+        let rent = Rent::get()?.minimum_balance(Clan::SPACE);
+        let lamports = rent.saturating_sub(clan_info.lamports());
+        if lamports > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.payer.to_account_info(),
+                        to: clan_info.clone(),
+                    },
+                ),
+                lamports,
+            )?;
+        }
+        clan_info.realloc(Clan::SPACE, true)?;
+        clan.try_serialize(&mut &mut clan_info.try_borrow_mut_data()?[..])?;
+
+        emit!(ClanMigrated {
+            clan: self.clan.key(),
+            root: clan.root,
+            owner: clan.owner,
+        });
+        Ok(())
+    }
+}
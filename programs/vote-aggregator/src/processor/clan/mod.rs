@@ -1,17 +1,51 @@
+pub mod cast_veto_vote;
+pub mod claim_clan_commission;
 pub mod configure_clan;
 pub mod create_clan;
+pub mod create_clan_reward_vault;
+pub mod create_clan_snapshot_vwr;
+pub mod create_clan_vote_history;
+pub mod create_clan_weight_checkpoints;
+pub mod deposit_clan_rewards;
+pub mod deposit_clan_treasury;
+pub mod deposit_clan_treasury_tokens;
+pub mod execute_recall_proposal;
 pub mod forced_cancel_proposal;
+pub mod link_sibling_clan;
+pub mod migrate_clan;
+pub mod propose_treasury_withdrawal;
 pub mod resize_clan;
+pub mod revise_clan_vwr;
+pub mod set_clan_frozen;
 pub mod set_clan_owner;
 pub mod set_voting_delegate;
 pub mod update_clan;
 pub mod update_proposal_vote;
+pub mod withdraw_clan_treasury;
+pub mod withdraw_clan_treasury_tokens;
 
+pub use cast_veto_vote::*;
+pub use claim_clan_commission::*;
 pub use configure_clan::*;
 pub use create_clan::*;
+pub use create_clan_reward_vault::*;
+pub use create_clan_snapshot_vwr::*;
+pub use create_clan_vote_history::*;
+pub use create_clan_weight_checkpoints::*;
+pub use deposit_clan_rewards::*;
+pub use deposit_clan_treasury::*;
+pub use deposit_clan_treasury_tokens::*;
+pub use execute_recall_proposal::*;
 pub use forced_cancel_proposal::*;
+pub use link_sibling_clan::*;
+pub use migrate_clan::*;
+pub use propose_treasury_withdrawal::*;
 pub use resize_clan::*;
+pub use revise_clan_vwr::*;
+pub use set_clan_frozen::*;
 pub use set_clan_owner::*;
 pub use set_voting_delegate::*;
 pub use update_clan::*;
 pub use update_proposal_vote::*;
+pub use withdraw_clan_treasury::*;
+pub use withdraw_clan_treasury_tokens::*;
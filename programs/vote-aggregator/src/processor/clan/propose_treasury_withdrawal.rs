@@ -0,0 +1,92 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::error::Error;
+use crate::events::clan::TreasuryWithdrawalProposed;
+use crate::state::{Clan, Root, TreasuryWithdrawal};
+
+/// The clan owner asks the members to approve spending above the limit
+#[derive(Accounts)]
+pub struct ProposeTreasuryWithdrawal<'info> {
+    root: Account<'info, Root>,
+
+    #[account(
+        mut,
+        has_one = root,
+        has_one = owner,
+        constraint = !clan.frozen @ Error::ClanFrozen,
+    )]
+    clan: Account<'info, Clan>,
+
+    owner: Signer<'info>,
+
+    #[account(
+        init,
+        seeds = [
+            TreasuryWithdrawal::ADDRESS_SEED,
+            &clan.key().to_bytes(),
+            &clan.treasury_withdrawals.to_le_bytes(),
+        ],
+        bump,
+        payer = payer,
+        space = TreasuryWithdrawal::SPACE,
+    )]
+    withdrawal: Account<'info, TreasuryWithdrawal>,
+
+    #[account(
+        mut,
+        owner = system_program::ID
+    )]
+    payer: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> ProposeTreasuryWithdrawal<'info> {
+    pub fn process(
+        &mut self,
+        mint: Option<Pubkey>,
+        amount: u64,
+        destination: Pubkey,
+        bumps: ProposeTreasuryWithdrawalBumps,
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+        self.clan
+            .apply_pending_treasury_policy(clock.unix_timestamp);
+        let policy = self
+            .clan
+            .treasury_policy
+            .as_ref()
+            .ok_or(error!(Error::TreasuryPolicyRequired))?;
+        // Fixed at the proposal so the joining members can not dilute the approvals
+        let required_weight =
+            (self.clan.reward_weight as u128 * policy.approval_threshold_bp as u128 / 10000) as u64;
+        // Same lifetime as the proposals the members can leave the clan for
+        let expires_at = self.root.max_proposal_lifetime_end(clock.unix_timestamp);
+        let index = self.clan.treasury_withdrawals;
+        self.clan.treasury_withdrawals += 1;
+        self.withdrawal.set_inner(TreasuryWithdrawal {
+            clan: self.clan.key(),
+            index,
+            mint,
+            amount,
+            destination,
+            required_weight,
+            approved_weight: 0,
+            approvals: 0,
+            expires_at,
+            executed: false,
+            bump: bumps.withdrawal,
+        });
+        emit!(TreasuryWithdrawalProposed {
+            clan: self.clan.key(),
+            withdrawal: self.withdrawal.key(),
+            index,
+            mint,
+            amount,
+            destination,
+            required_weight,
+            expires_at,
+        });
+        Ok(())
+    }
+}
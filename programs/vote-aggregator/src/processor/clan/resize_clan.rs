@@ -7,6 +7,7 @@ use crate::error::Error;
 #[derive(Accounts)]
 #[instruction(size: u32)]
 pub struct ResizeClan<'info> {
+    /// Frozen clans are resizable too so their members can still be updated and leave
     #[account(
         mut,
         realloc = size as usize,
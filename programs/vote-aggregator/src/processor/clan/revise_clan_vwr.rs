@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::error::Error;
+use crate::state::{Clan, Root, VoterWeightRecord};
+
+/// Prepended to the delegate's cast_vote to satisfy the same slot VWR expiry
+#[derive(Accounts)]
+pub struct ReviseClanVwr<'info> {
+    root: Account<'info, Root>,
+
+    #[account(
+        mut,
+        has_one = root,
+        constraint = clan.is_updated(&root) @ Error::TemporaryMembersNotUpdated,
+        constraint = clan.is_migrated(&root) @ Error::WeightTransformNotMigrated,
+        constraint = !clan.frozen @ Error::ClanFrozen,
+    )]
+    clan: Account<'info, Clan>,
+
+    #[account(
+        mut,
+        seeds = [
+            VoterWeightRecord::ADDRESS_SEED,
+            &clan.key().to_bytes()
+        ],
+        bump = clan.bumps.voter_weight_record,
+    )]
+    clan_vwr: Account<'info, VoterWeightRecord>,
+}
+
+impl<'info> ReviseClanVwr<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        self.root.check_not_paused(Root::PAUSE_PROPOSAL_VOTE)?;
+        self.root.check_no_plugin_drift()?;
+        let clock = Clock::get()?;
+        self.clan.revise_voter_weight(&mut self.clan_vwr, &clock);
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::events::clan::ClanFrozenChanged;
+use crate::processor::root::configure_root::*;
+use crate::state::Clan;
+use crate::error::Error;
+
+#[derive(Accounts)]
+pub struct SetClanFrozen<'info> {
+    configure_root: ConfigureRoot<'info>,
+
+    #[account(
+        mut,
+        constraint = clan.root == configure_root.root.key() @ Error::UnexpectedClan,
+    )]
+    clan: Account<'info, Clan>,
+}
+
+impl<'info> SetClanFrozen<'info> {
+    pub fn process(&mut self, frozen: bool) -> Result<()> {
+        // Same as pause/resume for the whole root
+        if frozen {
+            self.configure_root.check_pause_authority()?;
+        } else {
+            self.configure_root.check_authority()?;
+        }
+        let old_frozen = self.clan.frozen;
+        self.clan.frozen = frozen;
+        if frozen != old_frozen {
+            emit!(ClanFrozenChanged {
+                clan: self.clan.key(),
+                root: self.clan.root,
+                frozen,
+            });
+        }
+        Ok(())
+    }
+}
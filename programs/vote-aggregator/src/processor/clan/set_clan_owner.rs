@@ -1,27 +1,62 @@
 use anchor_lang::prelude::*;
 
 use crate::events::clan::ClanOwnerChanged;
-use crate::state::Clan;
+use crate::state::{Clan, Root};
+use crate::error::Error;
 
 #[derive(Accounts)]
 pub struct SetClanOwner<'info> {
     #[account(
         mut,
         has_one = owner,
+        constraint = !clan.frozen @ Error::ClanFrozen,
     )]
     clan: Account<'info, Clan>,
     owner: Signer<'info>,
+
+    /// Required if the clan is linked. Linked clans must keep the same owner
+    #[account(
+        mut,
+        has_one = owner,
+        constraint = !sibling_clan.frozen @ Error::ClanFrozen,
+    )]
+    sibling_clan: Option<Account<'info, Clan>>,
+
+    #[account(
+        constraint = root.key() == clan.root @ Error::UnexpectedClan,
+    )]
+    root: Account<'info, Root>,
 }
 
 impl <'info> SetClanOwner<'info> {
     pub fn process(&mut self, owner: Pubkey) -> Result<()> {
+        self.root.check_not_paused(Root::PAUSE_CLAN_CONFIG)?;
+        require!(
+            self.sibling_clan.as_ref().map(|c| c.key()) == self.clan.sibling,
+            Error::WrongSiblingClan
+        );
+        let time = Clock::get()?.unix_timestamp;
         let old_owner = self.clan.owner;
+        if owner == old_owner {
+            // The sibling clan has the same owner
+            return Ok(());
+        }
         self.clan.owner = owner;
         emit!(ClanOwnerChanged {
             clan: self.clan.key(),
             old_owner,
             new_owner: owner,
+            control_changed_at: self.clan.record_control_change(time),
         });
+        if let Some(sibling_clan) = self.sibling_clan.as_mut() {
+            sibling_clan.owner = owner;
+            emit!(ClanOwnerChanged {
+                clan: sibling_clan.key(),
+                old_owner,
+                new_owner: owner,
+                control_changed_at: sibling_clan.record_control_change(time),
+            });
+        }
         Ok(())
     }
 }
\ No newline at end of file
@@ -16,6 +16,7 @@ pub struct SetVotingDelegate<'info> {
     #[account(
         mut,
         has_one = root,
+        constraint = !clan.frozen @ Error::ClanFrozen,
     )]
     clan: Account<'info, Clan>,
     #[account(
@@ -60,6 +61,7 @@ pub struct SetVotingDelegate<'info> {
 
 impl<'info> SetVotingDelegate<'info> {
     pub fn process(&mut self, new_voting_delegate: Pubkey) -> Result<()> {
+        self.root.check_not_paused(Root::PAUSE_CLAN_CONFIG)?;
         let old_voting_delegate = get_token_owner_record_data(
             self.governance_program.key,
             &self.clan_tor.to_account_info(),
@@ -75,6 +77,9 @@ impl<'info> SetVotingDelegate<'info> {
         } else {
             Some(new_voting_delegate)
         };
+        if new_voting_delegate == old_voting_delegate {
+            return Ok(());
+        }
 
         invoke_signed(
             &set_governance_delegate(
@@ -100,6 +105,9 @@ impl<'info> SetVotingDelegate<'info> {
             clan: self.clan.key(),
             new_voting_delegate,
             old_voting_delegate,
+            control_changed_at: self
+                .clan
+                .record_control_change(Clock::get()?.unix_timestamp),
         });
         Ok(())
     }
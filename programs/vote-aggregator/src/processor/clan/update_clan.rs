@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 
+use crate::events::clan::ClanWeightTransformSynced;
 use crate::state::{Clan, Root, VoterWeightRecord};
 
 #[derive(Accounts)]
@@ -7,10 +8,22 @@ pub struct UpdateClan<'info> {
     #[account(mut)]
     root: Account<'info, Root>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        has_one = root,
+    )]
     clan: Account<'info, Clan>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [
+            VoterWeightRecord::ADDRESS_SEED,
+            &clan.key().to_bytes()
+        ],
+        bump = clan.bumps.voter_weight_record,
+        constraint = clan_wvr.realm == root.realm,
+        constraint = clan_wvr.governing_token_mint == root.governing_token_mint,
+    )]
     clan_wvr: Account<'info, VoterWeightRecord>,
 }
 
@@ -20,6 +33,18 @@ impl<'info> UpdateClan<'info> {
         self.root.update_next_voter_weight_reset_time(&clock);
         self.clan
             .reset_voter_weight_if_needed(&mut self.root, &mut self.clan_wvr);
+        self.clan
+            .apply_voter_weight_decay(&mut self.clan_wvr, clock.unix_timestamp);
+        self.clan
+            .apply_membership_warmup(&mut self.clan_wvr, clock.unix_timestamp);
+        if self.clan.sync_weight_transform(&self.root) {
+            emit!(ClanWeightTransformSynced {
+                clan: self.clan.key(),
+                weight_transform: self.clan.weight_transform.clone(),
+                weight_transform_epoch: self.clan.weight_transform_epoch,
+                unmigrated_members: self.clan.unmigrated_members,
+            });
+        }
         Ok(())
     }
 }
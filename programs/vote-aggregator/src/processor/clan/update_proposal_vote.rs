@@ -3,19 +3,27 @@ use anchor_lang::{prelude::*, solana_program::program::invoke_signed, system_pro
 use anchor_spl::token::Mint;
 use spl_governance::{
     instruction::{cast_vote, relinquish_vote},
-    state::vote_record::get_vote_record_data,
+    state::{
+        proposal::get_proposal_data_for_governance,
+        realm::get_realm_data_for_governing_token_mint,
+        vote_record::{get_vote_record_data, Vote},
+    },
     PROGRAM_AUTHORITY_SEED,
 };
 
 use crate::events::clan::ProposalVoteUpdated;
-use crate::state::{Clan, Root, VoterWeightRecord};
+use crate::state::{Clan, ClanVoteHistory, MaxVoterWeightRecord, Root, VoterWeightRecord};
 use crate::error::Error;
 
 #[derive(Accounts)]
 pub struct UpdateProposalVote<'info> {
     #[account(
+        mut,
         has_one = root,
-        constraint = clan.is_updated(&root) @ Error::TemporaryMembersNotUpdated 
+        constraint = clan.is_updated(&root) @ Error::TemporaryMembersNotUpdated,
+        constraint = clan.is_migrated(&root) @ Error::WeightTransformNotMigrated,
+        constraint = !clan.snapshot_voting @ Error::ClanSnapshotVoting,
+        constraint = !clan.frozen @ Error::ClanFrozen,
     )]
     clan: Box<Account<'info, Clan>>,
     #[account(
@@ -84,6 +92,7 @@ pub struct UpdateProposalVote<'info> {
     )]
     clan_tor: UncheckedAccount<'info>,
     #[account(
+        mut,
         seeds = [
             VoterWeightRecord::ADDRESS_SEED,
             &clan.key().to_bytes()
@@ -92,8 +101,15 @@ pub struct UpdateProposalVote<'info> {
         address = clan.voter_weight_record,
     )]
     clan_vwr: Box<Account<'info, VoterWeightRecord>>,
-    /// CHECK: CPI
-    max_vwr: Option<UncheckedAccount<'info>>,
+    /// Max voter weight of the voting mint, which is also the veto mint
+    #[account(
+        seeds = [
+            MaxVoterWeightRecord::ADDRESS_SEED,
+            &root.key().to_bytes()
+        ],
+        bump = root.bumps.max_voter_weight,
+    )]
+    max_vwr: Option<Box<Account<'info, MaxVoterWeightRecord>>>,
     /// CHECK: dynamic owner
     #[account(
         mut,
@@ -106,6 +122,16 @@ pub struct UpdateProposalVote<'info> {
         seeds::program = governance_program.key(),
     )]
     vote_record: UncheckedAccount<'info>,
+    /// Required once the clan has a vote history
+    #[account(
+        mut,
+        seeds = [
+            ClanVoteHistory::ADDRESS_SEED,
+            &clan.key().to_bytes()
+        ],
+        bump = vote_history.bump,
+    )]
+    vote_history: Option<Box<Account<'info, ClanVoteHistory>>>,
     #[account(
         mut,
         owner = system_program::ID,
@@ -119,7 +145,33 @@ pub struct UpdateProposalVote<'info> {
 }
 
 impl<'info> UpdateProposalVote<'info> {
+    fn check_proposal_mint(&self, is_veto: bool) -> Result<()> {
+        let realm = get_realm_data_for_governing_token_mint(
+            self.governance_program.key,
+            &self.realm.to_account_info(),
+            &self.root.governing_token_mint,
+        )
+        .map_err(|e| ProgramErrorWithOrigin::from(e).with_account_name("realm"))?;
+        let proposal = get_proposal_data_for_governance(
+            self.governance_program.key,
+            &self.proposal.to_account_info(),
+            self.governance.key,
+        )
+        .map_err(|e| ProgramErrorWithOrigin::from(e).with_account_name("proposal"))?;
+        require_keys_eq!(
+            proposal.governing_token_mint,
+            self.root.proposal_mint_for_vote(&realm, is_veto)?,
+            Error::InvalidProposalMint
+        );
+        Ok(())
+    }
+
     pub fn process(&mut self) -> Result<()> {
+        self.root.check_not_paused(Root::PAUSE_PROPOSAL_VOTE)?;
+        self.root.check_no_plugin_drift()?;
+        let clock = Clock::get()?;
+        // The clan is updated so its weight is valid for the current slot
+        self.clan.revise_voter_weight(&mut self.clan_vwr, &clock);
         let vote_record = get_vote_record_data(
             self.governance_program.key,
             &self.vote_record.to_account_info(),
@@ -135,6 +187,7 @@ impl<'info> UpdateProposalVote<'info> {
         }
         let old_voting_weight = vote_record.voter_weight;
         let vote = vote_record.vote;
+        self.check_proposal_mint(matches!(vote, Vote::Veto))?;
         invoke_signed(
             &relinquish_vote(
                 self.governance_program.key,
@@ -193,8 +246,8 @@ impl<'info> UpdateProposalVote<'info> {
                 &self.governing_token_mint.key(),
                 self.payer.key,
                 Some(self.clan_vwr.key()),
-                self.max_vwr.as_ref().map(UncheckedAccount::key),
-                vote,
+                self.max_vwr.as_ref().map(|max_vwr| max_vwr.key()),
+                vote.clone(),
             ),
             &cast_vote_accounts,
             &[&[
@@ -203,6 +256,16 @@ impl<'info> UpdateProposalVote<'info> {
                 &[self.clan.bumps.voter_authority],
             ]],
         )?;
+        self.clan.record_vote(
+            self.vote_history
+                .as_deref_mut()
+                .map(|vote_history| &mut **vote_history),
+            self.proposal.key(),
+            &vote,
+            self.clan_vwr.voter_weight,
+            clock.unix_timestamp,
+            true,
+        )?;
         emit!(ProposalVoteUpdated {
             clan: self.clan.key(),
             proposal: self.proposal.key(),
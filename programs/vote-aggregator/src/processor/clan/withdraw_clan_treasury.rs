@@ -0,0 +1,80 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, Transfer},
+};
+
+use crate::error::Error;
+use crate::events::clan::ClanTreasuryWithdrawn;
+use crate::state::{Clan, TreasuryWithdrawal};
+
+/// The clan owner spends SOL from the clan treasury
+#[derive(Accounts)]
+pub struct WithdrawClanTreasury<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        constraint = !clan.frozen @ Error::ClanFrozen,
+    )]
+    clan: Account<'info, Clan>,
+
+    owner: Signer<'info>,
+
+    /// CHECK: PDA
+    #[account(
+        mut,
+        seeds = [
+            Clan::TREASURY_SEED,
+            &clan.key().to_bytes()
+        ],
+        bump = clan.bumps.treasury,
+    )]
+    treasury: UncheckedAccount<'info>,
+
+    /// Required above the clan spending limit
+    #[account(
+        mut,
+        has_one = clan,
+    )]
+    withdrawal: Option<Account<'info, TreasuryWithdrawal>>,
+
+    /// CHECK: any SOL receiver
+    #[account(mut)]
+    destination: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> WithdrawClanTreasury<'info> {
+    pub fn process(&mut self, amount: u64) -> Result<()> {
+        self.clan.check_treasury_withdrawal(
+            self.withdrawal.as_deref_mut(),
+            None,
+            amount,
+            self.destination.key(),
+            Clock::get()?.unix_timestamp,
+        )?;
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.treasury.to_account_info(),
+                    to: self.destination.to_account_info(),
+                },
+                &[&[
+                    Clan::TREASURY_SEED,
+                    &self.clan.key().to_bytes(),
+                    &[self.clan.bumps.treasury],
+                ]],
+            ),
+            amount,
+        )?;
+        emit!(ClanTreasuryWithdrawn {
+            clan: self.clan.key(),
+            mint: None,
+            amount,
+            destination: self.destination.key(),
+            withdrawal: self.withdrawal.as_ref().map(|w| w.key()),
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::error::Error;
+use crate::events::clan::ClanTreasuryWithdrawn;
+use crate::state::{Clan, TreasuryWithdrawal};
+
+/// The clan owner spends tokens from a token account of the clan treasury
+#[derive(Accounts)]
+pub struct WithdrawClanTreasuryTokens<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        constraint = !clan.frozen @ Error::ClanFrozen,
+    )]
+    clan: Account<'info, Clan>,
+
+    owner: Signer<'info>,
+
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            Clan::TREASURY_SEED,
+            &clan.key().to_bytes()
+        ],
+        bump = clan.bumps.treasury,
+    )]
+    treasury: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = treasury_tokens.owner == treasury.key() @ Error::WrongTreasuryAccount,
+    )]
+    treasury_tokens: Account<'info, TokenAccount>,
+
+    /// Required above the clan spending limit
+    #[account(
+        mut,
+        has_one = clan,
+    )]
+    withdrawal: Option<Account<'info, TreasuryWithdrawal>>,
+
+    #[account(mut)]
+    destination: Account<'info, TokenAccount>,
+
+    token_program: Program<'info, Token>,
+}
+
+impl<'info> WithdrawClanTreasuryTokens<'info> {
+    pub fn process(&mut self, amount: u64) -> Result<()> {
+        self.clan.check_treasury_withdrawal(
+            self.withdrawal.as_deref_mut(),
+            Some(self.treasury_tokens.mint),
+            amount,
+            self.destination.key(),
+            Clock::get()?.unix_timestamp,
+        )?;
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.treasury_tokens.to_account_info(),
+                    to: self.destination.to_account_info(),
+                    authority: self.treasury.to_account_info(),
+                },
+                &[&[
+                    Clan::TREASURY_SEED,
+                    &self.clan.key().to_bytes(),
+                    &[self.clan.bumps.treasury],
+                ]],
+            ),
+            amount,
+        )?;
+        emit!(ClanTreasuryWithdrawn {
+            clan: self.clan.key(),
+            mint: Some(self.treasury_tokens.mint),
+            amount,
+            destination: self.destination.key(),
+            withdrawal: self.withdrawal.as_ref().map(|w| w.key()),
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::{
+    error::Error,
+    events::member::TreasuryWithdrawalApproved,
+    state::{Member, TreasuryWithdrawal, TreasuryWithdrawalApproval},
+};
+
+/// The member backs the clan treasury withdrawal with its contribution to the clan
+#[derive(Accounts)]
+pub struct ApproveTreasuryWithdrawal<'info> {
+    member: Account<'info, Member>,
+    #[account(
+        constraint = member_authority.key() == member.owner ||
+            member_authority.key() == member.delegate
+        @ Error::WrongMemberAuthority
+    )]
+    member_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = !withdrawal.executed @ Error::TreasuryWithdrawalExecuted,
+    )]
+    withdrawal: Account<'info, TreasuryWithdrawal>,
+
+    #[account(
+        init,
+        seeds = [
+            TreasuryWithdrawalApproval::ADDRESS_SEED,
+            &withdrawal.key().to_bytes(),
+            &member.key().to_bytes(),
+        ],
+        bump,
+        payer = payer,
+        space = TreasuryWithdrawalApproval::SPACE,
+    )]
+    approval: Account<'info, TreasuryWithdrawalApproval>,
+
+    #[account(
+        mut,
+        owner = system_program::ID
+    )]
+    payer: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> ApproveTreasuryWithdrawal<'info> {
+    pub fn process(&mut self, bumps: ApproveTreasuryWithdrawalBumps) -> Result<()> {
+        require_gt!(
+            self.withdrawal.expires_at,
+            Clock::get()?.unix_timestamp,
+            Error::TreasuryWithdrawalExpired
+        );
+        // The leaving members give no weight to the clan
+        let voter_weight = self
+            .member
+            .membership
+            .iter()
+            .find(|entry| entry.clan == self.withdrawal.clan && entry.exitable_at.is_none())
+            .ok_or(error!(Error::UnexpectedClan))?
+            .reward_weight;
+        self.withdrawal.approved_weight += voter_weight;
+        self.withdrawal.approvals += 1;
+        self.approval.set_inner(TreasuryWithdrawalApproval {
+            withdrawal: self.withdrawal.key(),
+            member: self.member.key(),
+            voter_weight,
+            bump: bumps.approval,
+        });
+        emit!(TreasuryWithdrawalApproved {
+            withdrawal: self.withdrawal.key(),
+            clan: self.withdrawal.clan,
+            member: self.member.key(),
+            voter_weight,
+            approved_weight: self.withdrawal.approved_weight,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::{
+    error::Error,
+    events::member::ClanRewardsClaimed,
+    state::{Clan, Member},
+};
+
+#[derive(Accounts)]
+pub struct ClaimClanRewards<'info> {
+    #[account(mut)]
+    member: Account<'info, Member>,
+    #[account(
+        constraint = member_authority.key() == member.owner ||
+            member_authority.key() == member.delegate
+        @ Error::WrongMemberAuthority
+    )]
+    member_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = clan.root == member.root @ Error::UnexpectedClan,
+    )]
+    clan: Account<'info, Clan>,
+
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            Clan::VOTER_AUTHORITY_SEED,
+            &clan.key().to_bytes()
+        ],
+        bump = clan.bumps.voter_authority,
+    )]
+    voter_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            Clan::REWARD_VAULT_SEED,
+            &clan.key().to_bytes()
+        ],
+        bump = clan.bumps.reward_vault,
+    )]
+    reward_vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    destination: Account<'info, TokenAccount>,
+
+    token_program: Program<'info, Token>,
+}
+
+impl<'info> ClaimClanRewards<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let entry = self
+            .member
+            .membership
+            .iter_mut()
+            .find(|entry| entry.clan == self.clan.key())
+            .ok_or(error!(Error::UnexpectedClan))?;
+        // The commission is charged by the rate in force when the rewards were distributed
+        let commission = self
+            .clan
+            .accrue_member_rewards(entry, Clock::get()?.unix_timestamp);
+        let amount = entry.unclaimed_rewards;
+        entry.unclaimed_rewards = 0;
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.reward_vault.to_account_info(),
+                    to: self.destination.to_account_info(),
+                    authority: self.voter_authority.to_account_info(),
+                },
+                &[&[
+                    Clan::VOTER_AUTHORITY_SEED,
+                    &self.clan.key().to_bytes(),
+                    &[self.clan.bumps.voter_authority],
+                ]],
+            ),
+            amount,
+        )?;
+        emit!(ClanRewardsClaimed {
+            member: self.member.key(),
+            clan: self.clan.key(),
+            owner: self.member.owner,
+            amount,
+            commission,
+        });
+        Ok(())
+    }
+}
@@ -50,6 +50,9 @@ pub struct CreateMember<'info> {
 
 impl<'info> CreateMember<'info> {
     pub fn process(&mut self, bumps: CreateMemberBumps) -> Result<()> {
+        self.root.check_not_paused(Root::PAUSE_CREATE)?;
+        self.root.check_not_winding_down()?;
+        self.root.check_installed()?;
         // Check TOR
         let member_tor = get_token_owner_record_data_for_realm_and_governing_mint(
             &self.root.governance_program,
@@ -67,6 +70,9 @@ impl<'info> CreateMember<'info> {
             voter_weight: 0,
             voter_weight_expiry: None,
             next_voter_weight_reset_time: self.root.next_voter_weight_reset_time(),
+            voter_weight_decay: None,
+            transformed_voter_weight: 0,
+            loyalty_bonus_weight: 0,
             membership: vec![],
             bumps: MemberBumps {
                 address: bumps.member,
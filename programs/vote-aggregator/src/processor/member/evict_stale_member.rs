@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::Error,
+    events::member::StaleMemberEvicted,
+    state::{Clan, ClanWeightCheckpoints, Member, Root, VoterWeightRecord},
+};
+
+#[derive(Accounts)]
+pub struct EvictStaleMember<'info> {
+    #[account(
+        mut,
+        has_one = root,
+    )]
+    member: Account<'info, Member>,
+    #[account(mut)]
+    root: Account<'info, Root>,
+    #[account(
+        mut,
+        has_one = root,
+    )]
+    clan: Account<'info, Clan>,
+    #[account(
+        mut,
+        seeds = [
+            VoterWeightRecord::ADDRESS_SEED,
+            &clan.key().to_bytes()
+        ],
+        bump = clan.bumps.voter_weight_record,
+    )]
+    clan_vwr: Box<Account<'info, VoterWeightRecord>>,
+    /// Required once the clan has weight checkpoints
+    #[account(
+        mut,
+        seeds = [
+            ClanWeightCheckpoints::ADDRESS_SEED,
+            &clan.key().to_bytes()
+        ],
+        bump = weight_checkpoints.bump,
+    )]
+    weight_checkpoints: Option<Account<'info, ClanWeightCheckpoints>>,
+}
+
+impl<'info> EvictStaleMember<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        self.root.check_not_paused(Root::PAUSE_LEAVE)?;
+        let grace_period = self
+            .root
+            .stale_member_grace_period
+            .ok_or(error!(Error::StaleMemberEvictionDisabled))?;
+        let clock = Clock::get()?;
+        self.root.update_next_voter_weight_reset_time(&clock);
+        self.clan
+            .reset_voter_weight_if_needed(&self.root, &mut self.clan_vwr);
+
+        // Only temporary and decaying members are waited for by Clan::is_updated
+        require!(
+            self.clan.is_member_outdated(&self.member),
+            Error::MemberIsNotStale
+        );
+        // The reset the member missed. The last root reset if the member was never reset
+        let missed_reset_time = if let Some(time) = self.member.next_voter_weight_reset_time {
+            time
+        } else {
+            let reset = self
+                .root
+                .voter_weight_reset
+                .as_ref()
+                .ok_or(error!(Error::MemberIsNotStale))?;
+            reset
+                .next_reset_time
+                .saturating_sub(i64::try_from(reset.step).unwrap_or(i64::MAX))
+        };
+        require_gte!(
+            clock.unix_timestamp,
+            missed_reset_time.saturating_add(i64::try_from(grace_period).unwrap_or(i64::MAX)),
+            Error::TooEarlyToEvictMember
+        );
+
+        let exitable_at =
+            clock.unix_timestamp + i64::try_from(self.root.max_proposal_lifetime).unwrap();
+        let entry = self
+            .member
+            .membership
+            .iter_mut()
+            .find(|entry| entry.clan == self.clan.key())
+            .ok_or(error!(Error::UnexpectedClan))?;
+        require!(entry.exitable_at.is_none(), Error::RerequestingLeavingClan);
+        entry.exitable_at = Some(exitable_at);
+
+        let share_bp = entry.share_bp;
+        Clan::update_member(
+            &mut self.clan,
+            &mut self.member,
+            Some(share_bp),
+            None,
+            None, // Leaving the clan
+            &mut self.clan_vwr,
+            &clock,
+        )?;
+        self.clan.record_weight_checkpoint(
+            self.weight_checkpoints.as_deref_mut(),
+            &self.clan_vwr,
+            clock.slot,
+        )?;
+        self.clan.leaving_members += 1;
+        emit!(StaleMemberEvicted {
+            member: self.member.key(),
+            clan: self.clan.key(),
+            root: self.root.key(),
+            owner: self.member.owner,
+            exitable_at,
+        });
+        Ok(())
+    }
+}
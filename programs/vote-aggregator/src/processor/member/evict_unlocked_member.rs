@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use spl_governance::{
+    state::token_owner_record::get_token_owner_record_data_for_realm_and_governing_mint,
+    PROGRAM_AUTHORITY_SEED,
+};
+
+use crate::{
+    error::Error,
+    events::member::UnlockedMemberEvicted,
+    state::{Clan, ClanWeightCheckpoints, Member, Root, VoterWeightRecord},
+};
+
+/// Removes the weight of a member whose tokens are not locked anymore
+#[derive(Accounts)]
+pub struct EvictUnlockedMember<'info> {
+    #[account(
+        mut,
+        has_one = root,
+    )]
+    member: Account<'info, Member>,
+    #[account(mut)]
+    root: Account<'info, Root>,
+
+    /// CHECK: dynamic owner
+    #[account(
+        seeds = [
+            PROGRAM_AUTHORITY_SEED,
+            &root.realm.to_bytes(),
+            &root.governing_token_mint.to_bytes(),
+            &member.owner.to_bytes(),
+        ],
+        seeds::program = root.governance_program,
+        bump = member.bumps.token_owner_record,
+        address = member.token_owner_record,
+    )]
+    member_tor: UncheckedAccount<'info>,
+
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            Root::LOCK_AUTHORITY_SEED,
+            &root.key().to_bytes()
+        ],
+        bump = root.bumps.lock_authority,
+    )]
+    lock_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        has_one = root,
+    )]
+    clan: Account<'info, Clan>,
+    #[account(
+        mut,
+        seeds = [
+            VoterWeightRecord::ADDRESS_SEED,
+            &clan.key().to_bytes()
+        ],
+        bump = clan.bumps.voter_weight_record,
+    )]
+    clan_vwr: Box<Account<'info, VoterWeightRecord>>,
+    /// Required once the clan has weight checkpoints
+    #[account(
+        mut,
+        seeds = [
+            ClanWeightCheckpoints::ADDRESS_SEED,
+            &clan.key().to_bytes()
+        ],
+        bump = weight_checkpoints.bump,
+    )]
+    weight_checkpoints: Option<Account<'info, ClanWeightCheckpoints>>,
+}
+
+impl<'info> EvictUnlockedMember<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        self.root.check_not_paused(Root::PAUSE_LEAVE)?;
+        let clock = Clock::get()?;
+        let member_tor = get_token_owner_record_data_for_realm_and_governing_mint(
+            &self.root.governance_program,
+            &self.member_tor.to_account_info(),
+            &self.root.realm,
+            &self.root.governing_token_mint,
+        )
+        .map_err(|e| ProgramErrorWithOrigin::from(e).with_account_name("member_tor"))?;
+        // An expired lock may be still listed in the TOR
+        let is_locked = member_tor.locks.iter().any(|l| {
+            l.authority == self.lock_authority.key()
+                && l.lock_id == 0
+                && l.expiry
+                    .map_or(true, |expiry| expiry > clock.unix_timestamp)
+        });
+        require!(!is_locked, Error::MemberStillLocked);
+
+        self.root.update_next_voter_weight_reset_time(&clock);
+        self.clan
+            .reset_voter_weight_if_needed(&self.root, &mut self.clan_vwr);
+
+        let exitable_at =
+            clock.unix_timestamp + i64::try_from(self.root.max_proposal_lifetime).unwrap();
+        let entry = self
+            .member
+            .membership
+            .iter_mut()
+            .find(|entry| entry.clan == self.clan.key())
+            .ok_or(error!(Error::UnexpectedClan))?;
+        require!(entry.exitable_at.is_none(), Error::RerequestingLeavingClan);
+        entry.exitable_at = Some(exitable_at);
+
+        let share_bp = entry.share_bp;
+        Clan::update_member(
+            &mut self.clan,
+            &mut self.member,
+            Some(share_bp),
+            None,
+            None, // Leaving the clan
+            &mut self.clan_vwr,
+            &clock,
+        )?;
+        self.clan.record_weight_checkpoint(
+            self.weight_checkpoints.as_deref_mut(),
+            &self.clan_vwr,
+            clock.slot,
+        )?;
+        self.clan.leaving_members += 1;
+        emit!(UnlockedMemberEvicted {
+            member: self.member.key(),
+            clan: self.clan.key(),
+            root: self.root.key(),
+            owner: self.member.owner,
+            exitable_at,
+        });
+        Ok(())
+    }
+}
@@ -104,7 +104,23 @@ pub struct ExitClan<'info> {
 }
 
 impl<'info> ExitClan<'info> {
+    /// The lock may be already expired and removed
+    fn has_lock(&self) -> Result<bool> {
+        let member_tor = get_token_owner_record_data_for_realm_and_governing_mint(
+            &self.root.governance_program,
+            &self.member_tor.to_account_info(),
+            &self.root.realm,
+            &self.root.governing_token_mint,
+        )
+        .map_err(|e| ProgramErrorWithOrigin::from(e).with_account_name("member_tor"))?;
+        Ok(member_tor
+            .locks
+            .iter()
+            .any(|l| l.authority == self.lock_authority.key() && l.lock_id == 0))
+    }
+
     pub fn process(&mut self) -> Result<()> {
+        self.root.check_not_paused(Root::PAUSE_LEAVE)?;
         let (index, entry) = self
             .member
             .membership
@@ -115,9 +131,11 @@ impl<'info> ExitClan<'info> {
         let leaving_time = entry
             .exitable_at
             .ok_or(error!(Error::UnexpectedExitingClan))?;
+        // The rewards would be lost with the membership entry
+        require_eq!(entry.unclaimed_rewards, 0, Error::UnclaimedClanRewards);
         let clock = Clock::get()?;
 
-        let safe_to_exit = if let Some(clan_tor) = self.clan_tor.as_ref() {
+        let (no_votes, no_proposals) = if let Some(clan_tor) = self.clan_tor.as_ref() {
             let clan_tor = get_token_owner_record_data_for_realm_and_governing_mint(
                 &self.root.governance_program,
                 &clan_tor.to_account_info(),
@@ -126,12 +144,24 @@ impl<'info> ExitClan<'info> {
             )
             .map_err(|e| ProgramErrorWithOrigin::from(e).with_account_name("clan_tor"))?;
 
-            clan_tor.unrelinquished_votes_count == 0 && clan_tor.outstanding_proposal_count == 0
+            (
+                clan_tor.unrelinquished_votes_count == 0,
+                clan_tor.outstanding_proposal_count == 0,
+            )
         } else {
-            false
+            (false, false)
         };
+        let safe_to_exit = no_votes && no_proposals;
+
+        // The members who joined before a control change skip the cooldown.
+        // Their weight must not be in the live votes or flipping the delegate
+        // would let them vote and leave right away
+        let free_exit = no_votes
+            && self
+                .clan
+                .is_free_exit(&self.root, entry.joined_at, clock.unix_timestamp);
 
-        if !safe_to_exit {
+        if !safe_to_exit && !free_exit {
             require_gte!(
                 clock.unix_timestamp,
                 leaving_time,
@@ -142,7 +172,7 @@ impl<'info> ExitClan<'info> {
         self.member.membership.remove(index);
         self.clan.leaving_members -= 1;
 
-        if self.member.membership.is_empty() {
+        if self.member.membership.is_empty() && self.has_lock()? {
             invoke_signed(
                 &relinquish_token_owner_record_locks(
                     self.governance_program.key,
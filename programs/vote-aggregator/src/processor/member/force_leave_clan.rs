@@ -0,0 +1,246 @@
+use anchor_lang::{prelude::*, system_program};
+use spl_governance::{
+    instruction::relinquish_token_owner_record_locks, solana_program::program::invoke_signed,
+    state::token_owner_record::{
+        get_token_owner_record_data_for_realm_and_governing_mint, TokenOwnerRecordV2,
+    },
+    PROGRAM_AUTHORITY_SEED,
+};
+
+use crate::{
+    error::Error,
+    events::{clan::ClanMemberLeft, member::MemberForcedOut},
+    processor::root::configure_root::*,
+    state::{Clan, ClanWeightCheckpoints, Member, MemberLockAccounts, Root, VoterWeightRecord},
+};
+
+/// Removal of a compromised or sanctioned member by the realm authority
+#[derive(Accounts)]
+pub struct ForceLeaveClan<'info> {
+    configure_root: ConfigureRoot<'info>,
+
+    #[account(
+        mut,
+        constraint = member.root == configure_root.root.key(),
+    )]
+    member: Account<'info, Member>,
+
+    #[account(
+        mut,
+        constraint = clan.root == configure_root.root.key() @ Error::UnexpectedClan,
+    )]
+    clan: Account<'info, Clan>,
+
+    #[account(
+        mut,
+        seeds = [
+            VoterWeightRecord::ADDRESS_SEED,
+            &clan.key().to_bytes()
+        ],
+        bump = clan.bumps.voter_weight_record,
+    )]
+    clan_vwr: Box<Account<'info, VoterWeightRecord>>,
+    /// Required once the clan has weight checkpoints
+    #[account(
+        mut,
+        seeds = [
+            ClanWeightCheckpoints::ADDRESS_SEED,
+            &clan.key().to_bytes()
+        ],
+        bump = weight_checkpoints.bump,
+    )]
+    weight_checkpoints: Option<Account<'info, ClanWeightCheckpoints>>,
+
+    /// CHECK: dynamic owner
+    #[account(
+        mut,
+        seeds = [
+            PROGRAM_AUTHORITY_SEED,
+            &configure_root.root.realm.to_bytes(),
+            &configure_root.root.governing_token_mint.to_bytes(),
+            &member.owner.to_bytes(),
+        ],
+        seeds::program = configure_root.root.governance_program,
+        bump = member.bumps.token_owner_record,
+        address = member.token_owner_record,
+    )]
+    member_tor: UncheckedAccount<'info>,
+
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            Root::LOCK_AUTHORITY_SEED,
+            &configure_root.root.key().to_bytes()
+        ],
+        bump = configure_root.root.bumps.lock_authority,
+    )]
+    lock_authority: UncheckedAccount<'info>,
+
+    /// CHECK: dynamic owner
+    #[account(
+        owner = governance_program.key(),
+        seeds = [
+            b"realm-config",
+            &configure_root.realm.key.to_bytes()
+        ],
+        bump,
+        seeds::program = governance_program.key(),
+    )]
+    realm_config: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        owner = system_program::ID
+    )]
+    payer: Signer<'info>,
+
+    system_program: Program<'info, System>,
+    /// CHECK: program
+    #[account(
+        executable,
+        address = configure_root.root.governance_program,
+    )]
+    governance_program: UncheckedAccount<'info>,
+}
+
+impl<'info> ForceLeaveClan<'info> {
+    fn member_tor(&self) -> Result<TokenOwnerRecordV2> {
+        Ok(get_token_owner_record_data_for_realm_and_governing_mint(
+            &self.configure_root.root.governance_program,
+            &self.member_tor.to_account_info(),
+            &self.configure_root.root.realm,
+            &self.configure_root.root.governing_token_mint,
+        )
+        .map_err(|e| ProgramErrorWithOrigin::from(e).with_account_name("member_tor"))?)
+    }
+
+    pub fn process(&mut self, immediate: bool, release_lock: bool, reason: u16) -> Result<()> {
+        self.configure_root.check_realm_authority()?;
+        let (index, entry) = self
+            .member
+            .membership
+            .iter()
+            .enumerate()
+            .find(|(_, entry)| entry.clan == self.clan.key())
+            .ok_or(error!(Error::UnexpectedClan))?;
+        let entry = entry.clone();
+
+        let clock = Clock::get()?;
+        if entry.exitable_at.is_some() {
+            // Already out of the clan voter weight, only the cooldown can be skipped
+            require!(immediate, Error::RerequestingLeavingClan);
+            self.clan.leaving_members -= 1;
+        } else {
+            let root = &mut self.configure_root.root;
+            root.update_next_voter_weight_reset_time(&clock);
+            self.clan
+                .reset_voter_weight_if_needed(root, &mut self.clan_vwr);
+            Clan::update_member(
+                &mut self.clan,
+                &mut self.member,
+                Some(entry.share_bp),
+                None,
+                None, // Leaving the clan
+                &mut self.clan_vwr,
+                &clock,
+            )?;
+            self.clan.record_weight_checkpoint(
+                self.weight_checkpoints.as_deref_mut(),
+                &self.clan_vwr,
+                clock.slot,
+            )?;
+        }
+
+        let cooldown_end = clock.unix_timestamp
+            + i64::try_from(self.configure_root.root.max_proposal_lifetime).unwrap();
+        let exitable_at = if immediate {
+            let entry = &mut self.member.membership[index];
+            self.clan.accrue_member_rewards(entry, clock.unix_timestamp);
+            // The rewards would be lost with the membership entry
+            require_eq!(entry.unclaimed_rewards, 0, Error::UnclaimedClanRewards);
+            self.member.membership.remove(index);
+            None
+        } else {
+            let exitable_at = cooldown_end;
+            self.member.membership[index].exitable_at = Some(exitable_at);
+            self.clan.leaving_members += 1;
+            Some(exitable_at)
+        };
+
+        let member_tor = self.member_tor()?;
+        if release_lock {
+            // Other clans still rely on the member tokens
+            require!(
+                self.member.membership.is_empty(),
+                Error::MemberLockStillRequired
+            );
+            if member_tor
+                .locks
+                .iter()
+                .any(|l| l.authority == self.lock_authority.key() && l.lock_id == 0)
+            {
+                invoke_signed(
+                    &relinquish_token_owner_record_locks(
+                        self.governance_program.key,
+                        self.configure_root.realm.key,
+                        self.member_tor.key,
+                        Some(self.lock_authority.key()),
+                        Some(vec![0]),
+                    ),
+                    &[
+                        self.configure_root.realm.to_account_info(),
+                        self.realm_config.to_account_info(),
+                        self.governance_program.to_account_info(),
+                        self.member_tor.to_account_info(),
+                        self.lock_authority.to_account_info(),
+                    ],
+                    &[&[
+                        Root::LOCK_AUTHORITY_SEED,
+                        &self.configure_root.root.key().to_bytes(),
+                        &[self.configure_root.root.bumps.lock_authority],
+                    ]],
+                )?;
+            }
+        } else {
+            // Tokens must stay locked until the member can exit.
+            // A removed member stays locked for the skipped cooldown
+            let lock_expiry = self
+                .member
+                .lock_expiry(&self.configure_root.root, clock.unix_timestamp)
+                .map_or(cooldown_end, |expiry| expiry.max(cooldown_end));
+            Member::set_lock(
+                &self.configure_root.root,
+                &member_tor,
+                MemberLockAccounts {
+                    governance_program: &self.governance_program.to_account_info(),
+                    realm: &self.configure_root.realm.to_account_info(),
+                    realm_config: &self.realm_config.to_account_info(),
+                    member_tor: &self.member_tor.to_account_info(),
+                    lock_authority: &self.lock_authority.to_account_info(),
+                    payer: &self.payer.to_account_info(),
+                    system_program: &self.system_program.to_account_info(),
+                },
+                lock_expiry,
+            )?;
+        }
+
+        if immediate {
+            emit!(ClanMemberLeft {
+                member: self.member.key(),
+                clan: self.clan.key(),
+                root: self.configure_root.root.key(),
+                owner: self.member.owner,
+            });
+        }
+        emit!(MemberForcedOut {
+            member: self.member.key(),
+            clan: self.clan.key(),
+            root: self.configure_root.root.key(),
+            owner: self.member.owner,
+            exitable_at,
+            lock_released: release_lock,
+            reason,
+        });
+        Ok(())
+    }
+}
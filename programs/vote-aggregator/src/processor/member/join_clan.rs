@@ -1,7 +1,6 @@
 use anchor_lang::{prelude::*, system_program};
 use spl_governance::{
     addins::voter_weight::get_voter_weight_record_data_for_token_owner_record,
-    instruction::set_token_owner_record_lock, solana_program::program::invoke_signed,
     state::token_owner_record::get_token_owner_record_data_for_realm_and_governing_mint,
     PROGRAM_AUTHORITY_SEED,
 };
@@ -9,7 +8,10 @@ use spl_governance::{
 use crate::{
     error::Error,
     events::clan::ClanMemberAdded,
-    state::{Clan, MaxVoterWeightRecord, Member, MembershipEntry, Root, VoterWeightRecord},
+    state::{
+        Clan, ClanWeightCheckpoints, MaxVoterWeightRecord, Member, MemberLockAccounts,
+        MembershipEntry, Root, VoterWeightRecord,
+    },
 };
 
 #[derive(Accounts)]
@@ -29,6 +31,7 @@ pub struct JoinClan<'info> {
     #[account(
         mut,
         has_one = root,
+        constraint = !clan.frozen @ Error::ClanFrozen,
     )]
     clan: Account<'info, Clan>,
 
@@ -76,6 +79,16 @@ pub struct JoinClan<'info> {
         bump = clan.bumps.voter_weight_record,
     )]
     clan_vwr: Box<Account<'info, VoterWeightRecord>>,
+    /// Required once the clan has weight checkpoints
+    #[account(
+        mut,
+        seeds = [
+            ClanWeightCheckpoints::ADDRESS_SEED,
+            &clan.key().to_bytes()
+        ],
+        bump = weight_checkpoints.bump,
+    )]
+    weight_checkpoints: Option<Account<'info, ClanWeightCheckpoints>>,
 
     /// CHECK: dynamic owner
     #[account(
@@ -126,7 +139,9 @@ impl<'info> JoinClan<'info> {
         share_bp: u16,
         rest: &'c [AccountInfo<'info>],
     ) -> Result<()> {
-        require!(!self.root.paused, Error::Paused);
+        self.root.check_not_paused(Root::PAUSE_JOIN)?;
+        self.root.check_not_winding_down()?;
+        self.root.check_no_plugin_drift()?;
         let member_tor = get_token_owner_record_data_for_realm_and_governing_mint(
             &self.root.governance_program,
             &self.member_tor.to_account_info(),
@@ -163,7 +178,14 @@ impl<'info> JoinClan<'info> {
 
         let clock = Clock::get()?;
         self.root.update_next_voter_weight_reset_time(&clock);
+        self.root
+            .check_voter_weight_expiry(new_member_vwr.voter_weight_expiry, &clock)?;
 
+        let new_voter_weight_decay =
+            self.member
+                .next_voter_weight_decay(&new_member_vwr, &self.root, &clock);
+
+        let warmup_end = self.root.membership_warmup_end(clock.unix_timestamp);
         let old_share_bp = if let Some(entry) = self
             .member
             .membership
@@ -172,6 +194,25 @@ impl<'info> JoinClan<'info> {
         {
             require_gte!(share_bp, entry.share_bp, Error::InvalidShareBp);
             let was_member = entry.exitable_at.is_none();
+            if !was_member {
+                self.clan.leaving_members -= 1;
+                // The tenure and the warmup start over after leaving
+                entry.joined_at = clock.unix_timestamp;
+                entry.warmup_share_bp = share_bp;
+                entry.warmup_end = warmup_end;
+            } else if share_bp > entry.share_bp {
+                // The added share warms up together with the part still in warmup
+                let warming_share_bp = if entry
+                    .warmup_end
+                    .map_or(false, |end| end > clock.unix_timestamp)
+                {
+                    entry.warmup_share_bp
+                } else {
+                    0
+                };
+                entry.warmup_share_bp = warming_share_bp + share_bp - entry.share_bp;
+                entry.warmup_end = warmup_end;
+            }
             entry.exitable_at = None;
             let old_share_bp = entry.share_bp;
             entry.share_bp = share_bp;
@@ -190,6 +231,10 @@ impl<'info> JoinClan<'info> {
                 clan: self.clan.key(),
                 share_bp,
                 exitable_at: None,
+                warmup_end,
+                warmup_share_bp: share_bp,
+                joined_at: clock.unix_timestamp,
+                ..Default::default()
             });
             None
         };
@@ -217,9 +262,10 @@ impl<'info> JoinClan<'info> {
 
             self.member.refresh_membership(
                 &mut self.root,
-                entry,
+                &entry,
                 &mut chunk,
                 &new_member_vwr,
+                new_voter_weight_decay.as_ref(),
                 &clock,
             )?;
 
@@ -229,51 +275,47 @@ impl<'info> JoinClan<'info> {
             .reset_voter_weight_if_needed(&mut self.root, &mut self.clan_vwr);
         Clan::update_member(
             &mut self.clan,
-            &self.member,
+            &mut self.member,
             old_share_bp,
-            Some(&new_member_vwr),
+            Some((&new_member_vwr, new_voter_weight_decay.as_ref())),
             Some(share_bp),
             &mut self.clan_vwr,
             &clock,
         )?;
+        self.clan.record_weight_checkpoint(
+            self.weight_checkpoints.as_deref_mut(),
+            &self.clan_vwr,
+            clock.slot,
+        )?;
 
         Member::update_voter_weight(
             &mut self.member,
             self.member_vwr.key(),
             &new_member_vwr,
+            new_voter_weight_decay,
+            &self.root,
             &mut self.max_vwr,
         )?;
         self.member.next_voter_weight_reset_time = self.root.next_voter_weight_reset_time();
 
-        if !member_tor.locks.iter().any(|l| {
-            l.authority == self.lock_authority.key() && l.lock_id == 0 && l.expiry.is_none()
-        }) {
-            invoke_signed(
-                &set_token_owner_record_lock(
-                    &self.governance_program.key(),
-                    self.realm.key,
-                    self.member_tor.key,
-                    self.lock_authority.key,
-                    self.payer.key,
-                    0,
-                    None,
-                ),
-                &[
-                    self.governance_program.to_account_info(),
-                    self.realm.to_account_info(),
-                    self.realm_config.to_account_info(),
-                    self.member_tor.to_account_info(),
-                    self.lock_authority.to_account_info(),
-                    self.payer.to_account_info(),
-                    self.system_program.to_account_info(),
-                ],
-                &[&[
-                    Root::LOCK_AUTHORITY_SEED,
-                    &self.root.key().to_bytes(),
-                    &[self.root.bumps.lock_authority],
-                ]],
-            )?;
-        }
+        let lock_expiry = self
+            .member
+            .lock_expiry(&self.root, clock.unix_timestamp)
+            .ok_or(error!(Error::MemberHasNoClans))?;
+        Member::set_lock(
+            &self.root,
+            &member_tor,
+            MemberLockAccounts {
+                governance_program: &self.governance_program.to_account_info(),
+                realm: &self.realm.to_account_info(),
+                realm_config: &self.realm_config.to_account_info(),
+                member_tor: &self.member_tor.to_account_info(),
+                lock_authority: &self.lock_authority.to_account_info(),
+                payer: &self.payer.to_account_info(),
+                system_program: &self.system_program.to_account_info(),
+            },
+            lock_expiry,
+        )?;
 
         emit!(ClanMemberAdded {
             clan: self.clan.key(),
@@ -0,0 +1,72 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, Transfer},
+    Discriminator,
+};
+
+use crate::error::Error;
+use crate::events::member::MemberMigrated;
+use crate::state::{LegacyMember, Member};
+
+/// Grows a member created with the legacy layout and converts it
+#[derive(Accounts)]
+pub struct MigrateMember<'info> {
+    /// CHECK: the legacy layout is parsed by the processor
+    #[account(
+        mut,
+        owner = crate::ID,
+    )]
+    member: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        owner = system_program::ID,
+    )]
+    payer: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateMember<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let member_info = self.member.to_account_info();
+        require_gt!(
+            Member::SPACE,
+            member_info.data_len(),
+            Error::MemberAlreadyMigrated
+        );
+        let legacy = {
+            let data = member_info.try_borrow_data()?;
+            require!(
+                data.len() >= 8 && data[..8] == Member::DISCRIMINATOR,
+                ErrorCode::AccountDiscriminatorMismatch
+            );
+            LegacyMember::deserialize(&mut &data[8..])?
+        };
+        let member = Member::from_legacy(legacy, Clock::get()?.unix_timestamp);
+
+        let rent = Rent::get()?.minimum_balance(Member::SPACE);
+        let lamports = rent.saturating_sub(member_info.lamports());
+        if lamports > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.payer.to_account_info(),
+                        to: member_info.clone(),
+                    },
+                ),
+                lamports,
+            )?;
+        }
+        member_info.realloc(Member::SPACE, true)?;
+        member.try_serialize(&mut &mut member_info.try_borrow_mut_data()?[..])?;
+
+        emit!(MemberMigrated {
+            member: self.member.key(),
+            root: member.root,
+            owner: member.owner,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::Error,
+    events::member::MemberWeightTransformMigrated,
+    state::{Clan, ClanWeightCheckpoints, Member, Root, VoterWeightRecord},
+};
+
+/// Re-aggregates the member weight with the clan transform.
+/// Permissionless so the unmigrated members can't block the clan forever
+#[derive(Accounts)]
+pub struct MigrateMemberTransform<'info> {
+    #[account(
+        mut,
+        has_one = root,
+    )]
+    member: Account<'info, Member>,
+    #[account(mut)]
+    root: Account<'info, Root>,
+    #[account(
+        mut,
+        has_one = root,
+    )]
+    clan: Account<'info, Clan>,
+    #[account(
+        mut,
+        seeds = [
+            VoterWeightRecord::ADDRESS_SEED,
+            &clan.key().to_bytes()
+        ],
+        bump = clan.bumps.voter_weight_record,
+    )]
+    clan_vwr: Box<Account<'info, VoterWeightRecord>>,
+    /// Required once the clan has weight checkpoints
+    #[account(
+        mut,
+        seeds = [
+            ClanWeightCheckpoints::ADDRESS_SEED,
+            &clan.key().to_bytes()
+        ],
+        bump = weight_checkpoints.bump,
+    )]
+    weight_checkpoints: Option<Account<'info, ClanWeightCheckpoints>>,
+}
+
+impl<'info> MigrateMemberTransform<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let clock = Clock::get()?;
+        self.root.update_next_voter_weight_reset_time(&clock);
+        self.clan
+            .reset_voter_weight_if_needed(&self.root, &mut self.clan_vwr);
+
+        // The weight of an outdated temporary member was dropped by the clan reset
+        let is_outdated = self.clan.is_member_outdated(&self.member);
+        let clan_key = self.clan.key();
+        let entry = self
+            .member
+            .membership
+            .iter_mut()
+            .find(|entry| entry.clan == clan_key)
+            .ok_or(error!(Error::UnexpectedClan))?;
+        // The leaving members are not counted as unmigrated
+        require!(
+            entry.exitable_at.is_none()
+                && entry.weight_transform_epoch != self.clan.weight_transform_epoch,
+            Error::MemberTransformAlreadyMigrated
+        );
+
+        if is_outdated {
+            // The next update of the member adds it back with the clan transform
+            entry.weight_transform = self.clan.weight_transform.clone();
+            entry.weight_transform_epoch = self.clan.weight_transform_epoch;
+            self.clan.unmigrated_members -= 1;
+        } else {
+            let share_bp = entry.share_bp;
+            Clan::update_member(
+                &mut self.clan,
+                &mut self.member,
+                Some(share_bp),
+                None,
+                Some(share_bp),
+                &mut self.clan_vwr,
+                &clock,
+            )?;
+            self.clan.record_weight_checkpoint(
+                self.weight_checkpoints.as_deref_mut(),
+                &self.clan_vwr,
+                clock.slot,
+            )?;
+        }
+        emit!(MemberWeightTransformMigrated {
+            member: self.member.key(),
+            clan: clan_key,
+            root: self.root.key(),
+            weight_transform_epoch: self.clan.weight_transform_epoch,
+            unmigrated_members: self.clan.unmigrated_members,
+        });
+        Ok(())
+    }
+}
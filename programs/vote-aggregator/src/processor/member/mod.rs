@@ -1,13 +1,37 @@
+pub mod approve_treasury_withdrawal;
+pub mod claim_clan_rewards;
 pub mod create_member;
-pub mod join_clan;
+pub mod evict_stale_member;
+pub mod evict_unlocked_member;
 pub mod exit_clan;
+pub mod force_leave_clan;
+pub mod join_clan;
+pub mod migrate_member;
+pub mod migrate_member_transform;
+pub mod open_recall_proposal;
+pub mod refresh_member_lock;
+pub mod set_voter_weight_decay;
 pub mod set_voter_weight_record;
+pub mod sign_recall_proposal;
 pub mod start_leaving_clan;
 pub mod update_voter_weight;
+pub mod wind_down_exit;
 
+pub use approve_treasury_withdrawal::*;
+pub use claim_clan_rewards::*;
 pub use create_member::*;
-pub use join_clan::*;
+pub use evict_stale_member::*;
+pub use evict_unlocked_member::*;
 pub use exit_clan::*;
+pub use force_leave_clan::*;
+pub use join_clan::*;
+pub use migrate_member::*;
+pub use migrate_member_transform::*;
+pub use open_recall_proposal::*;
+pub use refresh_member_lock::*;
+pub use set_voter_weight_decay::*;
 pub use set_voter_weight_record::*;
+pub use sign_recall_proposal::*;
 pub use start_leaving_clan::*;
 pub use update_voter_weight::*;
+pub use wind_down_exit::*;
@@ -0,0 +1,117 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::{
+    error::Error,
+    events::member::RecallProposalOpened,
+    state::{Clan, Member, RecallProposal, Root, VoterWeightRecord},
+};
+
+/// A clan member proposes to replace the clan owner or delegate
+#[derive(Accounts)]
+pub struct OpenRecallProposal<'info> {
+    #[account(
+        has_one = root,
+    )]
+    member: Account<'info, Member>,
+    #[account(
+        constraint = member_authority.key() == member.owner ||
+            member_authority.key() == member.delegate
+        @ Error::WrongMemberAuthority
+    )]
+    member_authority: Signer<'info>,
+
+    #[account(
+        constraint = root.recall_threshold_bp > 0 @ Error::RecallDisabled,
+    )]
+    root: Account<'info, Root>,
+
+    #[account(
+        mut,
+        has_one = root,
+        constraint = !clan.frozen @ Error::ClanFrozen,
+    )]
+    clan: Account<'info, Clan>,
+
+    #[account(
+        seeds = [
+            VoterWeightRecord::ADDRESS_SEED,
+            &clan.key().to_bytes()
+        ],
+        bump = clan.bumps.voter_weight_record,
+    )]
+    clan_vwr: Account<'info, VoterWeightRecord>,
+
+    #[account(
+        init,
+        seeds = [
+            RecallProposal::ADDRESS_SEED,
+            &clan.key().to_bytes(),
+            &clan.recall_proposals.to_le_bytes(),
+        ],
+        bump,
+        payer = payer,
+        space = RecallProposal::SPACE,
+    )]
+    recall: Account<'info, RecallProposal>,
+
+    #[account(
+        mut,
+        owner = system_program::ID
+    )]
+    payer: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> OpenRecallProposal<'info> {
+    pub fn process(
+        &mut self,
+        new_owner: Option<Pubkey>,
+        new_delegate: Option<Pubkey>,
+        bumps: OpenRecallProposalBumps,
+    ) -> Result<()> {
+        require!(
+            new_owner.is_some() || new_delegate.is_some(),
+            Error::EmptyRecallProposal
+        );
+        require!(
+            self.member
+                .membership
+                .iter()
+                .any(|entry| entry.clan == self.clan.key() && entry.exitable_at.is_none()),
+            Error::UnexpectedClan
+        );
+        // Fixed at the opening so the joining members can not dilute the signatures
+        let required_weight = (self.clan_vwr.voter_weight as u128
+            * self.root.recall_threshold_bp as u128
+            / 10000) as u64;
+        let expires_at =
+            Clock::get()?.unix_timestamp + i64::try_from(self.root.max_proposal_lifetime).unwrap();
+        let index = self.clan.recall_proposals;
+        self.clan.recall_proposals += 1;
+        self.recall.set_inner(RecallProposal {
+            clan: self.clan.key(),
+            index,
+            proposer: self.member.key(),
+            new_owner,
+            new_delegate,
+            required_weight,
+            signed_weight: 0,
+            signatures: 0,
+            expires_at,
+            executed: false,
+            bump: bumps.recall,
+        });
+        emit!(RecallProposalOpened {
+            recall: self.recall.key(),
+            clan: self.clan.key(),
+            member: self.member.key(),
+            index,
+            new_owner,
+            new_delegate,
+            required_weight,
+            expires_at,
+        });
+        Ok(())
+    }
+}
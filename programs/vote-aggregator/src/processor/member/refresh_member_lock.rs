@@ -0,0 +1,110 @@
+use anchor_lang::{prelude::*, system_program};
+use spl_governance::{
+    state::token_owner_record::get_token_owner_record_data_for_realm_and_governing_mint,
+    PROGRAM_AUTHORITY_SEED,
+};
+
+use crate::{
+    error::Error,
+    state::{Member, MemberLockAccounts, Root},
+};
+
+/// Extends the member TOR lock while the member stays in the clans
+#[derive(Accounts)]
+pub struct RefreshMemberLock<'info> {
+    #[account(
+        has_one = root,
+    )]
+    member: Account<'info, Member>,
+
+    #[account(
+        has_one = governance_program,
+        has_one = realm,
+    )]
+    root: Account<'info, Root>,
+
+    /// CHECK: dynamic owner
+    #[account(
+        mut,
+        seeds = [
+            PROGRAM_AUTHORITY_SEED,
+            &root.realm.to_bytes(),
+            &root.governing_token_mint.to_bytes(),
+            &member.owner.to_bytes(),
+        ],
+        seeds::program = root.governance_program,
+        bump = member.bumps.token_owner_record,
+        address = member.token_owner_record,
+    )]
+    member_tor: UncheckedAccount<'info>,
+
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            Root::LOCK_AUTHORITY_SEED,
+            &root.key().to_bytes()
+        ],
+        bump = root.bumps.lock_authority,
+    )]
+    lock_authority: UncheckedAccount<'info>,
+
+    /// CHECK: dynamic owner
+    #[account(
+        owner = governance_program.key(),
+    )]
+    realm: UncheckedAccount<'info>,
+
+    /// CHECK: dynamic owner
+    #[account(
+        owner = governance_program.key(),
+        seeds = [
+            b"realm-config",
+            &realm.key.to_bytes()
+        ],
+        bump,
+        seeds::program = governance_program.key(),
+    )]
+    realm_config: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        owner = system_program::ID
+    )]
+    payer: Signer<'info>,
+
+    system_program: Program<'info, System>,
+    /// CHECK: program
+    #[account(executable)]
+    governance_program: UncheckedAccount<'info>,
+}
+
+impl<'info> RefreshMemberLock<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let lock_expiry = self
+            .member
+            .lock_expiry(&self.root, Clock::get()?.unix_timestamp)
+            .ok_or(error!(Error::MemberHasNoClans))?;
+        let member_tor = get_token_owner_record_data_for_realm_and_governing_mint(
+            &self.root.governance_program,
+            &self.member_tor.to_account_info(),
+            &self.root.realm,
+            &self.root.governing_token_mint,
+        )
+        .map_err(|e| ProgramErrorWithOrigin::from(e).with_account_name("member_tor"))?;
+        Member::set_lock(
+            &self.root,
+            &member_tor,
+            MemberLockAccounts {
+                governance_program: &self.governance_program.to_account_info(),
+                realm: &self.realm.to_account_info(),
+                realm_config: &self.realm_config.to_account_info(),
+                member_tor: &self.member_tor.to_account_info(),
+                lock_authority: &self.lock_authority.to_account_info(),
+                payer: &self.payer.to_account_info(),
+                system_program: &self.system_program.to_account_info(),
+            },
+            lock_expiry,
+        )?;
+        Ok(())
+    }
+}
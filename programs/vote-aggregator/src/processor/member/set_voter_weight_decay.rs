@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::state::VoterWeightDecay;
+
+use super::set_voter_weight_record::*;
+
+#[derive(Accounts)]
+pub struct SetVoterWeightDecay<'info> {
+    set_voter_weight_record: SetVoterWeightRecord<'info>,
+}
+
+impl<'info> SetVoterWeightDecay<'info> {
+    /// Zero rate removes the decay
+    pub fn process<'c: 'info>(
+        &mut self,
+        rate: u64,
+        end_time: i64,
+        rest: &'c [AccountInfo<'info>],
+    ) -> Result<()> {
+        let new_voter_weight_decay = if rate == 0 {
+            None
+        } else {
+            Some(VoterWeightDecay {
+                start_time: Clock::get()?.unix_timestamp,
+                rate,
+                end_time,
+            })
+        };
+        self.set_voter_weight_record
+            .update(rest, Some(new_voter_weight_decay))
+    }
+}
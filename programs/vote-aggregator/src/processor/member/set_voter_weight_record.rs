@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 use spl_governance::addins::voter_weight::get_voter_weight_record_data;
 
 use crate::error::Error;
-use crate::state::{MaxVoterWeightRecord, Member, Root};
+use crate::state::{MaxVoterWeightRecord, Member, Root, VoterWeightDecay};
 
 #[derive(Accounts)]
 pub struct SetVoterWeightRecord<'info> {
@@ -10,23 +10,23 @@ pub struct SetVoterWeightRecord<'info> {
         mut,
         has_one = root,
     )]
-    member: Account<'info, Member>,
+    pub(crate) member: Account<'info, Member>,
 
     #[account(
         constraint = member_authority.key() == member.owner ||
             member_authority.key() == member.delegate
         @ Error::WrongMemberAuthority
     )]
-    member_authority: Signer<'info>,
+    pub(crate) member_authority: Signer<'info>,
 
     /// CHECK: dynamic owner
     #[account(
         owner = root.voting_weight_plugin,
     )]
-    member_vwr: UncheckedAccount<'info>,
+    pub(crate) member_vwr: UncheckedAccount<'info>,
 
     #[account(mut)]
-    root: Account<'info, Root>,
+    pub(crate) root: Account<'info, Root>,
     #[account(
         mut,
         seeds = [
@@ -35,12 +35,23 @@ pub struct SetVoterWeightRecord<'info> {
         ],
         bump = root.bumps.max_voter_weight,
     )]
-    max_vwr: Account<'info, MaxVoterWeightRecord>,
+    pub(crate) max_vwr: Account<'info, MaxVoterWeightRecord>,
 }
 
 impl<'info> SetVoterWeightRecord<'info> {
     pub fn process<'c: 'info>(&mut self, rest: &'c [AccountInfo<'info>]) -> Result<()> {
-        require!(!self.root.paused, Error::Paused);
+        self.update(rest, None)
+    }
+
+    /// None for `new_voter_weight_decay` keeps the registered decay
+    pub(crate) fn update<'c: 'info>(
+        &mut self,
+        rest: &'c [AccountInfo<'info>],
+        new_voter_weight_decay: Option<Option<VoterWeightDecay>>,
+    ) -> Result<()> {
+        self.root.check_not_paused(Root::PAUSE_VOTER_WEIGHT)?;
+        self.root.check_not_winding_down()?;
+        self.root.check_no_plugin_drift()?;
         let new_member_vwr =
             get_voter_weight_record_data(&self.root.voting_weight_plugin, &self.member_vwr)
                 .map_err(|e| {
@@ -55,6 +66,25 @@ impl<'info> SetVoterWeightRecord<'info> {
 
         let clock = Clock::get()?;
         self.root.update_next_voter_weight_reset_time(&clock);
+        self.root
+            .check_voter_weight_expiry(new_member_vwr.voter_weight_expiry, &clock)?;
+        let new_voter_weight_decay = if let Some(new_voter_weight_decay) = new_voter_weight_decay {
+            if let Some(decay) = &new_voter_weight_decay {
+                require!(
+                    new_member_vwr.voter_weight_expiry.is_some(),
+                    Error::InvalidVoterWeightDecay
+                );
+                decay.check(
+                    new_member_vwr.voter_weight,
+                    self.root.next_voter_weight_reset_time(),
+                    &clock,
+                )?;
+            }
+            new_voter_weight_decay
+        } else {
+            self.member
+                .next_voter_weight_decay(&new_member_vwr, &self.root, &clock)
+        };
         for (mut chunk, entry) in self.member.load_clan_chunks(rest, |_| true)? {
             require!(
                 new_member_vwr.voter_weight_expiry.is_none() || chunk.clan.accept_temporary_members,
@@ -62,9 +92,10 @@ impl<'info> SetVoterWeightRecord<'info> {
             );
             self.member.refresh_membership(
                 &mut self.root,
-                entry,
+                &entry,
                 &mut chunk,
                 &new_member_vwr,
+                new_voter_weight_decay.as_ref(),
                 &clock,
             )?;
             chunk.exit(&crate::ID)?;
@@ -74,6 +105,8 @@ impl<'info> SetVoterWeightRecord<'info> {
             &mut self.member,
             self.member_vwr.key(),
             &new_member_vwr,
+            new_voter_weight_decay,
+            &self.root,
             &mut self.max_vwr,
         )?;
         self.member.next_voter_weight_reset_time = self.root.next_voter_weight_reset_time();
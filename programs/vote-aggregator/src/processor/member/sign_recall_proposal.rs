@@ -0,0 +1,81 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::{
+    error::Error,
+    events::member::RecallProposalSigned,
+    state::{Member, RecallProposal, RecallSignature},
+};
+
+/// The member supports the recall with its contribution to the clan
+#[derive(Accounts)]
+pub struct SignRecallProposal<'info> {
+    member: Account<'info, Member>,
+    #[account(
+        constraint = member_authority.key() == member.owner ||
+            member_authority.key() == member.delegate
+        @ Error::WrongMemberAuthority
+    )]
+    member_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = !recall.executed @ Error::RecallProposalExecuted,
+    )]
+    recall: Account<'info, RecallProposal>,
+
+    #[account(
+        init,
+        seeds = [
+            RecallSignature::ADDRESS_SEED,
+            &recall.key().to_bytes(),
+            &member.key().to_bytes(),
+        ],
+        bump,
+        payer = payer,
+        space = RecallSignature::SPACE,
+    )]
+    signature: Account<'info, RecallSignature>,
+
+    #[account(
+        mut,
+        owner = system_program::ID
+    )]
+    payer: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> SignRecallProposal<'info> {
+    pub fn process(&mut self, bumps: SignRecallProposalBumps) -> Result<()> {
+        let time = Clock::get()?.unix_timestamp;
+        require_gt!(self.recall.expires_at, time, Error::RecallProposalExpired);
+        // The leaving members give no weight to the clan
+        let entry = self
+            .member
+            .membership
+            .iter()
+            .find(|entry| entry.clan == self.recall.clan && entry.exitable_at.is_none())
+            .ok_or(error!(Error::UnexpectedClan))?;
+        // Same as the clan VWR the threshold is measured by
+        if let Some(warmup_end) = entry.warmup_end {
+            require_gte!(time, warmup_end, Error::MemberInWarmup);
+        }
+        let voter_weight = entry.reward_weight;
+        self.recall.signed_weight += voter_weight;
+        self.recall.signatures += 1;
+        self.signature.set_inner(RecallSignature {
+            recall: self.recall.key(),
+            member: self.member.key(),
+            voter_weight,
+            bump: bumps.signature,
+        });
+        emit!(RecallProposalSigned {
+            recall: self.recall.key(),
+            clan: self.recall.clan,
+            member: self.member.key(),
+            voter_weight,
+            signed_weight: self.recall.signed_weight,
+        });
+        Ok(())
+    }
+}
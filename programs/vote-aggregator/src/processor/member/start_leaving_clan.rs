@@ -1,9 +1,13 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, system_program};
+use spl_governance::{
+    state::token_owner_record::get_token_owner_record_data_for_realm_and_governing_mint,
+    PROGRAM_AUTHORITY_SEED,
+};
 
 use crate::{
     error::Error,
     events::member::StartingLeavingClan,
-    state::{Clan, Member, Root, VoterWeightRecord},
+    state::{Clan, ClanWeightCheckpoints, Member, MemberLockAccounts, Root, VoterWeightRecord},
 };
 
 #[derive(Accounts)]
@@ -13,7 +17,11 @@ pub struct StartLeavingClan<'info> {
         has_one = root,
     )]
     member: Account<'info, Member>,
-    #[account(mut)]
+    #[account(
+        mut,
+        has_one = governance_program,
+        has_one = realm,
+    )]
     root: Account<'info, Root>,
     #[account(
         mut,
@@ -29,16 +37,81 @@ pub struct StartLeavingClan<'info> {
         bump = clan.bumps.voter_weight_record,
     )]
     clan_vwr: Box<Account<'info, VoterWeightRecord>>,
+    /// Required once the clan has weight checkpoints
+    #[account(
+        mut,
+        seeds = [
+            ClanWeightCheckpoints::ADDRESS_SEED,
+            &clan.key().to_bytes()
+        ],
+        bump = weight_checkpoints.bump,
+    )]
+    weight_checkpoints: Option<Account<'info, ClanWeightCheckpoints>>,
     #[account(
         constraint = member_authority.key() == member.owner ||
             member_authority.key() == member.delegate
         @ Error::WrongMemberAuthority
     )]
     member_authority: Signer<'info>,
+
+    /// CHECK: dynamic owner
+    #[account(
+        mut,
+        seeds = [
+            PROGRAM_AUTHORITY_SEED,
+            &root.realm.to_bytes(),
+            &root.governing_token_mint.to_bytes(),
+            &member.owner.to_bytes(),
+        ],
+        seeds::program = root.governance_program,
+        bump = member.bumps.token_owner_record,
+        address = member.token_owner_record,
+    )]
+    member_tor: UncheckedAccount<'info>,
+
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            Root::LOCK_AUTHORITY_SEED,
+            &root.key().to_bytes()
+        ],
+        bump = root.bumps.lock_authority,
+    )]
+    lock_authority: UncheckedAccount<'info>,
+
+    /// CHECK: dynamic owner
+    #[account(
+        owner = governance_program.key(),
+    )]
+    realm: UncheckedAccount<'info>,
+
+    /// CHECK: dynamic owner
+    #[account(
+        owner = governance_program.key(),
+        seeds = [
+            b"realm-config",
+            &realm.key.to_bytes()
+        ],
+        bump,
+        seeds::program = governance_program.key(),
+    )]
+    realm_config: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        owner = system_program::ID
+    )]
+    payer: Signer<'info>,
+
+    system_program: Program<'info, System>,
+    /// CHECK: program
+    #[account(executable)]
+    governance_program: UncheckedAccount<'info>,
 }
 
 impl<'info> StartLeavingClan<'info> {
     pub fn process(&mut self) -> Result<()> {
+        self.root.check_not_paused(Root::PAUSE_LEAVE)?;
         let entry = self
             .member
             .membership
@@ -48,8 +121,9 @@ impl<'info> StartLeavingClan<'info> {
         require!(entry.exitable_at.is_none(), Error::RerequestingLeavingClan);
 
         let clock = Clock::get()?;
-        entry.exitable_at =
-            Some(clock.unix_timestamp + i64::try_from(self.root.max_proposal_lifetime).unwrap());
+        let exitable_at =
+            clock.unix_timestamp + i64::try_from(self.root.max_proposal_lifetime).unwrap();
+        entry.exitable_at = Some(exitable_at);
 
         self.root.update_next_voter_weight_reset_time(&clock);
         self.clan
@@ -57,14 +131,45 @@ impl<'info> StartLeavingClan<'info> {
         let share_bp = entry.share_bp;
         Clan::update_member(
             &mut self.clan,
-            &self.member,
+            &mut self.member,
             Some(share_bp),
             None,
             None, // Leaving the clan
             &mut self.clan_vwr,
             &clock,
         )?;
+        self.clan.record_weight_checkpoint(
+            self.weight_checkpoints.as_deref_mut(),
+            &self.clan_vwr,
+            clock.slot,
+        )?;
         self.clan.leaving_members += 1;
+
+        // Tokens must stay locked until the member can exit all the clans.
+        // Leaving the last active clan shortens the lock to the exit time
+        let member_tor = get_token_owner_record_data_for_realm_and_governing_mint(
+            &self.root.governance_program,
+            &self.member_tor.to_account_info(),
+            &self.root.realm,
+            &self.root.governing_token_mint,
+        )
+        .map_err(|e| ProgramErrorWithOrigin::from(e).with_account_name("member_tor"))?;
+        Member::set_lock(
+            &self.root,
+            &member_tor,
+            MemberLockAccounts {
+                governance_program: &self.governance_program.to_account_info(),
+                realm: &self.realm.to_account_info(),
+                realm_config: &self.realm_config.to_account_info(),
+                member_tor: &self.member_tor.to_account_info(),
+                lock_authority: &self.lock_authority.to_account_info(),
+                payer: &self.payer.to_account_info(),
+                system_program: &self.system_program.to_account_info(),
+            },
+            self.member
+                .lock_expiry(&self.root, clock.unix_timestamp)
+                .ok_or(error!(Error::MemberHasNoClans))?,
+        )?;
         emit!(StartingLeavingClan {
             member: self.member.key(),
             clan: self.clan.key(),
@@ -1,9 +1,13 @@
-use anchor_lang::prelude::*;
-use spl_governance::addins::voter_weight::get_voter_weight_record_data;
+use anchor_lang::{prelude::*, system_program};
+use spl_governance::{
+    addins::voter_weight::get_voter_weight_record_data,
+    state::token_owner_record::get_token_owner_record_data_for_realm_and_governing_mint,
+    PROGRAM_AUTHORITY_SEED,
+};
 use spl_governance_addin_api::voter_weight::VoterWeightRecord as SplVoterWeightRecord;
 
 use crate::error::Error;
-use crate::state::{MaxVoterWeightRecord, Member, Root};
+use crate::state::{MaxVoterWeightRecord, Member, MemberLockAccounts, Root};
 
 #[derive(Accounts)]
 pub struct UpdateVoterWeight<'info> {
@@ -31,12 +35,73 @@ pub struct UpdateVoterWeight<'info> {
         bump = root.bumps.max_voter_weight,
     )]
     max_vwr: Account<'info, MaxVoterWeightRecord>,
+
+    /// Extends the member TOR lock when provided together with the accounts below
+    /// CHECK: dynamic owner
+    #[account(
+        mut,
+        seeds = [
+            PROGRAM_AUTHORITY_SEED,
+            &root.realm.to_bytes(),
+            &root.governing_token_mint.to_bytes(),
+            &member.owner.to_bytes(),
+        ],
+        seeds::program = root.governance_program,
+        bump = member.bumps.token_owner_record,
+        address = member.token_owner_record,
+    )]
+    member_tor: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            Root::LOCK_AUTHORITY_SEED,
+            &root.key().to_bytes()
+        ],
+        bump = root.bumps.lock_authority,
+    )]
+    lock_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: dynamic owner
+    #[account(
+        address = root.realm,
+        owner = root.governance_program,
+    )]
+    realm: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: dynamic owner
+    #[account(
+        owner = root.governance_program,
+        seeds = [
+            b"realm-config",
+            &root.realm.to_bytes()
+        ],
+        bump,
+        seeds::program = root.governance_program,
+    )]
+    realm_config: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        owner = system_program::ID
+    )]
+    payer: Option<Signer<'info>>,
+
+    system_program: Option<Program<'info, System>>,
+    /// CHECK: program
+    #[account(
+        executable,
+        address = root.governance_program,
+    )]
+    governance_program: Option<UncheckedAccount<'info>>,
 }
 
 impl<'info> UpdateVoterWeight<'info> {
     pub fn process<'c: 'info>(&mut self, rest: &'c [AccountInfo<'info>]) -> Result<()> {
         let new_member_vwr = if let Some(member_vwr) = self.member_vwr.as_ref() {
-            require!(!self.root.paused, Error::Paused);
+            self.root.check_not_paused(Root::PAUSE_VOTER_WEIGHT)?;
+            self.root.check_not_winding_down()?;
+            self.root.check_no_plugin_drift()?;
             let new_member_vwr = get_voter_weight_record_data(
                 &self.root.voting_weight_plugin,
                 member_vwr,
@@ -52,7 +117,11 @@ impl<'info> UpdateVoterWeight<'info> {
             require_keys_eq!(new_member_vwr.governing_token_owner, self.member.owner);
             new_member_vwr
         } else {
-            require!(self.root.paused, Error::MemberVwrRequired);
+            // Voter weights may be reset only while the updates are paused
+            require!(
+                self.root.is_paused(Root::PAUSE_VOTER_WEIGHT),
+                Error::MemberVwrRequired
+            );
 
             SplVoterWeightRecord {
                 account_discriminator: SplVoterWeightRecord::ACCOUNT_DISCRIMINATOR,
@@ -69,12 +138,18 @@ impl<'info> UpdateVoterWeight<'info> {
 
         let clock = Clock::get()?;
         self.root.update_next_voter_weight_reset_time(&clock);
+        self.root
+            .check_voter_weight_expiry(new_member_vwr.voter_weight_expiry, &clock)?;
+        let new_voter_weight_decay =
+            self.member
+                .next_voter_weight_decay(&new_member_vwr, &self.root, &clock);
         for (mut chunk, entry) in self.member.load_clan_chunks(rest, |_| true)? {
             self.member.refresh_membership(
                 &mut self.root,
-                entry,
+                &entry,
                 &mut chunk,
                 &new_member_vwr,
+                new_voter_weight_decay.as_ref(),
                 &clock,
             )?;
             chunk.exit(&crate::ID)?;
@@ -85,10 +160,44 @@ impl<'info> UpdateVoterWeight<'info> {
             &mut self.member,
             member_vwr_key,
             &new_member_vwr,
+            new_voter_weight_decay,
+            &self.root,
             &mut self.max_vwr,
         )?;
         self.member.next_voter_weight_reset_time = self.root.next_voter_weight_reset_time();
 
+        // An active member stays locked for member_lock_duration since the last update
+        let lock_expiry = self.member.lock_expiry(&self.root, clock.unix_timestamp);
+        if let (Some(member_tor_info), Some(lock_expiry)) = (self.member_tor.as_ref(), lock_expiry)
+        {
+            self.set_lock(member_tor_info, lock_expiry)?;
+        }
+
         Ok(())
     }
+
+    fn set_lock(&self, member_tor_info: &AccountInfo<'info>, lock_expiry: i64) -> Result<()> {
+        let required = || error!(Error::MemberLockAccountsRequired);
+        let member_tor = get_token_owner_record_data_for_realm_and_governing_mint(
+            &self.root.governance_program,
+            member_tor_info,
+            &self.root.realm,
+            &self.root.governing_token_mint,
+        )
+        .map_err(|e| ProgramErrorWithOrigin::from(e).with_account_name("member_tor"))?;
+        Member::set_lock(
+            &self.root,
+            &member_tor,
+            MemberLockAccounts {
+                governance_program: self.governance_program.as_deref().ok_or_else(required)?,
+                realm: self.realm.as_deref().ok_or_else(required)?,
+                realm_config: self.realm_config.as_deref().ok_or_else(required)?,
+                member_tor: member_tor_info,
+                lock_authority: self.lock_authority.as_deref().ok_or_else(required)?,
+                payer: self.payer.as_deref().ok_or_else(required)?,
+                system_program: self.system_program.as_deref().ok_or_else(required)?,
+            },
+            lock_expiry,
+        )
+    }
 }
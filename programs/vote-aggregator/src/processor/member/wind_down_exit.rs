@@ -0,0 +1,203 @@
+use anchor_lang::prelude::*;
+use spl_governance::{
+    instruction::relinquish_token_owner_record_locks, solana_program::program::invoke_signed,
+    state::token_owner_record::get_token_owner_record_data_for_realm_and_governing_mint,
+    PROGRAM_AUTHORITY_SEED,
+};
+use spl_governance_addin_api::voter_weight::VoterWeightRecord as SplVoterWeightRecord;
+
+use crate::{
+    error::Error,
+    events::clan::ClanMemberLeft,
+    state::{Clan, MaxVoterWeightRecord, Member, Root},
+};
+
+/// Exits all the clans at once while the root is winding down.
+/// Remaining accounts are (clan, clan_vwr) pairs for every membership entry
+#[derive(Accounts)]
+pub struct WindDownExit<'info> {
+    #[account(
+        mut,
+        has_one = root,
+    )]
+    member: Account<'info, Member>,
+
+    #[account(
+        constraint = member_authority.key() == member.owner ||
+            member_authority.key() == member.delegate
+        @ Error::WrongMemberAuthority
+    )]
+    member_authority: Signer<'info>,
+
+    /// CHECK: dynamic owner
+    #[account(
+        mut,
+        seeds = [
+            PROGRAM_AUTHORITY_SEED,
+            &root.realm.to_bytes(),
+            &root.governing_token_mint.to_bytes(),
+            &member.owner.key().to_bytes(),
+        ],
+        seeds::program = governance_program.key(),
+        bump = member.bumps.token_owner_record,
+        address = member.token_owner_record,
+    )]
+    member_tor: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        has_one = governance_program,
+        has_one = realm,
+        constraint = root.wind_down @ Error::WindDownNotStarted,
+    )]
+    root: Account<'info, Root>,
+
+    /// CHECK: dynamic owner
+    #[account(
+        owner = governance_program.key(),
+    )]
+    realm: UncheckedAccount<'info>,
+
+    /// CHECK: dynamic owner
+    #[account(
+        owner = governance_program.key(),
+        seeds = [
+            b"realm-config",
+            &realm.key.to_bytes()
+        ],
+        bump,
+        seeds::program = governance_program.key(),
+    )]
+    realm_config: UncheckedAccount<'info>,
+
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            Root::LOCK_AUTHORITY_SEED,
+            &root.key().to_bytes()
+        ],
+        bump = root.bumps.lock_authority,
+    )]
+    lock_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            MaxVoterWeightRecord::ADDRESS_SEED,
+            &root.key().to_bytes()
+        ],
+        bump = root.bumps.max_voter_weight,
+    )]
+    max_vwr: Account<'info, MaxVoterWeightRecord>,
+
+    /// CHECK: program
+    #[account(executable)]
+    governance_program: UncheckedAccount<'info>,
+}
+
+impl<'info> WindDownExit<'info> {
+    pub fn process<'c: 'info>(&mut self, rest: &'c [AccountInfo<'info>]) -> Result<()> {
+        // Not gated by the leave pause so the members are never trapped in a retired root
+        let clock = Clock::get()?;
+        self.root.update_next_voter_weight_reset_time(&clock);
+
+        let member_tor = get_token_owner_record_data_for_realm_and_governing_mint(
+            &self.root.governance_program,
+            &self.member_tor.to_account_info(),
+            &self.root.realm,
+            &self.root.governing_token_mint,
+        )
+        .map_err(|e| ProgramErrorWithOrigin::from(e).with_account_name("member_tor"))?;
+        // The lock may be already expired and removed
+        let has_lock = member_tor
+            .locks
+            .iter()
+            .any(|l| l.authority == self.lock_authority.key() && l.lock_id == 0);
+        for (mut chunk, entry) in self.member.load_all_clan_chunks(rest, |_| true)? {
+            chunk
+                .clan
+                .reset_voter_weight_if_needed(&self.root, &mut chunk.vwr);
+            if entry.exitable_at.is_none() {
+                Clan::update_member(
+                    &mut chunk.clan,
+                    &mut self.member,
+                    Some(entry.share_bp),
+                    None,
+                    None, // Leaving the clan
+                    &mut chunk.vwr,
+                    &clock,
+                )?;
+                chunk.record_weight_checkpoint(clock.slot)?;
+            } else {
+                chunk.clan.leaving_members -= 1;
+            }
+            // The rewards would be lost with the membership entry
+            let clan_key = chunk.clan.key();
+            let entry = self
+                .member
+                .membership
+                .iter_mut()
+                .find(|e| e.clan == clan_key)
+                .unwrap();
+            chunk
+                .clan
+                .accrue_member_rewards(entry, clock.unix_timestamp);
+            require_eq!(entry.unclaimed_rewards, 0, Error::UnclaimedClanRewards);
+            emit!(ClanMemberLeft {
+                member: self.member.key(),
+                clan: chunk.clan.key(),
+                root: self.root.key(),
+                owner: self.member.owner,
+            });
+            chunk.exit(&crate::ID)?;
+        }
+        self.member.membership.clear();
+
+        // Not a member anywhere so the weight is not counted in the max voter weight
+        let zero_member_vwr = SplVoterWeightRecord {
+            account_discriminator: SplVoterWeightRecord::ACCOUNT_DISCRIMINATOR,
+            realm: self.root.realm,
+            governing_token_mint: self.root.governing_token_mint,
+            governing_token_owner: self.member.owner,
+            voter_weight: 0,
+            voter_weight_expiry: None,
+            weight_action: None,
+            weight_action_target: None,
+            reserved: [0; 8],
+        };
+        let member_vwr_key = self.member.voter_weight_record;
+        Member::update_voter_weight(
+            &mut self.member,
+            member_vwr_key,
+            &zero_member_vwr,
+            None,
+            &self.root,
+            &mut self.max_vwr,
+        )?;
+
+        if has_lock {
+            invoke_signed(
+                &relinquish_token_owner_record_locks(
+                    self.governance_program.key,
+                    self.realm.key,
+                    self.member_tor.key,
+                    Some(self.lock_authority.key()),
+                    Some(vec![0]),
+                ),
+                &[
+                    self.realm.to_account_info(),
+                    self.realm_config.to_account_info(),
+                    self.governance_program.to_account_info(),
+                    self.member_tor.to_account_info(),
+                    self.lock_authority.to_account_info(),
+                ],
+                &[&[
+                    Root::LOCK_AUTHORITY_SEED,
+                    &self.root.key().to_bytes(),
+                    &[self.root.bumps.lock_authority],
+                ]],
+            )?;
+        }
+        Ok(())
+    }
+}
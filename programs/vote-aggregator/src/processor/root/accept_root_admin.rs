@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::error::Error;
+use crate::events::root::RootAdminChanged;
+use crate::state::Root;
+
+#[derive(Accounts)]
+pub struct AcceptRootAdmin<'info> {
+    #[account(
+        mut,
+        constraint = root.pending_admin == Some(pending_admin.key())
+            @ Error::WrongPendingRootAdmin,
+    )]
+    root: Account<'info, Root>,
+    pending_admin: Signer<'info>,
+}
+
+impl<'info> AcceptRootAdmin<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let old_admin = self.root.admin;
+        self.root.admin = self.pending_admin.key();
+        self.root.pending_admin = None;
+        if self.root.admin != old_admin {
+            emit!(RootAdminChanged {
+                root: self.root.key(),
+                old_admin,
+                new_admin: self.root.admin,
+            });
+        }
+        Ok(())
+    }
+}
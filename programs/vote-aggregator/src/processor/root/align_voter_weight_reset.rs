@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use spl_governance::state::governance;
+
+use crate::error::Error;
+use crate::state::Root;
+use anchor_lang::error::Error as AnchorError;
+
+use super::configure_root::*;
+
+#[derive(Accounts)]
+pub struct AlignVoterWeightReset<'info> {
+    configure_root: ConfigureRoot<'info>,
+
+    /// CHECK: dynamic owner ID
+    #[account(
+        owner = configure_root.root.governance_program,
+    )]
+    governance: UncheckedAccount<'info>,
+}
+
+impl<'info> AlignVoterWeightReset<'info> {
+    /// Makes the reset step a whole number of the governance voting windows
+    /// so every reset period fits the same count of full votes
+    pub fn process(&mut self, voting_windows: u64, new_next_reset_time: Option<i64>) -> Result<()> {
+        self.configure_root.check_authority()?;
+        self.configure_root.check_not_timelocked()?;
+        let governance = governance::get_governance_data_for_realm(
+            &self.configure_root.root.governance_program,
+            &self.governance.to_account_info(),
+            &self.configure_root.root.realm,
+        )
+        .map_err(|e| {
+            AnchorError::from(e)
+                .with_source(source!())
+                .with_account_name("governance")
+        })?;
+        let voting_window = governance.config.voting_base_time as u64
+            + governance.config.voting_cool_off_time as u64;
+        let step = voting_windows
+            .checked_mul(voting_window)
+            .ok_or(error!(Error::InvalidResetStep))?;
+        require_neq!(step, 0, Error::InvalidResetStep);
+
+        let new_voter_weight_reset = self.configure_root.root.new_voter_weight_reset(
+            step,
+            new_next_reset_time,
+            Clock::get()?.unix_timestamp,
+        )?;
+        Root::change_voter_weight_reset(
+            &mut self.configure_root.root,
+            Some(new_voter_weight_reset),
+        );
+        Ok(())
+    }
+}
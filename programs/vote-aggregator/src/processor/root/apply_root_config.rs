@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+
+use crate::error::Error;
+use crate::events::root::RootConfigApplied;
+use crate::state::{MaxVoterWeightRecord, PendingRootConfig, Root};
+
+#[derive(Accounts)]
+pub struct ApplyRootConfig<'info> {
+    #[account(mut)]
+    root: Account<'info, Root>,
+
+    #[account(
+        mut,
+        seeds = [
+            PendingRootConfig::ADDRESS_SEED,
+            &root.key().to_bytes()
+        ],
+        bump = pending_root_config.bump,
+        has_one = root,
+        has_one = rent_payer,
+        close = rent_payer,
+    )]
+    pending_root_config: Account<'info, PendingRootConfig>,
+
+    #[account(
+        seeds = [
+            MaxVoterWeightRecord::ADDRESS_SEED,
+            &root.key().to_bytes()
+        ],
+        bump = root.bumps.max_voter_weight,
+    )]
+    max_vwr: Account<'info, MaxVoterWeightRecord>,
+
+    /// CHECK: receives the rent back
+    #[account(mut)]
+    rent_payer: UncheckedAccount<'info>,
+}
+
+impl<'info> ApplyRootConfig<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        require_gte!(
+            Clock::get()?.unix_timestamp,
+            self.pending_root_config.activation_time,
+            Error::RootConfigNotMatured
+        );
+
+        let change = self.pending_root_config.change.clone();
+        if let Some(new_max_proposal_lifetime) = change.max_proposal_lifetime {
+            Root::change_max_proposal_lifetime(&mut self.root, new_max_proposal_lifetime);
+        }
+
+        if let Some(new_voting_weight_plugin) = change.voting_weight_plugin {
+            if new_voting_weight_plugin != self.root.voting_weight_plugin {
+                require_eq!(
+                    self.max_vwr.max_voter_weight,
+                    0,
+                    Error::ResetAllVoterWeightsFirst
+                );
+                Root::change_voting_weight_plugin(&mut self.root, new_voting_weight_plugin);
+            }
+        }
+
+        if let Some(new_voter_weight_reset) = &change.voter_weight_reset {
+            // Clans with another next reset time will be reset on the next touch
+            Root::change_voter_weight_reset(&mut self.root, new_voter_weight_reset.clone());
+        }
+
+        if let Some(new_member_lock_duration) = change.member_lock_duration {
+            Root::change_member_lock_duration(&mut self.root, new_member_lock_duration);
+        }
+
+        if let Some(new_membership_warmup) = change.membership_warmup {
+            Root::change_membership_warmup(&mut self.root, new_membership_warmup);
+        }
+
+        if let Some(new_max_loyalty_bonus_bp) = change.max_loyalty_bonus_bp {
+            Root::change_max_loyalty_bonus(&mut self.root, new_max_loyalty_bonus_bp);
+        }
+
+        if let Some(new_weight_transform) = &change.weight_transform {
+            Root::change_weight_transform(&mut self.root, new_weight_transform.clone());
+        }
+
+        if let Some(new_recall_threshold_bp) = change.recall_threshold_bp {
+            Root::change_recall_threshold(&mut self.root, new_recall_threshold_bp);
+        }
+
+        if let Some(new_voter_weight_expiry_tolerance) = change.voter_weight_expiry_tolerance {
+            Root::change_voter_weight_expiry_tolerance(
+                &mut self.root,
+                new_voter_weight_expiry_tolerance,
+            );
+        }
+
+        if let Some(new_stale_member_grace_period) = change.stale_member_grace_period {
+            Root::change_stale_member_grace_period(&mut self.root, new_stale_member_grace_period);
+        }
+
+        if change.wind_down {
+            Root::start_wind_down(&mut self.root);
+        }
+
+        emit!(RootConfigApplied {
+            root: self.root.key(),
+            change,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::events::root::RootConfigCanceled;
+use crate::state::PendingRootConfig;
+
+use super::configure_root::*;
+
+#[derive(Accounts)]
+pub struct CancelRootConfig<'info> {
+    configure_root: ConfigureRoot<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PendingRootConfig::ADDRESS_SEED,
+            &configure_root.root.key().to_bytes()
+        ],
+        bump = pending_root_config.bump,
+        has_one = rent_payer,
+        close = rent_payer,
+    )]
+    pending_root_config: Account<'info, PendingRootConfig>,
+
+    /// CHECK: receives the rent back
+    #[account(mut)]
+    rent_payer: UncheckedAccount<'info>,
+}
+
+impl<'info> CancelRootConfig<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        if self.pending_root_config.change.requires_realm_authority() {
+            self.configure_root.check_realm_authority()?;
+        } else {
+            self.configure_root.check_authority()?;
+        }
+        emit!(RootConfigCanceled {
+            root: self.configure_root.root.key(),
+            change: self.pending_root_config.change.clone(),
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use spl_governance::state::{realm, realm_config::get_realm_config_data_for_realm};
+
+use crate::{
+    error::Error,
+    events::root::{PluginConfigDrift, PluginConfigRestored},
+    state::Root,
+};
+use anchor_lang::error::Error as AnchorError;
+
+/// Permissionless comparison of the realm config with the root plugin chain
+#[derive(Accounts)]
+pub struct CheckPluginConfig<'info> {
+    #[account(
+        mut,
+        has_one = realm,
+        has_one = governance_program,
+    )]
+    root: Account<'info, Root>,
+
+    /// CHECK: dynamic owner
+    #[account(
+        owner = governance_program.key(),
+    )]
+    realm: UncheckedAccount<'info>,
+
+    /// CHECK: dynamic owner
+    #[account(
+        owner = governance_program.key(),
+        seeds = [
+            b"realm-config",
+            &realm.key.to_bytes()
+        ],
+        bump,
+        seeds::program = governance_program.key(),
+    )]
+    realm_config: UncheckedAccount<'info>,
+
+    /// CHECK: the plugin the root reads the member weights from.
+    /// Required once the root has a plugin
+    #[account(
+        address = root.voting_weight_plugin,
+    )]
+    voting_weight_plugin: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: program
+    #[account(executable)]
+    governance_program: UncheckedAccount<'info>,
+}
+
+impl<'info> CheckPluginConfig<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let realm = realm::get_realm_data_for_governing_token_mint(
+            self.governance_program.key,
+            &self.realm.to_account_info(),
+            &self.root.governing_token_mint,
+        )
+        .map_err(|e| {
+            AnchorError::from(e)
+                .with_source(source!())
+                .with_account_name("realm")
+        })?;
+
+        let realm_config = get_realm_config_data_for_realm(
+            self.governance_program.key,
+            &self.realm_config.to_account_info(),
+            self.realm.key,
+        )
+        .map_err(|e| {
+            AnchorError::from(e)
+                .with_source(source!())
+                .with_account_name("realm_config")
+        })?;
+
+        let token_config = if self.root.governing_token_mint == realm.community_mint {
+            realm_config.community_token_config
+        } else {
+            realm_config.council_token_config
+        };
+        // The realm must keep using the aggregator on top of the recorded plugin
+        let realm_drift = token_config.voter_weight_addin != Some(crate::ID)
+            || token_config.max_voter_weight_addin != Some(crate::ID);
+        let plugin_drift = if self.root.voting_weight_plugin == Pubkey::default() {
+            false
+        } else {
+            let voting_weight_plugin = self
+                .voting_weight_plugin
+                .as_ref()
+                .ok_or(error!(Error::VotingWeightPluginRequired))?;
+            // The chain must end in a deployed plugin other than the aggregator
+            self.root.voting_weight_plugin == crate::ID || !voting_weight_plugin.executable
+        };
+        // The plugin changed since the realm config was verified
+        let registry_drift =
+            self.root.voting_weight_plugin != self.root.verified_voting_weight_plugin;
+        let drift = realm_drift || plugin_drift || registry_drift;
+
+        if drift && !self.root.plugin_drift {
+            emit!(PluginConfigDrift {
+                root: self.root.key(),
+                realm_voter_weight_addin: token_config.voter_weight_addin,
+                realm_max_voter_weight_addin: token_config.max_voter_weight_addin,
+                voting_weight_plugin: self.root.voting_weight_plugin,
+                verified_voting_weight_plugin: self.root.verified_voting_weight_plugin,
+            });
+        } else if !drift && self.root.plugin_drift {
+            emit!(PluginConfigRestored {
+                root: self.root.key(),
+            });
+        }
+        self.root.plugin_drift = drift;
+        Ok(())
+    }
+}
@@ -2,8 +2,10 @@ use anchor_lang::prelude::*;
 use spl_governance::state::realm;
 
 use crate::error::Error;
-use crate::events::root::{MaxProposalLifetimeChanged, Paused, Resumed, VoterWeightResetChanged};
-use crate::state::{Root, VoterWeightReset};
+use crate::events::root::{
+    PauseGuardianChanged, Paused, Resumed, RootAdminChanged, RootAdminProposed,
+};
+use crate::state::{Root, WeightTransform};
 use anchor_lang::error::Error as AnchorError;
 
 #[derive(Accounts)]
@@ -18,11 +20,25 @@ pub struct ConfigureRoot<'info> {
         owner = root.governance_program,
     )]
     pub(crate) realm: UncheckedAccount<'info>,
-    pub(crate) realm_authority: Signer<'info>,
+    /// The root admin, the pause guardian or the realm authority depending on the action
+    pub(crate) admin: Signer<'info>,
 }
 
 impl<'info> ConfigureRoot<'info> {
     pub(crate) fn check_authority(&self) -> Result<()> {
+        require_keys_eq!(self.root.admin, self.admin.key(), Error::WrongRootAdmin);
+        Ok(())
+    }
+
+    pub(crate) fn check_pause_authority(&self) -> Result<()> {
+        if self.root.pause_guardian == Some(self.admin.key()) {
+            return Ok(());
+        }
+        self.check_authority()
+    }
+
+    /// The voting rules stay with the realm authority
+    pub(crate) fn check_realm_authority(&self) -> Result<()> {
         let realm = realm::get_realm_data_for_governing_token_mint(
             &self.root.governance_program,
             &self.realm.to_account_info(),
@@ -36,24 +52,30 @@ impl<'info> ConfigureRoot<'info> {
 
         require_keys_eq!(
             realm.authority.ok_or(error!(Error::EmptyRealmAuthority))?,
-            self.realm_authority.key(),
+            self.admin.key(),
             Error::WrongRealmAuthority
         );
 
         Ok(())
     }
+
+    /// Changes affecting the members must go through queue_root_config once anybody joined
+    pub(crate) fn check_not_timelocked(&self) -> Result<()> {
+        require!(
+            !self.root.config_change_requires_timelock(),
+            Error::RootConfigChangeMustBeQueued
+        );
+        Ok(())
+    }
+
     pub fn set_max_proposal_lifetime(&mut self, new_max_proposal_lifetime: u64) -> Result<()> {
         self.check_authority()?;
-
-        let old_max_proposal_lifetime = self.root.max_proposal_lifetime;
-        self.root.max_proposal_lifetime = new_max_proposal_lifetime;
-        if new_max_proposal_lifetime != old_max_proposal_lifetime {
-            emit!(MaxProposalLifetimeChanged {
-                root: self.root.key(),
-                old_max_proposal_lifetime,
-                new_max_proposal_lifetime
-            });
+        // A shorter exit cooldown can not trap anybody
+        if new_max_proposal_lifetime > self.root.max_proposal_lifetime {
+            self.check_not_timelocked()?;
         }
+
+        Root::change_max_proposal_lifetime(&mut self.root, new_max_proposal_lifetime);
         Ok(())
     }
 
@@ -63,59 +85,191 @@ impl<'info> ConfigureRoot<'info> {
         new_next_reset_time: Option<i64>,
     ) -> Result<()> {
         self.check_authority()?;
+        self.check_not_timelocked()?;
 
-        let old_voter_weight_reset = self.root.voter_weight_reset.clone();
-        if let Some(VoterWeightReset {
-            step,
-            next_reset_time,
-        }) = &mut self.root.voter_weight_reset
-        {
-            require!(
-                new_next_reset_time.is_none(),
-                Error::CanNotChangeNextResetTime
-            );
-            // Check the next reset time is not overflowing
-            let _ = *next_reset_time + new_step as i64;
-            *step = new_step;
-        } else {
-            require_neq!(new_step, 0, Error::InvalidResetStep);
-            let current_time = Clock::get()?.unix_timestamp;
-            let next_reset_time = new_next_reset_time.unwrap_or(current_time + new_step as i64);
-            require_gt!(next_reset_time, current_time, Error::InvalidNextResetTime);
-            self.root.voter_weight_reset = Some(VoterWeightReset {
-                next_reset_time,
-                step: new_step,
-            });
-            // Check the next reset time is not overflowing
-            let _ = next_reset_time + new_step as i64;
+        let new_voter_weight_reset = self.root.new_voter_weight_reset(
+            new_step,
+            new_next_reset_time,
+            Clock::get()?.unix_timestamp,
+        )?;
+        Root::change_voter_weight_reset(&mut self.root, Some(new_voter_weight_reset));
+        Ok(())
+    }
+
+    pub fn disable_voter_weight_reset(&mut self) -> Result<()> {
+        self.check_authority()?;
+        self.check_not_timelocked()?;
+
+        // Clans drop the temporary weight on their first touch (update_clan)
+        // after the current reset period and can not vote after it until then
+        Root::change_voter_weight_reset(&mut self.root, None);
+        Ok(())
+    }
+
+    pub fn set_voter_weight_expiry_tolerance(
+        &mut self,
+        new_voter_weight_expiry_tolerance: u64,
+    ) -> Result<()> {
+        self.check_authority()?;
+        // A bigger tolerance can not reject anybody's voter weight
+        if new_voter_weight_expiry_tolerance < self.root.voter_weight_expiry_tolerance {
+            self.check_not_timelocked()?;
+        }
+
+        Root::change_voter_weight_expiry_tolerance(
+            &mut self.root,
+            new_voter_weight_expiry_tolerance,
+        );
+        Ok(())
+    }
+
+    pub fn set_stale_member_grace_period(
+        &mut self,
+        new_stale_member_grace_period: Option<u64>,
+    ) -> Result<()> {
+        self.check_authority()?;
+        // A longer grace period or no evictions can not evict anybody earlier
+        if let Some(new_stale_member_grace_period) = new_stale_member_grace_period {
+            if self
+                .root
+                .stale_member_grace_period
+                .map_or(true, |old| new_stale_member_grace_period < old)
+            {
+                self.check_not_timelocked()?;
+            }
         }
-        emit!(VoterWeightResetChanged {
+
+        Root::change_stale_member_grace_period(&mut self.root, new_stale_member_grace_period);
+        Ok(())
+    }
+
+    pub fn propose_root_admin(&mut self, new_pending_admin: Option<Pubkey>) -> Result<()> {
+        self.check_authority()?;
+
+        self.root.pending_admin = new_pending_admin;
+        emit!(RootAdminProposed {
             root: self.root.key(),
-            old_voter_weight_reset,
-            new_voter_weight_reset: self.root.voter_weight_reset.clone()
+            admin: self.root.admin,
+            pending_admin: new_pending_admin,
         });
         Ok(())
     }
 
-    pub fn pause(&mut self) -> Result<()> {
+    pub fn reclaim_root_admin(&mut self) -> Result<()> {
+        self.check_realm_authority()?;
+
+        self.root.pending_admin = None;
+        self.change_admin(self.admin.key());
+        Ok(())
+    }
+
+    pub(crate) fn change_admin(&mut self, new_admin: Pubkey) {
+        let old_admin = self.root.admin;
+        self.root.admin = new_admin;
+        if new_admin != old_admin {
+            emit!(RootAdminChanged {
+                root: self.root.key(),
+                old_admin,
+                new_admin,
+            });
+        }
+    }
+
+    pub fn set_pause_guardian(&mut self, new_pause_guardian: Option<Pubkey>) -> Result<()> {
+        self.check_authority()?;
+
+        let old_pause_guardian = self.root.pause_guardian;
+        self.root.pause_guardian = new_pause_guardian;
+        if new_pause_guardian != old_pause_guardian {
+            emit!(PauseGuardianChanged {
+                root: self.root.key(),
+                old_pause_guardian,
+                new_pause_guardian,
+            });
+        }
+        Ok(())
+    }
+
+    pub fn set_member_lock_duration(&mut self, new_member_lock_duration: u64) -> Result<()> {
         self.check_authority()?;
-        let old_paused = self.root.paused;
-        self.root.paused = true;
-        if !old_paused {
+        // A shorter lock can not trap anybody
+        if new_member_lock_duration > self.root.member_lock_duration {
+            self.check_not_timelocked()?;
+        }
+
+        Root::change_member_lock_duration(&mut self.root, new_member_lock_duration);
+        Ok(())
+    }
+
+    pub fn set_membership_warmup(&mut self, new_membership_warmup: u64) -> Result<()> {
+        self.check_authority()?;
+        // A shorter warmup can not hurt anybody
+        if new_membership_warmup > self.root.membership_warmup {
+            self.check_not_timelocked()?;
+        }
+
+        Root::change_membership_warmup(&mut self.root, new_membership_warmup);
+        Ok(())
+    }
+
+    pub fn set_max_loyalty_bonus(&mut self, new_max_loyalty_bonus_bp: u16) -> Result<()> {
+        self.check_authority()?;
+        self.check_not_timelocked()?;
+        Root::check_max_loyalty_bonus(new_max_loyalty_bonus_bp)?;
+
+        Root::change_max_loyalty_bonus(&mut self.root, new_max_loyalty_bonus_bp);
+        Ok(())
+    }
+
+    pub fn set_weight_transform(&mut self, new_weight_transform: WeightTransform) -> Result<()> {
+        self.check_realm_authority()?;
+        self.check_not_timelocked()?;
+        Root::check_weight_transform(&new_weight_transform)?;
+
+        Root::change_weight_transform(&mut self.root, new_weight_transform);
+        Ok(())
+    }
+
+    pub fn set_recall_threshold(&mut self, new_recall_threshold_bp: u16) -> Result<()> {
+        self.check_realm_authority()?;
+        self.check_not_timelocked()?;
+        Root::check_recall_threshold(new_recall_threshold_bp)?;
+
+        Root::change_recall_threshold(&mut self.root, new_recall_threshold_bp);
+        Ok(())
+    }
+
+    pub fn start_wind_down(&mut self) -> Result<()> {
+        self.check_realm_authority()?;
+        self.check_not_timelocked()?;
+
+        Root::start_wind_down(&mut self.root);
+        Ok(())
+    }
+
+    pub fn pause(&mut self, flags: u32) -> Result<()> {
+        self.check_pause_authority()?;
+        let old_pause_flags = self.root.pause_flags;
+        self.root.pause_flags |= flags;
+        if self.root.pause_flags != old_pause_flags {
             emit!(Paused {
                 root: self.root.key(),
+                flags: self.root.pause_flags & !old_pause_flags,
+                pause_flags: self.root.pause_flags,
             })
         }
         Ok(())
     }
 
-    pub fn resume(&mut self) -> Result<()> {
+    pub fn resume(&mut self, flags: u32) -> Result<()> {
         self.check_authority()?;
-        let old_paused = self.root.paused;
-        self.root.paused = false;
-        if old_paused {
+        let old_pause_flags = self.root.pause_flags;
+        self.root.pause_flags &= !flags;
+        if self.root.pause_flags != old_pause_flags {
             emit!(Resumed {
                 root: self.root.key(),
+                flags: old_pause_flags & !self.root.pause_flags,
+                pause_flags: self.root.pause_flags,
             })
         }
         Ok(())
@@ -7,7 +7,7 @@ use crate::{
     events::root::RootCreated,
     program::VoteAggregator,
     state::{
-        root::{Root, RootBumps},
+        root::{Root, RootBumps, WeightTransform},
         MaxVoterWeightRecord,
     },
 };
@@ -106,11 +106,26 @@ impl<'info> CreateRoot<'info> {
             Error::WrongRealmAuthority
         );
 
-        let voting_weight_plugin = if self.governing_token_mint.key() == realm.community_mint {
+        let is_community = self.governing_token_mint.key() == realm.community_mint;
+        if !is_community {
+            require_keys_eq!(
+                realm
+                    .config
+                    .council_mint
+                    .ok_or(error!(Error::CouncilMintRequired))?,
+                self.governing_token_mint.key(),
+                Error::InvalidCouncilMint
+            );
+        }
+        let voting_weight_plugin = if is_community {
             realm_config.community_token_config.voter_weight_addin
         } else {
             realm_config.council_token_config.voter_weight_addin
         };
+        require!(
+            voting_weight_plugin != Some(crate::ID),
+            Error::CircularPluginChain
+        );
 
         let (lock_authority, lock_authority_bump) = Pubkey::find_program_address(
             &[Root::LOCK_AUTHORITY_SEED, &self.root.key().to_bytes()],
@@ -125,7 +140,23 @@ impl<'info> CreateRoot<'info> {
             voting_weight_plugin: voting_weight_plugin.unwrap_or_default(),
             max_proposal_lifetime,
             voter_weight_reset: None,
-            paused: false,
+            pause_flags: 0,
+            voter_weight_expiry_tolerance: 0,
+            stale_member_grace_period: None,
+            admin: self.realm_authority.key(),
+            pending_admin: None,
+            pause_guardian: None,
+            wind_down: false,
+            member_lock_duration: Root::DEFAULT_MEMBER_LOCK_DURATION,
+            // The realm config may be switched later in another transaction
+            // (e.g. by a DAO proposal). Set by verify_root once it is switched
+            installed: false,
+            plugin_drift: false,
+            verified_voting_weight_plugin: Pubkey::default(),
+            membership_warmup: 0,
+            max_loyalty_bonus_bp: 0,
+            weight_transform: WeightTransform::Linear,
+            recall_threshold_bp: 0,
             clan_count: 0,
             member_count: 0,
             bumps: RootBumps {
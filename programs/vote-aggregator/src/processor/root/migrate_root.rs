@@ -0,0 +1,89 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, Transfer},
+    Discriminator,
+};
+use spl_governance::state::realm;
+
+use crate::error::Error;
+use crate::events::root::RootMigrated;
+use crate::state::{LegacyRoot, Root};
+use anchor_lang::error::Error as AnchorError;
+
+/// Grows a root created with the legacy layout and converts it
+#[derive(Accounts)]
+pub struct MigrateRoot<'info> {
+    /// CHECK: the legacy layout is parsed by the processor
+    #[account(
+        mut,
+        owner = crate::ID,
+    )]
+    root: UncheckedAccount<'info>,
+
+    /// CHECK: checked against the legacy root
+    realm: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        owner = system_program::ID,
+    )]
+    payer: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateRoot<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let root_info = self.root.to_account_info();
+        require_gt!(
+            Root::SPACE,
+            root_info.data_len(),
+            Error::RootAlreadyMigrated
+        );
+        let legacy = {
+            let data = root_info.try_borrow_data()?;
+            require!(
+                data.len() >= 8 && data[..8] == Root::DISCRIMINATOR,
+                ErrorCode::AccountDiscriminatorMismatch
+            );
+            LegacyRoot::deserialize(&mut &data[8..])?
+        };
+        require_keys_eq!(self.realm.key(), legacy.realm);
+        let realm = realm::get_realm_data_for_governing_token_mint(
+            &legacy.governance_program,
+            &self.realm.to_account_info(),
+            &legacy.governing_token_mint,
+        )
+        .map_err(|e| {
+            AnchorError::from(e)
+                .with_source(source!())
+                .with_account_name("realm")
+        })?;
+        let admin = realm.authority.ok_or(error!(Error::EmptyRealmAuthority))?;
+        let root = Root::from_legacy(legacy, admin);
+
+        let rent = Rent::get()?.minimum_balance(Root::SPACE);
+        let lamports = rent.saturating_sub(root_info.lamports());
+        if lamports > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.payer.to_account_info(),
+                        to: root_info.clone(),
+                    },
+                ),
+                lamports,
+            )?;
+        }
+        root_info.realloc(Root::SPACE, true)?;
+        root.try_serialize(&mut &mut root_info.try_borrow_mut_data()?[..])?;
+
+        emit!(RootMigrated {
+            root: self.root.key(),
+            admin,
+            pause_flags: root.pause_flags,
+        });
+        Ok(())
+    }
+}
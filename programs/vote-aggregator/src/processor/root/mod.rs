@@ -1,9 +1,25 @@
+pub mod accept_root_admin;
+pub mod align_voter_weight_reset;
+pub mod apply_root_config;
+pub mod cancel_root_config;
+pub mod check_plugin_config;
 pub mod configure_root;
 pub mod create_root;
+pub mod migrate_root;
+pub mod queue_root_config;
 pub mod set_voting_weight_plugin;
 pub mod update_root;
+pub mod verify_root;
 
+pub use accept_root_admin::*;
+pub use align_voter_weight_reset::*;
+pub use apply_root_config::*;
+pub use cancel_root_config::*;
+pub use check_plugin_config::*;
 pub use configure_root::*;
 pub use create_root::*;
+pub use migrate_root::*;
+pub use queue_root_config::*;
 pub use set_voting_weight_plugin::*;
 pub use update_root::*;
+pub use verify_root::*;
@@ -0,0 +1,58 @@
+use anchor_lang::{prelude::*, solana_program::system_program};
+
+use crate::events::root::RootConfigQueued;
+use crate::state::{PendingRootConfig, RootConfigChange};
+
+use super::configure_root::*;
+
+#[derive(Accounts)]
+pub struct QueueRootConfig<'info> {
+    configure_root: ConfigureRoot<'info>,
+
+    #[account(
+        init,
+        seeds = [
+            PendingRootConfig::ADDRESS_SEED,
+            &configure_root.root.key().to_bytes()
+        ],
+        bump,
+        payer = payer,
+        space = PendingRootConfig::SPACE,
+    )]
+    pending_root_config: Account<'info, PendingRootConfig>,
+
+    #[account(
+        mut,
+        owner = system_program::ID,
+    )]
+    payer: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+impl<'info> QueueRootConfig<'info> {
+    pub fn process(&mut self, change: RootConfigChange, bump: u8) -> Result<()> {
+        if change.requires_realm_authority() {
+            self.configure_root.check_realm_authority()?;
+        } else {
+            self.configure_root.check_authority()?;
+        }
+
+        // Members starting to leave right now will be able to exit before the activation
+        let activation_time = Clock::get()?.unix_timestamp
+            + i64::try_from(self.configure_root.root.max_proposal_lifetime).unwrap();
+        change.check(activation_time)?;
+        self.pending_root_config.set_inner(PendingRootConfig {
+            root: self.configure_root.root.key(),
+            rent_payer: self.payer.key(),
+            activation_time,
+            change: change.clone(),
+            bump,
+        });
+        emit!(RootConfigQueued {
+            root: self.configure_root.root.key(),
+            activation_time,
+            change,
+        });
+        Ok(())
+    }
+}
@@ -1,8 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::error::Error;
-use crate::events::root::VoterWeightPluginChanged;
-use crate::state::MaxVoterWeightRecord;
+use crate::state::{MaxVoterWeightRecord, Root};
 
 use super::configure_root::*;
 
@@ -24,16 +23,9 @@ pub struct SetVotingWeightPlugin<'info> {
 
 impl<'info> SetVotingWeightPlugin<'info> {
     pub fn process(&mut self, new_voting_weight_plugin: Pubkey) -> Result<()> {
-        self.configure_root.check_authority()?;
-        let old_voting_weight_plugin = self.configure_root.root.voting_weight_plugin;
-        self.configure_root.root.voting_weight_plugin = new_voting_weight_plugin;
-        if new_voting_weight_plugin != old_voting_weight_plugin {
-            emit!(VoterWeightPluginChanged {
-                root: self.configure_root.root.key(),
-                old_voting_weight_plugin,
-                new_voting_weight_plugin
-            });
-        }
+        self.configure_root.check_realm_authority()?;
+        self.configure_root.check_not_timelocked()?;
+        Root::change_voting_weight_plugin(&mut self.configure_root.root, new_voting_weight_plugin);
         Ok(())
     }
 }
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use spl_governance::state::{realm, realm_config::get_realm_config_data_for_realm};
+
+use crate::{
+    events::root::{RootInstalled, VotingWeightPluginVerified},
+    state::Root,
+};
+use anchor_lang::error::Error as AnchorError;
+
+/// Permissionless check that the realm is using this program as its addins.
+/// Registers the voting weight plugin the realm pointed to before the aggregator
+#[derive(Accounts)]
+pub struct VerifyRoot<'info> {
+    #[account(
+        mut,
+        has_one = realm,
+        has_one = governance_program,
+    )]
+    root: Account<'info, Root>,
+
+    /// CHECK: dynamic owner
+    #[account(
+        owner = governance_program.key(),
+    )]
+    realm: UncheckedAccount<'info>,
+
+    /// CHECK: dynamic owner
+    #[account(
+        owner = governance_program.key(),
+        seeds = [
+            b"realm-config",
+            &realm.key.to_bytes()
+        ],
+        bump,
+        seeds::program = governance_program.key(),
+    )]
+    realm_config: UncheckedAccount<'info>,
+
+    /// CHECK: program
+    #[account(executable)]
+    governance_program: UncheckedAccount<'info>,
+}
+
+impl<'info> VerifyRoot<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let realm = realm::get_realm_data_for_governing_token_mint(
+            self.governance_program.key,
+            &self.realm.to_account_info(),
+            &self.root.governing_token_mint,
+        )
+        .map_err(|e| {
+            AnchorError::from(e)
+                .with_source(source!())
+                .with_account_name("realm")
+        })?;
+
+        let realm_config = get_realm_config_data_for_realm(
+            self.governance_program.key,
+            &self.realm_config.to_account_info(),
+            self.realm.key,
+        )
+        .map_err(|e| {
+            AnchorError::from(e)
+                .with_source(source!())
+                .with_account_name("realm_config")
+        })?;
+
+        let is_community = self.root.governing_token_mint == realm.community_mint;
+        let token_config = if is_community {
+            realm_config.community_token_config
+        } else {
+            realm_config.council_token_config
+        };
+        Root::check_realm_addins(
+            is_community,
+            token_config.voter_weight_addin,
+            token_config.max_voter_weight_addin,
+        )?;
+
+        let old_verified_voting_weight_plugin = self.root.verified_voting_weight_plugin;
+        self.root.verified_voting_weight_plugin = self.root.voting_weight_plugin;
+        if self.root.verified_voting_weight_plugin != old_verified_voting_weight_plugin {
+            emit!(VotingWeightPluginVerified {
+                root: self.root.key(),
+                old_verified_voting_weight_plugin,
+                new_verified_voting_weight_plugin: self.root.verified_voting_weight_plugin,
+            });
+        }
+
+        if !self.root.installed {
+            self.root.installed = true;
+            emit!(RootInstalled {
+                root: self.root.key(),
+            });
+        }
+        Ok(())
+    }
+}
@@ -1,14 +1,130 @@
+use crate::error::Error;
 use crate::events::clan::ClanVoterWeightChanged;
 use anchor_lang::prelude::*;
+use spl_governance::state::vote_record::Vote;
 use spl_governance_addin_api::voter_weight::VoterWeightRecord as SplVoterWeightRecord;
 
-use super::{Member, Root, VoterWeightRecord, VoterWeightReset};
+use super::{
+    ClanVoteHistory, ClanWeightCheckpoints, Member, MembershipEntry, Root, TreasuryWithdrawal,
+    VoterWeightDecay, VoterWeightRecord, WeightTransform,
+};
 
 #[derive(Clone, AnchorSerialize, AnchorDeserialize, Default)]
 pub struct ClanBumps {
     pub voter_authority: u8,
     pub token_owner_record: u8,
     pub voter_weight_record: u8,
+    pub reward_vault: u8,
+    pub treasury: u8,
+}
+
+/// The moment a decaying member stops decaying.
+/// `rate_bp` is the member decay rate multiplied by its share in basis points
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, Default, PartialEq)]
+pub struct VoterWeightDecayEnd {
+    pub end_time: i64,
+    pub rate_bp: u128,
+}
+
+/// The moment the weight of a new member starts to count
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, Default, PartialEq)]
+pub struct WarmupEnd {
+    pub end_time: i64,
+    pub voter_weight: u64,
+    pub is_permanent: bool,
+}
+
+/// Linear growth of the member contribution with the membership tenure
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, Default, PartialEq)]
+pub struct LoyaltyCurve {
+    /// Reached after `period` seconds in the clan
+    pub max_bonus_bp: u16,
+    pub period: u64,
+}
+
+impl LoyaltyCurve {
+    pub fn bonus_bp(&self, tenure: i64) -> u16 {
+        let tenure = (tenure.max(0) as u64).min(self.period);
+        (self.max_bonus_bp as u128 * tenure as u128 / self.period as u128) as u16
+    }
+
+    /// The bonus is applied as a bigger share of the member weight
+    pub fn boost_share_bp(share_bp: u16, bonus_bp: u16) -> u16 {
+        (share_bp as u32 * (10000 + bonus_bp as u32) / 10000) as u16
+    }
+}
+
+/// Commission increase waiting for its notice period
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, Default)]
+pub struct PendingCommission {
+    pub commission_bp: u16,
+    pub effective_at: i64,
+}
+
+/// Withdrawals above `spending_limit` need the approval of the members
+/// holding `approval_threshold_bp` of the clan reward weight
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, Default, PartialEq)]
+pub struct TreasuryPolicy {
+    /// Lamports spent without approvals per spending period.
+    /// Token withdrawals are always approved because the mints have no common unit
+    pub spending_limit: u64,
+    /// In seconds
+    pub spending_period: u64,
+    pub approval_threshold_bp: u16,
+}
+
+impl TreasuryPolicy {
+    /// One year
+    pub const MAX_SPENDING_PERIOD: u64 = 365 * 24 * 60 * 60;
+
+    /// Nothing the new policy allows was forbidden by this one
+    pub fn is_stricter(&self, new_policy: &Option<TreasuryPolicy>) -> bool {
+        match new_policy {
+            Some(new_policy) => {
+                new_policy.spending_limit <= self.spending_limit
+                    && new_policy.spending_period >= self.spending_period
+                    && new_policy.approval_threshold_bp >= self.approval_threshold_bp
+            }
+            None => false,
+        }
+    }
+}
+
+/// Treasury policy loosening waiting for its notice period
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, Default)]
+pub struct PendingTreasuryPolicy {
+    pub policy: Option<TreasuryPolicy>,
+    pub effective_at: i64,
+}
+
+#[derive(AnchorDeserialize)]
+pub struct LegacyClanBumps {
+    pub voter_authority: u8,
+    pub token_owner_record: u8,
+    pub voter_weight_record: u8,
+}
+
+/// Layout of the clans created before the voter weight decay was introduced.
+/// Converted by migrate_clan
+#[derive(AnchorDeserialize)]
+pub struct LegacyClan {
+    pub root: Pubkey,
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+    pub voter_authority: Pubkey,
+    pub token_owner_record: Pubkey,
+    pub voter_weight_record: Pubkey,
+    pub min_voting_weight_to_join: u64,
+    pub permanent_members: u64,
+    pub temporary_members: u64,
+    pub updated_temporary_members: u64,
+    pub leaving_members: u64,
+    pub accept_temporary_members: bool,
+    pub permanent_voter_weight: u64,
+    pub next_voter_weight_reset_time: Option<i64>,
+    pub name: String,
+    pub description: String,
+    pub bumps: LegacyClanBumps,
 }
 
 #[account]
@@ -23,61 +139,341 @@ pub struct Clan {
     pub min_voting_weight_to_join: u64,
     pub permanent_members: u64,
     pub temporary_members: u64,
+    /// Temporary and decaying members refreshed since the last reset
     pub updated_temporary_members: u64,
     pub leaving_members: u64,
     pub accept_temporary_members: bool,
     pub permanent_voter_weight: u64, // not decaiying part
     pub next_voter_weight_reset_time: Option<i64>,
+    /// The reset time the members refreshed before a reschedule of the resets were
+    /// refreshed for. They stay updated until the next reset
+    pub rescheduled_voter_weight_reset_time: Option<i64>,
+    pub decaying_members: u64,
+    pub decaying_voter_weight: u64, // at voter_weight_decay_updated_at
+    pub voter_weight_decay_rate_bp: u128,
+    pub voter_weight_decay_updated_at: i64,
+    pub voter_weight_decay_ends: Vec<VoterWeightDecayEnd>, // sorted by end_time
+    /// Frozen clans can not vote, be joined or reconfigured. Members still can leave
+    pub frozen: bool,
+    /// The clan of the same owner in the root of the other realm mint.
+    /// Linked clans share their configuration
+    pub sibling: Option<Pubkey>,
+    /// Mint of the reward vault. None until the vault is created
+    pub reward_mint: Option<Pubkey>,
+    /// Sum of the member contributions the rewards are distributed by
+    pub reward_weight: u64,
+    /// Rewards per unit of the reward weight scaled by REWARD_PRECISION
+    pub reward_per_weight: u128,
+    /// Share of the claimed rewards kept for the clan owner
+    pub commission_bp: u16,
+    pub pending_commission: Option<PendingCommission>,
+    /// Commission collected from the member rewards
+    pub unclaimed_commission: u64,
+    /// The commission replaced by the last increase. Still charged on the rewards
+    /// distributed before the increase took effect
+    pub previous_commission_bp: u16,
+    /// reward_per_weight when the last commission increase took effect
+    pub commission_changed_reward_per_weight: u128,
+    /// Address of the vote history. None until it is created
+    pub vote_history: Option<Pubkey>,
+    /// Vetoes cast by cast_veto_vote. The voting delegate casts the other votes
+    /// in spl-governance directly so they are only seen once recast
+    pub veto_votes_cast: u64,
+    /// Recasts of the clan votes with its changed weight
+    pub vote_updates: u64,
+    /// Time of the last veto or recast
+    pub last_voted_at: Option<i64>,
+    /// Weight of the members in warmup left out of the clan VWR.
+    /// Decaying members are counted right away because their weight only shrinks
+    pub warming_up_voter_weight: u64,
+    pub warmup_ends: Vec<WarmupEnd>, // sorted by end_time
+    /// Bounded by the root max_loyalty_bonus_bp when configured
+    pub loyalty_curve: Option<LoyaltyCurve>,
+    /// The root transform the member updates are aggregated with.
+    /// Synced by update_clan
+    pub weight_transform: WeightTransform,
+    pub weight_transform_epoch: u64,
+    /// Members still aggregated with the previous transform.
+    /// The clan can not vote until they are updated
+    pub unmigrated_members: u64,
+    /// Address of the weight checkpoints. None until they are created
+    pub weight_checkpoints: Option<Pubkey>,
+    /// Proposal votes use the clan weight at the voting start
+    /// and are not updated with the membership changes
+    pub snapshot_voting: bool,
+    /// None if the owner spends the treasury without approvals
+    pub treasury_policy: Option<TreasuryPolicy>,
+    pub pending_treasury_policy: Option<PendingTreasuryPolicy>,
+    /// Lamports spent without approvals since treasury_period_start
+    pub treasury_spent: u64,
+    pub treasury_period_start: i64,
+    /// Index of the next proposed treasury withdrawal
+    pub treasury_withdrawals: u64,
+    /// Index of the next recall proposal
+    pub recall_proposals: u64,
+    /// Last change of the owner, the delegate or the voting delegate.
+    /// Opens the free exit window for the members who joined before it
+    pub control_changed_at: Option<i64>,
     pub name: String,
     pub description: String,
     pub bumps: ClanBumps,
 }
 
 impl Clan {
-    pub const SPACE: usize = 8 + std::mem::size_of::<Self>();
+    // More decaying or warming up members require resize_clan
+    pub const SPACE: usize = 8
+        + std::mem::size_of::<Self>()
+        + Self::RESERVED_DECAY_ENDS * std::mem::size_of::<VoterWeightDecayEnd>()
+        + Self::RESERVED_WARMUP_ENDS * std::mem::size_of::<WarmupEnd>();
     pub const VOTER_AUTHORITY_SEED: &'static [u8] = b"voter-authority";
+    pub const REWARD_VAULT_SEED: &'static [u8] = b"reward-vault";
+    pub const TREASURY_SEED: &'static [u8] = b"treasury";
+    pub const REWARD_PRECISION: u128 = 1_000_000_000_000;
+    pub const RESERVED_DECAY_ENDS: usize = 16;
+    pub const RESERVED_WARMUP_ENDS: usize = 16;
+
+    /// The legacy weight was aggregated linearly and without decay, warmup or rewards
+    pub fn from_legacy(legacy: LegacyClan, treasury_bump: u8, now: i64) -> Self {
+        Self {
+            root: legacy.root,
+            owner: legacy.owner,
+            delegate: legacy.delegate,
+            voter_authority: legacy.voter_authority,
+            token_owner_record: legacy.token_owner_record,
+            voter_weight_record: legacy.voter_weight_record,
+            min_voting_weight_to_join: legacy.min_voting_weight_to_join,
+            permanent_members: legacy.permanent_members,
+            temporary_members: legacy.temporary_members,
+            updated_temporary_members: legacy.updated_temporary_members,
+            leaving_members: legacy.leaving_members,
+            accept_temporary_members: legacy.accept_temporary_members,
+            permanent_voter_weight: legacy.permanent_voter_weight,
+            next_voter_weight_reset_time: legacy.next_voter_weight_reset_time,
+            voter_weight_decay_updated_at: now,
+            weight_transform: WeightTransform::Linear,
+            name: legacy.name,
+            description: legacy.description,
+            bumps: ClanBumps {
+                voter_authority: legacy.bumps.voter_authority,
+                token_owner_record: legacy.bumps.token_owner_record,
+                voter_weight_record: legacy.bumps.voter_weight_record,
+                reward_vault: 0,
+                treasury: treasury_bump,
+            },
+            ..Default::default()
+        }
+    }
 
     pub fn reset_voter_weight_if_needed(&mut self, root: &Root, clan_vwr: &mut VoterWeightRecord) {
-        if let Some(VoterWeightReset {
-            next_reset_time, ..
-        }) = &root.voter_weight_reset
-        {
-            if self.next_voter_weight_reset_time.is_none()
-                || self.next_voter_weight_reset_time.unwrap() < *next_reset_time
-            {
-                self.next_voter_weight_reset_time = Some(*next_reset_time);
-                clan_vwr.voter_weight = self.permanent_voter_weight;
-                clan_vwr.voter_weight_expiry = None;
-                self.updated_temporary_members = 0;
+        let next_reset_time = root.next_voter_weight_reset_time();
+        let now = Clock::get().unwrap().unix_timestamp;
+        let reset_time = self
+            .next_voter_weight_reset_time
+            .or(self.rescheduled_voter_weight_reset_time);
+        if reset_time.map_or(true, |reset_time| now < reset_time) {
+            // Rescheduling or disabling the resets only changes when the current period ends.
+            // Disabling the resets makes its end the final reset of the temporary weight
+            if self.next_voter_weight_reset_time != next_reset_time {
+                if self.rescheduled_voter_weight_reset_time.is_none() {
+                    self.rescheduled_voter_weight_reset_time = self.next_voter_weight_reset_time;
+                }
+                self.next_voter_weight_reset_time = next_reset_time;
             }
-        } else {
-            self.next_voter_weight_reset_time = None;
+            return;
+        }
+        self.next_voter_weight_reset_time = next_reset_time;
+        self.rescheduled_voter_weight_reset_time = None;
+        // Temporary members in warmup are reset together with their weight
+        self.warmup_ends.retain(|end| end.is_permanent);
+        self.warming_up_voter_weight = self.warmup_ends.iter().map(|end| end.voter_weight).sum();
+        // Decaying members are temporary and end their decay by the reset.
+        // They are waited for by is_updated until refreshed like the others
+        self.decaying_voter_weight = 0;
+        self.voter_weight_decay_rate_bp = 0;
+        self.voter_weight_decay_ends.clear();
+        clan_vwr.voter_weight = self.permanent_voter_weight - self.warming_up_voter_weight;
+        clan_vwr.voter_weight_expiry = None;
+        self.updated_temporary_members = 0;
+    }
+
+    /// The weight of an outdated temporary member was dropped by the clan reset
+    pub fn is_member_outdated(&self, member: &Member) -> bool {
+        member.voter_weight_expiry.is_some()
+            && member.next_voter_weight_reset_time != self.next_voter_weight_reset_time
+            && (self.rescheduled_voter_weight_reset_time.is_none()
+                || member.next_voter_weight_reset_time != self.rescheduled_voter_weight_reset_time)
+    }
+
+    /// Brings the decaying part of the clan voter weight to the given time
+    pub fn apply_voter_weight_decay(&mut self, clan_vwr: &mut VoterWeightRecord, time: i64) {
+        if self.voter_weight_decay_ends.is_empty() || time <= self.voter_weight_decay_updated_at {
+            // Nothing is decaying
+            return;
+        }
+        let mut decayed_bp: u128 = 0;
+        let mut last_time = self.voter_weight_decay_updated_at;
+        while let Some(end) = self.voter_weight_decay_ends.first() {
+            if end.end_time > time {
+                break;
+            }
+            decayed_bp += self.voter_weight_decay_rate_bp * (end.end_time - last_time) as u128;
+            self.voter_weight_decay_rate_bp -= end.rate_bp;
+            last_time = end.end_time;
+            self.voter_weight_decay_ends.remove(0);
+        }
+        decayed_bp += self.voter_weight_decay_rate_bp * (time - last_time) as u128;
+        let decayed = u64::try_from(decayed_bp / 10000)
+            .unwrap_or(u64::MAX)
+            .min(self.decaying_voter_weight);
+        self.decaying_voter_weight -= decayed;
+        clan_vwr.voter_weight -= decayed;
+        self.voter_weight_decay_updated_at = time;
+    }
+
+    fn add_voter_weight_decay(&mut self, decay: &VoterWeightDecay, share_bp: u16, time: i64) {
+        let end = VoterWeightDecayEnd {
+            end_time: decay.end_time,
+            rate_bp: decay.rate as u128 * share_bp as u128,
+        };
+        if end.end_time <= time || end.rate_bp == 0 {
+            return;
+        }
+        if self.voter_weight_decay_ends.is_empty() {
+            // Was not decaying before
+            self.voter_weight_decay_updated_at = time;
+        }
+        self.voter_weight_decay_rate_bp += end.rate_bp;
+        let index = self
+            .voter_weight_decay_ends
+            .partition_point(|e| e.end_time <= end.end_time);
+        self.voter_weight_decay_ends.insert(index, end);
+    }
+
+    fn remove_voter_weight_decay(&mut self, decay: &VoterWeightDecay, share_bp: u16, time: i64) {
+        if decay.end_time <= time {
+            // was already removed by apply_voter_weight_decay
+            return;
+        }
+        let end = VoterWeightDecayEnd {
+            end_time: decay.end_time,
+            rate_bp: decay.rate as u128 * share_bp as u128,
+        };
+        if let Some(index) = self.voter_weight_decay_ends.iter().position(|e| *e == end) {
+            self.voter_weight_decay_ends.remove(index);
+            self.voter_weight_decay_rate_bp -= end.rate_bp;
+        }
+    }
+
+    /// Counts the weight of the members whose warmup is over
+    pub fn apply_membership_warmup(&mut self, clan_vwr: &mut VoterWeightRecord, time: i64) {
+        while let Some(end) = self.warmup_ends.first() {
+            if end.end_time > time {
+                break;
+            }
+            self.warming_up_voter_weight -= end.voter_weight;
+            clan_vwr.voter_weight += end.voter_weight;
+            self.warmup_ends.remove(0);
+        }
+    }
+
+    /// Returns the held warmup to be released by the next member update
+    fn hold_warmup(
+        &mut self,
+        clan_vwr: &mut VoterWeightRecord,
+        end: WarmupEnd,
+        time: i64,
+    ) -> Option<WarmupEnd> {
+        if end.end_time <= time || end.voter_weight == 0 {
+            return None;
+        }
+        self.warming_up_voter_weight += end.voter_weight;
+        clan_vwr.voter_weight -= end.voter_weight;
+        let index = self
+            .warmup_ends
+            .partition_point(|e| e.end_time <= end.end_time);
+        self.warmup_ends.insert(index, end.clone());
+        Some(end)
+    }
+
+    fn release_warmup(&mut self, clan_vwr: &mut VoterWeightRecord, end: WarmupEnd, time: i64) {
+        if end.end_time <= time {
+            // was already released by apply_membership_warmup
+            return;
+        }
+        if let Some(index) = self.warmup_ends.iter().position(|e| *e == end) {
+            self.warmup_ends.remove(index);
+            self.warming_up_voter_weight -= end.voter_weight;
+            clan_vwr.voter_weight += end.voter_weight;
         }
     }
 
     pub fn update_member<'info>(
         clan: &mut Account<'info, Self>,
-        member: &Member,
+        member: &mut Member,
         old_share_bp: Option<u16>, // None means was not a member
         // None is useful if we need to change membership without updating voter weight
-        new_member_vwr: Option<&SplVoterWeightRecord>,
+        new_member_vwr: Option<(&SplVoterWeightRecord, Option<&VoterWeightDecay>)>,
         new_share_bp: Option<u16>, // None means will be not a member
         clan_vwr: &mut VoterWeightRecord,
         clock: &Clock,
     ) -> Result<()> {
+        clan.apply_voter_weight_decay(clan_vwr, clock.unix_timestamp);
+        clan.apply_membership_warmup(clan_vwr, clock.unix_timestamp);
+        let clan_key = clan.key();
+        let entry = member
+            .membership
+            .iter()
+            .find(|e| e.clan == clan_key)
+            .cloned()
+            .unwrap_or_default();
+        let held_warmup = entry.held_warmup.clone();
+        let new_loyalty_bonus_bp = clan.loyalty_curve.as_ref().map_or(0, |curve| {
+            curve.bonus_bp(clock.unix_timestamp - entry.joined_at)
+        });
+        let old_share_bp = old_share_bp
+            .map(|share_bp| LoyaltyCurve::boost_share_bp(share_bp, entry.loyalty_bonus_bp));
+        let new_share_bp = new_share_bp
+            .map(|share_bp| LoyaltyCurve::boost_share_bp(share_bp, new_loyalty_bonus_bp));
         let old_clan_voter_weight = clan_vwr.voter_weight;
         let old_clan_voter_weight_expiry = clan_vwr.voter_weight_expiry;
         let old_permament_clan_voter_weight = clan.permanent_voter_weight;
 
+        if old_share_bp.is_some() && entry.weight_transform_epoch != clan.weight_transform_epoch {
+            clan.unmigrated_members -= 1;
+        }
+
         // Remove the old state of the member from the clan
-        if let Some(old_share_bp) = old_share_bp {
+        // with the transform it was added with
+        let old_voter_weight = entry.weight_transform.apply(member.voter_weight);
+        // if temporary member was outdated
+        // then it's power was already removed on the previous clan reset
+        let is_outdated = clan.is_member_outdated(member);
+        if let Some(old_share_bp) = old_share_bp.filter(|_| member.voter_weight_decay.is_some()) {
+            if !is_outdated {
+                let decay = &entry.weight_transform.apply_decay(
+                    member.voter_weight,
+                    member.voter_weight_decay.as_ref().unwrap(),
+                );
+                let old_member_voter_weight =
+                    ((decay.voter_weight_at(old_voter_weight, clock.unix_timestamp) as u128)
+                        * (old_share_bp as u128)
+                        / 10000) as u64;
+                // The clan decay is rounded so the member part may be slightly off
+                let old_member_voter_weight =
+                    old_member_voter_weight.min(clan.decaying_voter_weight);
+                clan_vwr.voter_weight -= old_member_voter_weight;
+                clan.decaying_voter_weight -= old_member_voter_weight;
+                clan.remove_voter_weight_decay(decay, old_share_bp, clock.unix_timestamp);
+                clan.updated_temporary_members -= 1;
+            }
+            clan.decaying_members -= 1;
+        } else if let Some(old_share_bp) = old_share_bp {
             let old_member_voter_weight =
-                ((member.voter_weight as u128) * (old_share_bp as u128) / 10000) as u64;
-            let is_outdated = member.voter_weight_expiry.is_some()
-                && member.next_voter_weight_reset_time != clan.next_voter_weight_reset_time;
+                ((old_voter_weight as u128) * (old_share_bp as u128) / 10000) as u64;
             if !is_outdated {
-                // if temporary member was outdated
-                // then it's power was already removed on the previous clan reset
+                if let Some(held_warmup) = held_warmup {
+                    clan.release_warmup(clan_vwr, held_warmup, clock.unix_timestamp);
+                }
                 clan_vwr.voter_weight -= old_member_voter_weight;
             }
             if member.voter_weight_expiry.is_none() {
@@ -93,24 +489,47 @@ impl Clan {
         }
 
         // Install the new state of the member to the clan
+        let mut new_reward_weight = 0;
+        let mut new_held_warmup = None;
         if let Some(new_share_bp) = new_share_bp {
             // Not updating the member's VWR is the same as updating to the current values
-            let new_member_voter_weight = ((if let Some(new_member_vwr) = new_member_vwr {
-                new_member_vwr.voter_weight
+            let (new_member_voter_weight, new_member_voter_weight_expiry, new_decay) =
+                if let Some((new_member_vwr, new_decay)) = new_member_vwr {
+                    (
+                        new_member_vwr.voter_weight,
+                        new_member_vwr.voter_weight_expiry,
+                        new_decay,
+                    )
+                } else {
+                    (
+                        member.voter_weight,
+                        member.voter_weight_expiry,
+                        member.voter_weight_decay.as_ref(),
+                    )
+                };
+            let new_decay = new_decay.map(|decay| {
+                clan.weight_transform
+                    .apply_decay(new_member_voter_weight, decay)
+            });
+            let new_decay = new_decay.as_ref();
+            let new_member_voter_weight = clan.weight_transform.apply(new_member_voter_weight);
+            let new_member_voter_weight = ((if let Some(new_decay) = new_decay {
+                new_decay.voter_weight_at(new_member_voter_weight, clock.unix_timestamp)
             } else {
-                member.voter_weight
+                new_member_voter_weight
             } as u128)
                 * (new_share_bp as u128)
                 / 10000) as u64;
 
-            let new_member_voter_weight_expiry = if let Some(new_member_vwr) = new_member_vwr {
-                new_member_vwr.voter_weight_expiry
-            } else {
-                member.voter_weight_expiry
-            };
-
             clan_vwr.voter_weight += new_member_voter_weight;
-            if new_member_voter_weight_expiry.is_none() {
+            new_reward_weight = new_member_voter_weight;
+            if let Some(new_decay) = new_decay {
+                clan.decaying_voter_weight += new_member_voter_weight;
+                clan.add_voter_weight_decay(new_decay, new_share_bp, clock.unix_timestamp);
+                clan.decaying_members += 1;
+                // Decaying members are temporary too
+                clan.updated_temporary_members += 1;
+            } else if new_member_voter_weight_expiry.is_none() {
                 clan.permanent_voter_weight += new_member_voter_weight;
                 clan.permanent_members += 1;
             } else {
@@ -118,10 +537,52 @@ impl Clan {
                 // Counts as updated in any case
                 clan.updated_temporary_members += 1;
             }
+            if let Some(end_time) = entry.warmup_end.filter(|_| new_decay.is_none()) {
+                // Only the share added by the last join is held back
+                let warmup_voter_weight = if entry.warmup_share_bp >= entry.share_bp {
+                    new_member_voter_weight
+                } else {
+                    (new_member_voter_weight as u128 * entry.warmup_share_bp as u128
+                        / entry.share_bp as u128) as u64
+                };
+                new_held_warmup = clan.hold_warmup(
+                    clan_vwr,
+                    WarmupEnd {
+                        end_time,
+                        voter_weight: warmup_voter_weight,
+                        is_permanent: new_member_voter_weight_expiry.is_none(),
+                    },
+                    clock.unix_timestamp,
+                );
+            }
+        }
+
+        // Rewards accrued with the old contribution are kept by the member
+        if let Some(entry) = member.membership.iter_mut().find(|e| e.clan == clan_key) {
+            clan.accrue_member_rewards(entry, clock.unix_timestamp);
+            clan.reward_weight = clan.reward_weight - entry.reward_weight + new_reward_weight;
+            entry.reward_weight = new_reward_weight;
+            entry.loyalty_bonus_bp = if new_share_bp.is_some() {
+                new_loyalty_bonus_bp
+            } else {
+                0
+            };
+            entry.weight_transform = clan.weight_transform.clone();
+            entry.weight_transform_epoch = clan.weight_transform_epoch;
+            entry.held_warmup = new_held_warmup;
         }
 
+        // The decay and warmup ends may outgrow the space reserved by resize_clan
+        require_gte!(
+            clan.to_account_info().data_len(),
+            8 + clan.try_to_vec()?.len(),
+            Error::ClanResizeRequired
+        );
+
         // Update the clan's VWR permanent/temporary status
-        clan_vwr.voter_weight_expiry = if clan.permanent_voter_weight == clan_vwr.voter_weight {
+        clan_vwr.voter_weight_expiry = if clan.permanent_voter_weight
+            == clan_vwr.voter_weight + clan.warming_up_voter_weight
+        {
             None
         } else {
             Some(clock.slot as i64)
@@ -140,16 +601,199 @@ impl Clan {
         Ok(())
     }
 
+    /// Distributes the deposited rewards by the current member contributions
+    pub fn distribute_rewards(&mut self, amount: u64, time: i64) -> Result<()> {
+        require_gt!(self.reward_weight, 0, Error::NoClanRewardWeight);
+        // Rewards distributed after the commission increase are charged at the new rate
+        self.apply_pending_commission(time);
+        self.reward_per_weight +=
+            amount as u128 * Self::REWARD_PRECISION / self.reward_weight as u128;
+        Ok(())
+    }
+
+    /// Activates the commission increase once the notice period is over.
+    /// The rewards distributed so far keep the old rate
+    pub fn apply_pending_commission(&mut self, time: i64) {
+        if let Some(pending) = &self.pending_commission {
+            if pending.effective_at <= time {
+                self.previous_commission_bp = self.commission_bp;
+                self.commission_changed_reward_per_weight = self.reward_per_weight;
+                self.commission_bp = pending.commission_bp;
+                self.pending_commission = None;
+            }
+        }
+    }
+
+    /// Moves the member rewards accrued since the last checkpoint to unclaimed
+    /// and keeps the commission. Returns the commission
+    pub fn accrue_member_rewards(&mut self, entry: &mut MembershipEntry, time: i64) -> u64 {
+        self.apply_pending_commission(time);
+        let changed_at = self
+            .commission_changed_reward_per_weight
+            .max(entry.reward_per_weight_paid)
+            .min(self.reward_per_weight);
+        let before_change = entry.rewards_for(changed_at - entry.reward_per_weight_paid);
+        let after_change = entry.rewards_for(self.reward_per_weight - changed_at);
+        // A later decrease applies to the older rewards too
+        let commission = Self::commission_of(
+            before_change,
+            self.previous_commission_bp.min(self.commission_bp),
+        )
+        .saturating_add(Self::commission_of(after_change, self.commission_bp));
+        entry.unclaimed_rewards = entry
+            .unclaimed_rewards
+            .saturating_add(before_change.saturating_add(after_change) - commission);
+        entry.reward_per_weight_paid = self.reward_per_weight;
+        self.unclaimed_commission = self.unclaimed_commission.saturating_add(commission);
+        commission
+    }
+
+    fn commission_of(rewards: u64, commission_bp: u16) -> u64 {
+        (rewards as u128 * commission_bp as u128 / 10000) as u64
+    }
+
+    /// Activates the treasury policy loosening once the notice period is over
+    pub fn apply_pending_treasury_policy(&mut self, time: i64) {
+        if let Some(pending) = &self.pending_treasury_policy {
+            if pending.effective_at <= time {
+                self.treasury_policy = pending.policy.clone();
+                self.pending_treasury_policy = None;
+            }
+        }
+    }
+
+    /// Withdrawals above the spending limit of the period must be approved by the members
+    pub fn check_treasury_withdrawal(
+        &mut self,
+        withdrawal: Option<&mut TreasuryWithdrawal>,
+        mint: Option<Pubkey>,
+        amount: u64,
+        destination: Pubkey,
+        time: i64,
+    ) -> Result<()> {
+        self.apply_pending_treasury_policy(time);
+        match withdrawal {
+            Some(withdrawal) => withdrawal.execute(mint, amount, destination, time),
+            None => {
+                if let Some(policy) = &self.treasury_policy {
+                    require!(mint.is_none(), Error::TreasuryWithdrawalRequired);
+                    // Saturates for the policies set before the period was bounded
+                    let period = i64::try_from(policy.spending_period).unwrap_or(i64::MAX);
+                    if time >= self.treasury_period_start.saturating_add(period) {
+                        self.treasury_period_start = time;
+                        self.treasury_spent = 0;
+                    }
+                    let spent = self.treasury_spent.saturating_add(amount);
+                    require_gte!(
+                        policy.spending_limit,
+                        spent,
+                        Error::TreasuryWithdrawalRequired
+                    );
+                    self.treasury_spent = spent;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    pub fn record_control_change(&mut self, time: i64) -> i64 {
+        self.control_changed_at = Some(time);
+        time
+    }
+
+    /// The members who trusted the previous control can exit without the cooldown
+    /// for the max proposal lifetime after the change
+    pub fn is_free_exit(&self, root: &Root, joined_at: i64, time: i64) -> bool {
+        match self.control_changed_at {
+            Some(changed_at) => {
+                joined_at <= changed_at && time < root.max_proposal_lifetime_end(changed_at)
+            }
+            None => false,
+        }
+    }
+
+    /// Counts the votes cast or recast by the aggregator and keeps them in the clan history
+    pub fn record_vote(
+        &mut self,
+        vote_history: Option<&mut ClanVoteHistory>,
+        proposal: Pubkey,
+        vote: &Vote,
+        voter_weight: u64,
+        time: i64,
+        is_update: bool,
+    ) -> Result<()> {
+        if is_update {
+            self.vote_updates += 1;
+        } else {
+            self.veto_votes_cast += 1;
+        }
+        self.last_voted_at = Some(time);
+        match vote_history {
+            Some(vote_history) => vote_history.record(proposal, vote, voter_weight, time),
+            // Skipping the history would leave a gap in the clan record
+            None => require!(self.vote_history.is_none(), Error::ClanVoteHistoryRequired),
+        }
+        Ok(())
+    }
+
+    /// Called wherever the member changes update the clan voter weight
+    pub fn record_weight_checkpoint(
+        &self,
+        weight_checkpoints: Option<&mut ClanWeightCheckpoints>,
+        clan_vwr: &VoterWeightRecord,
+        slot: u64,
+    ) -> Result<()> {
+        match weight_checkpoints {
+            Some(weight_checkpoints) => weight_checkpoints.record(slot, clan_vwr.voter_weight),
+            // A missed change would corrupt the snapshots
+            None => require!(
+                self.weight_checkpoints.is_none(),
+                Error::ClanWeightCheckpointsRequired
+            ),
+        }
+        Ok(())
+    }
+
+    /// Makes the clan VWR valid for the current slot. The clan must be updated
+    pub fn revise_voter_weight(&mut self, clan_vwr: &mut VoterWeightRecord, clock: &Clock) {
+        self.apply_voter_weight_decay(clan_vwr, clock.unix_timestamp);
+        self.apply_membership_warmup(clan_vwr, clock.unix_timestamp);
+        if clan_vwr.voter_weight_expiry.is_some() {
+            clan_vwr.voter_weight_expiry = Some(clock.slot as i64);
+        }
+    }
+
+    /// Starts aggregating the member updates with the root transform.
+    /// Returns true if the transform was changed
+    pub fn sync_weight_transform(&mut self, root: &Root) -> bool {
+        if self.weight_transform == root.weight_transform {
+            return false;
+        }
+        self.weight_transform = root.weight_transform.clone();
+        self.weight_transform_epoch += 1;
+        // Every current member was added with an older transform
+        self.unmigrated_members =
+            self.permanent_members + self.temporary_members + self.decaying_members;
+        true
+    }
+
+    /// All the member weights are aggregated with the root transform
+    pub fn is_migrated(&self, root: &Root) -> bool {
+        self.weight_transform == root.weight_transform && self.unmigrated_members == 0
+    }
+
     pub fn is_updated(&self, root: &Root) -> bool {
         let clock = Clock::get().unwrap();
-        if root.voter_weight_reset.is_none() {
-            return true;
-        }
-        if let Some(next_voter_weight_reset_time) = self.next_voter_weight_reset_time {
-            clock.unix_timestamp < next_voter_weight_reset_time
-                && self.updated_temporary_members == self.temporary_members
+        let reset_time = self
+            .next_voter_weight_reset_time
+            .or(self.rescheduled_voter_weight_reset_time);
+        if let Some(reset_time) = reset_time {
+            // The temporary weight must be dropped by the final reset once the resets are disabled
+            clock.unix_timestamp < reset_time
+                && self.next_voter_weight_reset_time == root.next_voter_weight_reset_time()
+                && self.updated_temporary_members == self.temporary_members + self.decaying_members
         } else {
-            false
+            root.voter_weight_reset.is_none()
         }
     }
 }
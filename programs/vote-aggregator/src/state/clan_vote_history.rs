@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use spl_governance::state::vote_record::Vote;
+
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum ClanVoteKind {
+    Approve,
+    Deny,
+    Abstain,
+    Veto,
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct ClanVoteHistoryEntry {
+    pub proposal: Pubkey,
+    pub vote: ClanVoteKind,
+    /// Bit per proposal option the clan gave a weight to. Only for approve votes
+    pub approved_options: u64,
+    pub voter_weight: u64,
+    pub voted_at: i64,
+}
+
+/// Ring buffer of the last clan votes
+#[account]
+pub struct ClanVoteHistory {
+    pub clan: Pubkey,
+    /// Slot to be overwritten once the buffer is full
+    pub next_index: u32,
+    pub entries: Vec<ClanVoteHistoryEntry>,
+    pub bump: u8,
+}
+
+impl ClanVoteHistory {
+    pub const CAPACITY: usize = 32;
+    pub const SPACE: usize = 8
+        + std::mem::size_of::<Self>()
+        + Self::CAPACITY * std::mem::size_of::<ClanVoteHistoryEntry>();
+    pub const ADDRESS_SEED: &'static [u8] = b"vote-history";
+
+    pub fn record(&mut self, proposal: Pubkey, vote: &Vote, voter_weight: u64, voted_at: i64) {
+        let (vote, approved_options) = match vote {
+            Vote::Approve(choices) => (
+                ClanVoteKind::Approve,
+                choices
+                    .iter()
+                    .take(64)
+                    .enumerate()
+                    .filter(|(_, choice)| choice.weight_percentage > 0)
+                    .fold(0u64, |mask, (i, _)| mask | (1 << i)),
+            ),
+            Vote::Deny => (ClanVoteKind::Deny, 0),
+            Vote::Abstain => (ClanVoteKind::Abstain, 0),
+            Vote::Veto => (ClanVoteKind::Veto, 0),
+        };
+        let entry = ClanVoteHistoryEntry {
+            proposal,
+            vote,
+            approved_options,
+            voter_weight,
+            voted_at,
+        };
+        if self.entries.len() < Self::CAPACITY {
+            self.entries.push(entry);
+        } else {
+            self.entries[self.next_index as usize] = entry;
+        }
+        self.next_index = (self.next_index + 1) % Self::CAPACITY as u32;
+    }
+}
@@ -75,6 +75,7 @@ pub struct VoterWeightRecord {
 impl VoterWeightRecord {
     pub const SPACE: usize = 8 + std::mem::size_of::<Self>();
     pub const ADDRESS_SEED: &'static [u8] = b"voter-weight";
+    pub const SNAPSHOT_ADDRESS_SEED: &'static [u8] = b"snapshot-weight";
 
     pub fn new(
         realm: Pubkey,
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct ClanWeightCheckpoint {
+    pub slot: u64,
+    /// The clan voter weight at the end of the slot
+    pub voter_weight: u64,
+}
+
+/// Ring buffer of the last clan voter weight changes
+#[account]
+pub struct ClanWeightCheckpoints {
+    pub clan: Pubkey,
+    /// Slot to be overwritten once the buffer is full
+    pub next_index: u32,
+    pub checkpoints: Vec<ClanWeightCheckpoint>,
+    pub bump: u8,
+}
+
+impl ClanWeightCheckpoints {
+    pub const CAPACITY: usize = 64;
+    pub const SPACE: usize = 8
+        + std::mem::size_of::<Self>()
+        + Self::CAPACITY * std::mem::size_of::<ClanWeightCheckpoint>();
+    pub const ADDRESS_SEED: &'static [u8] = b"weight-checkpoints";
+
+    fn last_index(&self) -> Option<usize> {
+        if self.checkpoints.is_empty() {
+            None
+        } else if self.checkpoints.len() < Self::CAPACITY {
+            Some(self.checkpoints.len() - 1)
+        } else {
+            Some((self.next_index as usize + Self::CAPACITY - 1) % Self::CAPACITY)
+        }
+    }
+
+    pub fn record(&mut self, slot: u64, voter_weight: u64) {
+        if let Some(last) = self.last_index().map(|index| &mut self.checkpoints[index]) {
+            if last.slot == slot {
+                // Only the final weight of the slot matters
+                last.voter_weight = voter_weight;
+                return;
+            }
+            if last.voter_weight == voter_weight {
+                return;
+            }
+        }
+        let checkpoint = ClanWeightCheckpoint { slot, voter_weight };
+        if self.checkpoints.len() < Self::CAPACITY {
+            self.checkpoints.push(checkpoint);
+        } else {
+            self.checkpoints[self.next_index as usize] = checkpoint;
+        }
+        self.next_index = (self.next_index + 1) % Self::CAPACITY as u32;
+    }
+
+    /// The clan voter weight right before `slot`.
+    /// None if it was not recorded or was already overwritten
+    pub fn voter_weight_before(&self, slot: u64) -> Option<u64> {
+        self.checkpoints
+            .iter()
+            .filter(|checkpoint| checkpoint.slot < slot)
+            .max_by_key(|checkpoint| checkpoint.slot)
+            .map(|checkpoint| checkpoint.voter_weight)
+    }
+}
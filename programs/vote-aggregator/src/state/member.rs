@@ -1,11 +1,20 @@
 use std::collections::{BTreeMap, BTreeSet};
 
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
+use spl_governance::{
+    instruction::set_token_owner_record_lock, state::token_owner_record::TokenOwnerRecordV2,
+};
 use spl_governance_addin_api::voter_weight::VoterWeightRecord as SplVoterWeightRecord;
 
-use super::{Clan, MaxVoterWeightRecord, Root, VoterWeightRecord};
+use super::{
+    Clan, ClanWeightCheckpoints, LoyaltyCurve, MaxVoterWeightRecord, Root, VoterWeightRecord,
+    WarmupEnd, WeightTransform,
+};
 use crate::error::Error;
-use crate::events::{member::MemberVoterWeightChanged, root::MaxVoterWeightChanged};
+use crate::events::{
+    member::{MemberVoterWeightChanged, MemberVoterWeightDecayChanged},
+    root::MaxVoterWeightChanged,
+};
 use crate::ID;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
@@ -19,6 +28,107 @@ pub struct MembershipEntry {
     pub clan: Pubkey,
     pub share_bp: u16,
     pub exitable_at: Option<i64>,
+    /// The member contribution to the clan reward weight
+    pub reward_weight: u64,
+    /// The clan reward_per_weight at the last checkpoint
+    pub reward_per_weight_paid: u128,
+    /// Rewards after the commission. Forfeited if the membership is removed without exit_clan
+    pub unclaimed_rewards: u64,
+    /// The warmup_share_bp part of the share is not counted before this time
+    pub warmup_end: Option<i64>,
+    /// Restarted with the share added by join_clan. The rest of the share counts
+    pub warmup_share_bp: u16,
+    /// The warmup the clan holds back for the member. Released by the next update
+    pub held_warmup: Option<WarmupEnd>,
+    pub joined_at: i64,
+    /// The clan loyalty bonus at the last update of the member
+    pub loyalty_bonus_bp: u16,
+    /// The clan transform the member weight was aggregated with
+    pub weight_transform: WeightTransform,
+    pub weight_transform_epoch: u64,
+}
+
+impl MembershipEntry {
+    /// Rewards of the member contribution for the reward_per_weight growth
+    pub fn rewards_for(&self, delta: u128) -> u64 {
+        let weight = self.reward_weight as u128;
+        // Split to avoid overflowing on the scaled accumulator
+        let accrued = delta / Clan::REWARD_PRECISION * weight
+            + delta % Clan::REWARD_PRECISION * weight / Clan::REWARD_PRECISION;
+        u64::try_from(accrued).unwrap_or(u64::MAX)
+    }
+}
+
+/// Linear decay of the member voter weight registered by the member.
+/// The member voter weight is measured at `start_time` and loses `rate`
+/// every second until `end_time`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq)]
+pub struct VoterWeightDecay {
+    pub start_time: i64,
+    pub rate: u64,
+    pub end_time: i64,
+}
+
+impl VoterWeightDecay {
+    pub fn voter_weight_at(&self, voter_weight: u64, time: i64) -> u64 {
+        let elapsed = time.clamp(self.start_time, self.end_time) - self.start_time;
+        voter_weight.saturating_sub(self.rate.saturating_mul(elapsed as u64))
+    }
+
+    /// The decay must end by the next reset which drops the decaying weight
+    pub fn check(
+        &self,
+        voter_weight: u64,
+        next_reset_time: Option<i64>,
+        clock: &Clock,
+    ) -> Result<()> {
+        require_eq!(
+            self.start_time,
+            clock.unix_timestamp,
+            Error::InvalidVoterWeightDecay
+        );
+        require_gt!(self.rate, 0, Error::InvalidVoterWeightDecay);
+        require_gt!(
+            self.end_time,
+            self.start_time,
+            Error::InvalidVoterWeightDecay
+        );
+        require_gte!(
+            next_reset_time.ok_or(error!(Error::InvalidVoterWeightDecay))?,
+            self.end_time,
+            Error::InvalidVoterWeightDecay
+        );
+        require_gte!(
+            voter_weight as u128,
+            self.rate as u128 * (self.end_time - self.start_time) as u128,
+            Error::InvalidVoterWeightDecay
+        );
+        Ok(())
+    }
+}
+
+/// Membership entry of the members created before the clan rewards were introduced
+#[derive(AnchorDeserialize)]
+pub struct LegacyMembershipEntry {
+    pub clan: Pubkey,
+    pub share_bp: u16,
+    pub exitable_at: Option<i64>,
+}
+
+/// Layout of the members created before the clan rewards were introduced.
+/// Converted by migrate_member
+#[derive(AnchorDeserialize)]
+pub struct LegacyMember {
+    pub root: Pubkey,
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+    pub token_owner_record: Pubkey,
+    pub voter_weight_record: Pubkey,
+    pub voter_weight: u64,
+    pub voter_weight_expiry: Option<u64>,
+    pub next_voter_weight_reset_time: Option<i64>,
+    pub membership: Vec<LegacyMembershipEntry>,
+    pub bumps: MemberBumps,
 }
 
 #[account]
@@ -32,12 +142,17 @@ pub struct Member {
     pub voter_weight: u64,
     pub voter_weight_expiry: Option<u64>,
     pub next_voter_weight_reset_time: Option<i64>,
+    pub voter_weight_decay: Option<VoterWeightDecay>,
+    /// The member voter weight counted in the max voter weight
+    pub transformed_voter_weight: u64,
+    /// Part of the max voter weight added by the clan loyalty bonuses
+    pub loyalty_bonus_weight: u64,
     pub membership: Vec<MembershipEntry>,
     pub bumps: MemberBumps,
 }
 
 #[derive(Accounts)]
-pub struct ClanChunk<'info> {
+pub struct ClanChunkAccounts<'info> {
     #[account(mut)]
     pub clan: Account<'info, Clan>,
     #[account(
@@ -51,6 +166,41 @@ pub struct ClanChunk<'info> {
     pub vwr: Account<'info, VoterWeightRecord>,
 }
 
+/// The clan accounts passed in the remaining accounts
+pub struct ClanChunk<'info> {
+    pub clan: Account<'info, Clan>,
+    pub vwr: Account<'info, VoterWeightRecord>,
+    /// Follows the VWR only if the clan has the weight checkpoints
+    pub weight_checkpoints: Option<Account<'info, ClanWeightCheckpoints>>,
+}
+
+impl<'info> ClanChunk<'info> {
+    pub fn record_weight_checkpoint(&mut self, slot: u64) -> Result<()> {
+        self.clan
+            .record_weight_checkpoint(self.weight_checkpoints.as_deref_mut(), &self.vwr, slot)
+    }
+
+    pub fn exit(&self, program_id: &Pubkey) -> Result<()> {
+        self.clan.exit(program_id)?;
+        self.vwr.exit(program_id)?;
+        if let Some(weight_checkpoints) = self.weight_checkpoints.as_ref() {
+            weight_checkpoints.exit(program_id)?;
+        }
+        Ok(())
+    }
+}
+
+/// Accounts needed to (re)set the member TOR lock
+pub struct MemberLockAccounts<'a, 'info> {
+    pub governance_program: &'a AccountInfo<'info>,
+    pub realm: &'a AccountInfo<'info>,
+    pub realm_config: &'a AccountInfo<'info>,
+    pub member_tor: &'a AccountInfo<'info>,
+    pub lock_authority: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
 impl Member {
     pub const SPACE: usize = 8
         + std::mem::size_of::<Self>()
@@ -58,10 +208,43 @@ impl Member {
     pub const ADDRESS_SEED: &'static [u8] = b"member";
     pub const MAX_MEMBERSHIP: usize = 16;
 
+    /// The legacy weight was aggregated linearly and without the loyalty bonuses.
+    /// The reward weights are set by the next update of the member.
+    /// The join time is unknown so the loyalty and the free exits count from the migration
+    pub fn from_legacy(legacy: LegacyMember, now: i64) -> Self {
+        Self {
+            root: legacy.root,
+            owner: legacy.owner,
+            delegate: legacy.delegate,
+            token_owner_record: legacy.token_owner_record,
+            voter_weight_record: legacy.voter_weight_record,
+            voter_weight: legacy.voter_weight,
+            voter_weight_expiry: legacy.voter_weight_expiry,
+            next_voter_weight_reset_time: legacy.next_voter_weight_reset_time,
+            voter_weight_decay: None,
+            transformed_voter_weight: legacy.voter_weight,
+            loyalty_bonus_weight: 0,
+            membership: legacy
+                .membership
+                .into_iter()
+                .map(|entry| MembershipEntry {
+                    clan: entry.clan,
+                    share_bp: entry.share_bp,
+                    exitable_at: entry.exitable_at,
+                    joined_at: now,
+                    ..Default::default()
+                })
+                .collect(),
+            bumps: legacy.bumps,
+        }
+    }
+
     pub fn update_voter_weight<'info>(
         member: &mut Account<'info, Self>,
         member_vwr_key: Pubkey,
         member_vwr: &SplVoterWeightRecord,
+        voter_weight_decay: Option<VoterWeightDecay>,
+        root: &Root,
         max_vwr: &mut MaxVoterWeightRecord,
     ) -> Result<()> {
         require!(
@@ -75,11 +258,15 @@ impl Member {
         let old_voter_weight_record = member.voter_weight_record;
         let old_member_voter_weight = member.voter_weight;
         let old_max_voter_weight = max_vwr.max_voter_weight;
-        max_vwr.max_voter_weight -= old_member_voter_weight;
+        max_vwr.max_voter_weight -= member.transformed_voter_weight + member.loyalty_bonus_weight;
         member.voter_weight_record = member_vwr_key;
         member.voter_weight = member_vwr.voter_weight;
         member.voter_weight_expiry = member_vwr.voter_weight_expiry;
-        max_vwr.max_voter_weight += member_vwr.voter_weight;
+        member.transformed_voter_weight = root.weight_transform.apply(member.voter_weight);
+        member.loyalty_bonus_weight = member.current_loyalty_bonus_weight();
+        max_vwr.max_voter_weight += member.transformed_voter_weight + member.loyalty_bonus_weight;
+        let old_voter_weight_decay = member.voter_weight_decay.clone();
+        member.voter_weight_decay = voter_weight_decay;
 
         emit!(MemberVoterWeightChanged {
             member: member.key(),
@@ -94,21 +281,137 @@ impl Member {
             old_max_voter_weight,
             new_max_voter_weight: max_vwr.max_voter_weight
         });
+        // Rebasing the decay on refresh is not a change of the schedule
+        let schedule = |decay: &VoterWeightDecay| (decay.rate, decay.end_time);
+        if member.voter_weight_decay.as_ref().map(schedule)
+            != old_voter_weight_decay.as_ref().map(schedule)
+        {
+            emit!(MemberVoterWeightDecayChanged {
+                member: member.key(),
+                root: member.root.key(),
+                old_voter_weight_decay,
+                new_voter_weight_decay: member.voter_weight_decay.clone(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Keeps the max voter weight above the sum of the boosted clan weights
+    fn current_loyalty_bonus_weight(&self) -> u64 {
+        let voter_weight = self.transformed_voter_weight as u128;
+        self.membership
+            .iter()
+            .filter(|entry| entry.exitable_at.is_none())
+            .map(|entry| {
+                let boosted_share_bp =
+                    LoyaltyCurve::boost_share_bp(entry.share_bp, entry.loyalty_bonus_bp);
+                (voter_weight * boosted_share_bp as u128 / 10000
+                    - voter_weight * entry.share_bp as u128 / 10000) as u64
+            })
+            .sum()
+    }
 
+    /// The registered decay continued from the current time
+    /// to be applied to the refreshed member voter weight.
+    /// Bounded by the next reset in case it was moved earlier
+    pub fn next_voter_weight_decay(
+        &self,
+        new_member_vwr: &SplVoterWeightRecord,
+        root: &Root,
+        clock: &Clock,
+    ) -> Option<VoterWeightDecay> {
+        let next_reset_time = root.next_voter_weight_reset_time()?;
+        // Not expiring weight is not decaying anymore
+        new_member_vwr
+            .voter_weight_expiry
+            .and(self.voter_weight_decay.as_ref())
+            .filter(|decay| decay.end_time > clock.unix_timestamp)
+            .map(|decay| VoterWeightDecay {
+                start_time: clock.unix_timestamp,
+                rate: decay.rate,
+                end_time: decay.end_time.min(next_reset_time),
+            })
+    }
+
+    /// Until when the member TOR must stay locked and None if not a member anymore.
+    /// An active member is locked for member_lock_duration since the last refresh
+    pub fn lock_expiry(&self, root: &Root, now: i64) -> Option<i64> {
+        let active_expiry =
+            now.saturating_add(i64::try_from(root.member_lock_duration).unwrap_or(i64::MAX));
+        self.membership
+            .iter()
+            .map(|entry| entry.exitable_at.unwrap_or(active_expiry))
+            .max()
+    }
+
+    /// Sets the member TOR lock expiry
+    pub fn set_lock(
+        root: &Account<Root>,
+        member_tor: &TokenOwnerRecordV2,
+        accounts: MemberLockAccounts,
+        expiry: i64,
+    ) -> Result<()> {
+        let current_expiry = member_tor
+            .locks
+            .iter()
+            .find(|l| l.authority == accounts.lock_authority.key() && l.lock_id == 0)
+            .map(|l| l.expiry);
+        let now = Clock::get()?.unix_timestamp;
+        if current_expiry == Some(Some(expiry)) || expiry < now {
+            // Nothing to change or the lock would be already expired
+            return Ok(());
+        }
+
+        invoke_signed(
+            &set_token_owner_record_lock(
+                accounts.governance_program.key,
+                accounts.realm.key,
+                accounts.member_tor.key,
+                accounts.lock_authority.key,
+                accounts.payer.key,
+                0,
+                Some(expiry),
+            ),
+            &[
+                accounts.governance_program.clone(),
+                accounts.realm.clone(),
+                accounts.realm_config.clone(),
+                accounts.member_tor.clone(),
+                accounts.lock_authority.clone(),
+                accounts.payer.clone(),
+                accounts.system_program.clone(),
+            ],
+            &[&[
+                Root::LOCK_AUTHORITY_SEED,
+                &root.key().to_bytes(),
+                &[root.bumps.lock_authority],
+            ]],
+        )?;
         Ok(())
     }
 
+    /// Loads the clans the member is not leaving
     pub fn load_clan_chunks<'c: 'info, 'info>(
+        &self,
+        rest: &'c [AccountInfo<'info>],
+        f: impl Fn(&MembershipEntry) -> bool,
+    ) -> Result<Vec<(ClanChunk<'info>, MembershipEntry)>> {
+        self.load_all_clan_chunks(rest, |entry| entry.exitable_at.is_none() && f(entry))
+    }
+
+    /// Loads the clans including the ones the member is leaving
+    pub fn load_all_clan_chunks<'c: 'info, 'info>(
         &self,
         mut rest: &'c [AccountInfo<'info>],
         f: impl Fn(&MembershipEntry) -> bool,
-    ) -> Result<Vec<(ClanChunk<'info>, &MembershipEntry)>> {
+    ) -> Result<Vec<(ClanChunk<'info>, MembershipEntry)>> {
         let mut missing_clans = self
             .membership
             .iter()
             .filter_map(|entry| {
-                if entry.exitable_at.is_none() && f(entry) {
-                    Some((entry.clan, entry))
+                if f(entry) {
+                    Some((entry.clan, entry.clone()))
                 } else {
                     None
                 }
@@ -122,13 +425,29 @@ impl Member {
             }
             let mut chunk_infos;
             (chunk_infos, rest) = rest.split_at(2);
-            let chunk = ClanChunk::try_accounts(
+            let ClanChunkAccounts { clan, vwr } = ClanChunkAccounts::try_accounts(
                 &ID,
                 &mut chunk_infos,
                 &[],
-                &mut ClanChunkBumps {},
+                &mut ClanChunkAccountsBumps {},
                 &mut reallocs,
             )?;
+            let weight_checkpoints = if let Some(address) = clan.weight_checkpoints {
+                let (info, tail) = rest
+                    .split_first()
+                    .ok_or(ProgramError::NotEnoughAccountKeys)?;
+                rest = tail;
+                require_keys_eq!(*info.key, address, Error::ClanWeightCheckpointsRequired);
+                require!(info.is_writable, ErrorCode::ConstraintMut);
+                Some(Account::try_from(info)?)
+            } else {
+                None
+            };
+            let chunk = ClanChunk {
+                clan,
+                vwr,
+                weight_checkpoints,
+            };
             if let Some(entry) = missing_clans.remove(&chunk.clan.key()) {
                 result.push((chunk, entry));
             } else {
@@ -139,27 +458,30 @@ impl Member {
     }
 
     pub fn refresh_membership(
-        &self,
+        &mut self,
         root: &mut Root,
         entry: &MembershipEntry,
-        ClanChunk {
-            clan,
-            vwr: clan_vwr,
-        }: &mut ClanChunk,
+        chunk: &mut ClanChunk,
         new_member_vwr: &SplVoterWeightRecord,
+        new_voter_weight_decay: Option<&VoterWeightDecay>,
         clock: &Clock,
     ) -> Result<()> {
-        assert_eq!(entry.clan, clan.key());
+        assert_eq!(entry.clan, chunk.clan.key());
         assert!(entry.exitable_at.is_none());
-        clan.reset_voter_weight_if_needed(root, clan_vwr);
+        // Frozen clans can not vote but still count the member voter weight,
+        // so they are refreshed as well to stay consistent after the unfreeze
+        chunk
+            .clan
+            .reset_voter_weight_if_needed(root, &mut chunk.vwr);
         Clan::update_member(
-            clan,
+            &mut chunk.clan,
             self,
             Some(entry.share_bp),
-            Some(new_member_vwr),
+            Some((new_member_vwr, new_voter_weight_decay)),
             Some(entry.share_bp),
-            clan_vwr,
+            &mut chunk.vwr,
             clock,
-        )
+        )?;
+        chunk.record_weight_checkpoint(clock.slot)
     }
 }
@@ -1,11 +1,21 @@
 pub mod clan;
+pub mod clan_vote_history;
 pub mod clan_vwr;
+pub mod clan_weight_checkpoints;
 pub mod max_vwr;
 pub mod member;
+pub mod pending_root_config;
+pub mod recall_proposal;
 pub mod root;
+pub mod treasury_withdrawal;
 
 pub use clan::*;
+pub use clan_vote_history::*;
 pub use clan_vwr::*;
+pub use clan_weight_checkpoints::*;
 pub use max_vwr::*;
 pub use member::*;
+pub use pending_root_config::*;
+pub use recall_proposal::*;
 pub use root::*;
+pub use treasury_withdrawal::*;
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+
+use super::{Root, VoterWeightReset, WeightTransform};
+use crate::error::Error;
+
+/// Root settings changed together once the change is matured. None keeps the setting
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, Default)]
+pub struct RootConfigChange {
+    pub max_proposal_lifetime: Option<u64>,
+    pub voting_weight_plugin: Option<Pubkey>,
+    /// Some(None) disables the resets
+    pub voter_weight_reset: Option<Option<VoterWeightReset>>,
+    pub member_lock_duration: Option<u64>,
+    pub membership_warmup: Option<u64>,
+    pub max_loyalty_bonus_bp: Option<u16>,
+    pub weight_transform: Option<WeightTransform>,
+    pub recall_threshold_bp: Option<u16>,
+    pub voter_weight_expiry_tolerance: Option<u64>,
+    /// Some(None) disables the evictions of the stale members
+    pub stale_member_grace_period: Option<Option<u64>>,
+    pub wind_down: bool,
+}
+
+impl RootConfigChange {
+    pub const SPACE: usize = 10 // the option tags
+        + 8 // max_proposal_lifetime
+        + 32 // voting_weight_plugin
+        + 1 + 16 // voter_weight_reset
+        + 8 // member_lock_duration
+        + 8 // membership_warmup
+        + 2 // max_loyalty_bonus_bp
+        + 1 + 8 // weight_transform
+        + 2 // recall_threshold_bp
+        + 8 // voter_weight_expiry_tolerance
+        + 1 + 8 // stale_member_grace_period
+        + 1; // wind_down
+
+    pub fn is_empty(&self) -> bool {
+        self.max_proposal_lifetime.is_none()
+            && self.voting_weight_plugin.is_none()
+            && self.voter_weight_reset.is_none()
+            && self.member_lock_duration.is_none()
+            && self.membership_warmup.is_none()
+            && self.max_loyalty_bonus_bp.is_none()
+            && self.weight_transform.is_none()
+            && self.recall_threshold_bp.is_none()
+            && self.voter_weight_expiry_tolerance.is_none()
+            && self.stale_member_grace_period.is_none()
+            && !self.wind_down
+    }
+
+    /// The voting rules stay with the realm authority
+    pub fn requires_realm_authority(&self) -> bool {
+        self.voting_weight_plugin.is_some()
+            || self.weight_transform.is_some()
+            || self.recall_threshold_bp.is_some()
+            || self.wind_down
+    }
+
+    pub fn check(&self, activation_time: i64) -> Result<()> {
+        require!(!self.is_empty(), Error::EmptyRootConfigChange);
+        if let Some(Some(voter_weight_reset)) = &self.voter_weight_reset {
+            require_neq!(voter_weight_reset.step, 0, Error::InvalidResetStep);
+            // Rescheduled from the activation
+            require_gt!(
+                voter_weight_reset.next_reset_time,
+                activation_time,
+                Error::InvalidNextResetTime
+            );
+            // Check the next reset time is not overflowing
+            let _ = voter_weight_reset.next_reset_time + voter_weight_reset.step as i64;
+        }
+        if let Some(max_loyalty_bonus_bp) = self.max_loyalty_bonus_bp {
+            Root::check_max_loyalty_bonus(max_loyalty_bonus_bp)?;
+        }
+        if let Some(weight_transform) = &self.weight_transform {
+            Root::check_weight_transform(weight_transform)?;
+        }
+        if let Some(recall_threshold_bp) = self.recall_threshold_bp {
+            Root::check_recall_threshold(recall_threshold_bp)?;
+        }
+        Ok(())
+    }
+}
+
+/// Root configuration change waiting for its activation time
+#[account]
+pub struct PendingRootConfig {
+    pub root: Pubkey,
+    pub rent_payer: Pubkey,
+    pub activation_time: i64,
+    pub change: RootConfigChange,
+    pub bump: u8,
+}
+
+impl PendingRootConfig {
+    pub const SPACE: usize = 8 + 32 + 32 + 8 + RootConfigChange::SPACE + 1;
+    pub const ADDRESS_SEED: &'static [u8] = b"pending-root-config";
+}
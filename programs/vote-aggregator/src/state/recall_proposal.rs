@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+/// Replacement of the clan owner or delegate opened by the members.
+/// Executed without the current owner once the signatures reach `required_weight`
+#[account]
+pub struct RecallProposal {
+    pub clan: Pubkey,
+    pub index: u64,
+    pub proposer: Pubkey,
+    pub new_owner: Option<Pubkey>,
+    pub new_delegate: Option<Pubkey>,
+    /// Part of the clan voter weight at the opening
+    pub required_weight: u64,
+    /// Recounted by the execution with the current member weights
+    pub signed_weight: u64,
+    pub signatures: u64,
+    pub expires_at: i64,
+    pub executed: bool,
+    pub bump: u8,
+}
+
+impl RecallProposal {
+    pub const SPACE: usize = 8 + std::mem::size_of::<Self>();
+    pub const ADDRESS_SEED: &'static [u8] = b"recall";
+
+    pub fn is_supported(&self) -> bool {
+        self.signed_weight >= self.required_weight
+    }
+}
+
+/// Existence prevents the member from signing twice
+#[account]
+pub struct RecallSignature {
+    pub recall: Pubkey,
+    pub member: Pubkey,
+    pub voter_weight: u64,
+    pub bump: u8,
+}
+
+impl RecallSignature {
+    pub const SPACE: usize = 8 + std::mem::size_of::<Self>();
+    pub const ADDRESS_SEED: &'static [u8] = b"recall-signature";
+}
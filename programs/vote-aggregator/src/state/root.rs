@@ -1,4 +1,14 @@
 use anchor_lang::prelude::*;
+use spl_governance::state::realm::RealmV2;
+
+use super::VoterWeightDecay;
+use crate::error::Error;
+use crate::events::root::{
+    MaxLoyaltyBonusChanged, MaxProposalLifetimeChanged, MemberLockDurationChanged,
+    MembershipWarmupChanged, RecallThresholdChanged, StaleMemberGracePeriodChanged,
+    VoterWeightExpiryToleranceChanged, VoterWeightPluginChanged, VoterWeightResetChanged,
+    WeightTransformChanged, WindDownStarted,
+};
 
 #[derive(Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct VoterWeightReset {
@@ -6,6 +16,52 @@ pub struct VoterWeightReset {
     pub step: u64,
 }
 
+/// Applied to the member voter weights before they are aggregated by the clans
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, Default, PartialEq)]
+pub enum WeightTransform {
+    #[default]
+    Linear,
+    SquareRoot,
+    Cap {
+        max_voter_weight: u64,
+    },
+}
+
+impl WeightTransform {
+    pub fn apply(&self, voter_weight: u64) -> u64 {
+        match self {
+            Self::Linear => voter_weight,
+            Self::SquareRoot => {
+                if voter_weight < 2 {
+                    return voter_weight;
+                }
+                // Newton iterations converging from above
+                let mut root = voter_weight;
+                let mut next = voter_weight / 2 + 1;
+                while next < root {
+                    root = next;
+                    next = (root + voter_weight / root) / 2;
+                }
+                root
+            }
+            Self::Cap { max_voter_weight } => voter_weight.min(*max_voter_weight),
+        }
+    }
+
+    /// The transformed weight decays in proportion to the member weight
+    pub fn apply_decay(&self, voter_weight: u64, decay: &VoterWeightDecay) -> VoterWeightDecay {
+        let rate = if voter_weight == 0 {
+            0
+        } else {
+            (decay.rate as u128 * self.apply(voter_weight) as u128 / voter_weight as u128) as u64
+        };
+        VoterWeightDecay {
+            rate,
+            ..decay.clone()
+        }
+    }
+}
+
 #[derive(Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct RootBumps {
     pub root: u8,
@@ -13,6 +69,22 @@ pub struct RootBumps {
     pub lock_authority: u8,
 }
 
+/// Layout of the roots created before the root admin was introduced.
+/// Converted by migrate_root
+#[derive(AnchorDeserialize)]
+pub struct LegacyRoot {
+    pub governance_program: Pubkey,
+    pub realm: Pubkey,
+    pub governing_token_mint: Pubkey,
+    pub voting_weight_plugin: Pubkey,
+    pub max_proposal_lifetime: u64,
+    pub voter_weight_reset: Option<VoterWeightReset>,
+    pub clan_count: u64,
+    pub member_count: u64,
+    pub bumps: RootBumps,
+    pub paused: bool,
+}
+
 #[account]
 pub struct Root {
     pub governance_program: Pubkey,   // 8
@@ -24,7 +96,42 @@ pub struct Root {
     pub clan_count: u64,
     pub member_count: u64,
     pub bumps: RootBumps,
-    pub paused: bool,
+    pub pause_flags: u32,
+    /// How many slots an expiring member VWR may lag behind the current slot
+    pub voter_weight_expiry_tolerance: u64,
+    /// Time after a reset before a not updated temporary member can be evicted.
+    /// None disables the eviction
+    pub stale_member_grace_period: Option<u64>,
+    /// Configures the root. The realm authority can always reclaim it
+    pub admin: Pubkey,
+    pub pending_admin: Option<Pubkey>,
+    /// Can only pause the root
+    pub pause_guardian: Option<Pubkey>,
+    /// The root is being retired. Members may exit all clans at once
+    pub wind_down: bool,
+    /// How long the member TOR stays locked after joining or refreshing the lock.
+    /// Members whose lock expired can be evicted
+    pub member_lock_duration: u64,
+    /// The realm config was verified to use this program as the voter weight
+    /// and the max voter weight addins
+    pub installed: bool,
+    /// The realm config stopped pointing to this program or the voting weight plugin
+    /// differs from the verified one.
+    /// Voter weight changes are refused until the config is consistent again
+    pub plugin_drift: bool,
+    /// The voting weight plugin registered by verify_root. Changing the plugin
+    /// afterwards is a drift until the new plugin is verified
+    pub verified_voting_weight_plugin: Pubkey,
+    /// Seconds before the weight of a new clan member is counted by the clan
+    pub membership_warmup: u64,
+    /// Upper bound of the clan loyalty bonuses
+    pub max_loyalty_bonus_bp: u16,
+    /// Changes are migrated clan by clan with update_clan
+    /// and then member by member with update_voter_weight
+    pub weight_transform: WeightTransform,
+    /// Share of the clan voter weight the members need to replace
+    /// the clan owner or delegate. Zero disables the recalls
+    pub recall_threshold_bp: u16,
 }
 
 impl Root {
@@ -32,6 +139,243 @@ impl Root {
     pub const ADDRESS_SEED: &'static [u8] = b"root";
     pub const LOCK_AUTHORITY_SEED: &'static [u8] = b"lock-authority";
 
+    pub const DEFAULT_MEMBER_LOCK_DURATION: u64 = 30 * 24 * 60 * 60;
+
+    pub const PAUSE_CREATE: u32 = 1 << 0;
+    pub const PAUSE_JOIN: u32 = 1 << 1;
+    pub const PAUSE_LEAVE: u32 = 1 << 2;
+    pub const PAUSE_VOTER_WEIGHT: u32 = 1 << 3;
+    pub const PAUSE_PROPOSAL_VOTE: u32 = 1 << 4;
+    pub const PAUSE_CLAN_CONFIG: u32 = 1 << 5;
+    pub const PAUSE_ALL: u32 = u32::MAX;
+
+    /// The realm authority becomes the admin.
+    /// The realm config must be verified again with verify_root
+    pub fn from_legacy(legacy: LegacyRoot, admin: Pubkey) -> Self {
+        Self {
+            governance_program: legacy.governance_program,
+            realm: legacy.realm,
+            governing_token_mint: legacy.governing_token_mint,
+            voting_weight_plugin: legacy.voting_weight_plugin,
+            max_proposal_lifetime: legacy.max_proposal_lifetime,
+            voter_weight_reset: legacy.voter_weight_reset,
+            clan_count: legacy.clan_count,
+            member_count: legacy.member_count,
+            bumps: legacy.bumps,
+            pause_flags: if legacy.paused { Self::PAUSE_ALL } else { 0 },
+            voter_weight_expiry_tolerance: 0,
+            stale_member_grace_period: None,
+            admin,
+            pending_admin: None,
+            pause_guardian: None,
+            wind_down: false,
+            member_lock_duration: Self::DEFAULT_MEMBER_LOCK_DURATION,
+            installed: false,
+            plugin_drift: false,
+            verified_voting_weight_plugin: Pubkey::default(),
+            membership_warmup: 0,
+            max_loyalty_bonus_bp: 0,
+            weight_transform: WeightTransform::Linear,
+            recall_threshold_bp: 0,
+        }
+    }
+
+    /// The next reset with the given step. Keeps the current schedule
+    /// when no new next reset time is given
+    pub fn new_voter_weight_reset(
+        &self,
+        new_step: u64,
+        new_next_reset_time: Option<i64>,
+        current_time: i64,
+    ) -> Result<VoterWeightReset> {
+        let next_reset_time = if let Some(new_next_reset_time) = new_next_reset_time {
+            // Rescheduling. Clans adopt it on the next touch and keep the current period weight
+            require_gt!(
+                new_next_reset_time,
+                current_time,
+                Error::InvalidNextResetTime
+            );
+            new_next_reset_time
+        } else if let Some(VoterWeightReset {
+            next_reset_time, ..
+        }) = &self.voter_weight_reset
+        {
+            *next_reset_time
+        } else {
+            require_neq!(new_step, 0, Error::InvalidResetStep);
+            current_time + new_step as i64
+        };
+        // Check the next reset time is not overflowing
+        let _ = next_reset_time + new_step as i64;
+        Ok(VoterWeightReset {
+            next_reset_time,
+            step: new_step,
+        })
+    }
+
+    pub fn check_max_loyalty_bonus(max_loyalty_bonus_bp: u16) -> Result<()> {
+        require_gte!(10000, max_loyalty_bonus_bp, Error::InvalidLoyaltyCurve);
+        Ok(())
+    }
+
+    pub fn check_weight_transform(weight_transform: &WeightTransform) -> Result<()> {
+        if let WeightTransform::Cap { max_voter_weight } = weight_transform {
+            require_gt!(*max_voter_weight, 0, Error::InvalidWeightTransform);
+        }
+        Ok(())
+    }
+
+    /// Must be a majority of the clan voter weight
+    pub fn check_recall_threshold(recall_threshold_bp: u16) -> Result<()> {
+        require!(
+            recall_threshold_bp == 0
+                || (recall_threshold_bp > 5000 && recall_threshold_bp <= 10000),
+            Error::InvalidRecallThreshold
+        );
+        Ok(())
+    }
+
+    pub fn change_max_proposal_lifetime(root: &mut Account<Self>, new_max_proposal_lifetime: u64) {
+        let old_max_proposal_lifetime = root.max_proposal_lifetime;
+        root.max_proposal_lifetime = new_max_proposal_lifetime;
+        if new_max_proposal_lifetime != old_max_proposal_lifetime {
+            emit!(MaxProposalLifetimeChanged {
+                root: root.key(),
+                old_max_proposal_lifetime,
+                new_max_proposal_lifetime
+            });
+        }
+    }
+
+    pub fn change_voting_weight_plugin(root: &mut Account<Self>, new_voting_weight_plugin: Pubkey) {
+        let old_voting_weight_plugin = root.voting_weight_plugin;
+        root.voting_weight_plugin = new_voting_weight_plugin;
+        if new_voting_weight_plugin != old_voting_weight_plugin {
+            emit!(VoterWeightPluginChanged {
+                root: root.key(),
+                old_voting_weight_plugin,
+                new_voting_weight_plugin
+            });
+        }
+    }
+
+    pub fn change_voter_weight_reset(
+        root: &mut Account<Self>,
+        new_voter_weight_reset: Option<VoterWeightReset>,
+    ) {
+        let old_voter_weight_reset = root.voter_weight_reset.clone();
+        root.voter_weight_reset = new_voter_weight_reset;
+        emit!(VoterWeightResetChanged {
+            root: root.key(),
+            old_voter_weight_reset,
+            new_voter_weight_reset: root.voter_weight_reset.clone()
+        });
+    }
+
+    pub fn change_member_lock_duration(root: &mut Account<Self>, new_member_lock_duration: u64) {
+        let old_member_lock_duration = root.member_lock_duration;
+        root.member_lock_duration = new_member_lock_duration;
+        if new_member_lock_duration != old_member_lock_duration {
+            emit!(MemberLockDurationChanged {
+                root: root.key(),
+                old_member_lock_duration,
+                new_member_lock_duration
+            });
+        }
+    }
+
+    /// Only affects the members joining after the change
+    pub fn change_membership_warmup(root: &mut Account<Self>, new_membership_warmup: u64) {
+        let old_membership_warmup = root.membership_warmup;
+        root.membership_warmup = new_membership_warmup;
+        if new_membership_warmup != old_membership_warmup {
+            emit!(MembershipWarmupChanged {
+                root: root.key(),
+                old_membership_warmup,
+                new_membership_warmup
+            });
+        }
+    }
+
+    /// The clan curves above the new maximum stay until they are reconfigured
+    pub fn change_max_loyalty_bonus(root: &mut Account<Self>, new_max_loyalty_bonus_bp: u16) {
+        let old_max_loyalty_bonus_bp = root.max_loyalty_bonus_bp;
+        root.max_loyalty_bonus_bp = new_max_loyalty_bonus_bp;
+        if new_max_loyalty_bonus_bp != old_max_loyalty_bonus_bp {
+            emit!(MaxLoyaltyBonusChanged {
+                root: root.key(),
+                old_max_loyalty_bonus_bp,
+                new_max_loyalty_bonus_bp
+            });
+        }
+    }
+
+    pub fn change_weight_transform(
+        root: &mut Account<Self>,
+        new_weight_transform: WeightTransform,
+    ) {
+        let old_weight_transform = root.weight_transform.clone();
+        root.weight_transform = new_weight_transform.clone();
+        if new_weight_transform != old_weight_transform {
+            emit!(WeightTransformChanged {
+                root: root.key(),
+                old_weight_transform,
+                new_weight_transform,
+            });
+        }
+    }
+
+    /// The open recalls keep the threshold they were opened with
+    pub fn change_recall_threshold(root: &mut Account<Self>, new_recall_threshold_bp: u16) {
+        let old_recall_threshold_bp = root.recall_threshold_bp;
+        root.recall_threshold_bp = new_recall_threshold_bp;
+        if new_recall_threshold_bp != old_recall_threshold_bp {
+            emit!(RecallThresholdChanged {
+                root: root.key(),
+                old_recall_threshold_bp,
+                new_recall_threshold_bp
+            });
+        }
+    }
+
+    pub fn change_voter_weight_expiry_tolerance(
+        root: &mut Account<Self>,
+        new_voter_weight_expiry_tolerance: u64,
+    ) {
+        let old_voter_weight_expiry_tolerance = root.voter_weight_expiry_tolerance;
+        root.voter_weight_expiry_tolerance = new_voter_weight_expiry_tolerance;
+        if new_voter_weight_expiry_tolerance != old_voter_weight_expiry_tolerance {
+            emit!(VoterWeightExpiryToleranceChanged {
+                root: root.key(),
+                old_voter_weight_expiry_tolerance,
+                new_voter_weight_expiry_tolerance
+            });
+        }
+    }
+
+    pub fn change_stale_member_grace_period(
+        root: &mut Account<Self>,
+        new_stale_member_grace_period: Option<u64>,
+    ) {
+        let old_stale_member_grace_period = root.stale_member_grace_period;
+        root.stale_member_grace_period = new_stale_member_grace_period;
+        if new_stale_member_grace_period != old_stale_member_grace_period {
+            emit!(StaleMemberGracePeriodChanged {
+                root: root.key(),
+                old_stale_member_grace_period,
+                new_stale_member_grace_period
+            });
+        }
+    }
+
+    /// Irreversibly retires the root
+    pub fn start_wind_down(root: &mut Account<Self>) {
+        if !root.wind_down {
+            root.wind_down = true;
+            emit!(WindDownStarted { root: root.key() });
+        }
+    }
+
     pub fn update_next_voter_weight_reset_time(&mut self, clock: &Clock) {
         self.voter_weight_reset = if let Some(r) = self.voter_weight_reset.take() {
             if clock.unix_timestamp >= r.next_reset_time {
@@ -53,7 +397,120 @@ impl Root {
         };
     }
 
+    pub fn is_paused(&self, flags: u32) -> bool {
+        self.pause_flags & flags != 0
+    }
+
+    pub fn check_not_paused(&self, flags: u32) -> Result<()> {
+        require!(!self.is_paused(flags), Error::Paused);
+        Ok(())
+    }
+
+    /// None if the new members are counted right away
+    pub fn membership_warmup_end(&self, time: i64) -> Option<i64> {
+        if self.membership_warmup == 0 {
+            None
+        } else {
+            Some(time + i64::try_from(self.membership_warmup).unwrap())
+        }
+    }
+
+    /// When a proposal created at `time` can no longer be voted on.
+    /// Saturates instead of overflowing for an unbounded lifetime
+    pub fn max_proposal_lifetime_end(&self, time: i64) -> i64 {
+        time.saturating_add(i64::try_from(self.max_proposal_lifetime).unwrap_or(i64::MAX))
+    }
+
+    /// Checks the realm side addins are pointing to this program
+    pub fn check_realm_addins(
+        is_community: bool,
+        voter_weight_addin: Option<Pubkey>,
+        max_voter_weight_addin: Option<Pubkey>,
+    ) -> Result<()> {
+        if is_community {
+            require_keys_eq!(
+                voter_weight_addin.ok_or(error!(Error::EmptyCommunityVoterWeightAddin))?,
+                crate::ID,
+                Error::WrongCommunityVoterWeightAddin
+            );
+            require_keys_eq!(
+                max_voter_weight_addin.ok_or(error!(Error::EmptyMaxCommunityVoterWeightAddin))?,
+                crate::ID,
+                Error::WrongMaxCommunityVoterWeightAddin
+            );
+        } else {
+            require_keys_eq!(
+                voter_weight_addin.ok_or(error!(Error::MustUseCouncilVoterWeightAddin))?,
+                crate::ID,
+                Error::WrongCouncilVoterWeightAddin
+            );
+            require_keys_eq!(
+                max_voter_weight_addin.ok_or(error!(Error::MustUseCouncilMaxVoteWeightAddin))?,
+                crate::ID,
+                Error::WrongCouncilMaxVoteWeightAddin
+            );
+        }
+        Ok(())
+    }
+
+    /// The governing mint of the proposals the root can vote on.
+    /// Veto votes are cast on the proposals of the other realm mint
+    pub fn proposal_mint_for_vote(&self, realm: &RealmV2, is_veto: bool) -> Result<Pubkey> {
+        if !is_veto {
+            return Ok(self.governing_token_mint);
+        }
+        if self.governing_token_mint == realm.community_mint {
+            realm
+                .config
+                .council_mint
+                .ok_or(error!(Error::CouncilMintRequired))
+        } else {
+            Ok(realm.community_mint)
+        }
+    }
+
+    pub fn check_no_plugin_drift(&self) -> Result<()> {
+        require!(!self.plugin_drift, Error::PluginConfigDrifted);
+        Ok(())
+    }
+
+    pub fn check_not_winding_down(&self) -> Result<()> {
+        require!(!self.wind_down, Error::WindingDown);
+        Ok(())
+    }
+
+    /// Clans and members are only created once the realm uses this program
+    pub fn check_installed(&self) -> Result<()> {
+        require!(self.installed, Error::RootNotInstalled);
+        Ok(())
+    }
+
+    /// Changes which may trap the members must be queued for max_proposal_lifetime
+    pub fn config_change_requires_timelock(&self) -> bool {
+        self.member_count > 0
+    }
+
     pub fn next_voter_weight_reset_time(&self) -> Option<i64> {
         self.voter_weight_reset.as_ref().map(|r| r.next_reset_time)
     }
+
+    pub fn check_voter_weight_expiry(
+        &self,
+        voter_weight_expiry: Option<u64>,
+        clock: &Clock,
+    ) -> Result<()> {
+        if let Some(voter_weight_expiry) = voter_weight_expiry {
+            // Without resets the temporary weight would stay in the clans forever
+            require!(
+                self.voter_weight_reset.is_some(),
+                Error::VoterWeightExpiryIsNotImplemented
+            );
+            require_gte!(
+                voter_weight_expiry.saturating_add(self.voter_weight_expiry_tolerance),
+                clock.slot,
+                Error::VoterWeightExpired
+            );
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::error::Error;
+
+/// Withdrawal from the clan treasury above the spending limit
+/// waiting for the member approvals
+#[account]
+pub struct TreasuryWithdrawal {
+    pub clan: Pubkey,
+    pub index: u64,
+    /// None for SOL
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+    pub destination: Pubkey,
+    /// Part of the clan reward weight to be reached by the approvals
+    pub required_weight: u64,
+    pub approved_weight: u64,
+    pub approvals: u64,
+    pub expires_at: i64,
+    pub executed: bool,
+    pub bump: u8,
+}
+
+impl TreasuryWithdrawal {
+    pub const SPACE: usize = 8 + std::mem::size_of::<Self>();
+    pub const ADDRESS_SEED: &'static [u8] = b"treasury-withdrawal";
+
+    pub fn is_approved(&self) -> bool {
+        self.approved_weight >= self.required_weight
+    }
+
+    /// Marks the withdrawal as executed if it allows this transfer
+    pub fn execute(
+        &mut self,
+        mint: Option<Pubkey>,
+        amount: u64,
+        destination: Pubkey,
+        time: i64,
+    ) -> Result<()> {
+        require!(!self.executed, Error::TreasuryWithdrawalExecuted);
+        require_gt!(self.expires_at, time, Error::TreasuryWithdrawalExpired);
+        require!(self.is_approved(), Error::TreasuryWithdrawalNotApproved);
+        require!(
+            self.mint == mint && self.amount == amount && self.destination == destination,
+            Error::TreasuryWithdrawalMismatch
+        );
+        self.executed = true;
+        Ok(())
+    }
+}
+
+/// Existence prevents the member from approving twice
+#[account]
+pub struct TreasuryWithdrawalApproval {
+    pub withdrawal: Pubkey,
+    pub member: Pubkey,
+    pub voter_weight: u64,
+    pub bump: u8,
+}
+
+impl TreasuryWithdrawalApproval {
+    pub const SPACE: usize = 8 + std::mem::size_of::<Self>();
+    pub const ADDRESS_SEED: &'static [u8] = b"withdrawal-approval";
+}